@@ -23,12 +23,13 @@ extern crate hyper_openssl;
 extern crate log;
 extern crate pbr;
 extern crate protobuf;
-extern crate rand;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
 extern crate serde_json;
 extern crate tee;
+#[cfg(test)]
+extern crate tempdir;
 extern crate url;
 
 pub mod error;
@@ -40,19 +41,20 @@ use std::path::{Path, PathBuf};
 use std::string::ToString;
 
 use broadcast::BroadcastWriter;
-use hab_core::package::{Identifiable, PackageArchive};
+use hab_core::crypto::hash;
+use hab_core::package::{Identifiable, PackageArchive, VersionReq};
 use hab_http::ApiClient;
 use hyper::client::{Body, IntoUrl, Response, RequestBuilder};
 use hyper::status::StatusCode;
-use hyper::header::{Authorization, Bearer};
+use hyper::header::{Authorization, Bearer, ByteRangeSpec, ContentLength, Range};
 use hyper::Url;
 use protobuf::core::ProtobufEnum;
 use protocol::{originsrv, net};
-use rand::{Rng, thread_rng};
 use tee::TeeReader;
 
 header! { (XFileName, "X-Filename") => [String] }
 header! { (ETag, "ETag") => [String] }
+header! { (IfRange, "If-Range") => [String] }
 
 #[derive(Clone, Deserialize)]
 #[serde(rename = "error")]
@@ -260,6 +262,45 @@ impl Client {
         }
     }
 
+    /// Download the signed revocation list of an origin. Returns `None` if the origin has not
+    /// revoked any keys.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Depot is not available
+    pub fn fetch_origin_revocations(&self, origin: &str) -> Result<Option<String>> {
+        let mut res = try!(self.inner
+                               .get(&format!("origins/{}/revocations", origin))
+                               .send());
+        match res.status {
+            StatusCode::Ok => {
+                let mut content = String::new();
+                try!(res.read_to_string(&mut content));
+                Ok(Some(content))
+            }
+            StatusCode::NotFound => Ok(None),
+            _ => Err(err_from_response(res)),
+        }
+    }
+
+    /// Upload the signed revocation list of an origin, replacing the current one.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Depot is not available
+    /// * The list is not signed by a key of the origin, or drops a revoked key
+    pub fn put_origin_revocations(&self, origin: &str, content: &str, token: &str) -> Result<()> {
+        let path = format!("origins/{}/revocations", origin);
+        let result = self.add_authz(self.inner.post(&path), token)
+            .body(content)
+            .send();
+        match result {
+            Ok(Response { status: StatusCode::Created, .. }) => Ok(()),
+            Ok(response) => Err(err_from_response(response)),
+            Err(e) => Err(Error::from(e)),
+        }
+    }
+
     /// Download a secret key from a remote Depot to the given filepath.
     ///
     /// # Failures
@@ -379,6 +420,34 @@ impl Client {
         Ok(package)
     }
 
+    /// Returns a package struct for the latest release of a package whose version satisfies
+    /// `req`. Only the origin and name of `ident` are considered.
+    ///
+    /// # Failures
+    ///
+    /// * No release of the package satisfies `req`
+    /// * Remote Depot is not available
+    pub fn show_package_matching<I: Identifiable>(&self,
+                                                  ident: &I,
+                                                  req: &VersionReq)
+                                                  -> Result<originsrv::OriginPackage> {
+        let path = format!("pkgs/{}/{}/latest", ident.origin(), ident.name());
+        let req = req.to_string();
+        let custom = |url: &mut Url| { url.query_pairs_mut().append_pair("version_req", &req); };
+        let mut res = try!(self.inner.get_with_custom_url(&path, custom).send());
+
+        if res.status != StatusCode::Ok {
+            return Err(err_from_response(res));
+        }
+
+        let mut encoded = String::new();
+        try!(res.read_to_string(&mut encoded));
+        debug!("Body: {:?}", encoded);
+        let package: originsrv::OriginPackage = try!(serde_json::from_str::<Package>(&encoded))
+            .into();
+        Ok(package)
+    }
+
     /// Upload a package to a remote Depot.
     ///
     /// # Failures
@@ -516,49 +585,114 @@ impl Client {
         }
     }
 
+    /// Downloads `path` into `dst_path`, under the name the Depot gives in `X-Filename`.
+    ///
+    /// The download is written to a `.part` file named after `path` and only moved into place
+    /// once it's complete and matches the checksum the Depot sent as its `ETag`. A `.part` file
+    /// left behind by an earlier, interrupted download is resumed with a range request, sent with
+    /// an `If-Range` of the `ETag` it was started with so that the Depot sends the whole file
+    /// again if it has changed since. A download which doesn't match its checksum, for instance
+    /// because another process was writing the same `.part` file, is thrown away; the next
+    /// attempt starts over.
     fn download<D>(&self, path: &str, dst_path: &Path, progress: Option<D>) -> Result<PathBuf>
         where D: DisplayProgress + Sized
     {
-        let mut res = try!(self.inner.get(path).send());
-        debug!("Response: {:?}", res);
+        try!(fs::create_dir_all(&dst_path));
+        let tmp_file_path = dst_path.join(format!("{}.part", path.replace("/", "_")));
+        let etag_file_path = tmp_file_path.with_extension("part.etag");
+        let resume = match (fs::metadata(&tmp_file_path), read_etag(&etag_file_path)) {
+            (Ok(metadata), Some(etag)) if metadata.len() > 0 => Some((metadata.len(), etag)),
+            _ => None,
+        };
 
-        if res.status != hyper::status::StatusCode::Ok {
-            return Err(err_from_response(res));
+        let mut req = self.inner.get(path);
+        if let Some((offset, ref etag)) = resume {
+            debug!("Resuming {} from byte {}", &tmp_file_path.display(), offset);
+            req = req.header(Range::Bytes(vec![ByteRangeSpec::AllFrom(offset)]))
+                .header(IfRange(etag.clone()));
         }
-        try!(fs::create_dir_all(&dst_path));
+        let mut res = try!(req.send());
+        debug!("Response: {:?}", res);
+
+        let mut f = match res.status {
+            StatusCode::PartialContent if resume.is_some() => {
+                try!(fs::OpenOptions::new().append(true).open(&tmp_file_path))
+            }
+            StatusCode::Ok => try!(File::create(&tmp_file_path)),
+            StatusCode::RangeNotSatisfiable if resume.is_some() => {
+                // The partial download doesn't match what the Depot has, so start over.
+                try!(discard_partial(&tmp_file_path, &etag_file_path));
+                return self.download(path, dst_path, progress);
+            }
+            _ => return Err(err_from_response(res)),
+        };
 
         let file_name = match res.headers.get::<XFileName>() {
             Some(filename) => format!("{}", filename),
             None => return Err(Error::NoXFilename),
         };
-        let tmp_file_path = dst_path.join(format!("{}.tmp-{}",
-                                                  file_name,
-                                                  thread_rng()
-                                                      .gen_ascii_chars()
-                                                      .take(8)
-                                                      .collect::<String>()));
+        let etag = res.headers.get::<ETag>().map(|etag| etag.0.clone());
+        match etag {
+            Some(ref etag) => try!(write_etag(&etag_file_path, etag)),
+            None => try!(discard_file(&etag_file_path)),
+        }
         let dst_file_path = dst_path.join(file_name);
         debug!("Writing to {}", &tmp_file_path.display());
-        let mut f = try!(File::create(&tmp_file_path));
         match progress {
             Some(mut progress) => {
-                let size: u64 = res.headers
-                    .get::<hyper::header::ContentLength>()
-                    .map_or(0, |v| **v);
+                let size: u64 = res.headers.get::<ContentLength>().map_or(0, |v| **v);
                 progress.size(size);
                 let mut writer = BroadcastWriter::new(&mut f, progress);
                 try!(io::copy(&mut res, &mut writer))
             }
             None => try!(io::copy(&mut res, &mut f)),
         };
+        if let Some(etag) = etag {
+            if try!(hash::hash_file(&tmp_file_path)) != etag {
+                try!(discard_partial(&tmp_file_path, &etag_file_path));
+                return Err(Error::DownloadFailed(format!("{} doesn't match its checksum {}",
+                                                         path,
+                                                         etag)));
+            }
+        }
         debug!("Moving {} to {}",
                &tmp_file_path.display(),
                &dst_file_path.display());
         try!(fs::rename(&tmp_file_path, &dst_file_path));
+        try!(discard_file(&etag_file_path));
         Ok(dst_file_path)
     }
 }
 
+/// Returns the `ETag` a partial download was started with, if it was recorded.
+fn read_etag(path: &Path) -> Option<String> {
+    let mut etag = String::new();
+    match File::open(path).and_then(|mut f| f.read_to_string(&mut etag)) {
+        Ok(_) if !etag.is_empty() => Some(etag),
+        _ => None,
+    }
+}
+
+fn write_etag(path: &Path, etag: &str) -> Result<()> {
+    let mut f = try!(File::create(path));
+    try!(f.write_all(etag.as_bytes()));
+    Ok(())
+}
+
+/// Removes a partial download along with the `ETag` it was started with.
+fn discard_partial(tmp_file_path: &Path, etag_file_path: &Path) -> Result<()> {
+    try!(discard_file(tmp_file_path));
+    discard_file(etag_file_path)
+}
+
+fn discard_file(path: &Path) -> Result<()> {
+    match fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(Error::IO(e)),
+    }
+}
+
 fn err_from_response(mut response: hyper::client::Response) -> Error {
     let mut buff = String::new();
     match response.read_to_string(&mut buff) {
@@ -574,3 +708,219 @@ fn err_from_response(mut response: hyper::client::Response) -> Error {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::fs::{self, File};
+    use std::io::{self, Read, Write};
+    use std::path::{Path, PathBuf};
+    use std::sync::{Arc, Mutex};
+
+    use std::str::FromStr;
+
+    use hab_core::crypto::hash;
+    use hab_core::package;
+    use hyper::header::{ByteRangeSpec, Range};
+    use hyper::server::{Listening, Request, Response, Server};
+    use hyper::status::StatusCode;
+    use hyper::uri::RequestUri;
+    use tempdir::TempDir;
+
+    use super::*;
+
+    const PATH: &'static str = "pkgs/core/foo/1.0.0/20170101000000/download";
+    const FILE_NAME: &'static str = "core-foo-1.0.0-20170101000000-x86_64-linux.hart";
+
+    struct NoProgress;
+
+    impl DisplayProgress for NoProgress {
+        fn size(&mut self, _: u64) {}
+        fn finish(&mut self) {}
+    }
+
+    impl Write for NoProgress {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn artifact() -> Vec<u8> {
+        (0..4096u32).map(|n| (n % 251) as u8).collect()
+    }
+
+    /// Serves `artifact()` the way the Depot does, answering a resumed download with the rest of
+    /// it if `ranges` is set and with a 416 if not. Returns the server, a client for it keeping
+    /// its certificates under `fs_root` and the offsets the requests asked to start from.
+    fn serve(ranges: bool, fs_root: &Path) -> (Listening, Client, Arc<Mutex<Vec<Option<u64>>>>) {
+        let starts = Arc::new(Mutex::new(Vec::new()));
+        let seen = starts.clone();
+        let listening = Server::http("127.0.0.1:0")
+            .unwrap()
+            .handle(move |req: Request, mut res: Response| {
+                let body = artifact();
+                let etag = hash::hash_bytes(&body).unwrap();
+                let start = match req.headers.get::<Range>() {
+                    Some(&Range::Bytes(ref specs)) => {
+                        match specs[0] {
+                            ByteRangeSpec::AllFrom(start) => Some(start),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                };
+                let stale = req.headers.get::<IfRange>().map_or(false, |tag| tag.0 != etag);
+                seen.lock().unwrap().push(start);
+                res.headers_mut().set(XFileName(FILE_NAME.to_string()));
+                res.headers_mut().set(ETag(etag));
+                match start {
+                    Some(_) if !ranges => {
+                        *res.status_mut() = StatusCode::RangeNotSatisfiable;
+                        res.send(b"").unwrap();
+                    }
+                    Some(start) if !stale => {
+                        *res.status_mut() = StatusCode::PartialContent;
+                        res.send(&body[start as usize..]).unwrap();
+                    }
+                    _ => res.send(&body).unwrap(),
+                }
+            })
+            .unwrap();
+        let url = format!("http://{}", listening.socket);
+        let client = Client::new(url.as_str(), "hab", "0.0.0", Some(fs_root)).unwrap();
+        (listening, client, starts)
+    }
+
+    /// Leaves `bytes` behind in `dir` as an interrupted download started with `etag`.
+    fn partial(dir: &Path, bytes: &[u8], etag: &str) -> PathBuf {
+        let path = dir.join(format!("{}.part", PATH.replace("/", "_")));
+        File::create(&path).unwrap().write_all(bytes).unwrap();
+        File::create(path.with_extension("part.etag"))
+            .unwrap()
+            .write_all(etag.as_bytes())
+            .unwrap();
+        path
+    }
+
+    fn downloaded(path: &Path) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        File::open(path).unwrap().read_to_end(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn download_whole_artifact() {
+        let root = TempDir::new("download").unwrap();
+        let dir = TempDir::new("download").unwrap();
+        let (mut listening, client, starts) = serve(true, root.path());
+
+        let path = client.download(PATH, dir.path(), None::<NoProgress>).unwrap();
+        listening.close().unwrap();
+
+        assert_eq!(path, dir.path().join(FILE_NAME));
+        assert_eq!(downloaded(&path), artifact());
+        assert_eq!(*starts.lock().unwrap(), vec![None]);
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn download_resumes_partial_artifact() {
+        let root = TempDir::new("download").unwrap();
+        let dir = TempDir::new("download").unwrap();
+        let artifact = artifact();
+        partial(dir.path(), &artifact[..1000], &hash::hash_bytes(&artifact).unwrap());
+        let (mut listening, client, starts) = serve(true, root.path());
+
+        let path = client.download(PATH, dir.path(), None::<NoProgress>).unwrap();
+        listening.close().unwrap();
+
+        assert_eq!(downloaded(&path), artifact);
+        assert_eq!(*starts.lock().unwrap(), vec![Some(1000)]);
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn download_starts_over_for_changed_artifact() {
+        let root = TempDir::new("download").unwrap();
+        let dir = TempDir::new("download").unwrap();
+        partial(dir.path(), &[7; 1000], "another-checksum");
+        let (mut listening, client, starts) = serve(true, root.path());
+
+        let path = client.download(PATH, dir.path(), None::<NoProgress>).unwrap();
+        listening.close().unwrap();
+
+        assert_eq!(downloaded(&path), artifact());
+        assert_eq!(*starts.lock().unwrap(), vec![Some(1000)]);
+    }
+
+    #[test]
+    fn download_starts_over_when_range_not_satisfiable() {
+        let root = TempDir::new("download").unwrap();
+        let dir = TempDir::new("download").unwrap();
+        let artifact = artifact();
+        partial(dir.path(), &artifact[..1000], &hash::hash_bytes(&artifact).unwrap());
+        let (mut listening, client, starts) = serve(false, root.path());
+
+        let path = client.download(PATH, dir.path(), None::<NoProgress>).unwrap();
+        listening.close().unwrap();
+
+        assert_eq!(downloaded(&path), artifact);
+        assert_eq!(*starts.lock().unwrap(), vec![Some(1000), None]);
+    }
+
+    #[test]
+    fn download_discards_corrupt_partial_artifact() {
+        let root = TempDir::new("download").unwrap();
+        let dir = TempDir::new("download").unwrap();
+        let artifact = artifact();
+        let part = partial(dir.path(), &[7; 1000], &hash::hash_bytes(&artifact).unwrap());
+        let (mut listening, client, _) = serve(true, root.path());
+
+        match client.download(PATH, dir.path(), None::<NoProgress>) {
+            Err(Error::DownloadFailed(_)) => (),
+            other => panic!("Expected the download to fail, got {:?}", other),
+        }
+        assert!(!part.exists());
+        assert!(!part.with_extension("part.etag").exists());
+
+        // With the corrupt partial download gone, the next attempt starts over.
+        let path = client.download(PATH, dir.path(), None::<NoProgress>).unwrap();
+        listening.close().unwrap();
+        assert_eq!(downloaded(&path), artifact);
+    }
+
+    #[test]
+    fn show_package_matching_sends_the_version_req() {
+        let root = TempDir::new("show").unwrap();
+        let uris = Arc::new(Mutex::new(Vec::new()));
+        let seen = uris.clone();
+        let mut listening = Server::http("127.0.0.1:0")
+            .unwrap()
+            .handle(move |req: Request, res: Response| {
+                if let RequestUri::AbsolutePath(ref path) = req.uri {
+                    seen.lock().unwrap().push(path.clone());
+                }
+                res.send(br#"{"ident":{"origin":"core","name":"redis","version":"1.10.0",
+                              "release":"20170101010102"},"checksum":"","manifest":"",
+                              "deps":[],"tdeps":[],"exposes":[],"config":""}"#)
+                    .unwrap();
+            })
+            .unwrap();
+        let url = format!("http://{}", listening.socket);
+        let client = Client::new(url.as_str(), "hab", "0.0.0", Some(root.path())).unwrap();
+
+        let ident = package::PackageIdent::from_str("core/redis").unwrap();
+        let req = VersionReq::from_str(">=1.2,<2").unwrap();
+        let package = client.show_package_matching(&ident, &req).unwrap();
+        listening.close().unwrap();
+
+        assert_eq!(package.get_ident().get_version(), "1.10.0");
+        let uris = uris.lock().unwrap();
+        assert!(uris[0].ends_with("pkgs/core/redis/latest?version_req=%3E%3D1.2%2C%3C2"),
+                "{}",
+                uris[0]);
+    }
+}
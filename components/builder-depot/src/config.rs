@@ -43,6 +43,8 @@ pub struct Config {
     pub log_dir: String,
     /// A list of package platform and architecture combinations which can be uploaded and hosted
     pub targets: Vec<PackageTarget>,
+    /// URL of the Depot a mirror fetches packages and origin keys missing from its path from
+    pub upstream_url: Option<String>,
 }
 
 impl ConfigFile for Config {
@@ -61,7 +63,13 @@ impl Default for Config {
             builds_enabled: false,
             log_dir: env::temp_dir().to_string_lossy().into_owned(),
             targets: vec![PackageTarget::new(Platform::Linux, Architecture::X86_64),
+                          PackageTarget::new(Platform::Linux, Architecture::Aarch64),
+                          PackageTarget::new(Platform::Linux, Architecture::Armv7),
+                          PackageTarget::new(Platform::LinuxKernel2, Architecture::X86_64),
+                          PackageTarget::new(Platform::LinuxKernel2, Architecture::Aarch64),
+                          PackageTarget::new(Platform::LinuxKernel2, Architecture::Armv7),
                           PackageTarget::new(Platform::Windows, Architecture::X86_64)],
+            upstream_url: None,
         }
     }
 }
@@ -125,6 +133,7 @@ mod tests {
         builds_enabled = true
         events_enabled = true
         log_dir = "/hab/svc/hab-depot/var/log"
+        upstream_url = "https://willem.habitat.sh/v1/depot"
 
         [[targets]]
         platform = "linux"
@@ -155,6 +164,8 @@ mod tests {
         assert_eq!(config.builds_enabled, true);
         assert_eq!(config.events_enabled, true);
         assert_eq!(config.log_dir, "/hab/svc/hab-depot/var/log");
+        assert_eq!(config.upstream_url,
+                   Some("https://willem.habitat.sh/v1/depot".to_string()));
         assert_eq!(&format!("{}", config.http.listen), "127.0.0.1");
         assert_eq!(config.http.port, 9000);
         assert_eq!(&format!("{}", config.routers[0]), "172.18.0.2:9001");
@@ -178,5 +189,6 @@ mod tests {
 
         let config = Config::from_raw(&content).unwrap();
         assert_eq!(config.http.port, 9000);
+        assert_eq!(config.upstream_url, None);
     }
 }
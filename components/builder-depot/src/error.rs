@@ -20,6 +20,7 @@ use std::result;
 
 use hab_core;
 use hab_core::package::{self, Identifiable};
+use hab_http;
 use hab_net;
 use hyper;
 use protocol::net::NetError;
@@ -30,9 +31,13 @@ pub enum Error {
     ChannelAlreadyExists(String),
     ChannelDoesNotExist(String),
     HabitatCore(hab_core::Error),
+    HabitatHttpClient(hab_http::Error),
     HabitatNet(hab_net::Error),
     HTTP(hyper::status::StatusCode),
+    HyperError(hyper::error::Error),
     InvalidPackageIdent(String),
+    InvalidUpstreamContent(String),
+    InvalidUpstreamUrl(String),
     IO(io::Error),
     MessageTypeNotFound,
     NoXFilename,
@@ -53,13 +58,19 @@ impl fmt::Display for Error {
             Error::ChannelAlreadyExists(ref e) => format!("{} already exists.", e),
             Error::ChannelDoesNotExist(ref e) => format!("{} does not exist.", e),
             Error::HabitatCore(ref e) => format!("{}", e),
+            Error::HabitatHttpClient(ref e) => format!("{}", e),
             Error::HabitatNet(ref e) => format!("{}", e),
             Error::HTTP(ref e) => format!("{}", e),
+            Error::HyperError(ref e) => format!("{}", e),
             Error::InvalidPackageIdent(ref e) => {
                 format!("Invalid package identifier: {:?}. A valid identifier is in the form \
                          origin/name (example: acme/redis)",
                         e)
             }
+            Error::InvalidUpstreamContent(ref e) => {
+                format!("Refusing what the upstream Depot sent: {}", e)
+            }
+            Error::InvalidUpstreamUrl(ref e) => format!("{} is not a valid upstream Depot URL", e),
             Error::IO(ref e) => format!("{}", e),
             Error::MessageTypeNotFound => format!("Unable to find message for given type"),
             Error::NoXFilename => format!("Invalid download from a Depot - missing X-Filename header"),
@@ -92,9 +103,13 @@ impl error::Error for Error {
             Error::ChannelAlreadyExists(_) => "Channel already exists.",
             Error::ChannelDoesNotExist(_) => "Channel does not exist.",
             Error::HabitatCore(ref err) => err.description(),
+            Error::HabitatHttpClient(ref err) => err.description(),
             Error::HabitatNet(ref err) => err.description(),
             Error::HTTP(_) => "Received an HTTP error",
+            Error::HyperError(ref err) => err.description(),
             Error::InvalidPackageIdent(_) => "Package identifiers must be in origin/name format (example: acme/redis)",
+            Error::InvalidUpstreamContent(_) => "Upstream Depot sent an invalid artifact or key",
+            Error::InvalidUpstreamUrl(_) => "Upstream Depot URL is invalid",
             Error::IO(ref err) => err.description(),
             Error::NulError(_) => "An attempt was made to build a CString with a null byte inside it",
             Error::PackageIsAlreadyInChannel(_, _) => "Package is already in channel",
@@ -114,6 +129,18 @@ impl From<hab_core::Error> for Error {
     }
 }
 
+impl From<hab_http::Error> for Error {
+    fn from(err: hab_http::Error) -> Error {
+        Error::HabitatHttpClient(err)
+    }
+}
+
+impl From<hyper::error::Error> for Error {
+    fn from(err: hyper::error::Error) -> Error {
+        Error::HyperError(err)
+    }
+}
+
 impl From<ffi::NulError> for Error {
     fn from(err: ffi::NulError) -> Error {
         Error::NulError(err)
@@ -15,6 +15,7 @@
 extern crate habitat_builder_protocol as protocol;
 #[macro_use]
 extern crate habitat_core as hab_core;
+extern crate habitat_http_client as hab_http;
 extern crate habitat_net as hab_net;
 extern crate builder_core as bld_core;
 extern crate bodyparser;
@@ -49,6 +50,7 @@ extern crate uuid;
 pub mod config;
 pub mod error;
 pub mod doctor;
+pub mod mirror;
 pub mod server;
 
 pub use self::config::Config;
@@ -118,6 +120,12 @@ impl DepotUtil {
     fn packages_path(&self) -> PathBuf {
         Path::new(&self.config.path).join("pkgs")
     }
+
+    // Return the directory holding origin revocation lists, along with the public keys needed to
+    // verify them. It is laid out like a key cache.
+    fn keys_path(&self) -> PathBuf {
+        Path::new(&self.config.path).join("keys")
+    }
 }
 
 impl typemap::Key for DepotUtil {
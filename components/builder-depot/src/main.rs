@@ -28,7 +28,7 @@ use std::str::FromStr;
 
 use hab_core::config::ConfigFile;
 
-use depot::{server, Config, Error, Result};
+use depot::{mirror, server, Config, Error, Result};
 
 const VERSION: &'static str = include_str!(concat!(env!("OUT_DIR"), "/VERSION"));
 const CFG_DEFAULT_PATH: &'static str = "/hab/svc/hab-depot/config.toml";
@@ -62,6 +62,12 @@ fn app<'a, 'b>() -> clap::App<'a, 'b> {
             (@arg port: --port +takes_value "Listen port. [default: 9632]")
             (@arg insecure: --insecure)
         )
+        (@subcommand mirror =>
+            (about: "Run a read-only package Depot mirror serving packages from its path")
+            (@arg port: --port +takes_value "Listen port. [default: 9632]")
+            (@arg upstream: --upstream +takes_value
+                "URL of a Depot to fetch packages and origin keys missing from the path from")
+        )
         (@subcommand repair =>
             (about: "Verify and repair data integrity of the package Depot")
         )
@@ -91,12 +97,17 @@ fn config_from_args(matches: &clap::ArgMatches) -> Result<Config> {
     if let Some(path) = args.value_of("path") {
         config.path = path.to_string();
     }
+
+    if let Some(url) = args.value_of("upstream") {
+        config.upstream_url = Some(url.to_string());
+    }
     Ok(config)
 }
 
 fn dispatch(config: Config, matches: &clap::ArgMatches) -> Result<()> {
     match matches.subcommand_name() {
         Some("start") => start(config),
+        Some("mirror") => start_mirror(config),
         Some("repair") => repair(config),
         Some(cmd) => {
             debug!("Dispatch failed, no match for command: {:?}", cmd);
@@ -119,6 +130,24 @@ fn start(config: Config) -> Result<()> {
     server::run(config)
 }
 
+/// Starts a read-only mirror of a depot, serving the packages and origin keys in its path and
+/// fetching missing ones from the upstream depot, if there is one.
+///
+/// # Failures
+///
+/// * Fails if the upstream depot URL is invalid
+/// * Fails if the mirror fails to start - cannot bind to the port, etc.
+fn start_mirror(config: Config) -> Result<()> {
+    println!("Starting package Depot mirror at {}", config.path);
+    if let Some(ref url) = config.upstream_url {
+        println!("Fetching missing packages from {}", url);
+    }
+    println!("Depot mirror listening on {}:{}",
+             config.http.listen,
+             config.http.port);
+    mirror::run(config)
+}
+
 /// Analyzes the integrity of the depot's metadata by comparing the metadata with the packages
 /// on disk. If a package is found on disk that is not present in the metadata it is added to the
 /// metadata and if an entry in the metadata doesn't have a matching package archive on disk the
@@ -0,0 +1,793 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A read-only Depot serving packages and origin keys from its path, to sit close to the nodes
+//! installing them.
+//!
+//! A mirror answers the routes used to install packages: showing and downloading packages, and
+//! downloading public origin keys and revocation lists. Artifacts are kept in the same layout as
+//! a Depot's (see `DepotUtil::archive_path`) and keys are kept in a key cache, so the path of a
+//! Depot can be served as it is. None of the Depot's services are needed.
+//!
+//! With an upstream Depot, anything missing is fetched from it and kept for the next request,
+//! and the latest releases of packages are looked up on it, falling back to the mirror's own
+//! artifacts when it can't be reached.
+
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::Path;
+use std::result;
+use std::str::FromStr;
+
+use hab_core::crypto::{artifact, SigKeyPair};
+use hab_core::crypto::keys::{parse_name_with_rev, PairType};
+use hab_core::crypto::revocation::RevocationList;
+use hab_core::package::{FromArchive, Identifiable, PackageArchive, PackageIdent, PackageTarget,
+                        VersionReq};
+use hab_http::ApiClient;
+use hab_net::http::controller::*;
+use hyper::client::Response as UpstreamResponse;
+use hyper::mime::{Mime, TopLevel, SubLevel, Attr, Value};
+use hyper::status::StatusCode;
+use hyper::Url;
+use iron::{status, typemap};
+use iron::headers::{ContentType, UserAgent};
+use iron::prelude::*;
+use mount::Mount;
+use persistent;
+use protocol::originsrv::OriginPackage;
+use router::Router;
+use uuid::Uuid;
+use walkdir::WalkDir;
+
+use super::DepotUtil;
+use config::Config;
+use error::{Error, Result};
+use server::{archive_response, do_cache_response, dont_cache_response,
+             download_origin_revocations, extract_query_value, ident_from_params,
+             render_package, target_from_headers};
+
+const PRODUCT: &'static str = "bldr-depot";
+const VERSION: &'static str = include_str!(concat!(env!("OUT_DIR"), "/VERSION"));
+
+/// The Depot a mirror fetches what it's missing from, if it has one.
+pub struct Upstream {
+    client: Option<ApiClient>,
+}
+
+impl Upstream {
+    pub fn new(config: &Config) -> Result<Self> {
+        let client = match config.upstream_url {
+            Some(ref url) => {
+                let url = try!(Url::parse(url)
+                                   .map_err(|_| Error::InvalidUpstreamUrl(url.to_string())));
+                Some(try!(ApiClient::new(&url, PRODUCT, VERSION, None)))
+            }
+            None => None,
+        };
+        Ok(Upstream { client: client })
+    }
+}
+
+impl typemap::Key for Upstream {
+    type Value = Self;
+}
+
+fn show_package(req: &mut Request) -> IronResult<Response> {
+    let ident: PackageIdent = {
+        let params = req.extensions.get::<Router>().unwrap();
+        ident_from_params(params).into()
+    };
+    let lock = req.get::<persistent::State<DepotUtil>>()
+        .expect("depot not found");
+    let depot = lock.read().expect("depot read lock is poisoned");
+    let upstream = req.get::<persistent::Read<Upstream>>()
+        .expect("upstream not found");
+    let targets = match supported_targets(req, &depot) {
+        Ok(targets) => targets,
+        Err(response) => return Ok(response),
+    };
+
+    if !ident.fully_qualified() {
+        return show_latest_package(req, &depot, &upstream, &ident, &targets);
+    }
+    match find_archive(req, &depot, &upstream, &ident, &targets) {
+        Ok(Some(mut archive)) => package_response(&mut archive, true),
+        Ok(None) => Ok(Response::with(status::NotFound)),
+        Err(err) => {
+            error!("show_package:1, err={:?}", err);
+            Ok(Response::with(error_status(&err)))
+        }
+    }
+}
+
+/// Shows the latest release of `ident`, as the upstream Depot has it if it can be asked, or else
+/// from the artifacts in the mirror.
+fn show_latest_package(req: &mut Request,
+                       depot: &DepotUtil,
+                       upstream: &Upstream,
+                       ident: &PackageIdent,
+                       targets: &[PackageTarget])
+                       -> IronResult<Response> {
+    let version_req = extract_query_value("version_req", req);
+    let matching = match version_req {
+        Some(ref version_req) => {
+            match VersionReq::from_str(version_req) {
+                Ok(version_req) => Some(version_req),
+                Err(_) => return Ok(Response::with(status::BadRequest)),
+            }
+        }
+        None => None,
+    };
+
+    if let Some(ref client) = upstream.client {
+        let path = match ident.version() {
+            Some(version) => {
+                format!("pkgs/{}/{}/{}/latest", ident.origin(), ident.name(), version)
+            }
+            None => format!("pkgs/{}/{}/latest", ident.origin(), ident.name()),
+        };
+        match upstream_get(client, req, &path, version_req.as_ref().map(|v| v.as_str())) {
+            Ok(mut res) => {
+                let mut body = String::new();
+                if res.status == StatusCode::Ok && res.read_to_string(&mut body).is_ok() {
+                    let mut response = Response::with((status::Ok, body));
+                    response
+                        .headers
+                        .set(ContentType(Mime(TopLevel::Application,
+                                              SubLevel::Json,
+                                              vec![(Attr::Charset, Value::Utf8)])));
+                    dont_cache_response(&mut response);
+                    return Ok(response);
+                }
+                debug!("Upstream Depot has no latest release of {} ({})",
+                       ident,
+                       res.status);
+            }
+            Err(err) => debug!("Can't reach the upstream Depot for {}, err={:?}", ident, err),
+        }
+    }
+
+    match latest_archive(depot, ident, targets, matching.as_ref()) {
+        Some(mut archive) => package_response(&mut archive, false),
+        None => Ok(Response::with(status::NotFound)),
+    }
+}
+
+fn download_package(req: &mut Request) -> IronResult<Response> {
+    let ident: PackageIdent = {
+        let params = req.extensions.get::<Router>().unwrap();
+        ident_from_params(params).into()
+    };
+    let lock = req.get::<persistent::State<DepotUtil>>()
+        .expect("depot not found");
+    let depot = lock.read().expect("depot read lock is poisoned");
+    let upstream = req.get::<persistent::Read<Upstream>>()
+        .expect("upstream not found");
+    let targets = match supported_targets(req, &depot) {
+        Ok(targets) => targets,
+        Err(response) => return Ok(response),
+    };
+    if targets.is_empty() {
+        return Ok(Response::with(status::NotImplemented));
+    }
+
+    match find_archive(req, &depot, &upstream, &ident, &targets) {
+        Ok(Some(archive)) => archive_response(req, &archive),
+        Ok(None) => Ok(Response::with(status::NotFound)),
+        Err(err) => {
+            error!("download_package:1, err={:?}", err);
+            Ok(Response::with(error_status(&err)))
+        }
+    }
+}
+
+fn download_origin_key(req: &mut Request) -> IronResult<Response> {
+    let (origin, revision) = {
+        let params = req.extensions.get::<Router>().unwrap();
+        (params.find("origin").unwrap().to_string(), params.find("revision").unwrap().to_string())
+    };
+    let lock = req.get::<persistent::State<DepotUtil>>()
+        .expect("depot not found");
+    let depot = lock.read().expect("depot read lock is poisoned");
+    let upstream = req.get::<persistent::Read<Upstream>>()
+        .expect("upstream not found");
+    let keys_path = depot.keys_path();
+    let name_with_rev = format!("{}-{}", origin, revision);
+
+    if SigKeyPair::get_public_key_path(&name_with_rev, &keys_path).is_err() {
+        if let Some(ref client) = upstream.client {
+            if let Err(err) = fetch_origin_key(req, client, &keys_path, &origin, Some(&revision)) {
+                error!("download_origin_key:1, err={:?}", err);
+                return Ok(Response::with(error_status(&err)));
+            }
+        }
+    }
+    origin_key_response(&keys_path, &name_with_rev, true)
+}
+
+fn download_latest_origin_key(req: &mut Request) -> IronResult<Response> {
+    let origin = {
+        let params = req.extensions.get::<Router>().unwrap();
+        params.find("origin").unwrap().to_string()
+    };
+    let lock = req.get::<persistent::State<DepotUtil>>()
+        .expect("depot not found");
+    let depot = lock.read().expect("depot read lock is poisoned");
+    let upstream = req.get::<persistent::Read<Upstream>>()
+        .expect("upstream not found");
+    let keys_path = depot.keys_path();
+
+    if let Some(ref client) = upstream.client {
+        match fetch_origin_key(req, client, &keys_path, &origin, None) {
+            Ok(Some(name_with_rev)) => {
+                return origin_key_response(&keys_path, &name_with_rev, false)
+            }
+            Ok(None) => (),
+            Err(err) => debug!("Can't fetch the latest key of {}, err={:?}", origin, err),
+        }
+    }
+    match SigKeyPair::get_latest_pair_for(&origin, &keys_path) {
+        Ok(pair) => origin_key_response(&keys_path, &pair.name_with_rev(), false),
+        Err(_) => Ok(Response::with(status::NotFound)),
+    }
+}
+
+/// Serves the revocation list of an origin, refreshing it from the upstream Depot first if it can
+/// be reached. A list is only kept once it's verified with a key of the origin.
+fn download_mirrored_origin_revocations(req: &mut Request) -> IronResult<Response> {
+    let origin = {
+        let params = req.extensions.get::<Router>().unwrap();
+        params.find("origin").unwrap().to_string()
+    };
+    {
+        let lock = req.get::<persistent::State<DepotUtil>>()
+            .expect("depot not found");
+        let depot = lock.read().expect("depot read lock is poisoned");
+        let upstream = req.get::<persistent::Read<Upstream>>()
+            .expect("upstream not found");
+        if let Some(ref client) = upstream.client {
+            if let Err(err) = fetch_origin_revocations(req, client, &depot.keys_path(), &origin) {
+                debug!("Can't refresh the revocation list of {}, err={:?}",
+                       origin,
+                       err);
+            }
+        }
+    }
+    download_origin_revocations(req)
+}
+
+/// The targets the client of `req` can run packages of which the mirror serves, best first.
+fn supported_targets(req: &Request,
+                     depot: &DepotUtil)
+                     -> result::Result<Vec<PackageTarget>, Response> {
+    let agent_target = match req.headers.get::<UserAgent>() {
+        Some(user_agent) => try!(target_from_headers(user_agent)),
+        None => return Err(Response::with(status::BadRequest)),
+    };
+    Ok(agent_target
+           .compatible_targets()
+           .into_iter()
+           .filter(|t| depot.config.targets.contains(t))
+           .collect())
+}
+
+/// Returns the artifact of `ident` for the first of `targets` in the mirror, fetching it from the
+/// upstream Depot first if the mirror has none.
+fn find_archive(req: &Request,
+                depot: &DepotUtil,
+                upstream: &Upstream,
+                ident: &PackageIdent,
+                targets: &[PackageTarget])
+                -> Result<Option<PackageArchive>> {
+    if let Some(archive) = targets.iter().filter_map(|t| depot.archive(ident, t)).next() {
+        return Ok(Some(archive));
+    }
+    let client = match upstream.client {
+        Some(ref client) => client,
+        None => return Ok(None),
+    };
+    let mut res = try!(upstream_get(client, req, &format!("pkgs/{}/download", ident), None));
+    match res.status {
+        StatusCode::Ok => (),
+        StatusCode::NotFound => return Ok(None),
+        code => return Err(Error::HTTP(code)),
+    }
+    try!(fs::create_dir_all(depot.packages_path()));
+    let tmp_path = depot
+        .packages_path()
+        .join(format!("mirror-{}.tmp", Uuid::new_v4()));
+    let stored = store_archive(req, client, depot, ident, &mut res, &tmp_path);
+    let _ = fs::remove_file(&tmp_path);
+    try!(stored);
+    Ok(targets.iter().filter_map(|t| depot.archive(ident, t)).next())
+}
+
+/// Keeps the artifact of `ident` the upstream Depot sent in `res`. It's only kept if it really is
+/// a release of `ident` and it verifies with a key in the mirror's key cache, fetching the key of
+/// its signer from the upstream Depot first if needed. An artifact the mirror already has is never
+/// replaced.
+fn store_archive(req: &Request,
+                 upstream: &ApiClient,
+                 depot: &DepotUtil,
+                 ident: &PackageIdent,
+                 res: &mut UpstreamResponse,
+                 tmp_path: &Path)
+                 -> Result<()> {
+    {
+        let mut file = try!(File::create(tmp_path));
+        try!(io::copy(res, &mut file));
+    }
+    let mut archive = PackageArchive::new(tmp_path.to_path_buf());
+    let archive_ident = try!(archive.ident());
+    if archive_ident != *ident {
+        return Err(Error::InvalidUpstreamContent(format!("the artifact of {} when {} was \
+                                                          requested",
+                                                         archive_ident,
+                                                         ident)));
+    }
+    let keys_path = depot.keys_path();
+    let signer = try!(artifact::artifact_signer(&tmp_path));
+    if SigKeyPair::get_public_key_path(&signer, &keys_path).is_err() {
+        let (origin, revision) = try!(parse_name_with_rev(&signer));
+        try!(fetch_origin_key(req, upstream, &keys_path, &origin, Some(&revision)));
+    }
+    if let Err(err) = artifact::verify(tmp_path, &keys_path) {
+        return Err(Error::InvalidUpstreamContent(format!("the artifact of {} doesn't verify, {}",
+                                                         ident,
+                                                         err)));
+    }
+    let path = depot.archive_path(ident, &try!(archive.target()));
+    try!(fs::create_dir_all(depot.archive_parent(ident)));
+    debug!("Mirroring {} to {}", tmp_path.display(), path.display());
+    // Unlike a rename, a hard link never replaces a file already at `path`.
+    match fs::hard_link(tmp_path, &path) {
+        Ok(()) => Ok(()),
+        Err(ref err) if err.kind() == io::ErrorKind::AlreadyExists => Ok(()),
+        Err(err) => Err(Error::from(err)),
+    }
+}
+
+/// Returns the newest artifact in the mirror of a release of `ident` for one of `targets`, with a
+/// version matching `version_req` if one is given.
+fn latest_archive(depot: &DepotUtil,
+                  ident: &PackageIdent,
+                  targets: &[PackageTarget],
+                  version_req: Option<&VersionReq>)
+                  -> Option<PackageArchive> {
+    let prefix = format!("{}-{}-", ident.origin(), ident.name());
+    let mut latest: Option<(PackageIdent, PackageArchive)> = None;
+    for entry in WalkDir::new(depot.packages_path()).into_iter().filter_map(|e| e.ok()) {
+        let is_candidate = entry
+            .file_name()
+            .to_str()
+            .map_or(false, |n| n.starts_with(&prefix) && n.ends_with(".hart"));
+        if !is_candidate {
+            continue;
+        }
+        let mut archive = PackageArchive::new(entry.path().to_path_buf());
+        let candidate = match (archive.ident(), archive.target()) {
+            (Ok(candidate), Ok(target)) => {
+                if !targets.contains(&target) {
+                    continue;
+                }
+                candidate
+            }
+            _ => continue,
+        };
+        if !candidate.satisfies(ident) || !version_req.map_or(true, |r| candidate.matches_req(r)) {
+            continue;
+        }
+        if latest.as_ref().map_or(true, |&(ref newest, _)| candidate > *newest) {
+            latest = Some((candidate, archive));
+        }
+    }
+    latest.map(|(_, archive)| archive)
+}
+
+/// Fetches the public key of `origin` with `revision`, or its latest one, from the upstream Depot
+/// into `keys_path`, returning its name with revision, or `None` if the upstream Depot doesn't have
+/// it. A key other than the one requested is refused.
+fn fetch_origin_key(req: &Request,
+                    upstream: &ApiClient,
+                    keys_path: &Path,
+                    origin: &str,
+                    revision: Option<&str>)
+                    -> Result<Option<String>> {
+    let path = format!("origins/{}/keys/{}", origin, revision.unwrap_or("latest"));
+    let mut res = try!(upstream_get(upstream, req, &path, None));
+    match res.status {
+        StatusCode::Ok => (),
+        StatusCode::NotFound => return Ok(None),
+        code => return Err(Error::HTTP(code)),
+    }
+    let mut content = String::new();
+    try!(res.read_to_string(&mut content));
+    let (pair_type, name_with_rev, _) = try!(SigKeyPair::parse_key_str(&content));
+    let (key_origin, key_revision) = try!(parse_name_with_rev(&name_with_rev));
+    if pair_type != PairType::Public || key_origin != origin ||
+       revision.map_or(false, |r| r != key_revision) {
+        return Err(Error::InvalidUpstreamContent(format!("the key {} when a public key at {} \
+                                                          was requested",
+                                                         name_with_rev,
+                                                         path)));
+    }
+    try!(fs::create_dir_all(keys_path));
+    let (pair, _) = try!(SigKeyPair::write_file_from_str(&content, keys_path));
+    Ok(Some(pair.name_with_rev()))
+}
+
+fn fetch_origin_revocations(req: &Request,
+                            upstream: &ApiClient,
+                            keys_path: &Path,
+                            origin: &str)
+                            -> Result<()> {
+    let path = format!("origins/{}/revocations", origin);
+    let mut res = try!(upstream_get(upstream, req, &path, None));
+    match res.status {
+        StatusCode::Ok => (),
+        StatusCode::NotFound => return Ok(()),
+        code => return Err(Error::HTTP(code)),
+    }
+    let mut content = String::new();
+    try!(res.read_to_string(&mut content));
+    let signer = try!(RevocationList::signer(&content));
+    if SigKeyPair::get_public_key_path(&signer, keys_path).is_err() {
+        let (name, revision) = try!(parse_name_with_rev(&signer));
+        try!(fetch_origin_key(req, upstream, keys_path, &name, Some(&revision)));
+    }
+    try!(RevocationList::write_file_from_str(&content, keys_path));
+    Ok(())
+}
+
+/// Sends a GET for `path` to the upstream Depot on behalf of the client of `req`. The client's
+/// `User-Agent` is passed on, so that the upstream Depot serves packages for its target.
+fn upstream_get(upstream: &ApiClient,
+                req: &Request,
+                path: &str,
+                version_req: Option<&str>)
+                -> Result<UpstreamResponse> {
+    let mut builder = match version_req {
+        Some(version_req) => {
+            upstream.get_with_custom_url(path, |url| {
+                url.query_pairs_mut().append_pair("version_req", version_req);
+            })
+        }
+        None => upstream.get(path),
+    };
+    if let Some(user_agent) = req.headers.get::<UserAgent>() {
+        builder = builder.header(user_agent.clone());
+    }
+    Ok(try!(builder.send()))
+}
+
+fn package_response(archive: &mut PackageArchive, should_cache: bool) -> IronResult<Response> {
+    match OriginPackage::from_archive(archive) {
+        Ok(package) => render_package(&package, should_cache),
+        Err(err) => {
+            error!("Can't read package metadata of {}, err={:?}",
+                   archive.path.display(),
+                   err);
+            Ok(Response::with(status::InternalServerError))
+        }
+    }
+}
+
+fn origin_key_response(keys_path: &Path,
+                       name_with_rev: &str,
+                       should_cache: bool)
+                       -> IronResult<Response> {
+    let path = match SigKeyPair::get_public_key_path(name_with_rev, keys_path) {
+        Ok(path) => path,
+        Err(_) => return Ok(Response::with(status::NotFound)),
+    };
+    let mut content = String::new();
+    if let Err(err) = File::open(&path).and_then(|mut f| f.read_to_string(&mut content)) {
+        error!("Can't read key file {}, err={:?}", path.display(), err);
+        return Ok(Response::with(status::InternalServerError));
+    }
+    let xfilename = format!("{}.pub", name_with_rev);
+    let mut response = Response::with((status::Ok, content));
+    response
+        .headers
+        .set(ContentDisposition(format!("attachment; filename=\"{}\"", xfilename)));
+    response.headers.set(XFileName(xfilename));
+    if should_cache {
+        do_cache_response(&mut response);
+    } else {
+        dont_cache_response(&mut response);
+    }
+    Ok(response)
+}
+
+/// Failing to get something from the upstream Depot is a bad gateway, anything else is the
+/// mirror's own error.
+fn error_status(err: &Error) -> status::Status {
+    match *err {
+        Error::HTTP(_) |
+        Error::HyperError(_) |
+        Error::InvalidUpstreamContent(_) => status::BadGateway,
+        _ => status::InternalServerError,
+    }
+}
+
+pub fn routes() -> Router {
+    router!(
+        package_pkg_latest: get "/pkgs/:origin/:pkg/latest" => show_package,
+        package_version_latest: get "/pkgs/:origin/:pkg/:version/latest" => show_package,
+        package: get "/pkgs/:origin/:pkg/:version/:release" => show_package,
+        package_download: get "/pkgs/:origin/:pkg/:version/:release/download" => download_package,
+        origin_key_latest: get "/origins/:origin/keys/latest" => download_latest_origin_key,
+        origin_key: get "/origins/:origin/keys/:revision" => download_origin_key,
+        origin_revocations: get "/origins/:origin/revocations" => {
+            download_mirrored_origin_revocations
+        },
+    )
+}
+
+pub fn router(depot: DepotUtil, upstream: Upstream) -> Chain {
+    let mut chain = Chain::new(routes());
+    chain.link(persistent::State::<DepotUtil>::both(depot));
+    chain.link(persistent::Read::<Upstream>::both(upstream));
+    chain.link_after(Cors);
+    chain
+}
+
+pub fn run(config: Config) -> Result<()> {
+    let upstream = try!(Upstream::new(&config));
+    let v1 = router(DepotUtil::new(config.clone()), upstream);
+
+    let mut mount = Mount::new();
+    mount.mount("/v1", v1);
+    Iron::new(mount).http(&config.http).expect("Unable to start HTTP listener");
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::env;
+    use std::fs::{self, File};
+    use std::io;
+    use std::path::{Path, PathBuf};
+    use std::str::FromStr;
+
+    use hab_core::crypto::{artifact, SigKeyPair};
+    use hab_core::package::{PackageIdent, PackageTarget};
+    use hab_net::http::controller::XFileName;
+    use hyper::header::{ByteRangeSpec, Range};
+    use hyper::server::Listening;
+    use iron::{Headers, Iron, status};
+    use iron::headers::UserAgent;
+    use iron_test::{request, response};
+
+    use super::{router, Upstream};
+    use super::super::DepotUtil;
+    use config::Config;
+
+    const CACERTS: &'static str = "core/cacerts/2017.01.17/20170209064044";
+    const CACERTS_NEWER: &'static str = "core/cacerts/2017.01.17/20170209064045";
+
+    fn hart_file(name: &str) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join(name)
+    }
+
+    /// Returns a mirror holding the artifacts of the given idents, fetching anything else from
+    /// the Depot at `upstream_url` if one is given.
+    fn mirror(name: &str, idents: &[&str], upstream_url: Option<String>) -> (DepotUtil, Upstream) {
+        let mut config = Config::default();
+        config.path = env::temp_dir()
+            .join("mirror-tests")
+            .join(name)
+            .to_string_lossy()
+            .to_string();
+        config.upstream_url = upstream_url;
+        let _ = fs::remove_dir_all(&config.path);
+        let depot = DepotUtil::new(config.clone());
+        for ident in idents {
+            store_fixture(&depot, ident, ident);
+        }
+        (depot, Upstream::new(&config).unwrap())
+    }
+
+    /// Stores the fixture artifact of `fixture` in `depot` as the artifact of `ident`. The key
+    /// the fixtures are signed with isn't at hand, so the artifact is signed again with a key of
+    /// its origin kept in the depot's key cache.
+    fn store_fixture(depot: &DepotUtil, fixture: &str, ident: &str) {
+        let target = PackageTarget::from_str("x86_64-windows").unwrap();
+        let fixture = PackageIdent::from_str(fixture).unwrap();
+        let ident = PackageIdent::from_str(ident).unwrap();
+        let keys_path = depot.keys_path();
+        fs::create_dir_all(&keys_path).unwrap();
+        let pair = SigKeyPair::get_latest_pair_for("core", &keys_path)
+            .or_else(|_| SigKeyPair::generate_pair_for_origin("core", &keys_path))
+            .unwrap();
+
+        let path = depot.archive_path(&ident, &target);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let payload = path.with_extension("tar");
+        {
+            let src = hart_file(&fixture.archive_name_with_target(&target).unwrap());
+            let mut reader = artifact::get_archive_reader(&src).unwrap();
+            io::copy(&mut reader, &mut File::create(&payload).unwrap()).unwrap();
+        }
+        artifact::sign(&payload, &path, &pair).unwrap();
+        fs::remove_file(&payload).unwrap();
+    }
+
+    /// Serves `depot` as a mirror over HTTP, returning the listener and its URL.
+    fn serve(depot: DepotUtil, upstream: Upstream) -> (Listening, String) {
+        let listening = Iron::new(router(depot, upstream))
+            .http("127.0.0.1:0")
+            .unwrap();
+        let url = format!("http://{}", listening.socket);
+        (listening, url)
+    }
+
+    fn windows_client() -> Headers {
+        let mut headers = Headers::new();
+        headers.set(UserAgent("hab/0.20.0 (x86_64-windows; 10.0.14393)".to_string()));
+        headers
+    }
+
+    fn archive_path(depot: &DepotUtil, ident: &str) -> PathBuf {
+        depot.archive_path(&PackageIdent::from_str(ident).unwrap(),
+                           &PackageTarget::from_str("x86_64-windows").unwrap())
+    }
+
+    fn key_files(keys_path: &Path) -> usize {
+        fs::read_dir(keys_path).map(|d| d.count()).unwrap_or(0)
+    }
+
+    #[test]
+    fn download_package_from_mirror() {
+        let (depot, upstream) = mirror("download", &[CACERTS], None);
+        let file_name = "core-cacerts-2017.01.17-20170209064044-x86_64-windows.hart";
+        let len = fs::metadata(archive_path(&depot, CACERTS)).unwrap().len();
+        let chain = router(depot, upstream);
+        let url = format!("http://localhost/pkgs/{}/download", CACERTS);
+
+        let res = request::get(&url, windows_client(), &chain).unwrap();
+        assert_eq!(res.status, Some(status::Ok));
+        assert_eq!(res.headers.get::<XFileName>(),
+                   Some(&XFileName(file_name.to_string())));
+        assert_eq!(response::extract_body_to_bytes(res).len() as u64, len);
+
+        let mut headers = windows_client();
+        headers.set(Range::Bytes(vec![ByteRangeSpec::AllFrom(10)]));
+        let res = request::get(&url, headers, &chain).unwrap();
+        assert_eq!(res.status, Some(status::PartialContent));
+        assert_eq!(response::extract_body_to_bytes(res).len() as u64, len - 10);
+    }
+
+    #[test]
+    fn show_packages_from_mirror() {
+        let (depot, upstream) = mirror("show", &[CACERTS, CACERTS_NEWER], None);
+        let chain = router(depot, upstream);
+
+        let res = request::get(&format!("http://localhost/pkgs/{}", CACERTS),
+                               windows_client(),
+                               &chain)
+                .unwrap();
+        assert_eq!(res.status, Some(status::Ok));
+        assert!(response::extract_body_to_string(res).contains("20170209064044"));
+
+        let res = request::get("http://localhost/pkgs/core/cacerts/latest",
+                               windows_client(),
+                               &chain)
+                .unwrap();
+        assert_eq!(res.status, Some(status::Ok));
+        assert!(response::extract_body_to_string(res).contains("20170209064045"));
+    }
+
+    #[test]
+    fn missing_package_without_upstream() {
+        let (depot, upstream) = mirror("missing", &[], None);
+        let chain = router(depot, upstream);
+        let res = request::get(&format!("http://localhost/pkgs/{}/download", CACERTS),
+                               windows_client(),
+                               &chain)
+                .unwrap();
+        assert_eq!(res.status, Some(status::NotFound));
+    }
+
+    #[test]
+    fn fill_from_upstream() {
+        let (upstream_depot, upstream_upstream) = mirror("fill-upstream", &[CACERTS], None);
+        let (mut listening, url) = serve(upstream_depot, upstream_upstream);
+        let (depot, upstream) = mirror("fill", &[], Some(url));
+        let path = archive_path(&depot, CACERTS);
+        let keys_path = depot.keys_path();
+        let chain = router(depot, upstream);
+
+        let res = request::get(&format!("http://localhost/pkgs/{}/download", CACERTS),
+                               windows_client(),
+                               &chain)
+                .unwrap();
+        assert_eq!(res.status, Some(status::Ok));
+        assert!(path.is_file());
+        assert!(artifact::verify(&path, &keys_path).is_ok());
+
+        let res = request::get("http://localhost/origins/core/keys/latest",
+                               windows_client(),
+                               &chain)
+                .unwrap();
+        assert_eq!(res.status, Some(status::Ok));
+        listening.close().unwrap();
+    }
+
+    #[test]
+    fn refuse_another_package_from_upstream() {
+        let (upstream_depot, upstream_upstream) = mirror("mismatch-upstream", &[], None);
+        // The upstream Depot sends the older release when the newer one is asked for.
+        store_fixture(&upstream_depot, CACERTS, CACERTS_NEWER);
+        let (mut listening, url) = serve(upstream_depot, upstream_upstream);
+        let (depot, upstream) = mirror("mismatch", &[], Some(url));
+        let paths = [archive_path(&depot, CACERTS), archive_path(&depot, CACERTS_NEWER)];
+        let chain = router(depot, upstream);
+
+        let res = request::get(&format!("http://localhost/pkgs/{}/download", CACERTS_NEWER),
+                               windows_client(),
+                               &chain)
+                .unwrap();
+        assert_eq!(res.status, Some(status::BadGateway));
+        assert!(paths.iter().all(|p| !p.exists()));
+        listening.close().unwrap();
+    }
+
+    #[test]
+    fn refuse_unverified_package_from_upstream() {
+        let (upstream_depot, upstream_upstream) = mirror("unverified-upstream", &[CACERTS], None);
+        // The upstream Depot has lost the key the artifact is signed with.
+        fs::remove_dir_all(upstream_depot.keys_path()).unwrap();
+        let (mut listening, url) = serve(upstream_depot, upstream_upstream);
+        let (depot, upstream) = mirror("unverified", &[], Some(url));
+        let path = archive_path(&depot, CACERTS);
+        let chain = router(depot, upstream);
+
+        let res = request::get(&format!("http://localhost/pkgs/{}/download", CACERTS),
+                               windows_client(),
+                               &chain)
+                .unwrap();
+        assert_eq!(res.status, Some(status::BadGateway));
+        assert!(!path.exists());
+        listening.close().unwrap();
+    }
+
+    #[test]
+    fn refuse_another_key_from_upstream() {
+        let (upstream_depot, upstream_upstream) = mirror("key-upstream", &[CACERTS], None);
+        // The upstream Depot serves a key of `core` as the latest key of `acme`.
+        let keys_path = upstream_depot.keys_path();
+        let pair = SigKeyPair::get_latest_pair_for("core", &keys_path).unwrap();
+        let acme_path = keys_path.join(format!("acme-{}.pub", pair.rev));
+        fs::copy(SigKeyPair::get_public_key_path(&pair.name_with_rev(), &keys_path).unwrap(),
+                 &acme_path)
+                .unwrap();
+        let (mut listening, url) = serve(upstream_depot, upstream_upstream);
+        let (depot, upstream) = mirror("key", &[], Some(url));
+        let keys_path = depot.keys_path();
+        let chain = router(depot, upstream);
+
+        let res = request::get(&format!("http://localhost/origins/acme/keys/{}", pair.rev),
+                               windows_client(),
+                               &chain)
+                .unwrap();
+        assert_eq!(res.status, Some(status::BadGateway));
+        assert_eq!(key_files(&keys_path), 0);
+        listening.close().unwrap();
+    }
+}
@@ -15,29 +15,34 @@
 use std::any::TypeId;
 use std::collections::HashMap;
 use std::fs::{self, File};
-use std::path::PathBuf;
-use std::io::{Read, Write, BufWriter};
+use std::path::{Path, PathBuf};
+use std::io::{self, Read, Seek, SeekFrom, Write, BufWriter};
 use std::result;
 use std::str::FromStr;
 
 use uuid::Uuid;
 use bodyparser;
-use hab_core::package::{Identifiable, FromArchive, PackageArchive, PackageTarget};
+use hab_core::package::{Identifiable, FromArchive, PackageArchive, PackageIdent, PackageTarget,
+                        VersionReq};
 use hab_core::crypto::keys::{self, PairType};
 use hab_core::crypto::SigKeyPair;
+use hab_core::crypto::revocation::RevocationList;
 use hab_core::event::*;
 use hab_net::config::RouterCfg;
 use hab_net::http::controller::*;
 use hab_net::privilege;
 use hab_net::routing::{Broker, RouteResult};
 use hab_net::server::NetIdent;
-use hyper::header::{Charset, ContentDisposition, DispositionType, DispositionParam};
+use hyper::header::{AcceptRanges, ByteRangeSpec, Charset, ContentDisposition, ContentLength,
+                    ContentRange, ContentRangeSpec, DispositionType, DispositionParam, Range,
+                    RangeUnit};
 use hyper::mime::{Mime, TopLevel, SubLevel, Attr, Value};
 use iron::{status, headers, typemap};
-use iron::headers::{ContentType, UserAgent};
+use iron::headers::{ContentType, Headers, UserAgent};
 use iron::middleware::BeforeMiddleware;
 use iron::prelude::*;
 use iron::request::Body;
+use iron::response::WriteBody;
 use iron::typemap::Key;
 use mount::Mount;
 use persistent;
@@ -533,6 +538,114 @@ fn download_latest_origin_secret_key(req: &mut Request) -> IronResult<Response>
     }
 }
 
+fn upload_origin_revocations(req: &mut Request) -> IronResult<Response> {
+    debug!("Upload Origin Revocation List {:?}", req);
+    let session = req.extensions.get::<Authenticated>().unwrap().clone();
+    let origin = match req.extensions.get::<Router>().unwrap().find("origin") {
+        Some(origin) => origin.to_string(),
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    if !try!(check_origin_access(req, session.get_id(), &origin)) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let mut content = String::new();
+    if let Err(e) = req.body.read_to_string(&mut content) {
+        debug!("Can't read revocation list content {}", e);
+        return Ok(Response::with(status::BadRequest));
+    }
+    let revision = match RevocationList::signer(&content)
+              .and_then(|signer| keys::parse_name_with_rev(&signer)) {
+        Ok((name, revision)) => {
+            if name != origin {
+                debug!("Revocation list for {} is signed by a key of {}", origin, name);
+                return Ok(Response::with(status::BadRequest));
+            }
+            revision
+        }
+        Err(e) => {
+            debug!("Invalid revocation list content: {}", e);
+            return Ok(Response::with(status::BadRequest));
+        }
+    };
+
+    // The list must be signed by a key the origin has uploaded to us.
+    let mut conn = Broker::connect().unwrap();
+    let mut request = OriginPublicKeyGet::new();
+    request.set_owner_id(session.get_id());
+    request.set_origin(origin.clone());
+    request.set_revision(revision);
+    let key = match conn.route::<OriginPublicKeyGet, OriginPublicKey>(&request) {
+        Ok(key) => key,
+        Err(err) => {
+            debug!("Can't retrieve the revocation list signing key: {}", err);
+            return Ok(Response::with(status::UnprocessableEntity));
+        }
+    };
+
+    let lock = req.get::<persistent::State<DepotUtil>>()
+        .expect("depot not found");
+    let depot = lock.read().expect("depot read lock is poisoned");
+    let keys_path = depot.keys_path();
+    if let Err(e) = fs::create_dir_all(&keys_path) {
+        error!("Can't create the revocation list directory {}", e);
+        return Ok(Response::with(status::InternalServerError));
+    }
+    if let Err(e) = SigKeyPair::write_file_from_str(&String::from_utf8_lossy(key.get_body()),
+                                                    &keys_path) {
+        error!("Can't cache the revocation list signing key {}", e);
+        return Ok(Response::with(status::InternalServerError));
+    }
+    let list = match RevocationList::from_signed_str(&content, &keys_path) {
+        Ok(list) => list,
+        Err(e) => {
+            debug!("Revocation list failed verification: {}", e);
+            return Ok(Response::with(status::BadRequest));
+        }
+    };
+    if list.origin() != origin {
+        return Ok(Response::with(status::BadRequest));
+    }
+    // Revocations are permanent, so a new list must include every revision on the current one.
+    if let Ok(Some(current)) = RevocationList::get_for(&origin, &keys_path) {
+        if current.revoked().iter().any(|r| !list.is_revoked(r)) {
+            return Ok(Response::with(status::Conflict));
+        }
+    }
+    match RevocationList::write_file_from_str(&content, &keys_path) {
+        Ok(_) => {
+            Ok(Response::with((status::Created, format!("/origins/{}/revocations", &origin))))
+        }
+        Err(e) => {
+            error!("Can't write revocation list for {}: {}", origin, e);
+            Ok(Response::with(status::InternalServerError))
+        }
+    }
+}
+
+pub fn download_origin_revocations(req: &mut Request) -> IronResult<Response> {
+    let origin = {
+        let params = req.extensions.get::<Router>().unwrap();
+        params.find("origin").unwrap().to_owned()
+    };
+    let lock = req.get::<persistent::State<DepotUtil>>()
+        .expect("depot not found");
+    let depot = lock.read().expect("depot read lock is poisoned");
+    let mut content = String::new();
+    match File::open(RevocationList::path_for(&origin, &depot.keys_path())) {
+        Ok(mut file) => {
+            if let Err(e) = file.read_to_string(&mut content) {
+                error!("Can't read revocation list for {}: {}", origin, e);
+                return Ok(Response::with(status::InternalServerError));
+            }
+        }
+        Err(_) => return Ok(Response::with(status::NotFound)),
+    }
+    let mut response = Response::with((status::Ok, content));
+    dont_cache_response(&mut response);
+    Ok(response)
+}
+
 fn upload_origin_secret_key(req: &mut Request) -> IronResult<Response> {
     debug!("Upload Origin Secret Key {:?}", req);
     // TODO: SA - Eliminate need to clone the session and params
@@ -937,6 +1050,80 @@ fn download_latest_origin_key(req: &mut Request) -> IronResult<Response> {
     Ok(response)
 }
 
+/// Returns the offset to serve a file of `len` bytes from, if the request asks for its bytes from
+/// that offset on, as a client resuming a download does. Any other range gets the whole file, as
+/// does a resumed download whose `If-Range` isn't the file's current `etag`: the client's partial
+/// download is of something else.
+fn range_start(headers: &Headers, len: u64, etag: &str) -> Option<u64> {
+    match headers.get::<IfRange>() {
+        Some(&IfRange(ref tag)) if tag != etag => return None,
+        _ => (),
+    }
+    match headers.get::<Range>() {
+        Some(&Range::Bytes(ref specs)) if specs.len() == 1 => {
+            match specs[0] {
+                ByteRangeSpec::AllFrom(start) if start < len => Some(start),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn partial_response(path: &Path, start: u64, len: u64) -> io::Result<Response> {
+    let mut file = try!(File::open(path));
+    try!(file.seek(SeekFrom::Start(start)));
+    let body: Box<WriteBody> = Box::new(file);
+    let mut response = Response::with((status::PartialContent, body));
+    response.headers.set(ContentRange(ContentRangeSpec::Bytes {
+                                          range: Some((start, len - 1)),
+                                          instance_length: Some(len),
+                                      }));
+    response.headers.set(ContentLength(len - start));
+    Ok(response)
+}
+
+/// Serves the artifact of `archive` as a download, or the rest of it from the offset asked for by
+/// a client resuming a download. The `ETag` is the artifact's checksum, which clients check their
+/// download against.
+pub fn archive_response(req: &Request, archive: &PackageArchive) -> IronResult<Response> {
+    let len = match fs::metadata(&archive.path) {
+        Ok(metadata) => metadata.len(),
+        Err(_) => return Ok(Response::with(status::NotFound)),
+    };
+    let checksum = match archive.checksum() {
+        Ok(checksum) => checksum,
+        Err(e) => {
+            error!("archive_response:1, err={:?}", e);
+            return Ok(Response::with(status::InternalServerError));
+        }
+    };
+    let mut response = match range_start(&req.headers, len, &checksum) {
+        Some(start) => {
+            match partial_response(&archive.path, start, len) {
+                Ok(response) => response,
+                Err(e) => {
+                    error!("archive_response:2, err={:?}", e);
+                    return Ok(Response::with(status::InternalServerError));
+                }
+            }
+        }
+        None => Response::with((status::Ok, archive.path.clone())),
+    };
+    response.headers.set(AcceptRanges(vec![RangeUnit::Bytes]));
+    response.headers.set(ETag(checksum));
+    do_cache_response(&mut response);
+    let disp = ContentDisposition {
+        disposition: DispositionType::Attachment,
+        parameters: vec![DispositionParam::Filename(Charset::Iso_8859_1,
+                                                    None,
+                                                    archive.file_name().as_bytes().to_vec())],
+    };
+    response.headers.set(disp);
+    response.headers.set(XFileName(archive.file_name()));
+    Ok(response)
+}
+
 fn download_package(req: &mut Request) -> IronResult<Response> {
     let lock = req.get::<persistent::State<DepotUtil>>()
         .expect("depot not found");
@@ -947,7 +1134,13 @@ fn download_package(req: &mut Request) -> IronResult<Response> {
         ident_req.set_ident(ident_from_params(params));
     };
     let agent_target = target_from_headers(&req.headers.get::<UserAgent>().unwrap()).unwrap();
-    if !depot.config.targets.contains(&agent_target) {
+    // Serve the client's own target if we have it, or else any target that runs on it.
+    let targets: Vec<PackageTarget> = agent_target
+        .compatible_targets()
+        .into_iter()
+        .filter(|t| depot.config.targets.contains(t))
+        .collect();
+    if targets.is_empty() {
         error!("Unsupported client platform ({}) for this depot.",
                agent_target);
         return Ok(Response::with(status::NotImplemented));
@@ -955,26 +1148,11 @@ fn download_package(req: &mut Request) -> IronResult<Response> {
 
     match route_message::<OriginPackageGet, OriginPackage>(req, &ident_req) {
         Ok(package) => {
-            if let Some(archive) = depot.archive(package.get_ident(), &agent_target) {
-                match fs::metadata(&archive.path) {
-                    Ok(_) => {
-                        let mut response = Response::with((status::Ok, archive.path.clone()));
-                        do_cache_response(&mut response);
-                        let disp = ContentDisposition {
-                            disposition: DispositionType::Attachment,
-                            parameters: vec![DispositionParam::Filename(Charset::Iso_8859_1,
-                                                                        None,
-                                                                        archive
-                                                                            .file_name()
-                                                                            .as_bytes()
-                                                                            .to_vec())],
-                        };
-                        response.headers.set(disp);
-                        response.headers.set(XFileName(archive.file_name()));
-                        Ok(response)
-                    }
-                    Err(_) => Ok(Response::with(status::NotFound)),
-                }
+            if let Some(archive) = targets
+                   .iter()
+                   .filter_map(|t| depot.archive(package.get_ident(), t))
+                   .next() {
+                archive_response(req, &archive)
             } else {
                 // This should never happen. Writing the package to disk and recording it's
                 // existence in the metadata is a transactional operation and one cannot exist
@@ -1330,24 +1508,20 @@ fn show_package(req: &mut Request) -> IronResult<Response> {
 
     if let Some(channel) = channel {
         if !qualified {
-            let target = target_from_headers(&req.headers.get::<UserAgent>().unwrap())
-                .unwrap()
-                .to_string();
-            let mut request = OriginChannelPackageLatestGet::new();
-            request.set_name(channel.clone());
-            request.set_ident(ident);
-            request.set_target(target);
-            match route_message::<OriginChannelPackageLatestGet, OriginPackageIdent>(req,
-                                                                                     &request) {
-                Ok(id) => ident = id.into(),
+            let targets = client_targets(req);
+            let latest = latest_for_targets(req, &targets, |target| {
+                let mut request = OriginChannelPackageLatestGet::new();
+                request.set_name(channel.clone());
+                request.set_ident(ident.clone());
+                request.set_target(target.to_string());
+                request
+            });
+            match latest {
+                Ok(Some(id)) => ident = id.into(),
+                Ok(None) => return Ok(Response::with((status::NotFound))),
                 Err(err) => {
-                    match err.get_code() {
-                        ErrCode::ENTITY_NOT_FOUND => return Ok(Response::with((status::NotFound))),
-                        _ => {
-                            error!("show_package:2, err={:?}", err);
-                            return Ok(Response::with(status::InternalServerError));
-                        }
-                    }
+                    error!("show_package:2, err={:?}", err);
+                    return Ok(Response::with(status::InternalServerError));
                 }
             }
         }
@@ -1369,22 +1543,33 @@ fn show_package(req: &mut Request) -> IronResult<Response> {
         }
     } else {
         if !qualified {
-            let target = target_from_headers(&req.headers.get::<UserAgent>().unwrap())
-                .unwrap()
-                .to_string();
-            let mut request = OriginPackageLatestGet::new();
-            request.set_ident(ident);
-            request.set_target(target);
-            match route_message::<OriginPackageLatestGet, OriginPackageIdent>(req, &request) {
-                Ok(id) => ident = id.into(),
+            let targets = client_targets(req);
+            if let Some(version_req) = extract_query_value("version_req", req) {
+                let version_req = match VersionReq::from_str(&version_req) {
+                    Ok(version_req) => version_req,
+                    Err(_) => return Ok(Response::with(status::BadRequest)),
+                };
+                return match latest_matching_package(req, &ident, &version_req, &targets) {
+                           Ok(Some(pkg)) => render_package(&pkg, false),
+                           Ok(None) => Ok(Response::with(status::NotFound)),
+                           Err(err) => {
+                               error!("show_package:7, err={:?}", err);
+                               Ok(Response::with(status::InternalServerError))
+                           }
+                       };
+            }
+            let latest = latest_for_targets(req, &targets, |target| {
+                let mut request = OriginPackageLatestGet::new();
+                request.set_ident(ident.clone());
+                request.set_target(target.to_string());
+                request
+            });
+            match latest {
+                Ok(Some(id)) => ident = id.into(),
+                Ok(None) => return Ok(Response::with((status::NotFound))),
                 Err(err) => {
-                    match err.get_code() {
-                        ErrCode::ENTITY_NOT_FOUND => return Ok(Response::with((status::NotFound))),
-                        _ => {
-                            error!("show_package:5, err={:?}", err);
-                            return Ok(Response::with(status::InternalServerError));
-                        }
-                    }
+                    error!("show_package:5, err={:?}", err);
+                    return Ok(Response::with(status::InternalServerError));
                 }
             }
         }
@@ -1414,6 +1599,90 @@ fn show_package(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+/// Returns the targets whose packages can run on the requesting client, starting with its own.
+fn client_targets(req: &Request) -> Vec<String> {
+    target_from_headers(&req.headers.get::<UserAgent>().unwrap())
+        .unwrap()
+        .compatible_targets()
+        .iter()
+        .map(|t| t.to_string())
+        .collect()
+}
+
+/// Asks for the latest release built for each of `targets` in turn and returns the first one
+/// found.
+fn latest_for_targets<M, F>(req: &mut Request,
+                            targets: &[String],
+                            request_for: F)
+                            -> RouteResult<Option<OriginPackageIdent>>
+    where M: Routable,
+          F: Fn(&str) -> M
+{
+    for target in targets.iter() {
+        match route_message::<M, OriginPackageIdent>(req, &request_for(target.as_str())) {
+            Ok(id) => return Ok(Some(id)),
+            Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => continue,
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(None)
+}
+
+/// Returns the newest package among the releases of `ident` whose version satisfies
+/// `version_req`, preferring one built for the first of `targets` and otherwise taking the newest
+/// built for the next of them. The releases are listed to find the matching versions, and
+/// originsrv is then asked for the latest release of each version for a target, newest version
+/// first, so that no more than one request per version and target is made.
+fn latest_matching_package(req: &mut Request,
+                           ident: &OriginPackageIdent,
+                           version_req: &VersionReq,
+                           targets: &[String])
+                           -> RouteResult<Option<OriginPackage>> {
+    let mut versions: Vec<PackageIdent> = Vec::new();
+    let mut start = 0;
+    loop {
+        let mut request = OriginPackageListRequest::new();
+        request.set_ident(ident.clone());
+        request.set_start(start);
+        request.set_stop(start + PAGINATION_RANGE_MAX as u64 - 1);
+        let page = try!(route_message::<OriginPackageListRequest,
+                                        OriginPackageListResponse>(req, &request));
+        start += page.get_idents().len() as u64;
+        versions.extend(page.get_idents()
+                            .iter()
+                            .filter(|p| p.satisfies(ident))
+                            .map(|p| {
+                                     let mut version: PackageIdent = p.clone().into();
+                                     version.release = None;
+                                     version
+                                 })
+                            .filter(|p| p.matches_req(version_req)));
+        if page.get_idents().is_empty() || start >= page.get_count() {
+            break;
+        }
+    }
+    versions.sort();
+    versions.dedup();
+    for target in targets.iter() {
+        for version in versions.iter().rev() {
+            let mut request = OriginPackageLatestGet::new();
+            request.set_ident(version.clone().into());
+            request.set_target(target.clone());
+            match route_message::<OriginPackageLatestGet, OriginPackageIdent>(req, &request) {
+                Ok(id) => {
+                    let mut request = OriginPackageGet::new();
+                    request.set_ident(id);
+                    return route_message::<OriginPackageGet, OriginPackage>(req, &request)
+                               .map(Some);
+                }
+                Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => continue,
+                Err(err) => return Err(err),
+            }
+        }
+    }
+    Ok(None)
+}
+
 fn search_packages(req: &mut Request) -> IronResult<Response> {
     let mut request = OriginPackageSearchRequest::new();
     let (start, stop) = match extract_pagination(req) {
@@ -1469,7 +1738,7 @@ fn search_packages(req: &mut Request) -> IronResult<Response> {
     }
 }
 
-fn render_package(pkg: &OriginPackage, should_cache: bool) -> IronResult<Response> {
+pub fn render_package(pkg: &OriginPackage, should_cache: bool) -> IronResult<Response> {
     let body = serde_json::to_string(&pkg).unwrap();
     let mut response = Response::with((status::Ok, body));
     response
@@ -1576,7 +1845,7 @@ fn promote_package(req: &mut Request) -> IronResult<Response> {
     }
 }
 
-fn ident_from_params(params: &Params) -> OriginPackageIdent {
+pub fn ident_from_params(params: &Params) -> OriginPackageIdent {
     let mut ident = OriginPackageIdent::new();
     ident.set_origin(params.find("origin").unwrap().to_string());
     ident.set_name(params.find("pkg").unwrap().to_string());
@@ -1589,11 +1858,12 @@ fn ident_from_params(params: &Params) -> OriginPackageIdent {
     ident
 }
 
-fn target_from_headers(user_agent_header: &UserAgent) -> result::Result<PackageTarget, Response> {
+pub fn target_from_headers(user_agent_header: &UserAgent)
+                           -> result::Result<PackageTarget, Response> {
     let user_agent = user_agent_header.as_str();
     debug!("Headers = {}", &user_agent);
 
-    let user_agent_regex = Regex::new(r"(?P<client>\.*)\s\((?P<target>\w+-\w+); (?P<kernel>.*)\)")
+    let user_agent_regex = Regex::new(r"(?P<client>\.*)\s\((?P<target>\w+-[\w-]+); (?P<kernel>.*)\)")
         .unwrap();
     let user_agent_capture = user_agent_regex
         .captures(user_agent)
@@ -1627,7 +1897,7 @@ fn extract_pagination(req: &mut Request) -> result::Result<(isize, isize), Respo
     Ok((offset, offset + PAGINATION_RANGE_MAX - 1))
 }
 
-fn extract_query_value(key: &str, req: &mut Request) -> Option<String> {
+pub fn extract_query_value(key: &str, req: &mut Request) -> Option<String> {
     match req.get_ref::<UrlEncodedQuery>() {
         Ok(ref map) => {
             for (k, v) in map.iter() {
@@ -1644,13 +1914,13 @@ fn extract_query_value(key: &str, req: &mut Request) -> Option<String> {
     }
 }
 
-fn do_cache_response(response: &mut Response) {
+pub fn do_cache_response(response: &mut Response) {
     response
         .headers
         .set(CacheControl(format!("public, max-age={}", ONE_YEAR_IN_SECS)));
 }
 
-fn dont_cache_response(response: &mut Response) {
+pub fn dont_cache_response(response: &mut Response) {
     response
         .headers
         .set(CacheControl(format!("private, no-cache, no-store")));
@@ -1722,6 +1992,10 @@ pub fn routes<M: BeforeMiddleware + Clone>(insecure: bool, basic: M, worker: M)
                 XHandler::new(upload_origin_key).before(basic.clone())
             }
         },
+        origin_revocations: get "/origins/:origin/revocations" => download_origin_revocations,
+        origin_revocations_update: post "/origins/:origin/revocations" => {
+            XHandler::new(upload_origin_revocations).before(basic.clone())
+        },
         origin_secret_key_create: post "/origins/:origin/secret_keys/:revision" => {
             XHandler::new(upload_origin_secret_key).before(basic.clone())
         },
@@ -1804,7 +2078,7 @@ mod test {
 
     use std::env;
     use std::fs::File;
-    use std::io::Cursor;
+    use std::io::{Cursor, Read};
     use std::path::PathBuf;
 
     use super::*;
@@ -2497,6 +2771,120 @@ mod test {
         assert_eq!(package_req.get_ident().to_string(), ident.to_string());
     }
 
+    #[test]
+    fn show_package_latest_matching_version_req() {
+        let mut show_broker: TestableBroker = Default::default();
+
+        let mut pkg_res = OriginPackageListResponse::new();
+        pkg_res.set_start(0);
+        pkg_res.set_stop(2);
+        pkg_res.set_count(3);
+        let mut packages = protobuf::RepeatedField::new();
+        for &(version, release) in [("1.10.0-rc.1", "20170101010101"),
+                                    ("1.9.2", "20170101010102"),
+                                    ("2.0.0", "20170101010103")]
+                    .iter() {
+            let mut ident = OriginPackageIdent::new();
+            ident.set_origin("org".to_string());
+            ident.set_name("name".to_string());
+            ident.set_version(version.to_string());
+            ident.set_release(release.to_string());
+            packages.push(ident);
+        }
+        pkg_res.set_idents(packages);
+        show_broker.setup::<OriginPackageListRequest, OriginPackageListResponse>(&pkg_res);
+
+        let mut package = OriginPackage::new();
+        package.set_ident(OriginPackageIdent::from_str("org/name/1.10.0-rc.1/20170101010101").unwrap());
+        package.set_checksum("checksum".to_string());
+        package.set_manifest("manifest".to_string());
+        package.set_config("config".to_string());
+        package.set_target("x86_64-linux".to_string());
+        show_broker.setup::<OriginPackageGet, OriginPackage>(&package);
+        show_broker.setup::<OriginPackageLatestGet, OriginPackageIdent>(package.get_ident());
+
+        let mut headers = Headers::new();
+        headers.set(UserAgent("hab/0.20.0-dev/20170326090935 (x86_64-linux; 9.9.9)".to_string()));
+        let (response, msgs) =
+            iron_request(method::Get,
+                         "http://localhost/pkgs/org/name/latest?version_req=%3E%3D1.2%2C%3C2",
+                         &mut Vec::new(),
+                         headers,
+                         show_broker);
+
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::Ok));
+
+        // 1.10.0-rc.1 sorts above 1.9.2 and is the first version asked for
+        let latest_req = msgs.get::<OriginPackageLatestGet>().unwrap();
+        assert_eq!(latest_req.get_ident().to_string(),
+                   "org/name/1.10.0-rc.1".to_string());
+        assert_eq!(latest_req.get_target().to_string(),
+                   "x86_64-linux".to_string());
+        let package_req = msgs.get::<OriginPackageGet>().unwrap();
+        assert_eq!(package_req.get_ident().to_string(),
+                   "org/name/1.10.0-rc.1/20170101010101".to_string());
+        let list_req = msgs.get::<OriginPackageListRequest>().unwrap();
+        assert_eq!(list_req.get_ident().to_string(), "org/name".to_string());
+    }
+
+    #[test]
+    fn show_package_latest_tries_compatible_targets() {
+        let mut show_broker: TestableBroker = Default::default();
+        show_broker.setup_error::<OriginPackageLatestGet>(net::err(ErrCode::ENTITY_NOT_FOUND, ""));
+
+        let mut headers = Headers::new();
+        headers.set(UserAgent("hab/0.20.0-dev/20170326090935 (x86_64-linux; 9.9.9)".to_string()));
+        let (response, msgs) = iron_request(method::Get,
+                                            "http://localhost/pkgs/org/name/latest",
+                                            &mut Vec::new(),
+                                            headers,
+                                            show_broker);
+
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::NotFound));
+
+        // Having found nothing for x86_64-linux, the depot last asked for x86_64-linux-kernel2
+        let latest_req = msgs.get::<OriginPackageLatestGet>().unwrap();
+        assert_eq!(latest_req.get_target().to_string(),
+                   "x86_64-linux-kernel2".to_string());
+    }
+
+    #[test]
+    fn show_package_latest_matching_version_req_tries_compatible_targets() {
+        let mut show_broker: TestableBroker = Default::default();
+
+        let mut pkg_res = OriginPackageListResponse::new();
+        pkg_res.set_start(0);
+        pkg_res.set_stop(1);
+        pkg_res.set_count(2);
+        let mut packages = protobuf::RepeatedField::new();
+        packages.push(OriginPackageIdent::from_str("org/name/1.9.2/20170101010102").unwrap());
+        packages.push(OriginPackageIdent::from_str("org/name/1.9.2/20170101010103").unwrap());
+        pkg_res.set_idents(packages);
+        show_broker.setup::<OriginPackageListRequest, OriginPackageListResponse>(&pkg_res);
+        show_broker.setup_error::<OriginPackageLatestGet>(net::err(ErrCode::ENTITY_NOT_FOUND, ""));
+
+        let mut headers = Headers::new();
+        headers.set(UserAgent("hab/0.20.0-dev/20170326090935 (x86_64-linux; 9.9.9)".to_string()));
+        let (response, msgs) =
+            iron_request(method::Get,
+                         "http://localhost/pkgs/org/name/latest?version_req=%3E%3D1.2",
+                         &mut Vec::new(),
+                         headers,
+                         show_broker);
+
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::NotFound));
+
+        // The one matching version was asked for with each target in turn
+        let latest_req = msgs.get::<OriginPackageLatestGet>().unwrap();
+        assert_eq!(latest_req.get_ident().to_string(), "org/name/1.9.2".to_string());
+        assert_eq!(latest_req.get_target().to_string(),
+                   "x86_64-linux-kernel2".to_string());
+        assert!(msgs.get::<OriginPackageGet>().is_err());
+    }
+
     #[test]
     fn show_package_latest_with_channel() {
         let mut show_broker: TestableBroker = Default::default();
@@ -2818,4 +3206,54 @@ mod test {
         assert_eq!(delete.get_id(), 6000);
         assert_eq!(delete.get_origin_id(), 5000);
     }
+
+    fn resume_from(start: u64, etag: Option<&str>) -> Headers {
+        let mut headers = Headers::new();
+        headers.set(Range::Bytes(vec![ByteRangeSpec::AllFrom(start)]));
+        if let Some(etag) = etag {
+            headers.set(IfRange(etag.to_string()));
+        }
+        headers
+    }
+
+    #[test]
+    fn range_start_resumes_within_the_file() {
+        assert_eq!(range_start(&resume_from(10, None), 100, "abc"), Some(10));
+        assert_eq!(range_start(&resume_from(10, Some("abc")), 100, "abc"), Some(10));
+        assert_eq!(range_start(&Headers::new(), 100, "abc"), None);
+        assert_eq!(range_start(&resume_from(100, None), 100, "abc"), None);
+    }
+
+    #[test]
+    fn range_start_serves_everything_for_another_etag() {
+        assert_eq!(range_start(&resume_from(10, Some("def")), 100, "abc"), None);
+    }
+
+    #[test]
+    fn range_start_serves_everything_for_other_ranges() {
+        let mut headers = Headers::new();
+        headers.set(Range::Bytes(vec![ByteRangeSpec::FromTo(0, 10)]));
+        assert_eq!(range_start(&headers, 100, "abc"), None);
+        headers.set(Range::Bytes(vec![ByteRangeSpec::AllFrom(0), ByteRangeSpec::AllFrom(5)]));
+        assert_eq!(range_start(&headers, 100, "abc"), None);
+    }
+
+    #[test]
+    fn partial_response_serves_the_rest_of_the_file() {
+        let path = hart_file("core-cacerts-2017.01.17-20170209064044-x86_64-windows.hart");
+        let mut whole = Vec::new();
+        File::open(&path).unwrap().read_to_end(&mut whole).unwrap();
+        let len = whole.len() as u64;
+
+        let response = partial_response(&path, 100, len).unwrap();
+        assert_eq!(response.status, Some(status::PartialContent));
+        assert_eq!(response.headers.get::<ContentLength>(),
+                   Some(&ContentLength(len - 100)));
+        assert_eq!(response.headers.get::<ContentRange>(),
+                   Some(&ContentRange(ContentRangeSpec::Bytes {
+                                          range: Some((100, len - 1)),
+                                          instance_length: Some(len),
+                                      })));
+        assert_eq!(response::extract_body_to_bytes(response), &whole[100..]);
+    }
 }
@@ -25,6 +25,7 @@ use protocol::net::NetOk;
 use protocol::originsrv::Pageable;
 use postgres;
 use protobuf;
+use hab_core::package::{Identifiable, PackageIdent};
 
 use config::Config;
 use error::{Result, Error};
@@ -541,33 +542,23 @@ impl DataStore {
                                      opc: &originsrv::OriginPackageLatestGet)
                                      -> Result<Option<originsrv::OriginPackageIdent>> {
         let conn = self.pool.get(opc)?;
-        let rows = conn.query("SELECT * FROM get_origin_package_latest_v1($1, $2)",
+        let rows = conn.query("SELECT * FROM get_origin_packages_for_target_v1($1, $2)",
                               &[&opc.get_ident().to_string(), &opc.get_target()])
             .map_err(Error::OriginPackageLatestGet)?;
-        if rows.len() != 0 {
-            let row = rows.get(0);
-            Ok(Some(self.row_to_origin_package_ident(&row)))
-        } else {
-            Ok(None)
-        }
+        Ok(self.latest_origin_package_ident(&rows, opc.get_ident()))
     }
 
     pub fn get_origin_channel_package_latest(&self,
                                              ocpg: &originsrv::OriginChannelPackageLatestGet)
                                              -> Result<Option<originsrv::OriginPackageIdent>> {
         let conn = self.pool.get(ocpg)?;
-        let rows = conn.query("SELECT * FROM get_origin_channel_package_latest_v1($1, $2, $3, $4)",
+        let rows = conn.query("SELECT * FROM get_origin_channel_packages_for_target_v1($1, $2, $3, $4)",
                               &[&ocpg.get_ident().get_origin(),
                                 &ocpg.get_name(),
                                 &ocpg.get_ident().to_string(),
                                 &ocpg.get_target()])
             .map_err(Error::OriginChannelPackageLatestGet)?;
-        if rows.len() != 0 {
-            let row = rows.get(0);
-            Ok(Some(self.row_to_origin_package_ident(&row)))
-        } else {
-            Ok(None)
-        }
+        Ok(self.latest_origin_package_ident(&rows, ocpg.get_ident()))
     }
 
     pub fn list_origin_package_for_origin(&self,
@@ -737,6 +728,21 @@ impl DataStore {
         package
     }
 
+    /// Picks the newest of the packages in `rows` that satisfy `ident`. Versions are compared as
+    /// `PackageIdent`s do, since the database can only sort them as strings.
+    fn latest_origin_package_ident(&self,
+                                   rows: &Rows,
+                                   ident: &originsrv::OriginPackageIdent)
+                                   -> Option<originsrv::OriginPackageIdent> {
+        rows.iter()
+            .map(|row| self.row_to_origin_package_ident(&row))
+            .filter(|p| p.satisfies(ident))
+            .max_by(|a, b| {
+                        let a: PackageIdent = a.clone().into();
+                        a.cmp(&b.clone().into())
+                    })
+    }
+
     fn row_to_origin_package_ident(&self,
                                    row: &postgres::rows::Row)
                                    -> originsrv::OriginPackageIdent {
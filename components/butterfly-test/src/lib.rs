@@ -13,11 +13,14 @@
 // limitations under the License.
 
 extern crate env_logger;
+extern crate rand;
 extern crate time;
 #[macro_use]
 extern crate habitat_butterfly;
 extern crate habitat_core;
 
+pub mod sim;
+
 use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
 use std::thread;
 use std::ops::{Deref, DerefMut, Range};
@@ -25,8 +28,6 @@ use std::path::PathBuf;
 use std::time::Duration;
 use std::str::FromStr;
 
-use time::SteadyTime;
-
 use habitat_butterfly::server::{Server, Suitability};
 use habitat_butterfly::member::{Member, Health};
 use habitat_butterfly::server::timing::Timing;
@@ -39,6 +40,8 @@ use habitat_core::package::{Identifiable, PackageIdent};
 use habitat_core::crypto::keys::sym_key::SymKey;
 use habitat_butterfly::trace::Trace;
 
+use sim::SimNetwork;
+
 static SERVER_PORT: AtomicUsize = ATOMIC_USIZE_INIT;
 
 #[derive(Debug)]
@@ -50,6 +53,30 @@ impl Suitability for NSuitability {
 }
 
 pub fn start_server(name: &str, ring_key: Option<SymKey>, suitability: u64) -> Server {
+    let mut server = new_server(name, ring_key, suitability);
+    server
+        .start(Timing::default())
+        .expect("Cannot start server");
+    server
+}
+
+/// Start a server whose traffic is carried by the simulated `network`, using the network's
+/// `Timing`.
+pub fn start_simulated_server(name: &str,
+                              ring_key: Option<SymKey>,
+                              suitability: u64,
+                              network: &SimNetwork)
+                              -> Server {
+    let mut server = new_server(name, ring_key, suitability);
+    network.add_node(server.swim_addr(), server.gossip_addr());
+    server.set_network(network.clone());
+    server
+        .start(network.timing())
+        .expect("Cannot start server");
+    server
+}
+
+fn new_server(name: &str, ring_key: Option<SymKey>, suitability: u64) -> Server {
     SERVER_PORT.compare_and_swap(0, 6666, Ordering::Relaxed);
     let swim_port = SERVER_PORT.fetch_add(1, Ordering::Relaxed);
     let gossip_port = SERVER_PORT.fetch_add(1, Ordering::Relaxed);
@@ -58,19 +85,15 @@ pub fn start_server(name: &str, ring_key: Option<SymKey>, suitability: u64) -> S
     let mut member = Member::default();
     member.set_swim_port(swim_port as i32);
     member.set_gossip_port(gossip_port as i32);
-    let mut server = Server::new(&listen_swim[..],
-                                 &listen_gossip[..],
-                                 member,
-                                 Trace::default(),
-                                 ring_key,
-                                 Some(String::from(name)),
-                                 None::<PathBuf>,
-                                 Box::new(NSuitability(suitability)))
-            .unwrap();
-    server
-        .start(Timing::default())
-        .expect("Cannot start server");
-    server
+    Server::new(&listen_swim[..],
+                &listen_gossip[..],
+                member,
+                Trace::default(),
+                ring_key,
+                Some(String::from(name)),
+                None::<PathBuf>,
+                Box::new(NSuitability(suitability)))
+            .unwrap()
 }
 
 pub fn member_from_server(server: &Server) -> Member {
@@ -88,6 +111,7 @@ pub fn member_from_server(server: &Server) -> Member {
 #[derive(Debug)]
 pub struct SwimNet {
     pub members: Vec<Server>,
+    pub network: Option<SimNetwork>,
 }
 
 impl Deref for SwimNet {
@@ -111,7 +135,10 @@ impl SwimNet {
         for x in 0..count {
             members.push(start_server(&format!("{}", x), None, suitabilities[x]));
         }
-        SwimNet { members: members }
+        SwimNet {
+            members: members,
+            network: None,
+        }
     }
 
     pub fn new(count: usize) -> SwimNet {
@@ -125,7 +152,47 @@ impl SwimNet {
             let rk = ring_key.clone();
             members.push(start_server(&format!("{}", x), rk, 0));
         }
-        SwimNet { members: members }
+        SwimNet {
+            members: members,
+            network: None,
+        }
+    }
+
+    /// Start `count` servers whose traffic is carried by the simulated `network`. Member `n` is
+    /// node `n` on the network.
+    pub fn new_simulated(count: usize, network: SimNetwork) -> SwimNet {
+        let mut members = Vec::with_capacity(count);
+        for x in 0..count {
+            members.push(start_simulated_server(&format!("{}", x), None, 0, &network));
+        }
+        SwimNet {
+            members: members,
+            network: Some(network),
+        }
+    }
+
+    /// The simulated network carrying this ring's traffic.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ring was not created with `new_simulated`.
+    pub fn simulated(&self) -> &SimNetwork {
+        self.network
+            .as_ref()
+            .expect("This SwimNet is not running on a simulated network")
+    }
+
+    /// Partition the simulated network between two ranges of members, dropping their traffic on
+    /// the wire rather than blacklisting it in the servers.
+    pub fn sim_partition(&self, left_range: Range<usize>, right_range: Range<usize>) {
+        let left: Vec<usize> = left_range.collect();
+        let right: Vec<usize> = right_range.collect();
+        self.simulated().partition(&left, &right);
+    }
+
+    /// Remove every partition from the simulated network.
+    pub fn sim_heal(&self) {
+        self.simulated().heal();
     }
 
     pub fn connect(&mut self, from_entry: usize, to_entry: usize) {
@@ -439,10 +506,13 @@ impl SwimNet {
 
     #[allow(dead_code)]
     pub fn wait_protocol_period(&self) {
-        let timing = Timing::default();
+        let timing = match self.network {
+            Some(ref network) => network.timing(),
+            None => Timing::default(),
+        };
         let next_period = timing.next_protocol_period();
         loop {
-            if SteadyTime::now() <= next_period {
+            if timing.now() <= next_period {
                 thread::sleep(Duration::from_millis(100));
             } else {
                 return;
@@ -0,0 +1,665 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An in-process, simulated `Network` for running a ring of `Server`s under adverse conditions.
+//!
+//! Every SWIM datagram and gossip payload is routed through a shared `SimNetwork`, which can drop
+//! it, delay it, or refuse to deliver it because the two members are partitioned. Delays are
+//! randomised by up to the link's jitter, which reorders traffic between members. Drop and jitter
+//! decisions are drawn from a generator seeded by the caller, so a given seed produces the same
+//! sequence of decisions.
+//!
+//! Delays are measured on the network's `SimClock`, which is also the clock its servers run on:
+//! every timeout, sleep and socket read of a simulated server waits on it. The clock never follows
+//! the host's. It only moves forward once every server thread is waiting, and then straight to the
+//! next packet or timeout that is due. A slow link therefore holds traffic back exactly as long as
+//! its latency, however busy the host running the test is, and a ring never waits out its protocol
+//! periods in real time.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::time::Duration;
+
+use rand::{Rng, SeedableRng, XorShiftRng};
+use time::{Duration as TimeDuration, SteadyTime};
+
+use habitat_butterfly::error::Result;
+use habitat_butterfly::network::{GossipReceiver, GossipSender, Network, SwimSocket};
+use habitat_butterfly::server::timing::{Clock, Timing};
+
+/// How long a simulated SWIM socket blocks on a read, mirroring the real UDP socket.
+const SWIM_RECV_TIMEOUT_MS: u64 = 1000;
+
+/// The behaviour of traffic travelling from one member to another.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkConditions {
+    /// The probability, between 0.0 and 1.0, that any single message is dropped.
+    pub loss: f64,
+    /// The fixed delay before a message is delivered.
+    pub latency: Duration,
+    /// An additional, uniformly random delay of up to this much. Non-zero jitter reorders
+    /// messages.
+    pub jitter: Duration,
+}
+
+impl LinkConditions {
+    pub fn new(loss: f64, latency: Duration, jitter: Duration) -> LinkConditions {
+        LinkConditions {
+            loss: loss,
+            latency: latency,
+            jitter: jitter,
+        }
+    }
+}
+
+impl Default for LinkConditions {
+    fn default() -> LinkConditions {
+        LinkConditions::new(0.0, Duration::from_millis(0), Duration::from_millis(0))
+    }
+}
+
+/// Counters for everything the simulated network has handled.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SimStats {
+    pub sent: u64,
+    pub dropped: u64,
+    pub partitioned: u64,
+    pub delivered: u64,
+}
+
+/// The virtual clock of a `SimNetwork`, counting simulated milliseconds since the network was
+/// created, and measuring them in units of its `Timing`'s protocol and gossip periods. Clones share
+/// the same count.
+#[derive(Clone)]
+pub struct SimClock {
+    inner: Arc<SimInner>,
+    started: SteadyTime,
+    protocol_period_ms: i64,
+    gossip_period_ms: i64,
+}
+
+impl fmt::Debug for SimClock {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SimClock {{ elapsed_ms: {} }}", self.elapsed_ms())
+    }
+}
+
+impl SimClock {
+    /// Move the clock forward by `duration`, delivering the traffic and ending the waits that
+    /// fall due on the way.
+    pub fn advance(&self, duration: Duration) {
+        let mut state = self.inner.lock();
+        let to = state.now_ms + duration_ms(&duration);
+        state.advance_to(to);
+        state.advance_while_idle();
+        self.inner.changed.notify_all();
+    }
+
+    /// Simulated milliseconds since the network was created.
+    pub fn elapsed_ms(&self) -> i64 {
+        self.inner.lock().now_ms as i64
+    }
+
+    /// The number of whole SWIM protocol periods that have elapsed.
+    pub fn protocol_periods(&self) -> i64 {
+        self.elapsed_ms() / self.protocol_period_ms
+    }
+
+    /// The number of whole gossip periods that have elapsed.
+    pub fn gossip_periods(&self) -> i64 {
+        self.elapsed_ms() / self.gossip_period_ms
+    }
+
+    /// A `Duration` of `count` protocol periods.
+    pub fn protocol_period_duration(&self, count: u32) -> Duration {
+        Duration::from_millis(self.protocol_period_ms as u64) * count
+    }
+}
+
+impl Clock for SimClock {
+    fn now(&self) -> SteadyTime {
+        self.started + TimeDuration::milliseconds(self.elapsed_ms())
+    }
+
+    fn sleep(&self, duration: Duration) {
+        let _ = self.inner.wait(None, Some(duration_ms(&duration)));
+    }
+
+    fn add_thread(&self) {
+        self.inner.lock().threads += 1;
+    }
+}
+
+#[derive(Debug)]
+enum Packet {
+    Swim(SocketAddr, SocketAddr, Vec<u8>),
+    Gossip(SocketAddr, Vec<u8>),
+}
+
+#[derive(Debug)]
+struct Pending {
+    /// The time on the `SimClock` the packet is due.
+    deliver_at: u64,
+    seq: u64,
+    packet: Packet,
+}
+
+impl PartialEq for Pending {
+    fn eq(&self, other: &Pending) -> bool {
+        self.deliver_at == other.deliver_at && self.seq == other.seq
+    }
+}
+
+impl Eq for Pending {}
+
+impl PartialOrd for Pending {
+    fn partial_cmp(&self, other: &Pending) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Pending {
+    // Reversed, so that the `BinaryHeap` pops the earliest delivery first.
+    fn cmp(&self, other: &Pending) -> Ordering {
+        match other.deliver_at.cmp(&self.deliver_at) {
+            Ordering::Equal => other.seq.cmp(&self.seq),
+            ordering => ordering,
+        }
+    }
+}
+
+/// What a server thread blocked on the network is waiting to receive.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mailbox {
+    Swim(SocketAddr),
+    Gossip(SocketAddr),
+}
+
+/// A server thread blocked on the network, until its mailbox receives something or its deadline
+/// passes.
+#[derive(Debug)]
+struct Waiter {
+    mailbox: Option<Mailbox>,
+    deadline: Option<u64>,
+}
+
+struct SimState {
+    rng: XorShiftRng,
+    default_link: LinkConditions,
+    links: HashMap<(usize, usize), LinkConditions>,
+    partitions: HashSet<(usize, usize)>,
+    nodes: HashMap<SocketAddr, usize>,
+    node_count: usize,
+    swim_boxes: HashMap<SocketAddr, VecDeque<(Vec<u8>, SocketAddr)>>,
+    gossip_boxes: HashMap<SocketAddr, VecDeque<Vec<u8>>>,
+    queue: BinaryHeap<Pending>,
+    seq: u64,
+    stats: SimStats,
+    /// Simulated milliseconds since the network was created.
+    now_ms: u64,
+    /// The number of server threads that wait on this network's clock or sockets.
+    threads: usize,
+    /// The threads blocked right now. A thread is woken by removing its entry.
+    waiters: HashMap<u64, Waiter>,
+    next_waiter: u64,
+}
+
+impl SimState {
+    /// The conditions between two addresses, or `None` if they are partitioned.
+    fn link(&self, from: &SocketAddr, to: &SocketAddr) -> Option<LinkConditions> {
+        match (self.nodes.get(from), self.nodes.get(to)) {
+            (Some(f), Some(t)) => {
+                if self.partitions.contains(&(*f, *t)) {
+                    return None;
+                }
+                Some(self.links
+                         .get(&(*f, *t))
+                         .unwrap_or(&self.default_link)
+                         .clone())
+            }
+            _ => Some(self.default_link.clone()),
+        }
+    }
+
+    fn delay(&mut self, link: &LinkConditions) -> Duration {
+        let jitter_ms = duration_ms(&link.jitter);
+        if jitter_ms == 0 {
+            link.latency
+        } else {
+            link.latency + Duration::from_millis(self.rng.gen_range(0, jitter_ms + 1))
+        }
+    }
+
+    fn has_mail(&self, mailbox: Mailbox) -> bool {
+        match mailbox {
+            Mailbox::Swim(addr) => self.swim_boxes.get(&addr).map_or(false, |b| !b.is_empty()),
+            Mailbox::Gossip(addr) => {
+                self.gossip_boxes
+                    .get(&addr)
+                    .map_or(false, |b| !b.is_empty())
+            }
+        }
+    }
+
+    fn deliver(&mut self, packet: Packet) {
+        let mailbox = match packet {
+            Packet::Swim(from, to, bytes) => {
+                match self.swim_boxes.get_mut(&to) {
+                    Some(swim_box) => {
+                        swim_box.push_back((bytes, from));
+                        Some(Mailbox::Swim(to))
+                    }
+                    None => None,
+                }
+            }
+            Packet::Gossip(to, bytes) => {
+                match self.gossip_boxes.get_mut(&to) {
+                    Some(gossip_box) => {
+                        gossip_box.push_back(bytes);
+                        Some(Mailbox::Gossip(to))
+                    }
+                    None => None,
+                }
+            }
+        };
+        match mailbox {
+            Some(mailbox) => {
+                self.stats.delivered += 1;
+                let woken: Vec<u64> = self.waiters
+                    .iter()
+                    .filter(|&(_, waiter)| waiter.mailbox == Some(mailbox))
+                    .map(|(id, _)| *id)
+                    .collect();
+                for id in woken {
+                    self.waiters.remove(&id);
+                }
+            }
+            None => self.stats.dropped += 1,
+        }
+    }
+
+    /// Deliver every queued packet that is due.
+    fn deliver_due(&mut self) {
+        loop {
+            match self.queue.peek() {
+                Some(pending) if pending.deliver_at <= self.now_ms => {}
+                _ => return,
+            }
+            if let Some(pending) = self.queue.pop() {
+                self.deliver(pending.packet);
+            }
+        }
+    }
+
+    /// Move the clock forward to `ms`, unless it is already past it, then wake every thread whose
+    /// deadline has passed and deliver every packet that is due.
+    fn advance_to(&mut self, ms: u64) {
+        if ms > self.now_ms {
+            self.now_ms = ms;
+        }
+        let now = self.now_ms;
+        let woken: Vec<u64> = self.waiters
+            .iter()
+            .filter(|&(_, waiter)| waiter.deadline.map_or(false, |deadline| deadline <= now))
+            .map(|(id, _)| *id)
+            .collect();
+        for id in woken {
+            self.waiters.remove(&id);
+        }
+        self.deliver_due();
+    }
+
+    /// The earliest time a queued packet or a waiting thread's deadline is due.
+    fn next_due(&self) -> Option<u64> {
+        let mut next = self.queue.peek().map(|pending| pending.deliver_at);
+        for waiter in self.waiters.values() {
+            if let Some(deadline) = waiter.deadline {
+                next = match next {
+                    Some(ms) if ms <= deadline => Some(ms),
+                    _ => Some(deadline),
+                };
+            }
+        }
+        next
+    }
+
+    /// While every server thread is waiting, move the clock straight to whatever is due next.
+    /// Each step either wakes a thread or delivers a packet, so this stops as soon as a thread has
+    /// work to do, or nothing is left to happen.
+    fn advance_while_idle(&mut self) {
+        while self.threads > 0 && self.waiters.len() >= self.threads {
+            match self.next_due() {
+                Some(ms) => self.advance_to(ms),
+                None => return,
+            }
+        }
+    }
+}
+
+struct SimInner {
+    state: Mutex<SimState>,
+    changed: Condvar,
+}
+
+impl SimInner {
+    fn lock(&self) -> MutexGuard<SimState> {
+        self.state
+            .lock()
+            .expect("Simulated network lock is poisoned")
+    }
+
+    /// Block the calling thread until `mailbox` has something in it, or `timeout_ms` simulated
+    /// milliseconds have passed. Returns the locked state, so the caller can take what arrived.
+    fn wait(&self, mailbox: Option<Mailbox>, timeout_ms: Option<u64>) -> MutexGuard<SimState> {
+        let mut state = self.lock();
+        let deadline = timeout_ms.map(|ms| state.now_ms + ms);
+        loop {
+            if let Some(mailbox) = mailbox {
+                if state.has_mail(mailbox) {
+                    return state;
+                }
+            }
+            if let Some(deadline) = deadline {
+                if state.now_ms >= deadline {
+                    return state;
+                }
+            }
+            let id = state.next_waiter;
+            state.next_waiter += 1;
+            state
+                .waiters
+                .insert(id,
+                        Waiter {
+                            mailbox: mailbox,
+                            deadline: deadline,
+                        });
+            state.advance_while_idle();
+            self.changed.notify_all();
+            while state.waiters.contains_key(&id) {
+                state = self.changed
+                    .wait(state)
+                    .expect("Simulated network lock is poisoned");
+            }
+        }
+    }
+}
+
+/// A simulated network shared by every `Server` in a test ring.
+#[derive(Clone)]
+pub struct SimNetwork {
+    inner: Arc<SimInner>,
+    clock: SimClock,
+    timing: Timing,
+}
+
+impl fmt::Debug for SimNetwork {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SimNetwork {{ stats: {:?} }}", self.stats())
+    }
+}
+
+impl SimNetwork {
+    /// Create a new simulated network with perfect links, whose loss and jitter decisions are
+    /// derived from `seed`, and whose servers run on its clock with the periods of `timing`.
+    pub fn new(seed: u32, timing: Timing) -> SimNetwork {
+        let state = SimState {
+            rng: XorShiftRng::from_seed([seed, 0x193a_6754, 0xa8a7_d469, 0x9783_0e05]),
+            default_link: LinkConditions::default(),
+            links: HashMap::new(),
+            partitions: HashSet::new(),
+            nodes: HashMap::new(),
+            node_count: 0,
+            swim_boxes: HashMap::new(),
+            gossip_boxes: HashMap::new(),
+            queue: BinaryHeap::new(),
+            seq: 0,
+            stats: SimStats::default(),
+            now_ms: 0,
+            threads: 0,
+            waiters: HashMap::new(),
+            next_waiter: 0,
+        };
+        let inner = Arc::new(SimInner {
+                                 state: Mutex::new(state),
+                                 changed: Condvar::new(),
+                             });
+        let clock = SimClock {
+            inner: inner.clone(),
+            started: SteadyTime::now(),
+            protocol_period_ms: timing.protocol_period_ms(),
+            gossip_period_ms: timing.gossip_period_ms,
+        };
+        let timing = timing.with_clock(Arc::new(clock.clone()));
+        SimNetwork {
+            inner: inner,
+            clock: clock,
+            timing: timing,
+        }
+    }
+
+    /// The clock for this network.
+    pub fn clock(&self) -> &SimClock {
+        &self.clock
+    }
+
+    /// The `Timing` servers on this network must be started with, which runs on its clock.
+    pub fn timing(&self) -> Timing {
+        self.timing.clone()
+    }
+
+    /// Register a member listening on `swim_addr` and `gossip_addr`, returning its node number.
+    /// Link conditions and partitions are expressed in node numbers.
+    pub fn add_node(&self, swim_addr: SocketAddr, gossip_addr: SocketAddr) -> usize {
+        let mut state = self.lock();
+        let node = state.node_count;
+        state.node_count += 1;
+        state.nodes.insert(swim_addr, node);
+        state.nodes.insert(gossip_addr, node);
+        node
+    }
+
+    /// Set the conditions for every link without a more specific setting.
+    pub fn set_default_link(&self, conditions: LinkConditions) {
+        self.lock().default_link = conditions;
+    }
+
+    /// Set the conditions for traffic flowing from node `from` to node `to`.
+    pub fn set_link(&self, from: usize, to: usize, conditions: LinkConditions) {
+        self.lock().links.insert((from, to), conditions);
+    }
+
+    /// Set the conditions for traffic flowing in both directions between two nodes.
+    pub fn set_link_both(&self, left: usize, right: usize, conditions: LinkConditions) {
+        let mut state = self.lock();
+        state.links.insert((left, right), conditions.clone());
+        state.links.insert((right, left), conditions);
+    }
+
+    /// Drop all traffic between every node in `left` and every node in `right`.
+    pub fn partition(&self, left: &[usize], right: &[usize]) {
+        let mut state = self.lock();
+        for l in left.iter() {
+            for r in right.iter() {
+                if l == r {
+                    continue;
+                }
+                state.partitions.insert((*l, *r));
+                state.partitions.insert((*r, *l));
+            }
+        }
+    }
+
+    /// Drop traffic flowing from node `from` to node `to`, but not the other way.
+    pub fn partition_one_way(&self, from: usize, to: usize) {
+        self.lock().partitions.insert((from, to));
+    }
+
+    /// Remove every partition.
+    pub fn heal(&self) {
+        self.lock().partitions.clear();
+    }
+
+    /// A snapshot of the traffic counters.
+    pub fn stats(&self) -> SimStats {
+        self.lock().stats.clone()
+    }
+
+    fn lock(&self) -> MutexGuard<SimState> {
+        self.inner.lock()
+    }
+
+    fn transmit(&self, from: SocketAddr, to: SocketAddr, packet: Packet) {
+        let mut state = self.lock();
+        state.stats.sent += 1;
+        let link = match state.link(&from, &to) {
+            Some(link) => link,
+            None => {
+                state.stats.partitioned += 1;
+                return;
+            }
+        };
+        if link.loss > 0.0 && state.rng.gen::<f64>() < link.loss {
+            state.stats.dropped += 1;
+            return;
+        }
+        let delay = state.delay(&link);
+        let seq = state.seq;
+        state.seq += 1;
+        let deliver_at = state.now_ms + duration_ms(&delay);
+        state
+            .queue
+            .push(Pending {
+                      deliver_at: deliver_at,
+                      seq: seq,
+                      packet: packet,
+                  });
+        // Traffic without a delay arrives straight away. Push workers are not server threads, so
+        // their traffic may be all that is left for the clock to move on to.
+        state.deliver_due();
+        state.advance_while_idle();
+        self.inner.changed.notify_all();
+    }
+}
+
+impl Network for SimNetwork {
+    fn swim_socket(&self, addr: SocketAddr) -> Result<Box<SwimSocket>> {
+        self.lock().swim_boxes.insert(addr, VecDeque::new());
+        Ok(Box::new(SimSwimSocket {
+                        network: self.clone(),
+                        addr: addr,
+                    }))
+    }
+
+    fn gossip_sender(&self, from: SocketAddr, to: SocketAddr) -> Result<Box<GossipSender>> {
+        Ok(Box::new(SimGossipSender {
+                        network: self.clone(),
+                        from: from,
+                        to: to,
+                    }))
+    }
+
+    fn gossip_receiver(&self, addr: SocketAddr) -> Result<Box<GossipReceiver>> {
+        self.lock().gossip_boxes.insert(addr, VecDeque::new());
+        Ok(Box::new(SimGossipReceiver {
+                        network: self.clone(),
+                        addr: addr,
+                    }))
+    }
+}
+
+struct SimSwimSocket {
+    network: SimNetwork,
+    addr: SocketAddr,
+}
+
+impl SwimSocket for SimSwimSocket {
+    fn send_to(&self, buf: &[u8], addr: SocketAddr) -> io::Result<usize> {
+        self.network
+            .transmit(self.addr, addr, Packet::Swim(self.addr, addr, buf.to_vec()));
+        Ok(buf.len())
+    }
+
+    fn recv_from(&self, buf: &mut [u8]) -> io::Result<(usize, SocketAddr)> {
+        let mut state = self.network
+            .inner
+            .wait(Some(Mailbox::Swim(self.addr)), Some(SWIM_RECV_TIMEOUT_MS));
+        match state
+                  .swim_boxes
+                  .get_mut(&self.addr)
+                  .and_then(|swim_box| swim_box.pop_front()) {
+            Some((bytes, from)) => {
+                // Like UDP, anything that does not fit in the buffer is discarded.
+                let length = if bytes.len() > buf.len() {
+                    buf.len()
+                } else {
+                    bytes.len()
+                };
+                buf[..length].copy_from_slice(&bytes[..length]);
+                Ok((length, from))
+            }
+            None => Err(io::Error::new(io::ErrorKind::WouldBlock, "no datagram received")),
+        }
+    }
+
+    fn try_clone(&self) -> io::Result<Box<SwimSocket>> {
+        Ok(Box::new(SimSwimSocket {
+                        network: self.network.clone(),
+                        addr: self.addr,
+                    }))
+    }
+}
+
+struct SimGossipSender {
+    network: SimNetwork,
+    from: SocketAddr,
+    to: SocketAddr,
+}
+
+impl GossipSender for SimGossipSender {
+    fn send(&self, payload: &[u8]) -> Result<()> {
+        self.network
+            .transmit(self.from, self.to, Packet::Gossip(self.to, payload.to_vec()));
+        Ok(())
+    }
+}
+
+struct SimGossipReceiver {
+    network: SimNetwork,
+    addr: SocketAddr,
+}
+
+impl GossipReceiver for SimGossipReceiver {
+    fn recv(&self) -> Result<Vec<u8>> {
+        loop {
+            let mut state = self.network
+                .inner
+                .wait(Some(Mailbox::Gossip(self.addr)), None);
+            let payload = state
+                .gossip_boxes
+                .get_mut(&self.addr)
+                .and_then(|gossip_box| gossip_box.pop_front());
+            if let Some(payload) = payload {
+                return Ok(payload);
+            }
+        }
+    }
+}
+
+fn duration_ms(duration: &Duration) -> u64 {
+    duration.as_secs() * 1000 + (duration.subsec_nanos() / 1_000_000) as u64
+}
@@ -16,24 +16,23 @@
 
 # cat *.swimtrace | sort | ruby ~/src/habitat/components/swim/bin/trace-sequence.rb >! sequence.txt | java -DPLANTUML_LIMIT_SIZE=81920 -Xmx1024m  -jar ~/Downloads/plantuml.jar -verbose sequence.txt
 
+require 'json'
+
 output = [];
 actors = {};
 
-pattern = Regexp.new('(.*)\^(.*)\^(.*)\^(.*)\^(.*)\^(.*)\^(.*)\^(.*)\^(.*)\^(.*)\^(.*)\^(.*)');
 $stdin.each_line do |line|
-  if match = pattern.match(line)
-    time = match[1]
-    kind = match[2]
-    thread_name = match[3]
-    module_path = match[4]
-    line_no = match[5]
-    server_name = match[6]
-    member_id = match[7]
-    to_member_id = match[8]
-    listening = match[9]
-    to_addr = match[10]
-    swim = match[11]
-    rumor = match[12]
+  begin
+    event = JSON.parse(line)
+  rescue JSON::ParserError
+    event = nil
+  end
+  if event
+    kind = event['kind']
+    member_id = event['member_id']
+    to_member_id = event['to_member_id']
+    swim = event['swim']
+    rumor = event['rumor']
     actors[member_id] = true;
     case kind
     when /^ProbeConfirmed$/
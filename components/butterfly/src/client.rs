@@ -18,11 +18,13 @@
 
 use habitat_core::crypto::SymKey;
 use habitat_core::service::ServiceGroup;
+use protobuf::Message;
 use zmq;
 
 use ZMQ_CONTEXT;
 use message;
-use rumor::Rumor;
+use rumor::{Rumor, MAX_RUMOR_BYTES};
+use rumor::chunk::{self, CHUNK_SIZE};
 use rumor::service_config::ServiceConfig;
 use rumor::service_file::ServiceFile;
 use error::{Result, Error};
@@ -55,6 +57,9 @@ impl Client {
         socket
             .set_sndtimeo(500)
             .expect("Failure to set the ZMQ send timeout");
+        socket
+            .set_ipv6(true)
+            .expect("Failure to enable IPv6 on the ZMQ push socket");
         let to_addr = format!("tcp://{}", addr.to_string());
         try!(socket.connect(&to_addr).map_err(Error::ZmqConnectError));
         Ok(Client {
@@ -76,7 +81,9 @@ impl Client {
         self.send(sc)
     }
 
-    /// Create a service file and send it to the server.
+    /// Create a service file and send it to the server. Bodies larger than a single chunk are sent
+    /// as a rumor that names their chunks, followed by the chunks; the server only accepts chunks
+    /// named by a service file it holds.
     pub fn send_service_file<S: Into<String>>(&mut self,
                                               service_group: ServiceGroup,
                                               filename: S,
@@ -87,12 +94,30 @@ impl Client {
         let mut sf = ServiceFile::new("butterflyclient", service_group, filename, body);
         sf.set_incarnation(incarnation);
         sf.set_encrypted(encrypted);
-        self.send(sf)
+        let chunks = if sf.get_body().len() > CHUNK_SIZE {
+            try!(sf.chunk())
+        } else {
+            Vec::new()
+        };
+        try!(self.send(sf));
+        for (hash, bytes) in chunks {
+            let proto = chunk::chunk_rumor("butterflyclient", &hash, bytes);
+            try!(self.send_bytes(try!(proto.write_to_bytes())));
+        }
+        Ok(())
     }
 
     /// Send any `Rumor` to the server.
     pub fn send<T: Rumor>(&mut self, rumor: T) -> Result<()> {
         let bytes = try!(rumor.write_to_bytes());
+        self.send_bytes(bytes)
+    }
+
+    /// Send an encoded rumor, refusing anything the server would drop for being too large.
+    fn send_bytes(&mut self, bytes: Vec<u8>) -> Result<()> {
+        if bytes.len() > MAX_RUMOR_BYTES {
+            return Err(Error::RumorTooLarge(bytes.len(), MAX_RUMOR_BYTES));
+        }
         let wire_msg = try!(message::generate_wire(bytes, &self.ring_key));
         self.socket
             .send(&wire_msg, 0)
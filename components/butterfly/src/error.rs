@@ -32,16 +32,23 @@ pub enum Error {
     BadDatFile(PathBuf, io::Error),
     BadMessage(String),
     CannotBind(io::Error),
+    ChunkHashMismatch(String, String),
+    ChunkNotWanted(String),
     DatFileIO(PathBuf, io::Error),
     HabitatCore(habitat_core::error::Error),
+    InvalidTraceMode(String),
     NonExistentRumor(String, String),
     ProtobufError(protobuf::ProtobufError),
+    RumorTooLarge(usize, usize),
     ServiceConfigDecode(String, toml::de::Error),
     ServiceConfigNotUtf8(String, str::Utf8Error),
+    ServiceFileIncomplete(String, usize),
     SocketSetReadTimeout(io::Error),
     SocketSetWriteTimeout(io::Error),
     SocketCloneError,
+    ZmqBindError(zmq::Error),
     ZmqConnectError(zmq::Error),
+    ZmqRecvError(zmq::Error),
     ZmqSendError(zmq::Error),
 }
 
@@ -60,24 +67,44 @@ impl fmt::Display for Error {
             }
             Error::BadMessage(ref err) => format!("Bad Message: {:?}", err),
             Error::CannotBind(ref err) => format!("Cannot bind to port: {:?}", err),
+            Error::ChunkHashMismatch(ref expected, ref actual) => {
+                format!("Chunk content does not match its hash, expected {}, got {}",
+                        expected,
+                        actual)
+            }
+            Error::ChunkNotWanted(ref hash) => {
+                format!("Chunk {} is not named by any service file we hold", hash)
+            }
             Error::DatFileIO(ref path, ref err) => {
                 format!("Error reading or writing to DatFile, {}, {}",
                         path.display(),
                         err)
             }
             Error::HabitatCore(ref err) => format!("{}", err),
+            Error::InvalidTraceMode(ref mode) => {
+                format!("Invalid trace mode '{}', must be one of off, json or metrics",
+                        mode)
+            }
             Error::NonExistentRumor(ref member_id, ref rumor_id) => {
                 format!("Non existent rumor asked to be written to bytes: {} {}",
                         member_id,
                         rumor_id)
             }
             Error::ProtobufError(ref err) => format!("ProtoBuf Error: {}", err),
+            Error::RumorTooLarge(ref size, ref max) => {
+                format!("Rumor is {} bytes, which is larger than the maximum of {} bytes",
+                        size,
+                        max)
+            }
             Error::ServiceConfigDecode(ref sg, ref err) => {
                 format!("Cannot decode service config: group={}, {:?}", sg, err)
             }
             Error::ServiceConfigNotUtf8(ref sg, ref err) => {
                 format!("Cannot read service configuration: group={}, {}", sg, err)
             }
+            Error::ServiceFileIncomplete(ref filename, ref missing) => {
+                format!("Service file {} is still missing {} chunk(s)", filename, missing)
+            }
             Error::SocketSetReadTimeout(ref err) => {
                 format!("Cannot set UDP socket read timeout: {}", err)
             }
@@ -85,7 +112,11 @@ impl fmt::Display for Error {
                 format!("Cannot set UDP socket write timeout: {}", err)
             }
             Error::SocketCloneError => format!("Cannot clone the underlying UDP socket"),
+            Error::ZmqBindError(ref err) => format!("Cannot bind ZMQ socket: {}", err),
             Error::ZmqConnectError(ref err) => format!("Cannot connect ZMQ socket: {}", err),
+            Error::ZmqRecvError(ref err) => {
+                format!("Cannot receive message from ZMQ socket: {}", err)
+            }
             Error::ZmqSendError(ref err) => {
                 format!("Cannot send message through ZMQ socket: {}", err)
             }
@@ -102,16 +133,23 @@ impl error::Error for Error {
             Error::BadDatFile(_, _) => "Unable to decode contents of DatFile",
             Error::BadMessage(_) => "Bad Protobuf Message; should be Ping/Ack/PingReq",
             Error::CannotBind(_) => "Cannot bind to port",
+            Error::ChunkHashMismatch(_, _) => "Chunk content does not match its hash",
+            Error::ChunkNotWanted(_) => "Chunk is not named by any service file",
             Error::DatFileIO(_, _) => "Error reading or writing to DatFile",
             Error::HabitatCore(_) => "Habitat core error",
+            Error::InvalidTraceMode(_) => "Invalid trace mode",
             Error::NonExistentRumor(_, _) => "Cannot write rumor to bytes because it does not exist",
             Error::ProtobufError(ref err) => err.description(),
+            Error::RumorTooLarge(_, _) => "Rumor is larger than the maximum rumor size",
             Error::ServiceConfigDecode(_, _) => "Cannot decode service config into TOML",
             Error::ServiceConfigNotUtf8(_, _) => "Cannot read service config bytes to UTF-8",
+            Error::ServiceFileIncomplete(_, _) => "Service file is still missing chunks",
             Error::SocketSetReadTimeout(_) => "Cannot set UDP socket read timeout",
             Error::SocketSetWriteTimeout(_) => "Cannot set UDP socket write timeout",
             Error::SocketCloneError => "Cannot clone the underlying UDP socket",
+            Error::ZmqBindError(_) => "Cannot bind ZMQ socket",
             Error::ZmqConnectError(_) => "Cannot connect ZMQ socket",
+            Error::ZmqRecvError(_) => "Cannot receive message from ZMQ socket",
             Error::ZmqSendError(_) => "Cannot send message through ZMQ socket",
         }
     }
@@ -46,11 +46,13 @@ extern crate habitat_core;
 extern crate lazy_static;
 #[macro_use]
 extern crate log;
+extern crate net2;
 extern crate protobuf;
 extern crate rand;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
+extern crate serde_json;
 extern crate time;
 extern crate toml;
 extern crate uuid;
@@ -62,6 +64,7 @@ pub mod client;
 pub mod error;
 pub mod member;
 pub mod message;
+pub mod network;
 pub mod rumor;
 pub mod server;
 
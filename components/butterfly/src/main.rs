@@ -68,7 +68,7 @@ fn main() {
     for target in &targets {
         let addr: SocketAddr = target.parse().unwrap();
         let mut member = member::Member::default();
-        member.set_address(format!("{}", addr.ip()));
+        member.set_address(member::address_for(addr.ip()));
         member.set_swim_port(addr.port() as i32);
         member.set_gossip_port(addr.port() as i32);
         server.member_list.add_initial_member(member);
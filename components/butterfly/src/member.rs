@@ -17,7 +17,8 @@
 use std::collections::{hash_map, HashMap};
 use std::fmt;
 use std::iter::IntoIterator;
-use std::net::SocketAddr;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::str::FromStr;
 use std::ops::{Deref, DerefMut};
 use std::result;
 use std::sync::{Arc, RwLock};
@@ -33,6 +34,7 @@ use serde::ser::SerializeStruct;
 use message::swim::{Member as ProtoMember, Membership as ProtoMembership,
                     Membership_Health as ProtoMembership_Health, Rumor_Type};
 use rumor::RumorKey;
+use server::timing::{Clock, HostClock};
 
 /// How many nodes do we target when we need to run PingReq.
 const PINGREQ_TARGETS: usize = 5;
@@ -92,6 +94,28 @@ impl fmt::Display for Health {
     }
 }
 
+/// Returns the string we record as the address of a member that we reached at `ip`.
+///
+/// A dual-stack socket reports IPv4 peers as IPv4-mapped IPv6 addresses (`::ffff:a.b.c.d`). Those
+/// are turned back into plain IPv4 addresses, so that members without IPv6 can still reach them.
+pub fn address_for(ip: IpAddr) -> String {
+    match ip {
+        IpAddr::V6(v6) => {
+            let s = v6.segments();
+            if s[0..5] == [0, 0, 0, 0, 0] && s[5] == 0xffff {
+                let v4 = Ipv4Addr::new((s[6] >> 8) as u8,
+                                       s[6] as u8,
+                                       (s[7] >> 8) as u8,
+                                       s[7] as u8);
+                v4.to_string()
+            } else {
+                v6.to_string()
+            }
+        }
+        IpAddr::V4(v4) => v4.to_string(),
+    }
+}
+
 /// A member in the swim group. Passes most of its functionality along to the internal protobuf
 /// representation.
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -107,9 +131,30 @@ impl Member {
     /// This function panics if the address is un-parseable. In practice, it shouldn't be
     /// un-parseable, since its set from the inbound socket directly.
     pub fn swim_socket_address(&self) -> SocketAddr {
-        let address_str = format!("{}:{}", self.get_address(), self.get_swim_port());
-        match address_str.parse() {
-            Ok(addr) => addr,
+        SocketAddr::new(self.ip(), self.get_swim_port() as u16)
+    }
+
+    /// Returns the gossip socket address of this member.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the address is un-parseable.
+    pub fn gossip_socket_address(&self) -> SocketAddr {
+        SocketAddr::new(self.ip(), self.get_gossip_port() as u16)
+    }
+
+    /// Parses the address of this member, which may be an IPv4 address or an IPv6 address with or
+    /// without enclosing brackets.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the address is un-parseable.
+    fn ip(&self) -> IpAddr {
+        let address = self.get_address()
+            .trim_left_matches('[')
+            .trim_right_matches(']');
+        match IpAddr::from_str(address) {
+            Ok(ip) => ip,
             Err(e) => {
                 panic!("Cannot parse member {:?} address: {}", self, e);
             }
@@ -181,6 +226,7 @@ pub struct MemberList {
     suspect: Arc<RwLock<HashMap<UuidSimple, SteadyTime>>>,
     initial_members: Arc<RwLock<Vec<Member>>>,
     update_counter: Arc<AtomicUsize>,
+    clock: Arc<RwLock<Arc<Clock>>>,
 }
 
 impl Serialize for MemberList {
@@ -213,9 +259,20 @@ impl MemberList {
             suspect: Arc::new(RwLock::new(HashMap::new())),
             initial_members: Arc::new(RwLock::new(Vec::new())),
             update_counter: Arc::new(AtomicUsize::new(0)),
+            clock: Arc::new(RwLock::new(Arc::new(HostClock))),
         }
     }
 
+    /// Stamp the time members become suspect with `clock`. A server sets it to the clock of its
+    /// `Timing` when it starts.
+    pub fn set_clock(&self, clock: Arc<Clock>) {
+        *self.clock.write().expect("Clock lock is poisoned") = clock;
+    }
+
+    fn now(&self) -> SteadyTime {
+        self.clock.read().expect("Clock lock is poisoned").now()
+    }
+
     /// Increment the update counter for this store.
     ///
     /// We don't care if this repeats - it just needs to be unique for any given two states, which
@@ -320,7 +377,7 @@ impl MemberList {
                 self.suspect
                     .write()
                     .expect("Suspect lock is poisoned")
-                    .insert(String::from(member.get_id()), SteadyTime::now());
+                    .insert(String::from(member.get_id()), self.now());
             }
             if stop_suspicion == true {
                 self.suspect
@@ -411,7 +468,7 @@ impl MemberList {
         }
         if health == Health::Suspect {
             let mut sl = self.suspect.write().expect("Suspect lock is poisoned");
-            sl.insert(String::from(member_id), SteadyTime::now());
+            sl.insert(String::from(member_id), self.now());
         }
         self.health
             .write()
@@ -570,7 +627,7 @@ mod tests {
     mod member {
         use uuid::Uuid;
         use message::swim;
-        use member::Member;
+        use member::{address_for, Member};
 
         // Sets the uuid to simple, and the incarnation to zero.
         #[test]
@@ -591,6 +648,29 @@ mod tests {
             let member: Member = proto.into();
             assert_eq!(proto2, member.proto);
         }
+
+        #[test]
+        fn socket_addresses_accept_ipv6() {
+            let mut member = Member::default();
+            member.set_swim_port(9638);
+            member.set_gossip_port(9639);
+            member.set_address(String::from("fd00::1"));
+            assert_eq!(member.swim_socket_address().to_string(), "[fd00::1]:9638");
+            member.set_address(String::from("[fd00::1]"));
+            assert_eq!(member.gossip_socket_address().to_string(), "[fd00::1]:9639");
+            member.set_address(String::from("10.0.0.1"));
+            assert_eq!(member.swim_socket_address().to_string(), "10.0.0.1:9638");
+        }
+
+        #[test]
+        fn address_for_unmaps_ipv4_mapped_addresses() {
+            let mapped = "::ffff:10.0.0.1".parse().unwrap();
+            assert_eq!(address_for(mapped), "10.0.0.1");
+            let v6 = "fd00::1".parse().unwrap();
+            assert_eq!(address_for(v6), "fd00::1");
+            let loopback = "::1".parse().unwrap();
+            assert_eq!(address_for(loopback), "::1");
+        }
     }
 
     mod member_list {
@@ -0,0 +1,184 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The transport layer underneath the SWIM and Gossip threads.
+//!
+//! A `Server` never talks to the operating system directly; it asks its `Network` for a
+//! `SwimSocket` to send and receive UDP datagrams on, and for `GossipSender`s and a
+//! `GossipReceiver` to push and pull rumors with. `RealNetwork` is the production implementation,
+//! backed by a `UdpSocket` and ZeroMQ PUSH/PULL sockets. Test harnesses can supply their own
+//! implementation to inject loss, latency or partitions between members.
+
+use std::fmt::Debug;
+use std::io;
+use std::net::{SocketAddr, UdpSocket};
+use std::time::Duration;
+
+use net2::UdpBuilder;
+use zmq;
+
+use ZMQ_CONTEXT;
+use error::{Error, Result};
+
+/// How long a `RealNetwork` SWIM socket blocks on a read or a write.
+const SWIM_SOCKET_TIMEOUT_MS: u64 = 1000;
+
+/// A datagram socket for the SWIM protocol.
+pub trait SwimSocket: Send {
+    /// Send a single datagram to `addr`, returning the number of bytes sent.
+    fn send_to(&self, buf: &[u8], addr: SocketAddr) -> io::Result<usize>;
+
+    /// Receive a single datagram, returning the number of bytes read and the sender's address.
+    ///
+    /// Implementations must return an error of kind `WouldBlock` or `TimedOut` if nothing arrives
+    /// within a reasonable time, so that the inbound thread can check if it has been paused.
+    fn recv_from(&self, buf: &mut [u8]) -> io::Result<(usize, SocketAddr)>;
+
+    /// Create another handle to the same underlying socket.
+    fn try_clone(&self) -> io::Result<Box<SwimSocket>>;
+}
+
+/// The sending half of a gossip connection to a single member.
+pub trait GossipSender: Send {
+    /// Send a single rumor payload.
+    fn send(&self, payload: &[u8]) -> Result<()>;
+}
+
+/// The receiving end of all gossip sent to this member.
+pub trait GossipReceiver: Send {
+    /// Block until the next rumor payload arrives.
+    fn recv(&self) -> Result<Vec<u8>>;
+}
+
+/// Creates the sockets a `Server` uses to communicate with the rest of the ring.
+pub trait Network: Debug + Send + Sync {
+    /// Bind a SWIM socket to `addr`.
+    fn swim_socket(&self, addr: SocketAddr) -> Result<Box<SwimSocket>>;
+
+    /// Connect a gossip sender from the member listening on `from` to the member listening on
+    /// `to`.
+    fn gossip_sender(&self, from: SocketAddr, to: SocketAddr) -> Result<Box<GossipSender>>;
+
+    /// Bind a gossip receiver to `addr`.
+    fn gossip_receiver(&self, addr: SocketAddr) -> Result<Box<GossipReceiver>>;
+}
+
+/// The production network; UDP for SWIM and ZeroMQ for gossip.
+#[derive(Debug, Default)]
+pub struct RealNetwork;
+
+impl Network for RealNetwork {
+    fn swim_socket(&self, addr: SocketAddr) -> Result<Box<SwimSocket>> {
+        let socket = if addr.is_ipv6() {
+            // Accept IPv4 peers as well when bound to every IPv6 address, whatever the platform
+            // default is.
+            UdpBuilder::new_v6()
+                .and_then(|builder| {
+                              builder.only_v6(false)?;
+                              builder.bind(addr)
+                          })
+                .map_err(Error::CannotBind)?
+        } else {
+            UdpSocket::bind(addr).map_err(Error::CannotBind)?
+        };
+        socket
+            .set_read_timeout(Some(Duration::from_millis(SWIM_SOCKET_TIMEOUT_MS)))
+            .map_err(Error::SocketSetReadTimeout)?;
+        socket
+            .set_write_timeout(Some(Duration::from_millis(SWIM_SOCKET_TIMEOUT_MS)))
+            .map_err(Error::SocketSetWriteTimeout)?;
+        Ok(Box::new(socket))
+    }
+
+    fn gossip_sender(&self, _from: SocketAddr, to: SocketAddr) -> Result<Box<GossipSender>> {
+        let socket = (**ZMQ_CONTEXT)
+            .as_mut()
+            .socket(zmq::PUSH)
+            .expect("Failure to create the ZMQ push socket");
+        socket
+            .set_linger(1000)
+            .expect("Failure to set the ZMQ push socket to not linger");
+        socket
+            .set_tcp_keepalive(0)
+            .expect("Failure to set the ZMQ push socket to not use keepalive");
+        socket
+            .set_immediate(true)
+            .expect("Failure to set the ZMQ push socket to immediate");
+        socket
+            .set_sndhwm(1000)
+            .expect("Failure to set the ZMQ push socket hwm");
+        socket
+            .set_sndtimeo(500)
+            .expect("Failure to set the ZMQ send timeout");
+        socket
+            .set_ipv6(to.is_ipv6())
+            .expect("Failure to set IPv6 on the ZMQ push socket");
+        socket
+            .connect(&format!("tcp://{}", to))
+            .map_err(Error::ZmqConnectError)?;
+        Ok(Box::new(ZmqGossipSender(socket)))
+    }
+
+    fn gossip_receiver(&self, addr: SocketAddr) -> Result<Box<GossipReceiver>> {
+        let socket = (**ZMQ_CONTEXT)
+            .as_mut()
+            .socket(zmq::PULL)
+            .expect("Failure to create the ZMQ pull socket");
+        socket
+            .set_linger(0)
+            .expect("Failure to set the ZMQ Pull socket to not linger");
+        socket
+            .set_tcp_keepalive(0)
+            .expect("Failure to set the ZMQ Pull socket to not use keepalive");
+        socket
+            .set_ipv6(addr.is_ipv6())
+            .expect("Failure to set IPv6 on the ZMQ Pull socket");
+        socket
+            .bind(&format!("tcp://{}", addr))
+            .map_err(Error::ZmqBindError)?;
+        Ok(Box::new(ZmqGossipReceiver(socket)))
+    }
+}
+
+impl SwimSocket for UdpSocket {
+    fn send_to(&self, buf: &[u8], addr: SocketAddr) -> io::Result<usize> {
+        UdpSocket::send_to(self, buf, addr)
+    }
+
+    fn recv_from(&self, buf: &mut [u8]) -> io::Result<(usize, SocketAddr)> {
+        UdpSocket::recv_from(self, buf)
+    }
+
+    fn try_clone(&self) -> io::Result<Box<SwimSocket>> {
+        let socket = UdpSocket::try_clone(self)?;
+        Ok(Box::new(socket))
+    }
+}
+
+struct ZmqGossipSender(zmq::Socket);
+
+impl GossipSender for ZmqGossipSender {
+    fn send(&self, payload: &[u8]) -> Result<()> {
+        self.0.send(payload, 0).map_err(Error::ZmqSendError)
+    }
+}
+
+struct ZmqGossipReceiver(zmq::Socket);
+
+impl GossipReceiver for ZmqGossipReceiver {
+    fn recv(&self) -> Result<Vec<u8>> {
+        let msg = self.0.recv_msg(0).map_err(Error::ZmqRecvError)?;
+        Ok(msg.to_vec())
+    }
+}
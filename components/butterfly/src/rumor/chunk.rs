@@ -0,0 +1,317 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Content-addressed chunks for large service files.
+//!
+//! A service file whose rumor would exceed `MAX_RUMOR_BYTES` is split into `CHUNK_SIZE` pieces,
+//! each named by its hash. The `ServiceFile` rumor then only carries the list of chunk hashes and
+//! is gossiped as usual, while the chunks themselves are sent point-to-point: a member that is
+//! missing a chunk sends a `FileChunkRequest` to a few peers, and any peer holding the chunk
+//! replies with a `FileChunk`. Chunks are verified against their hash before they are stored, so
+//! they can be accepted from any member, but only chunks named by a service file we hold are
+//! accepted at all.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, RwLock};
+
+use habitat_core::crypto::hash;
+
+use error::{Error, Result};
+use message::swim::{Rumor as ProtoRumor, Rumor_Type as ProtoRumor_Type,
+                    ServiceFile as ProtoServiceFile};
+
+/// The size of every chunk but the last.
+pub const CHUNK_SIZE: usize = 64 * 1024;
+
+/// The largest service file body we are willing to distribute, chunked or not. Every member holds
+/// the chunks in memory.
+pub const MAX_CHUNKED_FILE_BYTES: usize = 16 * 1024 * 1024;
+
+/// The most chunk bytes a member holds in memory at once.
+pub const MAX_STORE_BYTES: usize = 4 * MAX_CHUNKED_FILE_BYTES;
+
+/// The most chunks a member waits for at once.
+const MAX_WANTED_CHUNKS: usize = MAX_STORE_BYTES / CHUNK_SIZE;
+
+/// Split `body` into `CHUNK_SIZE` pieces, returning each piece alongside its hash.
+pub fn split(body: &[u8]) -> Result<Vec<(String, Vec<u8>)>> {
+    if body.len() > MAX_CHUNKED_FILE_BYTES {
+        return Err(Error::RumorTooLarge(body.len(), MAX_CHUNKED_FILE_BYTES));
+    }
+    let mut chunks = Vec::with_capacity(body.len() / CHUNK_SIZE + 1);
+    for piece in body.chunks(CHUNK_SIZE) {
+        chunks.push((try!(hash::hash_bytes(piece)), piece.to_vec()));
+    }
+    Ok(chunks)
+}
+
+/// Create a request for the chunk named `hash`, to be answered by whoever holds it.
+pub fn request_rumor<S: Into<String>>(from_id: S, hash: &str) -> ProtoRumor {
+    let mut proto = ProtoServiceFile::new();
+    proto.set_filename(String::from(hash));
+    let mut rumor = ProtoRumor::new();
+    rumor.set_from_id(from_id.into());
+    rumor.set_field_type(ProtoRumor_Type::FileChunkRequest);
+    rumor.set_service_file(proto);
+    rumor
+}
+
+/// Create a message carrying the chunk named `hash`.
+pub fn chunk_rumor<S: Into<String>>(from_id: S, hash: &str, bytes: Vec<u8>) -> ProtoRumor {
+    let mut proto = ProtoServiceFile::new();
+    proto.set_filename(String::from(hash));
+    proto.set_body(bytes);
+    let mut rumor = ProtoRumor::new();
+    rumor.set_from_id(from_id.into());
+    rumor.set_field_type(ProtoRumor_Type::FileChunk);
+    rumor.set_service_file(proto);
+    rumor
+}
+
+/// Storage for chunks, keyed by their hash. Is thread-safe.
+///
+/// Only chunks that were asked for with `want` are accepted, and the store never holds more than
+/// `MAX_STORE_BYTES`; past that, the chunks stored first are evicted.
+#[derive(Debug, Clone, Default)]
+pub struct ChunkStore {
+    inner: Arc<RwLock<Chunks>>,
+}
+
+#[derive(Debug, Default)]
+struct Chunks {
+    chunks: HashMap<String, Vec<u8>>,
+    /// The hashes of the stored chunks, oldest first.
+    order: VecDeque<String>,
+    bytes: usize,
+    /// The hashes of the chunks we are waiting for.
+    wanted: HashSet<String>,
+}
+
+impl ChunkStore {
+    /// Start accepting the chunks named by `hashes` that we do not have yet.
+    pub fn want(&self, hashes: &[String]) {
+        let mut inner = self.inner.write().expect("Chunk store lock poisoned");
+        for hash in hashes.iter() {
+            if inner.wanted.len() >= MAX_WANTED_CHUNKS {
+                warn!("Not waiting for more than {} service file chunks",
+                      MAX_WANTED_CHUNKS);
+                break;
+            }
+            if !inner.chunks.contains_key(hash) {
+                inner.wanted.insert(hash.clone());
+            }
+        }
+    }
+
+    /// Store a chunk we asked for, after checking that `bytes` really hash to `hash`. Returns
+    /// true if we did not already have it.
+    pub fn insert(&self, hash: &str, bytes: Vec<u8>) -> Result<bool> {
+        if self.contains(hash) {
+            return Ok(false);
+        }
+        if !self.inner
+                .read()
+                .expect("Chunk store lock poisoned")
+                .wanted
+                .contains(hash) {
+            return Err(Error::ChunkNotWanted(String::from(hash)));
+        }
+        let actual = try!(hash::hash_bytes(&bytes));
+        if actual != hash {
+            return Err(Error::ChunkHashMismatch(String::from(hash), actual));
+        }
+        let mut inner = self.inner.write().expect("Chunk store lock poisoned");
+        if !inner.wanted.remove(hash) {
+            return Ok(false);
+        }
+        inner.bytes += bytes.len();
+        inner.order.push_back(String::from(hash));
+        inner.chunks.insert(String::from(hash), bytes);
+        while inner.bytes > MAX_STORE_BYTES {
+            let oldest = match inner.order.pop_front() {
+                Some(oldest) => oldest,
+                None => break,
+            };
+            if let Some(evicted) = inner.chunks.remove(&oldest) {
+                debug!("Evicting service file chunk {}", oldest);
+                inner.bytes -= evicted.len();
+            }
+        }
+        Ok(true)
+    }
+
+    /// Drop every chunk, stored or wanted, that isn't named in `needed`.
+    pub fn retain(&self, needed: &HashSet<String>) {
+        let mut inner = self.inner.write().expect("Chunk store lock poisoned");
+        let unneeded: Vec<String> = inner
+            .chunks
+            .keys()
+            .filter(|h| !needed.contains(*h))
+            .cloned()
+            .collect();
+        for hash in unneeded.iter() {
+            if let Some(chunk) = inner.chunks.remove(hash) {
+                inner.bytes -= chunk.len();
+            }
+        }
+        inner.order.retain(|h| needed.contains(h));
+        let unwanted: Vec<String> = inner
+            .wanted
+            .iter()
+            .filter(|h| !needed.contains(*h))
+            .cloned()
+            .collect();
+        for hash in unwanted.iter() {
+            inner.wanted.remove(hash);
+        }
+    }
+
+    pub fn get(&self, hash: &str) -> Option<Vec<u8>> {
+        let inner = self.inner.read().expect("Chunk store lock poisoned");
+        inner.chunks.get(hash).map(|c| c.clone())
+    }
+
+    pub fn contains(&self, hash: &str) -> bool {
+        let inner = self.inner.read().expect("Chunk store lock poisoned");
+        inner.chunks.contains_key(hash)
+    }
+
+    /// Returns the count of all chunks in the store.
+    pub fn len(&self) -> usize {
+        self.inner
+            .read()
+            .expect("Chunk store lock poisoned")
+            .chunks
+            .len()
+    }
+
+    /// Returns the hashes from `hashes` that we do not have yet.
+    pub fn missing(&self, hashes: &[String]) -> Vec<String> {
+        let inner = self.inner.read().expect("Chunk store lock poisoned");
+        hashes
+            .iter()
+            .filter(|h| !inner.chunks.contains_key(*h))
+            .map(|h| h.clone())
+            .collect()
+    }
+
+    /// Concatenate the chunks named by `hashes`, in order. Returns `None` if any are missing.
+    pub fn assemble(&self, hashes: &[String]) -> Option<Vec<u8>> {
+        let inner = self.inner.read().expect("Chunk store lock poisoned");
+        let mut body = Vec::new();
+        for h in hashes.iter() {
+            match inner.chunks.get(h) {
+                Some(chunk) => body.extend_from_slice(chunk),
+                None => return None,
+            }
+        }
+        Some(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{split, ChunkStore, CHUNK_SIZE, MAX_STORE_BYTES};
+
+    fn hashes(chunks: &[(String, Vec<u8>)]) -> Vec<String> {
+        chunks.iter().map(|c| c.0.clone()).collect()
+    }
+
+    #[test]
+    fn split_produces_chunk_size_pieces() {
+        let body = vec![7; CHUNK_SIZE * 2 + 10];
+        let chunks = split(&body).unwrap();
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[0].1.len(), CHUNK_SIZE);
+        assert_eq!(chunks[2].1.len(), 10);
+        assert_eq!(chunks[0].0, chunks[1].0);
+    }
+
+    #[test]
+    fn insert_rejects_chunks_that_do_not_match_their_hash() {
+        let store = ChunkStore::default();
+        let chunks = split(b"a small body").unwrap();
+        store.want(&hashes(&chunks));
+        assert!(store.insert(&chunks[0].0, Vec::from("tampered")).is_err());
+        assert_eq!(store.len(), 0);
+        assert_eq!(store.insert(&chunks[0].0, chunks[0].1.clone()).unwrap(), true);
+        assert_eq!(store.insert(&chunks[0].0, chunks[0].1.clone()).unwrap(), false);
+    }
+
+    #[test]
+    fn insert_rejects_chunks_that_were_not_wanted() {
+        let store = ChunkStore::default();
+        let chunks = split(b"a small body").unwrap();
+        assert!(store.insert(&chunks[0].0, chunks[0].1.clone()).is_err());
+        assert_eq!(store.len(), 0);
+    }
+
+    #[test]
+    fn assemble_requires_every_chunk() {
+        let store = ChunkStore::default();
+        let mut body = vec![1; CHUNK_SIZE];
+        body.extend(vec![2; CHUNK_SIZE]);
+        let chunks = split(&body).unwrap();
+        let hashes = hashes(&chunks);
+        store.want(&hashes);
+        store.insert(&chunks[0].0, chunks[0].1.clone()).unwrap();
+        assert_eq!(store.missing(&hashes), vec![chunks[1].0.clone()]);
+        assert!(store.assemble(&hashes).is_none());
+        store.insert(&chunks[1].0, chunks[1].1.clone()).unwrap();
+        assert_eq!(store.assemble(&hashes), Some(body));
+    }
+
+    #[test]
+    fn store_is_bounded() {
+        let store = ChunkStore::default();
+        let count = MAX_STORE_BYTES / CHUNK_SIZE + 1;
+        let mut all = Vec::with_capacity(count);
+        for i in 0..count {
+            let mut body = vec![0; CHUNK_SIZE];
+            body[..8].copy_from_slice(&[(i >> 24) as u8,
+                                        (i >> 16) as u8,
+                                        (i >> 8) as u8,
+                                        i as u8,
+                                        0,
+                                        0,
+                                        0,
+                                        0]);
+            let chunk = split(&body).unwrap().remove(0);
+            store.want(&[chunk.0.clone()]);
+            store.insert(&chunk.0, chunk.1).unwrap();
+            all.push(chunk.0);
+        }
+        assert_eq!(store.len(), count - 1);
+        assert!(!store.contains(&all[0]));
+        assert!(store.contains(&all[count - 1]));
+    }
+
+    #[test]
+    fn retain_drops_chunks_that_are_no_longer_needed() {
+        let store = ChunkStore::default();
+        let first = split(b"first body").unwrap();
+        let second = split(b"second body").unwrap();
+        store.want(&hashes(&first));
+        store.want(&hashes(&second));
+        store.insert(&first[0].0, first[0].1.clone()).unwrap();
+
+        let needed: HashSet<String> = hashes(&second).into_iter().collect();
+        store.retain(&needed);
+        assert!(!store.contains(&first[0].0));
+        assert!(store.insert(&first[0].0, first[0].1.clone()).is_err());
+        assert!(store.insert(&second[0].0, second[0].1.clone()).unwrap());
+    }
+}
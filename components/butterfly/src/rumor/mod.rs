@@ -23,6 +23,7 @@
 //! New rumors need to implement the `From` trait for `RumorKey`, and then can track the arrival of
 //! new rumors, and dispatch them according to their `kind`.
 
+pub mod chunk;
 pub mod dat_file;
 pub mod election;
 pub mod service;
@@ -48,6 +49,10 @@ use serde::ser::SerializeStruct;
 use message::swim::Rumor_Type;
 use error::{Result, Error};
 
+/// The largest encoded rumor we will send or accept. Service files that would be bigger are
+/// distributed as chunks instead; see `rumor::chunk`.
+pub const MAX_RUMOR_BYTES: usize = 128 * 1024;
+
 /// The description of a `RumorKey`.
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct RumorKey {
@@ -237,8 +242,9 @@ impl<T: Rumor> RumorStore<T> {
     /// Increment the update counter for this store.
     ///
     /// We don't care if this repeats - it just needs to be unique for any given two states, which
-    /// it will be.
-    fn increment_update_counter(&self) {
+    /// it will be. Call this directly when something a rumor depends on changes without the rumor
+    /// itself changing, such as the arrival of a service file's last chunk.
+    pub fn increment_update_counter(&self) {
         self.update_counter.fetch_add(1, Ordering::Relaxed);
     }
 }
@@ -15,19 +15,23 @@
 //! The ServiceFile rumor.
 //!
 //! Holds the toml configuration injected for a service.
+//!
+//! Files too large to fit in a single rumor are chunked: the rumor carries the hash of the whole
+//! body and the hashes of its chunks, and the chunks travel separately (see `rumor::chunk`).
 
 use std::cmp::Ordering;
 use std::mem;
 use std::ops::{Deref, DerefMut};
 
-use habitat_core::crypto::{BoxKeyPair, default_cache_key_path};
+use habitat_core::crypto::{hash, BoxKeyPair, default_cache_key_path};
 use habitat_core::service::ServiceGroup;
 use protobuf::{self, Message};
 
-use error::Result;
+use error::{Error, Result};
 use message::swim::{ServiceFile as ProtoServiceFile, Rumor as ProtoRumor,
                     Rumor_Type as ProtoRumor_Type};
 use rumor::Rumor;
+use rumor::chunk::{self, ChunkStore};
 
 #[derive(Debug, Clone, Serialize)]
 pub struct ServiceFile(ProtoRumor);
@@ -47,7 +51,8 @@ impl PartialEq for ServiceFile {
         self.get_service_group() == other.get_service_group() &&
         self.get_incarnation() == other.get_incarnation() &&
         self.get_encrypted() == other.get_encrypted() &&
-        self.get_filename() == other.get_filename() && self.get_body() == other.get_body()
+        self.get_filename() == other.get_filename() && self.get_body() == other.get_body() &&
+        self.get_content_hash() == other.get_content_hash()
     }
 }
 
@@ -111,14 +116,57 @@ impl ServiceFile {
         Ok(())
     }
 
+    /// Move the body of the service file out into content-addressed chunks, leaving only their
+    /// hashes behind. Returns the chunks, which must be distributed alongside the rumor. Must be
+    /// called after `encrypt`, if at all.
+    pub fn chunk(&mut self) -> Result<Vec<(String, Vec<u8>)>> {
+        let body = self.take_body();
+        let chunks = try!(chunk::split(&body));
+        self.set_content_hash(try!(hash::hash_bytes(&body)));
+        self.set_chunks(chunks.iter().map(|c| c.0.clone()).collect());
+        Ok(chunks)
+    }
+
+    /// True if the body of this service file is distributed as chunks.
+    pub fn is_chunked(&self) -> bool {
+        self.has_content_hash()
+    }
+
     /// Return the body of the service file as a stream of bytes. Always returns a new copy, due to
     /// the fact that we might be encrypted.
+    ///
+    /// Chunked service files have no body of their own; use `body_from` for those.
     pub fn body(&self) -> Result<Vec<u8>> {
+        self.decrypt(self.get_body())
+    }
+
+    /// Return the body of the service file, reassembling it from `chunks` if it was chunked.
+    /// Fails with `Error::ServiceFileIncomplete` until every chunk has arrived.
+    pub fn body_from(&self, chunks: &ChunkStore) -> Result<Vec<u8>> {
+        if !self.is_chunked() {
+            return self.body();
+        }
+        let body = match chunks.assemble(self.get_chunks()) {
+            Some(body) => body,
+            None => {
+                let missing = chunks.missing(self.get_chunks()).len();
+                return Err(Error::ServiceFileIncomplete(String::from(self.get_filename()),
+                                                        missing));
+            }
+        };
+        let actual = try!(hash::hash_bytes(&body));
+        if actual != self.get_content_hash() {
+            return Err(Error::ChunkHashMismatch(String::from(self.get_content_hash()), actual));
+        }
+        self.decrypt(&body)
+    }
+
+    fn decrypt(&self, body: &[u8]) -> Result<Vec<u8>> {
         if self.get_encrypted() {
-            let bytes = try!(BoxKeyPair::decrypt(self.get_body(), &default_cache_key_path(None)));
+            let bytes = try!(BoxKeyPair::decrypt(body, &default_cache_key_path(None)));
             Ok(bytes)
         } else {
-            Ok(self.get_body().to_vec())
+            Ok(body.to_vec())
         }
     }
 }
@@ -165,6 +213,7 @@ mod tests {
 
     use super::ServiceFile;
     use rumor::Rumor;
+    use rumor::chunk::{ChunkStore, CHUNK_SIZE};
 
     fn create_service_file(member_id: &str, filename: &str, body: &str) -> ServiceFile {
         let body_bytes: Vec<u8> = Vec::from(body);
@@ -243,4 +292,33 @@ mod tests {
                        .expect("cannot get a utf-8 string for the body"),
                    String::from("tcp-backlog = 128"));
     }
+
+    #[test]
+    fn chunked_body_is_reassembled_from_the_chunk_store() {
+        let body = vec![42; CHUNK_SIZE * 3 + 1];
+        let mut s1 = ServiceFile::new("adam",
+                                      ServiceGroup::new("neurosis", "production", None).unwrap(),
+                                      "bundle.pem",
+                                      body.clone());
+        let chunks = s1.chunk().unwrap();
+        assert!(s1.is_chunked());
+        assert!(!s1.has_body());
+        assert_eq!(s1.get_chunks().len(), 4);
+
+        let store = ChunkStore::default();
+        assert!(s1.body_from(&store).is_err());
+        store.want(s1.get_chunks());
+        for (hash, bytes) in chunks.into_iter() {
+            store.insert(&hash, bytes).unwrap();
+        }
+        assert_eq!(s1.body_from(&store).unwrap(), body);
+    }
+
+    #[test]
+    fn unchunked_body_ignores_the_chunk_store() {
+        let s1 = create_service_file("adam", "yep", "tcp-backlog = 128");
+        assert!(!s1.is_chunked());
+        assert_eq!(s1.body_from(&ChunkStore::default()).unwrap(),
+                   Vec::from("tcp-backlog = 128"));
+    }
 }
@@ -17,11 +17,8 @@
 //! This module keeps track of suspected members, and sets their status to confirmed if they remain
 //! suspect long enough.
 
-use std::thread;
 use std::time::Duration;
 
-use time::SteadyTime;
-
 use message::swim::Rumor_Type;
 use member::Health;
 use rumor::RumorKey;
@@ -50,7 +47,7 @@ impl Expire {
             self.server
                 .member_list
                 .with_suspects(|(id, suspect)| {
-                    let now = SteadyTime::now();
+                    let now = self.timing.now();
                     if *suspect + self.timing.suspicion_timeout_duration() > now {
                         expired_list.push(String::from(id));
                         self.server
@@ -74,7 +71,7 @@ impl Expire {
                     .rumor_list
                     .insert(RumorKey::new(Rumor_Type::Member, mid.clone(), ""));
             }
-            thread::sleep(Duration::from_millis(500));
+            self.timing.sleep(Duration::from_millis(500));
         }
     }
 }
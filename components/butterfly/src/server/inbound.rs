@@ -16,36 +16,41 @@
 //!
 //! This module handles all the inbound SWIM messages.
 
+use std::io;
 use std::sync::mpsc;
 use std::sync::atomic::Ordering;
-use std::net::{SocketAddr, UdpSocket};
-use std::thread;
+use std::net::SocketAddr;
 use std::time::Duration;
 
 use protobuf;
 
-use member::{Member, Health};
+use member::{self, Member, Health};
 use message::swim::{Swim, Swim_Type};
+use network::SwimSocket;
 use server::{Server, outbound};
+use server::timing::Timing;
 use trace::TraceKind;
 
 /// Takes the Server and a channel to send received Acks to the outbound thread.
 pub struct Inbound {
     pub server: Server,
-    pub socket: UdpSocket,
+    pub socket: Box<SwimSocket>,
     pub tx_outbound: mpsc::Sender<(SocketAddr, Swim)>,
+    pub timing: Timing,
 }
 
 impl Inbound {
     /// Create a new Inbound.
     pub fn new(server: Server,
-               socket: UdpSocket,
-               tx_outbound: mpsc::Sender<(SocketAddr, Swim)>)
+               socket: Box<SwimSocket>,
+               tx_outbound: mpsc::Sender<(SocketAddr, Swim)>,
+               timing: Timing)
                -> Inbound {
         Inbound {
             server: server,
             socket: socket,
             tx_outbound: tx_outbound,
+            timing: timing,
         }
     }
 
@@ -54,7 +59,7 @@ impl Inbound {
         let mut recv_buffer: Vec<u8> = vec![0; 1024];
         loop {
             if self.server.pause.load(Ordering::Relaxed) {
-                thread::sleep(Duration::from_millis(100));
+                self.timing.sleep(Duration::from_millis(100));
                 continue;
             }
             match self.socket.recv_from(&mut recv_buffer[..]) {
@@ -110,6 +115,10 @@ impl Inbound {
                         }
                     }
                 }
+                Err(ref e) if e.kind() == io::ErrorKind::WouldBlock ||
+                              e.kind() == io::ErrorKind::TimedOut => {
+                    // A read timeout from a non-OS socket; nothing arrived.
+                }
                 Err(e) => {
                     match e.raw_os_error() {
                         Some(35) | Some(11) | Some(10035) | Some(10060) => {
@@ -150,7 +159,7 @@ impl Inbound {
                 };
                 // Set the route-back address to the one we received the pingreq from
                 let mut from = msg.mut_pingreq().take_from();
-                from.set_address(format!("{}", addr.ip()));
+                from.set_address(member::address_for(addr.ip()));
                 outbound::ping(&self.server,
                                &self.socket,
                                target,
@@ -189,7 +198,7 @@ impl Inbound {
                       );
                 msg.mut_ack()
                     .mut_from()
-                    .set_address(format!("{}", addr.ip()));
+                    .set_address(member::address_for(addr.ip()));
                 outbound::forward_ack(&self.server, &self.socket, forward_to_addr, msg);
                 return;
             }
@@ -229,7 +238,7 @@ impl Inbound {
         let from = {
             let mut ping = msg.mut_ping();
             let mut from = ping.take_from();
-            from.set_address(format!("{}", addr.ip()));
+            from.set_address(member::address_for(addr.ip()));
             from
         };
         info!("Ping from {}@{}", from.get_id(), addr);
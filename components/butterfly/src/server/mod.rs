@@ -31,7 +31,7 @@ use std::ffi;
 use std::fmt::{self, Debug};
 use std::fs;
 use std::io;
-use std::net::{ToSocketAddrs, UdpSocket, SocketAddr};
+use std::net::{ToSocketAddrs, SocketAddr};
 use std::path::PathBuf;
 use std::result;
 use std::str::FromStr;
@@ -49,7 +49,9 @@ use serde::ser::SerializeStruct;
 use error::{Result, Error};
 use member::{Member, Health, MemberList};
 use message;
+use network::{Network, RealNetwork};
 use rumor::{Rumor, RumorList, RumorKey, RumorStore};
+use rumor::chunk::ChunkStore;
 use rumor::dat_file::DatFile;
 use rumor::service::Service;
 use rumor::service_config::ServiceConfig;
@@ -75,11 +77,13 @@ pub struct Server {
     pub service_file_store: RumorStore<ServiceFile>,
     pub election_store: RumorStore<Election>,
     pub update_store: RumorStore<ElectionUpdate>,
+    pub chunk_store: ChunkStore,
     swim_addr: Arc<RwLock<SocketAddr>>,
     gossip_addr: Arc<RwLock<SocketAddr>>,
     suitability_lookup: Arc<Box<Suitability>>,
     data_path: Arc<Option<PathBuf>>,
     dat_file: Arc<RwLock<Option<DatFile>>>,
+    network: Arc<Box<Network>>,
     // These are all here for testing support
     pause: Arc<AtomicBool>,
     pub trace: Arc<RwLock<Trace>>,
@@ -121,11 +125,13 @@ impl Server {
                        service_file_store: RumorStore::default(),
                        election_store: RumorStore::default(),
                        update_store: RumorStore::default(),
+                       chunk_store: ChunkStore::default(),
                        swim_addr: Arc::new(RwLock::new(swim_socket_addr)),
                        gossip_addr: Arc::new(RwLock::new(gossip_socket_addr)),
                        suitability_lookup: Arc::new(suitability_lookup),
                        data_path: Arc::new(data_path.as_ref().map(|p| p.into())),
                        dat_file: Arc::new(RwLock::new(None)),
+                       network: Arc::new(Box::new(RealNetwork)),
                        pause: Arc::new(AtomicBool::new(false)),
                        trace: Arc::new(RwLock::new(trace)),
                        swim_rounds: Arc::new(AtomicIsize::new(0)),
@@ -189,8 +195,14 @@ impl Server {
         }
     }
 
+    /// Replace the `Network` this server communicates over. Must be called before `start`; the
+    /// default is a `RealNetwork`.
+    pub fn set_network<N: Network + 'static>(&mut self, network: N) {
+        self.network = Arc::new(Box::new(network));
+    }
+
     /// Start the server, along with a `Timing` for outbound connections. Spawns the `inbound`,
-    /// `outbound`, and `expire` threads.
+    /// `outbound`, and `expire` threads, which all keep time by the `Timing`'s clock.
     ///
     /// # Errors
     ///
@@ -211,28 +223,21 @@ impl Server {
             *dat_file = Some(file);
         }
 
-        let socket = match UdpSocket::bind(*self.swim_addr
-                                                .read()
-                                                .expect("Swim address lock is poisoned")) {
-            Ok(socket) => socket,
-            Err(e) => return Err(Error::CannotBind(e)),
-        };
-        try!(socket
-                 .set_read_timeout(Some(Duration::from_millis(1000)))
-                 .map_err(|e| Error::SocketSetReadTimeout(e)));
-        try!(socket
-                 .set_write_timeout(Some(Duration::from_millis(1000)))
-                 .map_err(|e| Error::SocketSetReadTimeout(e)));
+        let socket = try!(self.network.swim_socket(self.swim_addr()));
+
+        self.member_list.set_clock(timing.clock());
 
         let server_a = self.clone();
         let socket_a = match socket.try_clone() {
             Ok(socket_a) => socket_a,
             Err(_) => return Err(Error::SocketCloneError),
         };
+        let timing_a = timing.clone();
+        timing.clock().add_thread();
         let _ = thread::Builder::new()
             .name(format!("inbound-{}", self.name()))
             .spawn(move || {
-                       inbound::Inbound::new(server_a, socket_a, tx_outbound).run();
+                       inbound::Inbound::new(server_a, socket_a, tx_outbound, timing_a).run();
                        panic!("You should never, ever get here, judy");
                    });
 
@@ -242,6 +247,7 @@ impl Server {
             Err(_) => return Err(Error::SocketCloneError),
         };
         let timing_b = timing.clone();
+        timing.clock().add_thread();
         let _ = thread::Builder::new()
             .name(format!("outbound-{}", self.name()))
             .spawn(move || {
@@ -251,6 +257,7 @@ impl Server {
 
         let server_c = self.clone();
         let timing_c = timing.clone();
+        timing.clock().add_thread();
         let _ = thread::Builder::new()
             .name(format!("expire-{}", self.name()))
             .spawn(move || {
@@ -259,14 +266,18 @@ impl Server {
                    });
 
         let server_d = self.clone();
+        let receiver = try!(self.network.gossip_receiver(self.gossip_addr()));
+        let timing_d = timing.clone();
+        timing.clock().add_thread();
         let _ = thread::Builder::new()
             .name(format!("pull-{}", self.name()))
             .spawn(move || {
-                       pull::Pull::new(server_d).run();
+                       pull::Pull::new(server_d, receiver, timing_d).run();
                        panic!("You should never, ever get here, davey");
                    });
 
         let server_e = self.clone();
+        timing.clock().add_thread();
         let _ = thread::Builder::new()
             .name(format!("push-{}", self.name()))
             .spawn(move || {
@@ -326,7 +337,7 @@ impl Server {
     }
 
     /// Return the swim address we are bound to
-    fn swim_addr(&self) -> SocketAddr {
+    pub fn swim_addr(&self) -> SocketAddr {
         let sa = self.swim_addr
             .read()
             .expect("Swim Address lock poisoned");
@@ -440,14 +451,53 @@ impl Server {
         }
     }
 
-    /// Insert a service file rumor into the service store.
+    /// Insert a service file rumor into the service store. The chunks of a chunked service file
+    /// are then accepted into the chunk store, and those of the files it replaces are dropped.
     pub fn insert_service_file(&self, service_file: ServiceFile) {
         let rk = RumorKey::from(&service_file);
+        let chunks = service_file.get_chunks().to_vec();
         if self.service_file_store.insert(service_file) {
             self.rumor_list.insert(rk);
+            let mut needed = HashSet::new();
+            self.service_file_store
+                .with_keys(|(_, rumors)| for service_file in rumors.values() {
+                               needed.extend(service_file.get_chunks().iter().cloned());
+                           });
+            self.chunk_store.retain(&needed);
+            self.chunk_store.want(&chunks);
+        }
+    }
+
+    /// Insert a chunk of a service file into the chunk store. Chunks are only ever sent in reply
+    /// to a request, or alongside a new service file, so a new chunk bumps the service file
+    /// store's update counter; anyone waiting on a chunked file will then take another look.
+    pub fn insert_chunk(&self, hash: &str, bytes: Vec<u8>) {
+        match self.chunk_store.insert(hash, bytes) {
+            Ok(true) => self.service_file_store.increment_update_counter(),
+            Ok(false) => {}
+            Err(Error::ChunkNotWanted(_)) => {
+                debug!("Dropping unwanted service file chunk {}", hash)
+            }
+            Err(e) => warn!("Dropping service file chunk: {}", e),
         }
     }
 
+    /// The hashes of every chunk that a service file we know about is still waiting for.
+    pub fn missing_chunks(&self) -> Vec<String> {
+        let mut missing = Vec::new();
+        self.service_file_store
+            .with_keys(|(_, rumors)| for service_file in rumors.values() {
+                           if service_file.is_chunked() {
+                               for hash in self.chunk_store.missing(service_file.get_chunks()) {
+                                   if !missing.contains(&hash) {
+                                       missing.push(hash);
+                                   }
+                               }
+                           }
+                       });
+        missing
+    }
+
     /// Get all the Member ID's who are present in a given service group.
     fn get_electorate(&self, key: &str) -> Vec<String> {
         let mut electorate = vec![];
@@ -18,18 +18,17 @@
 
 use std::sync::atomic::Ordering;
 use std::sync::mpsc;
-use std::net::{SocketAddr, UdpSocket};
-use std::thread;
+use std::net::SocketAddr;
 use std::time::Duration;
 use std::fmt;
 
-use time::SteadyTime;
 use protobuf::{Message, RepeatedField};
 
 use message::swim::{Ack, Ping, PingReq, Swim, Swim_Type, Rumor_Type};
 use server::Server;
 use server::timing::Timing;
-use member::{Member, Health};
+use member::{self, Member, Health};
+use network::SwimSocket;
 use trace::TraceKind;
 
 /// How long to sleep between calls to `recv`.
@@ -54,7 +53,7 @@ impl fmt::Display for AckFrom {
 /// The outbound thread
 pub struct Outbound {
     pub server: Server,
-    pub socket: UdpSocket,
+    pub socket: Box<SwimSocket>,
     pub rx_inbound: mpsc::Receiver<(SocketAddr, Swim)>,
     pub timing: Timing,
 }
@@ -62,7 +61,7 @@ pub struct Outbound {
 impl Outbound {
     /// Creates a new Outbound struct.
     pub fn new(server: Server,
-               socket: UdpSocket,
+               socket: Box<SwimSocket>,
                rx_inbound: mpsc::Receiver<(SocketAddr, Swim)>,
                timing: Timing)
                -> Outbound {
@@ -103,7 +102,7 @@ impl Outbound {
             }
 
             if self.server.pause.load(Ordering::Relaxed) {
-                thread::sleep(Duration::from_millis(100));
+                self.timing.sleep(Duration::from_millis(100));
                 continue;
             }
 
@@ -128,21 +127,21 @@ impl Outbound {
 
                     self.probe(member);
 
-                    if SteadyTime::now() <= next_protocol_period {
-                        let wait_time = (next_protocol_period - SteadyTime::now())
+                    if self.timing.now() <= next_protocol_period {
+                        let wait_time = (next_protocol_period - self.timing.now())
                             .num_milliseconds();
                         if wait_time > 0 {
                             debug!("Waiting {} until the next protocol period", wait_time);
-                            thread::sleep(Duration::from_millis(wait_time as u64));
+                            self.timing.sleep(Duration::from_millis(wait_time as u64));
                         }
                     }
                 }
             }
 
-            if SteadyTime::now() <= long_wait {
-                let wait_time = (long_wait - SteadyTime::now()).num_milliseconds();
+            if self.timing.now() <= long_wait {
+                let wait_time = (long_wait - self.timing.now()).num_milliseconds();
                 if wait_time > 0 {
-                    thread::sleep(Duration::from_millis(wait_time as u64));
+                    self.timing.sleep(Duration::from_millis(wait_time as u64));
                 }
             }
         }
@@ -220,7 +219,7 @@ impl Outbound {
                     // If this was forwarded to us, we want to retain the address of the member who
                     // sent the ack, not the one we received on the socket.
                     if !swim.get_ack().has_forward_to() {
-                        ack_from.set_address(format!("{}", real_addr.ip()));
+                        ack_from.set_address(member::address_for(real_addr.ip()));
                     }
                     let ack_from_member: Member = ack_from.into();
                     self.server.insert_member(ack_from_member, Health::Alive);
@@ -228,13 +227,13 @@ impl Outbound {
                     return true;
                 }
                 Err(mpsc::TryRecvError::Empty) => {
-                    if SteadyTime::now() > timeout {
+                    if self.timing.now() > timeout {
                         warn!("Timed out waiting for Ack from {}@{}",
                               member.get_id(),
                               addr);
                         return false;
                     }
-                    thread::sleep(Duration::from_millis(PING_RECV_QUEUE_EMPTY_SLEEP_MS));
+                    self.timing.sleep(Duration::from_millis(PING_RECV_QUEUE_EMPTY_SLEEP_MS));
                 }
                 Err(mpsc::TryRecvError::Disconnected) => {
                     panic!("Outbound thread has disconnected! This is fatal.");
@@ -270,7 +269,7 @@ pub fn populate_membership_rumors(server: &Server, target: &Member, swim: &mut S
 }
 
 /// Send a PingReq.
-pub fn pingreq(server: &Server, socket: &UdpSocket, pingreq_target: &Member, target: &Member) {
+pub fn pingreq(server: &Server, socket: &SwimSocket, pingreq_target: &Member, target: &Member) {
     let addr = pingreq_target.swim_socket_address();
     let mut swim = Swim::new();
     swim.set_field_type(Swim_Type::PINGREQ);
@@ -322,7 +321,7 @@ pub fn pingreq(server: &Server, socket: &UdpSocket, pingreq_target: &Member, tar
 
 /// Send a Ping.
 pub fn ping(server: &Server,
-            socket: &UdpSocket,
+            socket: &SwimSocket,
             target: &Member,
             addr: SocketAddr,
             mut forward_to: Option<Member>) {
@@ -376,7 +375,7 @@ pub fn ping(server: &Server,
 }
 
 /// Forward an ack on.
-pub fn forward_ack(server: &Server, socket: &UdpSocket, addr: SocketAddr, swim: Swim) {
+pub fn forward_ack(server: &Server, socket: &SwimSocket, addr: SocketAddr, swim: Swim) {
     trace_it!(SWIM: server,
               TraceKind::SendForwardAck,
               swim.get_ack().get_from().get_id(),
@@ -415,7 +414,7 @@ pub fn forward_ack(server: &Server, socket: &UdpSocket, addr: SocketAddr, swim:
 
 /// Send an Ack.
 pub fn ack(server: &Server,
-           socket: &UdpSocket,
+           socket: &SwimSocket,
            target: &Member,
            addr: SocketAddr,
            mut forward_to: Option<Member>) {
@@ -14,53 +14,63 @@
 
 //! The pull thread.
 //!
-//! This module handles pulling all the pushed rumors from every member off a ZMQ socket.
+//! This module handles pulling all the pushed rumors from every member off a ZMQ socket. It also
+//! answers requests for service file chunks, which a worker thread sends straight back to the
+//! requester rather than gossiping them.
 
 use std::sync::atomic::Ordering;
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
 use std::thread;
 use std::time::Duration;
 
-use protobuf;
-use zmq;
+use protobuf::{self, Message};
 
-use ZMQ_CONTEXT;
+use error::Error;
+use network::GossipReceiver;
 use server::Server;
+use server::timing::Timing;
 use message::swim::{Rumor, Rumor_Type};
+use rumor::MAX_RUMOR_BYTES;
+use rumor::chunk;
 use trace::TraceKind;
 
-/// Takes a reference to the server itself
+/// The most chunk requests waiting for a reply. Requests past it are dropped; their members ask
+/// again in a later gossip round.
+const CHUNK_REPLY_QUEUE: usize = 64;
+
+/// Takes a reference to the server itself, and the receiver bound to its `gossip_addr`
 pub struct Pull {
     pub server: Server,
+    pub receiver: Box<GossipReceiver>,
+    pub timing: Timing,
+    chunk_requests: SyncSender<(String, String)>,
 }
 
 impl Pull {
-    /// Create a new Pull
-    pub fn new(server: Server) -> Pull {
-        Pull { server: server }
+    /// Create a new Pull, along with the thread that replies to chunk requests.
+    pub fn new(server: Server, receiver: Box<GossipReceiver>, timing: Timing) -> Pull {
+        let (tx, rx) = mpsc::sync_channel(CHUNK_REPLY_QUEUE);
+        let worker = ChunkReplyWorker { server: server.clone() };
+        let _ = thread::Builder::new()
+            .name(format!("chunk-reply-{}", server.name()))
+            .spawn(move || worker.run(rx));
+        Pull {
+            server: server,
+            receiver: receiver,
+            timing: timing,
+            chunk_requests: tx,
+        }
     }
 
-    /// Run this thread. Creates a socket, binds to the `gossip_addr`, then processes messages as
-    /// they are received. Uses a ZMQ pull socket, so inbound messages are fair-queued.
+    /// Run this thread. Processes messages from the receiver as they arrive. With a
+    /// `RealNetwork` the receiver is a ZMQ pull socket, so inbound messages are fair-queued.
     pub fn run(&mut self) {
-        let socket = (**ZMQ_CONTEXT)
-            .as_mut()
-            .socket(zmq::PULL)
-            .expect("Failure to create the ZMQ pull socket");
-        socket
-            .set_linger(0)
-            .expect("Failure to set the ZMQ Pull socket to not linger");
-        socket
-            .set_tcp_keepalive(0)
-            .expect("Failure to set the ZMQ Pull socket to not use keepalive");
-        socket
-            .bind(&format!("tcp://{}", self.server.gossip_addr()))
-            .expect("Failure to bind the ZMQ Pull socket to the port");
         'recv: loop {
             if self.server.pause.load(Ordering::Relaxed) {
-                thread::sleep(Duration::from_millis(100));
+                self.timing.sleep(Duration::from_millis(100));
                 continue;
             }
-            let msg = match socket.recv_msg(0) {
+            let msg = match self.receiver.recv() {
                 Ok(msg) => msg,
                 Err(e) => {
                     error!("Error receiving message: {:?}", e);
@@ -76,6 +86,12 @@ impl Pull {
                     continue;
                 }
             };
+            if payload.len() > MAX_RUMOR_BYTES {
+                warn!("Dropping rumor of {} bytes; the maximum is {} bytes",
+                      payload.len(),
+                      MAX_RUMOR_BYTES);
+                continue 'recv;
+            }
             let mut proto: Rumor = match protobuf::parse_from_bytes(&payload) {
                 Ok(proto) => proto,
                 Err(e) => {
@@ -110,9 +126,86 @@ impl Pull {
                 Rumor_Type::ElectionUpdate => {
                     self.server.insert_update_election(proto.into());
                 }
+                Rumor_Type::FileChunkRequest => {
+                    self.send_chunk(proto.get_from_id(),
+                                    proto.get_service_file().get_filename());
+                }
+                Rumor_Type::FileChunk => {
+                    let body = proto.mut_service_file().take_body();
+                    self.server
+                        .insert_chunk(proto.get_service_file().get_filename(), body);
+                }
                 Rumor_Type::Fake |
                 Rumor_Type::Fake2 => debug!("Nothing to do for fake rumor types"),
             }
         }
     }
+
+    /// Queue a reply to a chunk request from `member_id`, if we have the chunk. Replies are sent
+    /// by a single worker, so that neither a slow requester nor a flood of requests can hold up
+    /// the receipt of rumors.
+    fn send_chunk(&self, member_id: &str, hash: &str) {
+        if !self.server.chunk_store.contains(hash) {
+            return;
+        }
+        match self.chunk_requests
+                  .try_send((String::from(member_id), String::from(hash))) {
+            Ok(()) => {}
+            Err(TrySendError::Full(_)) => {
+                debug!("Dropping chunk request from {} - too many are waiting",
+                       member_id)
+            }
+            Err(TrySendError::Disconnected(_)) => error!("Chunk reply thread is gone"),
+        }
+    }
+}
+
+/// Sends the chunks that members asked for, one at a time.
+struct ChunkReplyWorker {
+    server: Server,
+}
+
+impl ChunkReplyWorker {
+    fn run(&self, requests: Receiver<(String, String)>) {
+        for (member_id, hash) in requests.iter() {
+            self.reply(&member_id, &hash);
+        }
+    }
+
+    fn reply(&self, member_id: &str, hash: &str) {
+        let bytes = match self.server.chunk_store.get(hash) {
+            Some(bytes) => bytes,
+            None => return,
+        };
+        let mut to_addr = None;
+        self.server
+            .member_list
+            .with_member(member_id, |m| to_addr = m.map(|m| m.gossip_socket_address()));
+        let to_addr = match to_addr {
+            Some(addr) => addr,
+            None => {
+                debug!("Not sending chunk to {} - it is not a member", member_id);
+                return;
+            }
+        };
+        let reply = chunk::chunk_rumor(self.server.member_id(), hash, bytes);
+        trace_it!(GOSSIP: &self.server, TraceKind::SendRumor, member_id, &reply);
+        let payload = match reply
+                  .write_to_bytes()
+                  .map_err(Error::from)
+                  .and_then(|bytes| self.server.generate_wire(bytes)) {
+            Ok(payload) => payload,
+            Err(e) => {
+                error!("Generating chunk reply failed: {}", e);
+                return;
+            }
+        };
+        match self.server
+                  .network
+                  .gossip_sender(self.server.gossip_addr(), to_addr)
+                  .and_then(|sender| sender.send(&payload)) {
+            Ok(()) => debug!("Sent chunk to {}", member_id),
+            Err(e) => warn!("Could not send chunk to {}: {}", member_id, e),
+        }
+    }
 }
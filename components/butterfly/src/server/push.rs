@@ -22,19 +22,21 @@ use std::thread;
 use std::time::Duration;
 
 use protobuf::Message;
-use time::SteadyTime;
-use zmq;
 
-use ZMQ_CONTEXT;
 use message::swim::{Rumor as ProtoRumor, Rumor_Type as ProtoRumor_Type, Member as ProtoMember,
                     Membership as ProtoMembership};
 use rumor::{RumorKey, RumorVec};
+use rumor::chunk;
 use member::Member;
 use server::Server;
 use server::timing::Timing;
 use trace::TraceKind;
 
 const FANOUT: usize = 5;
+/// How many members we ask for each missing service file chunk, per gossip round.
+const CHUNK_REQUEST_FANOUT: usize = 3;
+/// How many missing chunks we ask for, per gossip round.
+const CHUNK_REQUESTS_PER_ROUND: usize = 16;
 
 /// The Push server
 #[derive(Debug)]
@@ -59,7 +61,7 @@ impl Push {
     pub fn run(&mut self) {
         'send: loop {
             if self.server.pause.load(Ordering::Relaxed) {
-                thread::sleep(Duration::from_millis(100));
+                self.timing.sleep(Duration::from_millis(100));
                 continue;
             }
 
@@ -70,6 +72,8 @@ impl Push {
                 .check_list(self.server.member_id());
             let long_wait = self.timing.gossip_timeout();
 
+            self.request_missing_chunks(&check_list);
+
             'fanout: loop {
                 let mut thread_list = Vec::with_capacity(FANOUT);
                 if check_list.len() == 0 {
@@ -119,17 +123,68 @@ impl Push {
                         .join()
                         .map_err(|e| println!("Push worker died: {:?}", e));
                 }
-                if SteadyTime::now() < next_gossip {
-                    let wait_time = (next_gossip - SteadyTime::now()).num_milliseconds();
+                if self.timing.now() < next_gossip {
+                    let wait_time = (next_gossip - self.timing.now()).num_milliseconds();
                     if wait_time > 0 {
-                        thread::sleep(Duration::from_millis(wait_time as u64));
+                        self.timing.sleep(Duration::from_millis(wait_time as u64));
                     }
                 }
             }
-            if SteadyTime::now() < long_wait {
-                let wait_time = (long_wait - SteadyTime::now()).num_milliseconds();
+            if self.timing.now() < long_wait {
+                let wait_time = (long_wait - self.timing.now()).num_milliseconds();
                 if wait_time > 0 {
-                    thread::sleep(Duration::from_millis(wait_time as u64));
+                    self.timing.sleep(Duration::from_millis(wait_time as u64));
+                }
+            }
+        }
+    }
+
+    /// Ask a few members for any service file chunks we are still missing. Whoever has a chunk
+    /// will send it straight back; whoever doesn't will ignore us, and we will try someone else
+    /// next round.
+    fn request_missing_chunks(&self, check_list: &[Member]) {
+        let mut missing = self.server.missing_chunks();
+        if missing.len() == 0 {
+            return;
+        }
+        missing.truncate(CHUNK_REQUESTS_PER_ROUND);
+        let targets = check_list
+            .iter()
+            .filter(|m| {
+                        self.server.member_list.pingable(m) &&
+                        !self.server.member_list.persistent_and_confirmed(m) &&
+                        !self.server.check_blacklist(m.get_id())
+                    })
+            .take(CHUNK_REQUEST_FANOUT);
+        for member in targets {
+            let sender = match self.server
+                      .network
+                      .gossip_sender(self.server.gossip_addr(), member.gossip_socket_address()) {
+                Ok(sender) => sender,
+                Err(e) => {
+                    println!("Cannot connect push socket to {:?}: {:?}", member, e);
+                    continue;
+                }
+            };
+            for hash in missing.iter() {
+                let request = chunk::request_rumor(self.server.member_id(), hash);
+                trace_it!(GOSSIP: &self.server, TraceKind::SendRumor, member.get_id(), &request);
+                let bytes = match request.write_to_bytes() {
+                    Ok(bytes) => bytes,
+                    Err(e) => {
+                        error!("Could not write chunk request to bytes: {}", e);
+                        continue;
+                    }
+                };
+                let payload = match self.server.generate_wire(bytes) {
+                    Ok(payload) => payload,
+                    Err(e) => {
+                        error!("Generating protobuf failed: {}", e);
+                        continue;
+                    }
+                };
+                if let Err(e) = sender.send(&payload) {
+                    println!("Could not send chunk request to {:?}: {}", member, e);
                 }
             }
         }
@@ -147,38 +202,23 @@ impl PushWorker {
         PushWorker { server: server }
     }
 
-    /// Send the list of rumors to a given member. This method creates an outbound socket and then
-    /// closes the connection as soon as we are done sending rumors. ZeroMQ may choose to keep the
-    /// connection and socket open for 1 second longer - so it is possible, but unlikely, that this
-    /// method can loose messages.
+    /// Send the list of rumors to a given member. This method creates an outbound sender from the
+    /// server's `Network` and then closes the connection as soon as we are done sending rumors.
+    /// ZeroMQ may choose to keep the connection and socket open for 1 second longer - so it is
+    /// possible, but unlikely, that this method can loose messages.
     fn send_rumors(&self, member: Member, rumors: RumorVec) {
-        let socket = (**ZMQ_CONTEXT)
-            .as_mut()
-            .socket(zmq::PUSH)
-            .expect("Failure to create the ZMQ push socket");
-        socket
-            .set_linger(1000)
-            .expect("Failure to set the ZMQ push socket to not linger");
-        socket
-            .set_tcp_keepalive(0)
-            .expect("Failure to set the ZMQ push socket to not use keepalive");
-        socket
-            .set_immediate(true)
-            .expect("Failure to set the ZMQ push socket to immediate");
-        socket
-            .set_sndhwm(1000)
-            .expect("Failure to set the ZMQ push socket hwm");
-        socket
-            .set_sndtimeo(500)
-            .expect("Failure to set the ZMQ send timeout");
-        let to_addr = format!("{}:{}", member.get_address(), member.get_gossip_port());
-        match socket.connect(&format!("tcp://{}", to_addr)) {
-            Ok(()) => debug!("Connected push socket to {:?}", member),
+        let sender = match self.server
+                  .network
+                  .gossip_sender(self.server.gossip_addr(), member.gossip_socket_address()) {
+            Ok(sender) => {
+                debug!("Connected push socket to {:?}", member);
+                sender
+            }
             Err(e) => {
                 println!("Cannot connect push socket to {:?}: {:?}", member, e);
                 return;
             }
-        }
+        };
         'rumorlist: for &(ref rumor_key, ref _heat) in rumors.iter() {
             let rumor_as_bytes = match rumor_key.kind {
                 ProtoRumor_Type::Member => {
@@ -279,6 +319,11 @@ impl PushWorker {
                         }
                     }
                 }
+                ProtoRumor_Type::FileChunkRequest |
+                ProtoRumor_Type::FileChunk => {
+                    debug!("Chunks are sent directly, never gossiped; how odd!");
+                    continue 'rumorlist;
+                }
                 ProtoRumor_Type::Fake |
                 ProtoRumor_Type::Fake2 => {
                     debug!("You have fake rumors; how odd!");
@@ -292,9 +337,9 @@ impl PushWorker {
                     continue 'rumorlist;
                 }
             };
-            match socket.send(&payload, 0) {
+            match sender.send(&payload) {
                 Ok(()) => debug!("Sent rumor {:?} to {:?}", rumor_key, member),
-                Err(e) => println!("Could not send rumor to {:?}; ZMQ said: {:?}", member, e),
+                Err(e) => println!("Could not send rumor to {:?}: {}", member, e),
             }
         }
         self.server
@@ -12,6 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
 use time::{SteadyTime, Duration as TimeDuration};
 
 /// How long to wait for an Ack after we ping
@@ -23,6 +28,34 @@ const SUSPICION_TIMEOUT_DEFAULT_PROTOCOL_PERIODS: i64 = 3;
 /// How long is the gossip period
 const GOSSIP_PERIOD_DEFAULT_MS: i64 = 1000;
 
+/// The source of time for the server threads. Every timeout and every wait of a running server
+/// goes through its clock, so a simulation can substitute a clock of its own.
+pub trait Clock: fmt::Debug + Send + Sync {
+    /// The current time.
+    fn now(&self) -> SteadyTime;
+
+    /// Block the calling thread for `duration`.
+    fn sleep(&self, duration: Duration);
+
+    /// Called by `Server::start` once for each thread it is about to spawn that waits on this
+    /// clock or on the server's network.
+    fn add_thread(&self) {}
+}
+
+/// The clock of the host.
+#[derive(Debug, Default)]
+pub struct HostClock;
+
+impl Clock for HostClock {
+    fn now(&self) -> SteadyTime {
+        SteadyTime::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration)
+    }
+}
+
 /// The timing of the outbound threads.
 #[derive(Debug, Clone)]
 pub struct Timing {
@@ -30,6 +63,7 @@ pub struct Timing {
     pub pingreq_ms: i64,
     pub gossip_period_ms: i64,
     pub suspicion_timeout_protocol_periods: i64,
+    clock: Arc<Clock>,
 }
 
 impl Default for Timing {
@@ -39,6 +73,7 @@ impl Default for Timing {
             pingreq_ms: PINGREQ_TIMING_DEFAULT_MS,
             gossip_period_ms: GOSSIP_PERIOD_DEFAULT_MS,
             suspicion_timeout_protocol_periods: SUSPICION_TIMEOUT_DEFAULT_PROTOCOL_PERIODS,
+            clock: Arc::new(HostClock),
         }
     }
 }
@@ -55,12 +90,34 @@ impl Timing {
             pingreq_ms: pingreq_ms,
             gossip_period_ms: gossip_period_ms,
             suspicion_timeout_protocol_periods: suspicion_timeout_protocol_periods,
+            clock: Arc::new(HostClock),
         }
     }
 
+    /// Run on `clock` instead of the clock of the host.
+    pub fn with_clock(mut self, clock: Arc<Clock>) -> Timing {
+        self.clock = clock;
+        self
+    }
+
+    /// The clock this timing runs on.
+    pub fn clock(&self) -> Arc<Clock> {
+        self.clock.clone()
+    }
+
+    /// The current time, according to our clock.
+    pub fn now(&self) -> SteadyTime {
+        self.clock.now()
+    }
+
+    /// Block the calling thread for `duration`, according to our clock.
+    pub fn sleep(&self, duration: Duration) {
+        self.clock.sleep(duration)
+    }
+
     /// When should this gossip period expire
     pub fn gossip_timeout(&self) -> SteadyTime {
-        SteadyTime::now() + TimeDuration::milliseconds(self.gossip_period_ms)
+        self.now() + TimeDuration::milliseconds(self.gossip_period_ms)
     }
 
     /// How long is a protocol period, in millis.
@@ -70,17 +127,17 @@ impl Timing {
 
     /// When should this ping record time out?
     pub fn ping_timeout(&self) -> SteadyTime {
-        SteadyTime::now() + TimeDuration::milliseconds(self.ping_ms)
+        self.now() + TimeDuration::milliseconds(self.ping_ms)
     }
 
     /// When should this pingreq timeout?
     pub fn pingreq_timeout(&self) -> SteadyTime {
-        SteadyTime::now() + TimeDuration::milliseconds(self.pingreq_ms)
+        self.now() + TimeDuration::milliseconds(self.pingreq_ms)
     }
 
     /// How long before the next scheduled protocol period
     pub fn next_protocol_period(&self) -> SteadyTime {
-        SteadyTime::now() + TimeDuration::milliseconds(self.ping_ms + self.pingreq_ms)
+        self.now() + TimeDuration::milliseconds(self.ping_ms + self.pingreq_ms)
     }
 
     /// How long before this suspect entry times out
@@ -12,9 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! This module handles the writing of swim trace files, which can later be post-processed to see
+//! This module handles tracing of SWIM and gossip events, which can later be post-processed to see
 //! whats happening in a network.
+//!
+//! Every event is a `TraceWrite`. Depending on the `TraceMode`, events are either appended as one
+//! JSON object per line to a trace file, handed to a `TraceObserver` (which the Supervisor uses to
+//! export Prometheus counters labelled by kind and peer), or discarded. The mode starts out as
+//! the one named by the `TRACE_SWIM` environment variable and can be changed at any time while
+//! the server is running.
 
+use serde_json;
 use time;
 
 use std::default::Default;
@@ -23,10 +30,12 @@ use std::fmt;
 use std::fs;
 use std::path::PathBuf;
 use std::io::Write;
+use std::str::FromStr;
 
+use error::{Error, Result};
 use server::Server;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub enum TraceKind {
     MemberUpdate,
     ProbeBegin,
@@ -47,43 +56,113 @@ pub enum TraceKind {
     TestEvent,
 }
 
+impl TraceKind {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            TraceKind::MemberUpdate => "MemberUpdate",
+            TraceKind::ProbeBegin => "ProbeBegin",
+            TraceKind::ProbeAckReceived => "ProbeAckReceived",
+            TraceKind::ProbeConfirmed => "ProbeConfirmed",
+            TraceKind::ProbeComplete => "ProbeComplete",
+            TraceKind::ProbeSuspect => "ProbeSuspect",
+            TraceKind::ProbePingReq => "ProbePingReq",
+            TraceKind::RecvAck => "RecvAck",
+            TraceKind::RecvPing => "RecvPing",
+            TraceKind::RecvPingReq => "RecvPingReq",
+            TraceKind::RecvRumor => "RecvRumor",
+            TraceKind::SendAck => "SendAck",
+            TraceKind::SendForwardAck => "SendForwardAck",
+            TraceKind::SendPing => "SendPing",
+            TraceKind::SendPingReq => "SendPingReq",
+            TraceKind::SendRumor => "SendRumor",
+            TraceKind::TestEvent => "TestEvent",
+        }
+    }
+}
+
 impl fmt::Display for TraceKind {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Chooses the `TraceMode` of every server started by this process.
+pub const TRACE_ENVVAR: &'static str = "TRACE_SWIM";
+
+/// Where trace events go.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceMode {
+    /// Events are discarded.
+    Off,
+    /// Events are appended to a trace file as JSON lines.
+    Json,
+    /// Events are handed to the registered `TraceObserver`.
+    Metrics,
+}
+
+impl TraceMode {
+    /// The mode chosen by the `TRACE_SWIM` environment variable: off when it is unset, else the
+    /// mode it names, defaulting to writing JSON lines for any other value.
+    pub fn from_env() -> TraceMode {
+        match env::var(TRACE_ENVVAR) {
+            Ok(val) => TraceMode::from_str(&val).unwrap_or(TraceMode::Json),
+            Err(_) => TraceMode::Off,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
         match *self {
-            TraceKind::MemberUpdate => write!(f, "MemberUpdate"),
-            TraceKind::ProbeBegin => write!(f, "ProbeBegin"),
-            TraceKind::ProbeAckReceived => write!(f, "ProbeAckReceived"),
-            TraceKind::ProbeConfirmed => write!(f, "ProbeConfirmed"),
-            TraceKind::ProbeComplete => write!(f, "ProbeComplete"),
-            TraceKind::ProbeSuspect => write!(f, "ProbeSuspect"),
-            TraceKind::ProbePingReq => write!(f, "ProbePingReq"),
-            TraceKind::RecvAck => write!(f, "RecvAck"),
-            TraceKind::RecvPing => write!(f, "RecvPing"),
-            TraceKind::RecvPingReq => write!(f, "RecvPingReq"),
-            TraceKind::RecvRumor => write!(f, "RecvRumor"),
-            TraceKind::SendAck => write!(f, "SendAck"),
-            TraceKind::SendForwardAck => write!(f, "SendForwardAck"),
-            TraceKind::SendPing => write!(f, "SendPing"),
-            TraceKind::SendPingReq => write!(f, "SendPingReq"),
-            TraceKind::SendRumor => write!(f, "SendRumor"),
-            TraceKind::TestEvent => write!(f, "TestEvent"),
+            TraceMode::Off => "off",
+            TraceMode::Json => "json",
+            TraceMode::Metrics => "metrics",
         }
     }
 }
 
-#[derive(Debug)]
+impl fmt::Display for TraceMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for TraceMode {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.to_lowercase().as_ref() {
+            "off" => Ok(TraceMode::Off),
+            "json" => Ok(TraceMode::Json),
+            "metrics" => Ok(TraceMode::Metrics),
+            _ => Err(Error::InvalidTraceMode(String::from(value))),
+        }
+    }
+}
+
+/// Receives every trace event while the `Trace` is in `TraceMode::Metrics`.
+pub trait TraceObserver: fmt::Debug + Send + Sync {
+    fn observe(&self, event: &TraceWrite);
+}
+
+#[derive(Debug, Serialize)]
 pub struct TraceWrite<'a> {
-    pub kind: TraceKind,
     pub time: String,
+    pub kind: TraceKind,
     pub module_path: &'a str,
     pub line: u32,
     pub thread_name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub server_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub member_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub to_member_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub listening: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub to_addr: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub swim: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub rumor: Option<&'a str>,
 }
 
@@ -94,13 +173,9 @@ impl<'a> TraceWrite<'a> {
                thread_name: &'a str)
                -> TraceWrite<'a> {
         let now = time::now_utc();
-        let time_string = format!("{}-{}-{}-{}-{}-{}-{}",
-                                  1900 + now.tm_year,
-                                  now.tm_mon + 1,
-                                  now.tm_mday,
-                                  now.tm_hour,
-                                  now.tm_min,
-                                  now.tm_sec,
+        let time_string = format!("{}.{:09}Z",
+                                  now.strftime("%Y-%m-%dT%H:%M:%S")
+                                      .expect("Trace timestamp format is invalid"),
                                   now.tm_nsec);
         TraceWrite {
             kind: kind,
@@ -117,32 +192,29 @@ impl<'a> TraceWrite<'a> {
             rumor: None,
         }
     }
+
+    /// The member this event was sent to or received from, if any.
+    pub fn peer(&self) -> &str {
+        self.to_member_id.unwrap_or("")
+    }
 }
 
 impl<'a> fmt::Display for TraceWrite<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        try!(write!(f, "{}", self.time));
-        try!(write!(f, "^{}", self.kind));
-        try!(write!(f, "^{}", self.thread_name));
-        try!(write!(f, "^{}", self.module_path));
-        try!(write!(f, "^{}", self.line));
-        try!(write!(f, "^{}", self.server_name.unwrap_or("")));
-        try!(write!(f, "^{}", self.member_id.unwrap_or("")));
-        try!(write!(f, "^{}", self.to_member_id.unwrap_or("")));
-        try!(write!(f, "^{}", self.listening.unwrap_or("")));
-        try!(write!(f, "^{}", self.to_addr.unwrap_or("")));
-        try!(write!(f, "^{}", self.swim.unwrap_or("")));
-        try!(write!(f, "^{}", self.rumor.unwrap_or("")));
-        write!(f, "\n")
+        match serde_json::to_string(self) {
+            Ok(json) => write!(f, "{}", json),
+            Err(_) => Err(fmt::Error),
+        }
     }
 }
 
-/// The trace struct handles writing trace files to a directory path.
+/// The trace struct routes trace events according to its `TraceMode`.
 #[derive(Debug)]
 pub struct Trace {
     pub directory: PathBuf,
     pub file: Option<fs::File>,
-    pub on: bool,
+    mode: TraceMode,
+    observer: Option<Box<TraceObserver>>,
 }
 
 impl Default for Trace {
@@ -150,50 +222,78 @@ impl Default for Trace {
         Trace {
             directory: PathBuf::from("/tmp/habitat-swim-trace"),
             file: None,
-            on: false,
+            mode: TraceMode::from_env(),
+            observer: None,
         }
     }
 }
 
 impl Trace {
-    /// Initialize the trace object; only happens once.
+    /// Initialize the trace file when writing JSON lines; only happens once.
     pub fn init(&mut self, server: &Server) {
-        if self.file.is_none() {
-            let now = time::now_utc();
-            let filename = format!("{}-{}.swimtrace", server.name(), now.rfc3339());
-            match fs::File::create(self.directory.join(&filename)) {
-                Ok(f) => self.file = Some(f),
-                Err(e) => {
-                    panic!("Trace requested, but cannot create file {:?}: {}",
-                           self.directory.join(&filename),
-                           e)
-                }
+        if self.mode() != TraceMode::Json || self.file.is_some() {
+            return;
+        }
+        let now = time::now_utc();
+        let filename = format!("{}-{}.swimtrace", server.name(), now.rfc3339());
+        match fs::File::create(self.directory.join(&filename)) {
+            Ok(f) => self.file = Some(f),
+            Err(e) => {
+                error!("Trace requested, but cannot create file {:?}: {}; turning trace off",
+                       self.directory.join(&filename),
+                       e);
+                self.mode = TraceMode::Off;
             }
         }
     }
 
-    /// Returns true if the TRACE_SWIM environment variable exists.
+    /// The current mode.
+    pub fn mode(&self) -> TraceMode {
+        self.mode
+    }
+
+    /// Change where trace events go from now on.
+    pub fn set_mode(&mut self, mode: TraceMode) {
+        self.mode = mode;
+    }
+
+    /// Register the observer which receives events in `TraceMode::Metrics`.
+    pub fn set_observer(&mut self, observer: Box<TraceObserver>) {
+        self.observer = Some(observer);
+    }
+
+    /// Returns true if trace events are being recorded anywhere.
     pub fn on(&self) -> bool {
-        match env::var("TRACE_SWIM") {
-            Ok(_val) => true,
-            Err(_e) => self.on,
+        match self.mode() {
+            TraceMode::Off => false,
+            TraceMode::Json => true,
+            TraceMode::Metrics => self.observer.is_some(),
         }
     }
 
-    /// Write a line to the trace file.
+    /// Record a trace event.
     pub fn write(&mut self, trace_write: TraceWrite) {
-        let dump = format!("{:#?}", self);
-        match self.file.as_mut() {
-            Some(mut file) => {
-                match write!(file, "{}", trace_write) {
-                    Ok(_) => {}
-                    Err(e) => panic!("Trace requested, but failed to write {:?}", e),
+        match self.mode() {
+            TraceMode::Off => {}
+            TraceMode::Json => {
+                let result = match self.file.as_mut() {
+                    Some(file) => writeln!(file, "{}", trace_write),
+                    None => {
+                        debug!("Trace requested, but init was never called; use the trace! \
+                                macro instead");
+                        return;
+                    }
+                };
+                if let Err(e) = result {
+                    error!("Trace requested, but failed to write; turning trace off: {}", e);
+                    self.mode = TraceMode::Off;
+                    self.file = None;
                 }
             }
-            None => {
-                panic!("Trace requested, but init was never called; use the trace! macro \
-                        instead: {:#?}",
-                       dump);
+            TraceMode::Metrics => {
+                if let Some(ref observer) = self.observer {
+                    observer.observe(&trace_write);
+                }
             }
         }
     }
@@ -386,6 +486,9 @@ macro_rules! trace_it {
                                 $payload.get_election().get_status(),
                                 $payload.get_election().get_votes())
                     }
+                    Rumor_Type::FileChunkRequest | Rumor_Type::FileChunk => {
+                        format!("{}", $payload.get_service_file().get_filename())
+                    }
                     Rumor_Type::Fake | Rumor_Type::Fake2 => format!("nothing-to-see"),
                 };
 
@@ -405,13 +508,61 @@ macro_rules! trace_it {
 #[cfg(test)]
 mod tests {
     mod trace {
-        use trace::Trace;
         use std::path::Path;
+        use std::str::FromStr;
+        use std::sync::{Arc, Mutex};
+
+        use trace::{Trace, TraceKind, TraceMode, TraceObserver, TraceWrite};
+
+        #[derive(Debug, Default)]
+        struct CountingObserver(Arc<Mutex<Vec<(TraceKind, String)>>>);
+
+        impl TraceObserver for CountingObserver {
+            fn observe(&self, event: &TraceWrite) {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push((event.kind, String::from(event.peer())));
+            }
+        }
 
         #[test]
         fn default() {
             let trace = Trace::default();
             assert_eq!(trace.directory, Path::new("/tmp/habitat-swim-trace"));
         }
+
+        #[test]
+        fn mode_from_str() {
+            assert_eq!(TraceMode::from_str("off").unwrap(), TraceMode::Off);
+            assert_eq!(TraceMode::from_str("JSON").unwrap(), TraceMode::Json);
+            assert_eq!(TraceMode::from_str("metrics").unwrap(), TraceMode::Metrics);
+            assert!(TraceMode::from_str("prometheus").is_err());
+        }
+
+        #[test]
+        fn write_is_json() {
+            let mut tw = TraceWrite::new(TraceKind::SendPing, "butterfly::server", 42, "outbound");
+            tw.to_member_id = Some("peer-1");
+            let json = format!("{}", tw);
+            assert!(json.starts_with("{\"time\":"));
+            assert!(json.contains("\"kind\":\"SendPing\""));
+            assert!(json.contains("\"to_member_id\":\"peer-1\""));
+            assert!(!json.contains("rumor"));
+        }
+
+        #[test]
+        fn metrics_mode_notifies_observer() {
+            let events = Arc::new(Mutex::new(Vec::new()));
+            let mut trace = Trace::default();
+            trace.set_observer(Box::new(CountingObserver(events.clone())));
+            trace.set_mode(TraceMode::Metrics);
+            assert!(trace.on());
+            let mut tw = TraceWrite::new(TraceKind::RecvRumor, "butterfly::server", 42, "pull");
+            tw.to_member_id = Some("peer-2");
+            trace.write(tw);
+            assert_eq!(*events.lock().unwrap(),
+                       vec![(TraceKind::RecvRumor, String::from("peer-2"))]);
+        }
     }
 }
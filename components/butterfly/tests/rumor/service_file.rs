@@ -15,6 +15,8 @@
 use btest;
 use habitat_core::service::ServiceGroup;
 use habitat_butterfly::client::Client;
+use habitat_butterfly::rumor::MAX_RUMOR_BYTES;
+use habitat_butterfly::rumor::chunk::CHUNK_SIZE;
 
 #[test]
 fn two_members_share_service_files() {
@@ -53,3 +55,46 @@ fn service_file_via_client() {
                     "devil-wears-prada.txt",
                     |u| assert!(u.is_some()));
 }
+
+#[test]
+fn large_service_file_via_client_is_chunked() {
+    let mut net = btest::SwimNet::new(3);
+    net.mesh();
+
+    net.wait_for_gossip_rounds(1);
+    let mut client =
+        Client::new(net[0].gossip_addr(), None).expect("Cannot create Butterfly Client");
+    let payload = vec![b'x'; CHUNK_SIZE * 4 + 17];
+    client
+        .send_service_file(ServiceGroup::new("witcher", "prod", None).unwrap(),
+                           "ca-bundle.pem",
+                           0,
+                           payload.clone(),
+                           false)
+        .expect("Cannot send the service file");
+    net.wait_for_gossip_rounds(3);
+    for member in 1..3 {
+        net[member]
+            .service_file_store
+            .with_rumor("witcher.prod", "ca-bundle.pem", |u| {
+                let service_file = u.expect("Service file was not gossiped");
+                assert!(service_file.is_chunked());
+                assert_eq!(service_file.body_from(&net[member].chunk_store).unwrap(),
+                           payload);
+            });
+    }
+}
+
+#[test]
+fn oversized_service_config_is_refused_by_the_client() {
+    let net = btest::SwimNet::new(1);
+    let mut client =
+        Client::new(net[0].gossip_addr(), None).expect("Cannot create Butterfly Client");
+    let config = vec![b'x'; MAX_RUMOR_BYTES + 1];
+    assert!(client
+                .send_service_config(ServiceGroup::new("witcher", "prod", None).unwrap(),
+                                     0,
+                                     config,
+                                     false)
+                .is_err());
+}
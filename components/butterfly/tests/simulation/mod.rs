@@ -0,0 +1,200 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use habitat_butterfly::member::Health;
+use habitat_butterfly::message::swim::Election_Status;
+use habitat_butterfly::server::timing::Timing;
+use habitat_butterfly::trace::{TraceKind, TraceMode, TraceObserver, TraceWrite};
+
+use btest;
+use btest::sim::{LinkConditions, SimClock, SimNetwork};
+
+/// A `Timing` ten times faster than the default, so simulated rings converge quickly in CI.
+fn fast_timing() -> Timing {
+    Timing::new(100, 210, 100, 3)
+}
+
+fn lossy(loss: f64) -> LinkConditions {
+    LinkConditions::new(loss, Duration::from_millis(5), Duration::from_millis(20))
+}
+
+/// Trace events, with the simulated time each happened.
+type Traced = Arc<Mutex<Vec<(TraceKind, i64)>>>;
+
+/// Records every trace event a member has about one peer.
+#[derive(Debug)]
+struct PeerEvents {
+    clock: SimClock,
+    peer: String,
+    events: Traced,
+}
+
+impl TraceObserver for PeerEvents {
+    fn observe(&self, event: &TraceWrite) {
+        if event.peer() == self.peer {
+            self.events
+                .lock()
+                .expect("Events lock is poisoned")
+                .push((event.kind, self.clock.elapsed_ms()));
+        }
+    }
+}
+
+/// Trace what member `from` does with member `peer`.
+fn trace_peer(net: &btest::SwimNet, from: usize, peer: usize) -> Traced {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let mut trace = net[from].trace.write().expect("Trace lock is poisoned");
+    trace.set_observer(Box::new(PeerEvents {
+                                    clock: net.simulated().clock().clone(),
+                                    peer: String::from(net[peer].member_id()),
+                                    events: events.clone(),
+                                }));
+    trace.set_mode(TraceMode::Metrics);
+    events
+}
+
+/// The simulated times of every traced event of `kind`.
+fn traced(events: &Traced, kind: TraceKind) -> Vec<i64> {
+    events
+        .lock()
+        .expect("Events lock is poisoned")
+        .iter()
+        .filter(|&&(k, _)| k == kind)
+        .map(|&(_, at)| at)
+        .collect()
+}
+
+#[test]
+fn simulated_members_mesh_and_stay_alive() {
+    let mut net = btest::SwimNet::new_simulated(3, SimNetwork::new(1, fast_timing()));
+    net.mesh();
+    assert_wait_for_health_of!(net, [0..3, 0..3], Health::Alive);
+    assert!(net.simulated().stats().delivered > 0);
+}
+
+#[test]
+fn simulated_members_stay_alive_under_packet_loss() {
+    let network = SimNetwork::new(2, fast_timing());
+    network.set_default_link(lossy(0.2));
+    let mut net = btest::SwimNet::new_simulated(5, network);
+    net.mesh();
+    net.wait_for_rounds(3);
+    assert_wait_for_health_of!(net, [0..5, 0..5], Health::Alive);
+    assert!(net.simulated().stats().dropped > 0);
+}
+
+#[test]
+fn simulated_partition_confirms_both_sides() {
+    let mut net = btest::SwimNet::new_simulated(6, SimNetwork::new(3, fast_timing()));
+    net.mesh();
+    assert_wait_for_health_of!(net, 0, Health::Alive);
+    net.sim_partition(0..3, 3..6);
+    assert_wait_for_health_of!(net, [0..3, 3..6], Health::Confirmed);
+    assert!(net.simulated().stats().partitioned > 0);
+}
+
+#[test]
+fn simulated_partition_heals_with_persistent_peers() {
+    let mut net = btest::SwimNet::new_simulated(6, SimNetwork::new(4, fast_timing()));
+    net[0]
+        .member
+        .write()
+        .expect("Member lock is poisoned")
+        .set_persistent(true);
+    net[4]
+        .member
+        .write()
+        .expect("Member lock is poisoned")
+        .set_persistent(true);
+    net.mesh();
+    assert_wait_for_health_of!(net, [0..6, 0..6], Health::Alive);
+    net.sim_partition(0..3, 3..6);
+    assert_wait_for_health_of!(net, [0..3, 3..6], Health::Confirmed);
+    net.sim_heal();
+    assert_wait_for_health_of!(net, [0..3, 3..6], Health::Alive);
+}
+
+#[test]
+fn simulated_slow_link_is_not_confirmed() {
+    let network = SimNetwork::new(5, fast_timing());
+    // Every round trip takes longer than the ping timeout, but well inside the pingreq timeout.
+    network.set_link_both(0,
+                          1,
+                          LinkConditions::new(0.0,
+                                              Duration::from_millis(60),
+                                              Duration::from_millis(20)));
+    let mut net = btest::SwimNet::new_simulated(3, network);
+    let probes = trace_peer(&net, 0, 1);
+    net.mesh();
+    net.wait_for_rounds(3);
+    assert_wait_for_health_of!(net, [0..3, 0..3], Health::Alive);
+    // The direct pings timed out, so 1 was only kept alive by asking 2 to probe it.
+    assert!(traced(&probes, TraceKind::ProbePingReq).len() > 0);
+}
+
+#[test]
+fn simulated_election_finishes_under_packet_loss() {
+    let network = SimNetwork::new(6, fast_timing());
+    network.set_default_link(lossy(0.1));
+    let mut net = btest::SwimNet::new_simulated(3, network);
+    net.mesh();
+    net.add_service(0, "core/witcher/1.2.3/20161208121212");
+    net.add_service(1, "core/witcher/1.2.3/20161208121212");
+    net.add_service(2, "core/witcher/1.2.3/20161208121212");
+    net.add_election(0, "witcher");
+    assert_wait_for_election_status!(net, [0..3], "witcher.prod", Election_Status::Finished);
+    assert_wait_for_equal_election!(net, [0..3, 0..3], "witcher.prod");
+}
+
+#[test]
+fn simulated_rumor_crosses_a_one_way_partition() {
+    let network = SimNetwork::new(7, fast_timing());
+    network.partition_one_way(0, 2);
+    let mut net = btest::SwimNet::new_simulated(3, network);
+    net.mesh();
+    net.add_service_file(0, "witcher", "yeppers", "routed the long way round");
+    net.wait_for_gossip_rounds(3);
+    net[2]
+        .service_file_store
+        .with_rumor("witcher.prod", "yeppers", |u| assert!(u.is_some()));
+}
+
+#[test]
+fn simulated_clock_counts_protocol_periods() {
+    let timing = fast_timing();
+    let network = SimNetwork::new(8, timing.clone());
+    let clock = network.clock().clone();
+    assert_eq!(clock.protocol_periods(), 0);
+    clock.advance(clock.protocol_period_duration(2));
+    assert_eq!(clock.protocol_periods(), 2);
+
+    // Every packet arrives a whole protocol period after it was sent, on the clock the servers
+    // run on.
+    let period = clock.protocol_period_duration(1);
+    network.set_default_link(LinkConditions::new(0.0, period, Duration::from_millis(0)));
+    let mut net = btest::SwimNet::new_simulated(2, network);
+    let sent = trace_peer(&net, 0, 1);
+    let received = trace_peer(&net, 1, 0);
+    net.mesh();
+    net.wait_for_rounds(3);
+    let sent_at = traced(&sent, TraceKind::SendPing);
+    let received_at = traced(&received, TraceKind::RecvPing);
+    assert!(sent_at.len() > 0);
+    assert!(received_at.len() > 0);
+    assert!(received_at[0] - sent_at[0] >= timing.protocol_period_ms());
+    assert!(clock.protocol_periods() >= 5);
+}
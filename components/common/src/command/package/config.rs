@@ -22,7 +22,6 @@
 //!
 //! Will show the `default.toml`.
 
-use std::io::{self, Write};
 use std::path::Path;
 
 use hcore::package::{PackageIdent, PackageInstall};
@@ -30,20 +29,18 @@ use hcore::package::install::DEFAULT_CFG_FILE;
 use toml;
 
 use error::Result;
+use ui::UI;
 
-pub fn start<P>(ident: &PackageIdent, fs_root_path: P) -> Result<()>
+pub fn start<P>(ui: &mut UI, ident: &PackageIdent, fs_root_path: P) -> Result<()>
     where P: AsRef<Path>
 {
     let package = try!(PackageInstall::load(ident, Some(fs_root_path.as_ref())));
     match package.default_cfg() {
-        Some(cfg) => println!("{}", try!(toml::ser::to_string(&cfg))),
-        None => {
-            writeln!(&mut io::stderr(),
-                     "No '{}' found for {}",
-                     DEFAULT_CFG_FILE,
-                     package.ident())
-                    .expect("Failed printing to stderr")
+        Some(cfg) => {
+            let text = try!(toml::ser::to_string(&cfg));
+            try!(ui.result(&cfg, &[text]));
         }
+        None => try!(ui.warn(format!("No '{}' found for {}", DEFAULT_CFG_FILE, package.ident()))),
     }
     Ok(())
 }
@@ -28,23 +28,40 @@
 //!
 //! This would install the `3.0.1` version of redis.
 //!
+//! ```bash
+//! $ hab pkg install --bundle core-redis-3.0.1-20160614125453.bundle
+//! ```
+//!
+//! Will install `core/redis` and its dependencies from a bundle made with `hab pkg bundle`,
+//! without contacting a depot.
+//!
 //! # Internals
 //!
 //! * Download the artifact
-//! * Verify it is un-altered
-//! * Unpack it
+//! * Download the artifacts of its missing dependencies, several at a time
+//! * Verify they are un-altered
+//! * Unpack them
 //!
 
+use std::cmp;
 use std::fs;
+use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::sync::mpsc::{self, Sender};
+use std::thread;
 
-use depot_client::{self, Client};
+use depot_client::{self, Client, DisplayProgress};
 use hcore;
-use hcore::fs::{am_i_root, cache_key_path};
+use hcore::env as henv;
+use hcore::fs::{am_i_root, cache_artifact_path, cache_key_path, CACHE_UNPACK_PATH, FS_ROOT_PATH};
 use hcore::crypto::{artifact, SigKeyPair};
 use hcore::crypto::keys::parse_name_with_rev;
-use hcore::package::{Identifiable, PackageArchive, PackageIdent, Target, PackageInstall};
+use hcore::crypto::revocation::RevocationList;
+use hcore::package::{Identifiable, PackageArchive, PackageBundle, PackageIdent, PackageTarget,
+                     Target, PackageInstall, VersionReq};
+use hcore::url::DEFAULT_DEPOT_URL;
 use hyper::status::StatusCode;
 
 use error::{Error, Result};
@@ -55,6 +72,10 @@ use retry::retry;
 pub const RETRIES: u64 = 5;
 pub const RETRY_WAIT: u64 = 3000;
 
+/// The number of artifacts downloaded at the same time, unless set by `DOWNLOAD_WORKERS_ENVVAR`.
+pub const DOWNLOAD_WORKERS: usize = 4;
+pub const DOWNLOAD_WORKERS_ENVVAR: &'static str = "HAB_DOWNLOAD_WORKERS";
+
 pub fn start<P1: ?Sized, P2: ?Sized>(ui: &mut UI,
                                      url: &str,
                                      ident_or_archive: &str,
@@ -84,7 +105,8 @@ pub fn start<P1: ?Sized, P2: ?Sized>(ui: &mut UI,
                                      fs_root_path.as_ref(),
                                      cache_artifact_path.as_ref(),
                                      &cache_key_path,
-                                     ignore_target));
+                                     ignore_target,
+                                     false));
 
     if Path::new(ident_or_archive).is_file() {
         task.from_artifact(ui, &Path::new(ident_or_archive))
@@ -93,12 +115,164 @@ pub fn start<P1: ?Sized, P2: ?Sized>(ui: &mut UI,
     }
 }
 
+/// Installs the newest release of `ident` in the Depot whose version satisfies `version_req`, and
+/// its dependencies, like `start`.
+pub fn start_matching<P1: ?Sized, P2: ?Sized>(ui: &mut UI,
+                                              url: &str,
+                                              ident: &PackageIdent,
+                                              version_req: &VersionReq,
+                                              product: &str,
+                                              version: &str,
+                                              fs_root_path: &P1,
+                                              cache_artifact_path: &P2,
+                                              ignore_target: bool)
+                                              -> Result<PackageIdent>
+    where P1: AsRef<Path>,
+          P2: AsRef<Path>
+{
+    if !am_i_root() {
+        try!(ui.warn("Installing a package requires root or administrator privileges. Please retry \
+                   this command as a super user or use a privilege-granting facility such as \
+                   sudo."));
+        try!(ui.br());
+        return Err(Error::RootRequired);
+    }
+
+    let cache_key_path = cache_key_path(Some(fs_root_path.as_ref()));
+    let task = try!(InstallTask::new(url,
+                                     product,
+                                     version,
+                                     fs_root_path.as_ref(),
+                                     cache_artifact_path.as_ref(),
+                                     &cache_key_path,
+                                     ignore_target,
+                                     false));
+    task.from_ident_matching(ui, ident.clone(), version_req)
+}
+
+/// Installs `ident` and its dependencies into `fs_root_path` like `start`, but only requires root
+/// to install into the system's root: it's for callers which may install into a filesystem root
+/// of their own, such as the native exporters of `hab pkg export`.
+pub fn start_in_root(ui: &mut UI,
+                     url: &str,
+                     ident: &PackageIdent,
+                     product: &str,
+                     version: &str,
+                     fs_root_path: &Path)
+                     -> Result<PackageIdent> {
+    if !am_i_root() && fs_root_path == Path::new(&*FS_ROOT_PATH) {
+        try!(ui.warn("Installing a package requires root or administrator privileges. Please retry \
+                   this command as a super user or use a privilege-granting facility such as \
+                   sudo."));
+        try!(ui.br());
+        return Err(Error::RootRequired);
+    }
+    let cache_key_path = cache_key_path(Some(fs_root_path));
+    let cache_artifact_path = cache_artifact_path(Some(fs_root_path));
+    let task = try!(InstallTask::new(url,
+                                     product,
+                                     version,
+                                     fs_root_path,
+                                     &cache_artifact_path,
+                                     &cache_key_path,
+                                     false,
+                                     false));
+    task.from_ident(ui, ident.clone())
+}
+
+/// Install a package and its dependencies from a bundle made with `hab pkg bundle`, without
+/// contacting a Depot. The key that signed the bundle must already be in the key cache; the
+/// origin keys in the bundle, which its signature vouches for whatever their origin, are then
+/// imported, and every artifact is verified as it is installed.
+pub fn start_from_bundle<P1: ?Sized, P2: ?Sized>(ui: &mut UI,
+                                                 bundle_path: &Path,
+                                                 product: &str,
+                                                 version: &str,
+                                                 fs_root_path: &P1,
+                                                 cache_artifact_path: &P2,
+                                                 ignore_target: bool)
+                                                 -> Result<PackageIdent>
+    where P1: AsRef<Path>,
+          P2: AsRef<Path>
+{
+    if !am_i_root() {
+        try!(ui.warn("Installing a package requires root or administrator privileges. Please retry \
+                   this command as a super user or use a privilege-granting facility such as \
+                   sudo."));
+        try!(ui.br());
+        return Err(Error::RootRequired);
+    }
+
+    let cache_key_path = cache_key_path(Some(fs_root_path.as_ref()));
+    let bundle = PackageBundle::new(bundle_path);
+    let unpack_path = fs_root_path
+        .as_ref()
+        .join(CACHE_UNPACK_PATH)
+        .join(try!(bundle_path.file_name().ok_or(Error::FileNameError)));
+    if unpack_path.exists() {
+        try!(fs::remove_dir_all(&unpack_path));
+    }
+    let result = install_bundle(ui,
+                                &bundle,
+                                &unpack_path,
+                                product,
+                                version,
+                                fs_root_path.as_ref(),
+                                cache_artifact_path.as_ref(),
+                                &cache_key_path,
+                                ignore_target);
+    if let Err(e) = fs::remove_dir_all(&unpack_path) {
+        debug!("Failed to remove {}, {}", unpack_path.display(), e);
+    }
+    result
+}
+
+fn install_bundle(ui: &mut UI,
+                  bundle: &PackageBundle,
+                  unpack_path: &Path,
+                  product: &str,
+                  version: &str,
+                  fs_root_path: &Path,
+                  cache_artifact_path: &Path,
+                  cache_key_path: &Path,
+                  ignore_target: bool)
+                  -> Result<PackageIdent> {
+    let contents = try!(bundle.unpack_verified(unpack_path, &cache_key_path));
+    try!(ui.status(Status::Verified,
+                   format!("bundle for {} signed by {}", &contents.ident, &contents.signer)));
+    let (imported, skipped) = try!(contents.import_keys(cache_key_path));
+    for name_with_rev in imported {
+        try!(ui.status(Status::Cached, format!("{} public origin key", name_with_rev)));
+    }
+    for name_with_rev in skipped {
+        try!(ui.warn(format!("Not importing {} from the bundle: only public origin keys are \
+                              imported from a bundle",
+                             name_with_rev)));
+    }
+
+    // The Depot URL is never used for an offline install.
+    let task = try!(InstallTask::new(DEFAULT_DEPOT_URL,
+                                     product,
+                                     version,
+                                     fs_root_path,
+                                     cache_artifact_path,
+                                     cache_key_path,
+                                     ignore_target,
+                                     true));
+    task.from_artifact(ui, &contents.artifact)
+}
+
 struct InstallTask<'a> {
     depot_client: Client,
+    url: String,
+    product: String,
+    version: String,
     fs_root_path: &'a Path,
     cache_artifact_path: &'a Path,
     cache_key_path: &'a Path,
     ignore_target: bool,
+    /// Never contact the Depot; every artifact and key must already be available locally.
+    offline: bool,
 }
 
 impl<'a> InstallTask<'a> {
@@ -108,14 +282,19 @@ impl<'a> InstallTask<'a> {
                fs_root_path: &'a Path,
                cache_artifact_path: &'a Path,
                cache_key_path: &'a Path,
-               ignore_target: bool)
+               ignore_target: bool,
+               offline: bool)
                -> Result<Self> {
         Ok(InstallTask {
                depot_client: try!(Client::new(url, product, version, Some(fs_root_path))),
+               url: url.to_string(),
+               product: product.to_string(),
+               version: version.to_string(),
                fs_root_path: fs_root_path,
                cache_artifact_path: cache_artifact_path,
                cache_key_path: cache_key_path,
                ignore_target: ignore_target,
+               offline: offline,
            })
     }
 
@@ -125,6 +304,22 @@ impl<'a> InstallTask<'a> {
         if !ident.fully_qualified() {
             ident = try!(self.fetch_latest_pkg_ident_for(&ident));
         }
+        self.install_release(ui, ident)
+    }
+
+    pub fn from_ident_matching(&self,
+                               ui: &mut UI,
+                               ident: PackageIdent,
+                               version_req: &VersionReq)
+                               -> Result<PackageIdent> {
+        try!(ui.begin(format!("Installing {} ({})", &ident, version_req)));
+        let ident: PackageIdent =
+            try!(self.depot_client.show_package_matching(&ident, version_req)).into();
+        self.install_release(ui, ident)
+    }
+
+    /// Installs a fully qualified `ident`, unless it's installed already.
+    fn install_release(&self, ui: &mut UI, ident: PackageIdent) -> Result<PackageIdent> {
         if try!(self.is_package_installed(&ident)) {
             try!(ui.status(Status::Using, &ident));
             try!(ui.end(format!("Install of {} complete with {} new packages installed.",
@@ -157,15 +352,22 @@ impl<'a> InstallTask<'a> {
                        src_path: Option<&Path>)
                        -> Result<PackageIdent> {
         let mut artifact = try!(self.get_cached_artifact(ui, ident.clone(), src_path));
-        let mut artifacts: Vec<PackageArchive> = Vec::new();
-
+        let mut missing = Vec::new();
         for ident in try!(artifact.tdeps()) {
             if try!(self.is_package_installed(&ident)) {
                 try!(ui.status(Status::Using, &ident));
             } else {
-                artifacts.push(try!(self.get_cached_artifact(ui, ident, src_path)));
+                missing.push(ident);
             }
         }
+
+        if src_path.is_none() && !self.offline {
+            try!(self.download_artifacts(ui, &missing));
+        }
+        let mut artifacts: Vec<PackageArchive> = Vec::new();
+        for ident in missing {
+            artifacts.push(try!(self.get_cached_artifact(ui, ident, src_path)));
+        }
         artifacts.push(artifact);
 
         let num_installed = artifacts.len();
@@ -186,6 +388,8 @@ impl<'a> InstallTask<'a> {
         if try!(self.is_artifact_cached(&ident)) {
             debug!("Found {} in artifact cache, skipping remote download",
                    &ident);
+        } else if self.offline {
+            try!(self.fetch_artifact(ui, &ident, src_path));
         } else {
             if retry(RETRIES,
                      RETRY_WAIT,
@@ -207,9 +411,90 @@ impl<'a> InstallTask<'a> {
         Ok(artifact)
     }
 
+    /// Downloads the artifacts of `idents` which aren't in the artifact cache yet into it, with
+    /// a pool of workers each fetching one artifact at a time. Verifying the artifacts is left
+    /// to `get_cached_artifact`, which finds them in the cache afterwards.
+    fn download_artifacts(&self, ui: &mut UI, idents: &[PackageIdent]) -> Result<()> {
+        let mut pending = Vec::new();
+        for ident in idents {
+            if !try!(self.is_artifact_cached(ident)) {
+                pending.push(ident.clone());
+            }
+        }
+        if pending.is_empty() {
+            return Ok(());
+        }
+        let count = pending.len();
+        let workers = cmp::min(download_workers(), count);
+        try!(ui.status(Status::Downloading,
+                       format!("{} packages, {} at a time", count, workers)));
+
+        // Workers take idents from the end of the queue, so reverse it to download in order.
+        pending.reverse();
+        let queue = Arc::new(Mutex::new(pending));
+        let (tx, rx) = mpsc::channel();
+        let mut handles = Vec::new();
+        for _ in 0..workers {
+            let worker = DownloadWorker {
+                queue: queue.clone(),
+                events: tx.clone(),
+                url: self.url.clone(),
+                product: self.product.clone(),
+                version: self.version.clone(),
+                fs_root_path: self.fs_root_path.to_path_buf(),
+                cache_artifact_path: self.cache_artifact_path.to_path_buf(),
+            };
+            handles.push(thread::spawn(move || worker.run()));
+        }
+        drop(tx);
+
+        let mut progress = ui.combined_progress(count);
+        let mut cached = Vec::new();
+        let mut failures = Vec::new();
+        for event in rx.iter() {
+            match event {
+                DownloadEvent::Size(size) => progress.as_mut().map_or((), |p| p.add_size(size)),
+                DownloadEvent::Unfinished(size) => {
+                    progress.as_mut().map_or((), |p| p.remove_size(size))
+                }
+                DownloadEvent::Progress(n) => progress.as_mut().map_or((), |p| p.add(n)),
+                DownloadEvent::Finished(ident) => {
+                    progress.as_mut().map_or((), |p| p.finish_one());
+                    cached.push(ident);
+                }
+                // An unsupported artifact is left out of the cache, to be reported when it's
+                // fetched on its own.
+                DownloadEvent::Unsupported => progress.as_mut().map_or((), |p| p.finish_one()),
+                DownloadEvent::Failed(msg) => failures.push(msg),
+            }
+        }
+        for handle in handles {
+            if handle.join().is_err() {
+                failures.push(String::from("A download worker panicked"));
+            }
+        }
+        if let Some(ref mut progress) = progress {
+            progress.finish();
+        }
+
+        for ident in cached {
+            try!(ui.status(Status::Cached, ident));
+        }
+        match failures.into_iter().next() {
+            Some(msg) => Err(Error::from(depot_client::Error::DownloadFailed(msg))),
+            None => Ok(()),
+        }
+    }
+
     fn extract_artifact(&self, ui: &mut UI, artifact: &mut PackageArchive) -> Result<()> {
-        try!(artifact.unpack(Some(self.fs_root_path)));
-        try!(ui.status(Status::Installed, try!(artifact.ident())));
+        // Signatures are checked, and the payload is hashed, while it is unpacked.
+        let required = artifact::required_signers();
+        let (signers, _) = try!(artifact.unpack_verified(Some(self.fs_root_path),
+                                                         &self.cache_key_path,
+                                                         &required));
+        let ident = try!(artifact.ident());
+        info!("Verified {} signed by {}", &ident, signers.join(", "));
+        try!(ui.status(Status::Installed, ident));
         Ok(())
     }
 
@@ -225,14 +510,25 @@ impl<'a> InstallTask<'a> {
         Ok(try!(self.cached_artifact_path(ident)).is_file())
     }
 
+    /// The path of the artifact of `ident` in the artifact cache. The Depot may serve an artifact
+    /// for any target compatible with this system, so the first compatible one in the cache is
+    /// returned, or the path for this system's own target if there is none.
     fn cached_artifact_path(&self, ident: &PackageIdent) -> Result<PathBuf> {
-        let name = match ident.archive_name() {
-            Some(n) => n,
-            None => {
-                return Err(Error::HabitatCore(hcore::Error::InvalidPackageIdent(ident.to_string())))
+        let mut paths = Vec::new();
+        for target in PackageTarget::default().compatible_targets() {
+            match ident.archive_name_with_target(&target) {
+                Some(n) => paths.push(self.cache_artifact_path.join(n)),
+                None => {
+                    return Err(Error::HabitatCore(hcore::Error::InvalidPackageIdent(ident
+                                                                                   .to_string())))
+                }
             }
-        };
-        Ok(self.cache_artifact_path.join(name))
+        }
+        Ok(paths
+               .iter()
+               .find(|p| p.is_file())
+               .unwrap_or(&paths[0])
+               .clone())
     }
 
     fn fetch_latest_pkg_ident_for(&self, fuzzy_ident: &PackageIdent) -> Result<PackageIdent> {
@@ -245,25 +541,30 @@ impl<'a> InstallTask<'a> {
                       src_path: Option<&Path>)
                       -> Result<()> {
         if let Some(src_path) = src_path {
-            let name = match ident.archive_name() {
-                Some(n) => n,
-                None => return Err(
-                    Error::HabitatCore(hcore::Error::InvalidPackageIdent(ident.to_string()))),
-            };
-            let local_artifact = src_path.join(name);
-            if local_artifact.is_file() {
-                try!(self.cache_artifact(ident, &local_artifact));
-                return Ok(());
+            for target in PackageTarget::default().compatible_targets() {
+                let name = match ident.archive_name_with_target(&target) {
+                    Some(n) => n,
+                    None => return Err(
+                        Error::HabitatCore(hcore::Error::InvalidPackageIdent(ident.to_string()))),
+                };
+                let local_artifact = src_path.join(name);
+                if local_artifact.is_file() {
+                    try!(self.cache_artifact(ident, &local_artifact));
+                    return Ok(());
+                }
             }
         }
 
+        if self.offline {
+            return Err(Error::OfflineArtifactMissing(ident.to_string()));
+        }
         try!(ui.status(Status::Downloading, ident));
         match self.depot_client
                   .fetch_package(ident, self.cache_artifact_path, ui.progress()) {
             Ok(_) => Ok(()),
             Err(depot_client::Error::APIError(StatusCode::NotImplemented, _)) => {
-                println!("Host platform or architecture not supported by the targted depot; \
-                          skipping.");
+                try!(ui.warn("Host platform or architecture not supported by the targeted depot; \
+                              skipping."));
                 Ok(())
             }
             Err(e) => Err(Error::from(e)),
@@ -271,6 +572,10 @@ impl<'a> InstallTask<'a> {
     }
 
     fn fetch_origin_key(&self, ui: &mut UI, name_with_rev: &str) -> Result<()> {
+        if self.offline {
+            return Err(Error::CryptoKeyError(format!("{} is not in the key cache",
+                                                     name_with_rev)));
+        }
         try!(ui.status(Status::Downloading,
                        format!("{} public origin key", &name_with_rev)));
         let (name, rev) = try!(parse_name_with_rev(&name_with_rev));
@@ -281,8 +586,38 @@ impl<'a> InstallTask<'a> {
         Ok(())
     }
 
+    /// Refresh the cached revocation list of `origin` from the Depot. If the Depot can't be
+    /// reached, the cached list, if any, is used as it is. A list that drops a revocation of the
+    /// cached one is refused, failing the install.
+    fn fetch_origin_revocations(&self, ui: &mut UI, origin: &str) -> Result<()> {
+        if self.offline {
+            return Ok(());
+        }
+        let content = match self.depot_client.fetch_origin_revocations(origin) {
+            Ok(Some(content)) => content,
+            Ok(None) => return Ok(()),
+            Err(e) => {
+                debug!("Failed to fetch the revocation list of {}, {}", origin, e);
+                return Ok(());
+            }
+        };
+        let signer = try!(RevocationList::signer(&content));
+        if let Err(_) = SigKeyPair::get_public_key_path(&signer, self.cache_key_path) {
+            try!(self.fetch_origin_key(ui, &signer));
+        }
+        try!(RevocationList::write_file_from_str(&content, self.cache_key_path));
+        Ok(())
+    }
+
     fn cache_artifact(&self, ident: &PackageIdent, artifact_path: &Path) -> Result<()> {
-        let name = match ident.archive_name() {
+        // Artifacts installed with target validation turned off are cached as if they were built
+        // for this system, so they can be found again.
+        let target = if self.ignore_target {
+            PackageTarget::default()
+        } else {
+            try!(PackageArchive::new(artifact_path).target())
+        };
+        let name = match ident.archive_name_with_target(&target) {
             Some(n) => n,
             None => {
                 return Err(Error::HabitatCore(hcore::Error::InvalidPackageIdent(ident.to_string())))
@@ -293,6 +628,8 @@ impl<'a> InstallTask<'a> {
         Ok(())
     }
 
+    /// Check that the artifact is the package we asked for and can run here, and fetch the keys
+    /// and revocation lists needed to verify its signatures when it is unpacked.
     fn verify_artifact(&self,
                        ui: &mut UI,
                        ident: &PackageIdent,
@@ -313,13 +650,323 @@ impl<'a> InstallTask<'a> {
         }
 
 
-        let nwr = try!(artifact::artifact_signer(&artifact.path));
-        if let Err(_) = SigKeyPair::get_public_key_path(&nwr, self.cache_key_path) {
-            try!(self.fetch_origin_key(ui, &nwr));
+        let header = try!(artifact::get_artifact_header(&artifact.path));
+        let required = artifact::required_signers();
+        let mut origins = Vec::new();
+        for signature in header.signatures.iter() {
+            let (origin, _) = try!(parse_name_with_rev(&signature.key_name));
+            if !origins.contains(&origin) {
+                try!(self.fetch_origin_revocations(ui, &origin));
+                origins.push(origin);
+            }
+        }
+        for (i, signature) in header.signatures.iter().enumerate() {
+            let nwr = &signature.key_name;
+            if let Ok(_) = SigKeyPair::get_public_key_path(nwr, self.cache_key_path) {
+                continue;
+            }
+            if i == 0 {
+                try!(self.fetch_origin_key(ui, nwr));
+            } else {
+                // Counter-signatures are only checked when they are required, and a missing
+                // required signature is reported by the verification below.
+                let (name, _) = try!(parse_name_with_rev(nwr));
+                if required.contains(&name) {
+                    if let Err(e) = self.fetch_origin_key(ui, nwr) {
+                        debug!("Failed to fetch counter-signing key {}, {}", nwr, e);
+                    }
+                }
+            }
         }
+        Ok(())
+    }
+}
+
+/// Returns the number of download workers, from `DOWNLOAD_WORKERS_ENVVAR` if it's set to a
+/// positive number.
+fn download_workers() -> usize {
+    download_workers_from(henv::var(DOWNLOAD_WORKERS_ENVVAR).ok())
+}
+
+/// Returns the number of download workers `value` asks for, if it's a positive number.
+fn download_workers_from(value: Option<String>) -> usize {
+    match value.and_then(|v| v.parse::<usize>().ok()) {
+        Some(n) if n > 0 => n,
+        _ => DOWNLOAD_WORKERS,
+    }
+}
+
+/// What a download worker reports back to the thread showing the progress of the downloads.
+enum DownloadEvent {
+    Size(u64),
+    Progress(u64),
+    /// The part of a download's size which wasn't downloaded before it failed.
+    Unfinished(u64),
+    Finished(PackageIdent),
+    Unsupported,
+    Failed(String),
+}
+
+/// Downloads artifacts taken from a shared queue until it's empty. Each worker has a Depot client
+/// of its own.
+struct DownloadWorker {
+    queue: Arc<Mutex<Vec<PackageIdent>>>,
+    events: Sender<DownloadEvent>,
+    url: String,
+    product: String,
+    version: String,
+    fs_root_path: PathBuf,
+    cache_artifact_path: PathBuf,
+}
 
-        try!(artifact.verify(&self.cache_key_path));
-        info!("Verified {} signed by {}", ident, &nwr);
+impl DownloadWorker {
+    fn run(self) {
+        let client = match Client::new(self.url.as_str(),
+                                       &self.product,
+                                       &self.version,
+                                       Some(self.fs_root_path.as_path())) {
+            Ok(client) => client,
+            Err(e) => {
+                self.events.send(DownloadEvent::Failed(e.to_string())).ok();
+                return;
+            }
+        };
+        loop {
+            let ident = match self.queue.lock().expect("Download queue lock poisoned").pop() {
+                Some(ident) => ident,
+                None => return,
+            };
+            let res = retry(RETRIES,
+                            RETRY_WAIT,
+                            || {
+                                let progress = ChannelProgress::new(self.events.clone());
+                                client.fetch_package(&ident,
+                                                     &self.cache_artifact_path,
+                                                     Some(progress))
+                            },
+                            |res| match *res {
+                                Ok(_) |
+                                Err(depot_client::Error::APIError(StatusCode::NotImplemented,
+                                                                  _)) => true,
+                                Err(_) => false,
+                            });
+            let event = match res {
+                Ok(Ok(_)) => DownloadEvent::Finished(ident),
+                Ok(Err(_)) => DownloadEvent::Unsupported,
+                Err(_) => {
+                    DownloadEvent::Failed(format!("We tried {} times but could not download {}. \
+                                                   Giving up.",
+                                                  RETRIES,
+                                                  &ident))
+                }
+            };
+            if self.events.send(event).is_err() {
+                return;
+            }
+        }
+    }
+}
+
+/// Sends the progress of one download attempt to the thread showing the progress of them all.
+/// Whatever is left of the attempt's size when it's dropped is taken out of the total again, so
+/// a failed attempt followed by a resumed one adds up to the artifact's size.
+struct ChannelProgress {
+    events: Sender<DownloadEvent>,
+    size: u64,
+    written: u64,
+}
+
+impl ChannelProgress {
+    fn new(events: Sender<DownloadEvent>) -> Self {
+        ChannelProgress {
+            events: events,
+            size: 0,
+            written: 0,
+        }
+    }
+}
+
+impl DisplayProgress for ChannelProgress {
+    fn size(&mut self, size: u64) {
+        self.size = size;
+        self.events.send(DownloadEvent::Size(size)).ok();
+    }
+
+    fn finish(&mut self) {}
+}
+
+impl Write for ChannelProgress {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.written += buf.len() as u64;
+        self.events.send(DownloadEvent::Progress(buf.len() as u64)).ok();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
         Ok(())
     }
 }
+
+impl Drop for ChannelProgress {
+    fn drop(&mut self) {
+        if self.written < self.size {
+            self.events.send(DownloadEvent::Unfinished(self.size - self.written)).ok();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::File;
+    use std::io::Read;
+    use std::path::PathBuf;
+    use std::str::FromStr;
+    use std::sync::{Arc, Mutex};
+
+    use depot_client::XFileName;
+    use hcore::crypto::SigKeyPair;
+    use hcore::package::{PackageBundle, PackageIdent, PackageInstall};
+    use hyper::server::{Listening, Request, Response, Server};
+    use hyper::status::StatusCode;
+    use hyper::uri::RequestUri;
+    use tempdir::TempDir;
+
+    use super::{download_workers_from, install_bundle, InstallTask, DOWNLOAD_WORKERS};
+    use ui::{Coloring, UI};
+
+    const IDENTS: &'static [&'static str] = &["core/glibc/2.22/20170101000000",
+                                              "core/zlib/1.2.8/20170101000000",
+                                              "core/openssl/1.0.2j/20170101000000",
+                                              "core/redis/3.2.4/20170101000000",
+                                              "core/unsupported/1.0.0/20170101000000"];
+
+    fn ident(ident: &str) -> PackageIdent {
+        PackageIdent::from_str(ident).unwrap()
+    }
+
+    fn fixture_path(name: &str) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join(name)
+    }
+
+    /// Serves every artifact download with the ident as its content, except for the
+    /// `core/unsupported` package, which isn't available for this platform. Returns the server,
+    /// its URL and the idents of the downloads asked for.
+    fn serve() -> (Listening, String, Arc<Mutex<Vec<String>>>) {
+        let requested = Arc::new(Mutex::new(Vec::new()));
+        let seen = requested.clone();
+        let listening = Server::http("127.0.0.1:0")
+            .unwrap()
+            .handle(move |req: Request, mut res: Response| {
+                let path = match req.uri {
+                    RequestUri::AbsolutePath(ref path) => path.clone(),
+                    _ => String::new(),
+                };
+                let name = path.trim_left_matches("/pkgs/").trim_right_matches("/download");
+                seen.lock().unwrap().push(name.to_string());
+                if name.starts_with("core/unsupported/") {
+                    *res.status_mut() = StatusCode::NotImplemented;
+                    res.send(b"").unwrap();
+                    return;
+                }
+                res.headers_mut().set(XFileName(ident(name).archive_name().unwrap()));
+                res.send(name.as_bytes()).unwrap();
+            })
+            .unwrap();
+        let url = format!("http://{}", listening.socket);
+        (listening, url, requested)
+    }
+
+    #[test]
+    fn download_workers_from_value() {
+        assert_eq!(download_workers_from(Some(String::from("2"))), 2);
+        assert_eq!(download_workers_from(Some(String::from("0"))), DOWNLOAD_WORKERS);
+        assert_eq!(download_workers_from(Some(String::from("many"))), DOWNLOAD_WORKERS);
+        assert_eq!(download_workers_from(None), DOWNLOAD_WORKERS);
+    }
+
+    #[test]
+    fn download_artifacts_fills_the_cache() {
+        let root = TempDir::new("install").unwrap();
+        let cache = TempDir::new("install-cache").unwrap();
+        let (mut listening, url, requested) = serve();
+        let task = InstallTask::new(&url,
+                                    "hab",
+                                    "0.0.0",
+                                    root.path(),
+                                    cache.path(),
+                                    root.path(),
+                                    false,
+                                    false)
+            .unwrap();
+        let idents: Vec<PackageIdent> = IDENTS.iter().map(|i| ident(i)).collect();
+        // One artifact is already cached, so only the others are downloaded.
+        let cached = ident(IDENTS[0]);
+        File::create(task.cached_artifact_path(&cached).unwrap()).unwrap();
+        let mut ui = UI::default_with(Coloring::Never, Some(false));
+
+        task.download_artifacts(&mut ui, &idents).unwrap();
+        listening.close().unwrap();
+
+        let mut requested = requested.lock().unwrap().clone();
+        requested.sort();
+        let mut expected: Vec<String> = IDENTS[1..].iter().map(|i| i.to_string()).collect();
+        expected.sort();
+        assert_eq!(requested, expected);
+        for ident in &idents[1..4] {
+            let mut content = String::new();
+            File::open(task.cached_artifact_path(ident).unwrap())
+                .unwrap()
+                .read_to_string(&mut content)
+                .unwrap();
+            assert_eq!(content, ident.to_string());
+        }
+        assert!(!task.is_artifact_cached(&idents[4]).unwrap());
+    }
+
+    #[test]
+    fn install_bundle_imports_the_keys_of_every_origin() {
+        let root = TempDir::new("install").unwrap();
+        let cache_keys = TempDir::new("install-keys").unwrap();
+        let out = TempDir::new("install-bundle").unwrap();
+        // Only the key signing the bundle is trusted beforehand. The packages in it are signed by
+        // two other origins, whose keys come from the bundle.
+        let pair = SigKeyPair::generate_pair_for_origin("bundler", cache_keys.path()).unwrap();
+        let rainbows = ident("unicornfarm/rainbows/1.0.0/20170101000000");
+        let possums = ident("happyhumans/possums/8.1.4/20160427165340");
+        let artifact = fixture_path("unicornfarm-rainbows-1.0.0-20170101000000-x86_64-linux.hart");
+        let artifacts =
+            vec![fixture_path("happyhumans-possums-8.1.4-20160427165340-x86_64-linux.hart")];
+        let keys = vec![fixture_path("happyhumans-20160424223347.pub"),
+                        fixture_path("unicornfarm-20170101000000.pub")];
+        let dst = out.path().join(PackageBundle::file_name_for(&rainbows).unwrap());
+        let bundle = PackageBundle::create(&dst,
+                                           &rainbows,
+                                           &artifact,
+                                           &artifacts,
+                                           &keys,
+                                           &pair)
+                .unwrap();
+        let mut ui = UI::default_with(Coloring::Never, Some(false));
+
+        let installed = install_bundle(&mut ui,
+                                       &bundle,
+                                       &out.path().join("unpacked"),
+                                       "hab",
+                                       "0.0.0",
+                                       root.path(),
+                                       &root.path().join("artifacts"),
+                                       cache_keys.path(),
+                                       false)
+                .unwrap();
+
+        assert_eq!(installed, rainbows);
+        assert!(PackageInstall::load(&rainbows, Some(root.path())).is_ok());
+        assert!(PackageInstall::load(&possums, Some(root.path())).is_ok());
+        for name_with_rev in &["happyhumans-20160424223347", "unicornfarm-20170101000000"] {
+            assert!(SigKeyPair::get_public_key_path(name_with_rev, cache_keys.path()).is_ok());
+        }
+    }
+}
@@ -0,0 +1,498 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Uninstalls Habitat packages from the local package path.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg uninstall core/redis/3.0.1/20160614125453
+//! ```
+//!
+//! Will remove that release of `core/redis`, unless another installed package depends on it. The
+//! release must be named by its fully qualified identifier.
+//!
+//! ```bash
+//! $ hab pkg uninstall core/redis --keep-latest 2
+//! ```
+//!
+//! Will remove every installed release of `core/redis` but the two newest ones, skipping any
+//! release that is still needed.
+//!
+//! # Internals
+//!
+//! * Read the `TDEPS` of every installed package to find which packages depend on each other
+//! * Read the specs and services state of every Supervisor to find which releases are running
+//! * Remove each release that is neither running nor depended on
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use hcore::fs::{am_i_root, pkg_install_path, pkg_root_path};
+use hcore::package::{Identifiable, PackageIdent, PackageInstall};
+use serde_json;
+use toml;
+
+use error::{Error, Result};
+use ui::{Status, UI};
+
+/// The path, relative to the filesystem root, holding the state of every Supervisor.
+const SUP_STATE_PATH: &'static str = "hab/sup";
+const SPEC_FILE_EXT: &'static str = "spec";
+/// The path, relative to a Supervisor's state, where it records the services it runs.
+const SERVICES_DATA_PATH: &'static str = "data/services.dat";
+
+/// The part of a service in a Supervisor's services state file that tells which release it runs.
+#[derive(Deserialize)]
+struct LoadedService {
+    spec_ident: PackageIdent,
+    package: LoadedPackage,
+}
+
+#[derive(Deserialize)]
+struct LoadedPackage {
+    ident: PackageIdent,
+}
+
+/// Remove an installed package.
+///
+/// Without `keep_latest`, `ident` must be fully qualified, and that release is removed; it is an
+/// error if that release is running or, unless `force` is set, if another installed package
+/// depends on it. With `keep_latest`, every installed release matching `ident` but the newest
+/// `keep_latest` ones of each package is removed, and releases that cannot be removed are
+/// skipped.
+pub fn start(ui: &mut UI,
+             ident: &PackageIdent,
+             fs_root_path: &Path,
+             force: bool,
+             keep_latest: Option<usize>)
+             -> Result<()> {
+    if !am_i_root() {
+        try!(ui.warn("Uninstalling a package requires root or administrator privileges. \
+                      Please retry this command as a super user or use a privilege-granting \
+                      facility such as sudo."));
+        try!(ui.br());
+        return Err(Error::RootRequired);
+    }
+
+    let installed = try!(PackageInstall::list_installed(Some(fs_root_path)));
+    let running = try!(running_packages(fs_root_path));
+    let candidates = match keep_latest {
+        Some(count) => outdated_releases(ident, &installed, count),
+        None => vec![try!(named_release(ident, &installed, fs_root_path))],
+    };
+
+    try!(ui.begin(format!("Uninstalling {}", ident)));
+    let mut removed = 0;
+    for candidate in candidates.iter() {
+        match check_removable(candidate, &candidates, &installed, &running, force, fs_root_path) {
+            Ok(()) => {
+                try!(ui.status(Status::Deleting, candidate));
+                try!(remove_package(candidate, fs_root_path));
+                removed += 1;
+            }
+            Err(e) => {
+                if keep_latest.is_none() {
+                    return Err(e);
+                }
+                try!(ui.status(Status::Custom('☛', String::from("Keeping")),
+                               format!("{}, {}", candidate, e)));
+            }
+        }
+    }
+    try!(ui.end(format!("Uninstall of {} complete with {} packages removed.",
+                        ident,
+                        removed)));
+    Ok(())
+}
+
+/// Returns the installed release `ident` names. It must be fully qualified, so that a partial
+/// identifier never picks the release to remove; otherwise the error lists the releases it
+/// matches.
+fn named_release(ident: &PackageIdent,
+                 installed: &[PackageIdent],
+                 fs_root_path: &Path)
+                 -> Result<PackageIdent> {
+    if ident.fully_qualified() {
+        return Ok(try!(PackageInstall::load(ident, Some(fs_root_path))).ident().clone());
+    }
+    let mut releases: Vec<PackageIdent> =
+        installed.iter().filter(|p| p.satisfies(ident)).cloned().collect();
+    releases.sort_by(|a, b| b.cmp(a));
+    Err(Error::PackageIdentNotFullyQualified(ident.clone(), releases))
+}
+
+/// Returns the installed releases matching `ident` but the newest `count` ones of each package,
+/// newest first.
+fn outdated_releases(ident: &PackageIdent,
+                     installed: &[PackageIdent],
+                     count: usize)
+                     -> Vec<PackageIdent> {
+    let mut releases: Vec<&PackageIdent> =
+        installed.iter().filter(|p| p.satisfies(ident)).collect();
+    releases.sort_by(|a, b| match (&a.origin, &a.name).cmp(&(&b.origin, &b.name)) {
+                         Ordering::Equal => b.cmp(a),
+                         order => order,
+                     });
+    let mut outdated = Vec::new();
+    let mut kept = 0;
+    for (i, release) in releases.iter().enumerate() {
+        let same_package = i > 0 && releases[i - 1].origin == release.origin &&
+                           releases[i - 1].name == release.name;
+        kept = if same_package { kept + 1 } else { 1 };
+        if kept > count {
+            outdated.push((*release).clone());
+        }
+    }
+    outdated
+}
+
+/// Returns an error if `candidate` is running or, unless `force` is set, if an installed package
+/// other than those in `removing` depends on it.
+fn check_removable(candidate: &PackageIdent,
+                   removing: &[PackageIdent],
+                   installed: &[PackageIdent],
+                   running: &HashSet<PackageIdent>,
+                   force: bool,
+                   fs_root_path: &Path)
+                   -> Result<()> {
+    if running.contains(candidate) {
+        return Err(Error::PackageRunning(candidate.clone()));
+    }
+    if force {
+        return Ok(());
+    }
+    let dependents = try!(dependents_of(candidate, installed, removing, fs_root_path));
+    if dependents.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::PackageHasDependents(candidate.clone(), dependents))
+    }
+}
+
+/// Returns the installed packages, other than those in `removing`, that depend on `ident`.
+fn dependents_of(ident: &PackageIdent,
+                 installed: &[PackageIdent],
+                 removing: &[PackageIdent],
+                 fs_root_path: &Path)
+                 -> Result<Vec<PackageIdent>> {
+    let mut dependents = Vec::new();
+    for other in installed.iter() {
+        if other == ident || removing.contains(other) {
+            continue;
+        }
+        // Packages built for other targets can still depend on `ident`, so skip target checks.
+        let package = PackageInstall::new_from_parts(other.clone(),
+                                                     fs_root_path.to_path_buf(),
+                                                     pkg_root_path(Some(fs_root_path)),
+                                                     pkg_install_path(other, Some(fs_root_path)));
+        if try!(package.tdeps()).contains(ident) {
+            dependents.push(other.clone());
+        }
+    }
+    Ok(dependents)
+}
+
+/// Returns every release that a loaded service spec of any Supervisor is running, along with its
+/// transitive dependencies.
+fn running_packages(fs_root_path: &Path) -> Result<HashSet<PackageIdent>> {
+    let mut running = HashSet::new();
+    for (spec_ident, loaded) in try!(loaded_services(fs_root_path)) {
+        // A service the Supervisor hasn't started yet will load the newest release matching its
+        // spec.
+        let ident = match loaded {
+            Some(ident) => ident,
+            None => {
+                match PackageInstall::load(&spec_ident, Some(fs_root_path)) {
+                    Ok(package) => package.ident().clone(),
+                    Err(_) => continue,
+                }
+            }
+        };
+        let package = PackageInstall::new_from_parts(ident.clone(),
+                                                     fs_root_path.to_path_buf(),
+                                                     pkg_root_path(Some(fs_root_path)),
+                                                     pkg_install_path(&ident, Some(fs_root_path)));
+        for dep in try!(package.tdeps()) {
+            running.insert(dep);
+        }
+        running.insert(ident);
+    }
+    Ok(running)
+}
+
+/// Returns the package identifier of every spec in `<fs_root>/hab/sup/*/specs`, along with the
+/// release its Supervisor has loaded for it, if the Supervisor recorded one in its
+/// `data/services.dat`.
+fn loaded_services(fs_root_path: &Path) -> Result<Vec<(PackageIdent, Option<PackageIdent>)>> {
+    let mut services = Vec::new();
+    let state_path = fs_root_path.join(SUP_STATE_PATH);
+    if !state_path.is_dir() {
+        return Ok(services);
+    }
+    for sup in try!(fs::read_dir(&state_path)) {
+        let sup_path = try!(sup).path();
+        let specs_path = sup_path.join("specs");
+        if !specs_path.is_dir() {
+            continue;
+        }
+        let loaded = read_loaded_releases(&sup_path.join(SERVICES_DATA_PATH));
+        for entry in try!(fs::read_dir(&specs_path)) {
+            let path = try!(entry).path();
+            if path.extension().and_then(|e| e.to_str()) != Some(SPEC_FILE_EXT) {
+                continue;
+            }
+            match read_spec_ident(&path) {
+                Some(ident) => {
+                    let release = loaded
+                        .iter()
+                        .find(|&&(ref spec_ident, _)| *spec_ident == ident)
+                        .map(|&(_, ref release)| release.clone());
+                    services.push((ident, release));
+                }
+                None => debug!("Skipping unreadable spec {}", path.display()),
+            }
+        }
+    }
+    Ok(services)
+}
+
+/// Returns the spec identifier and the loaded release of every service in a Supervisor's
+/// services state file.
+fn read_loaded_releases(path: &Path) -> Vec<(PackageIdent, PackageIdent)> {
+    let mut content = String::new();
+    if File::open(path)
+           .and_then(|mut f| f.read_to_string(&mut content))
+           .is_err() {
+        return Vec::new();
+    }
+    let services = match serde_json::from_str::<Vec<LoadedService>>(&content) {
+        Ok(services) => services,
+        Err(err) => {
+            debug!("Skipping unreadable services state {}, {}", path.display(), err);
+            return Vec::new();
+        }
+    };
+    services
+        .into_iter()
+        .map(|s| (s.spec_ident, s.package.ident))
+        .collect()
+}
+
+fn read_spec_ident(path: &Path) -> Option<PackageIdent> {
+    let mut content = String::new();
+    if File::open(path)
+           .and_then(|mut f| f.read_to_string(&mut content))
+           .is_err() {
+        return None;
+    }
+    let value = match content.parse::<toml::Value>() {
+        Ok(value) => value,
+        Err(_) => return None,
+    };
+    value
+        .as_table()
+        .and_then(|t| t.get("ident"))
+        .and_then(|i| i.as_str())
+        .and_then(|i| PackageIdent::from_str(i).ok())
+}
+
+/// Remove the install directory of `ident`, along with any version, name and origin directories
+/// it leaves empty.
+fn remove_package(ident: &PackageIdent, fs_root_path: &Path) -> Result<()> {
+    let pkg_root = pkg_root_path(Some(fs_root_path));
+    let install_path = pkg_install_path(ident, Some(fs_root_path));
+    try!(fs::remove_dir_all(&install_path));
+    let mut parent: Option<PathBuf> = install_path.parent().map(|p| p.to_path_buf());
+    while let Some(dir) = parent {
+        if dir == pkg_root || try!(fs::read_dir(&dir)).next().is_some() {
+            break;
+        }
+        try!(fs::remove_dir(&dir));
+        parent = dir.parent().map(|p| p.to_path_buf());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+    use std::fs::{self, File};
+    use std::io::Write;
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use hcore::fs::pkg_install_path;
+    use hcore::package::{PackageIdent, PackageTarget};
+    use serde_json;
+    use tempdir::TempDir;
+
+    use super::{check_removable, loaded_services, named_release, outdated_releases,
+                remove_package, running_packages};
+    use error::Error;
+
+    const REDIS_OLD: &'static str = "core/redis/3.0.1/20160614125453";
+    const REDIS_NEW: &'static str = "core/redis/3.2.4/20170103160441";
+    const APP: &'static str = "acme/app/1.0.0/20170301000000";
+
+    fn ident(ident: &str) -> PackageIdent {
+        PackageIdent::from_str(ident).unwrap()
+    }
+
+    /// Installs an empty package under `root`, with the given transitive dependencies.
+    fn install(root: &Path, package: &str, tdeps: &[&str]) -> PackageIdent {
+        let package = ident(package);
+        let path = pkg_install_path(&package, Some(root));
+        fs::create_dir_all(&path).unwrap();
+        write!(File::create(path.join("TARGET")).unwrap(),
+               "{}",
+               PackageTarget::default())
+                .unwrap();
+        if !tdeps.is_empty() {
+            write!(File::create(path.join("TDEPS")).unwrap(), "{}", tdeps.join("\n")).unwrap();
+        }
+        package
+    }
+
+    /// Loads a service from a spec for `spec_ident` into the `default` Supervisor, recording the
+    /// release it runs if `loaded` is given.
+    fn load_service(root: &Path, spec_ident: &str, loaded: Option<&str>) {
+        let sup = root.join("hab/sup/default");
+        fs::create_dir_all(sup.join("specs")).unwrap();
+        let mut f = File::create(sup.join("specs").join("svc.spec")).unwrap();
+        write!(f, "ident = \"{}\"\ngroup = \"default\"\n", spec_ident).unwrap();
+        if let Some(loaded) = loaded {
+            fs::create_dir_all(sup.join("data")).unwrap();
+            let mut f = File::create(sup.join("data/services.dat")).unwrap();
+            write!(f,
+                   "[{{\"spec_ident\":{},\"package\":{{\"ident\":{}}}}}]",
+                   serde_json::to_string(&ident(spec_ident)).unwrap(),
+                   serde_json::to_string(&ident(loaded)).unwrap())
+                    .unwrap();
+        }
+    }
+
+    #[test]
+    fn loaded_services_reads_every_supervisor() {
+        let root = TempDir::new("uninstall").unwrap();
+        for &(sup, ident) in [("default", "core/redis"), ("other", "core/nginx/1.11.10")].iter() {
+            let specs = root.path().join("hab/sup").join(sup).join("specs");
+            fs::create_dir_all(&specs).unwrap();
+            let mut f = File::create(specs.join("svc.spec")).unwrap();
+            write!(f, "ident = \"{}\"\ngroup = \"default\"\n", ident).unwrap();
+        }
+        let mut services = loaded_services(root.path()).unwrap();
+        services.sort();
+        assert_eq!(services,
+                   vec![(ident("core/nginx/1.11.10"), None), (ident("core/redis"), None)]);
+    }
+
+    #[test]
+    fn only_a_fully_qualified_ident_names_a_release() {
+        let root = TempDir::new("uninstall").unwrap();
+        let old = install(root.path(), REDIS_OLD, &[]);
+        let new = install(root.path(), REDIS_NEW, &[]);
+        let installed = vec![old.clone(), new.clone()];
+
+        assert_eq!(named_release(&old, &installed, root.path()).unwrap(), old);
+        match named_release(&ident("core/redis"), &installed, root.path()) {
+            Err(Error::PackageIdentNotFullyQualified(_, ref releases)) => {
+                assert_eq!(releases, &vec![new.clone(), old.clone()])
+            }
+            other => panic!("Expected a not fully qualified error, got {:?}", other),
+        }
+        match named_release(&ident("core/redis/3.2.4"), &installed, root.path()) {
+            Err(Error::PackageIdentNotFullyQualified(_, ref releases)) => {
+                assert_eq!(releases, &vec![new.clone()])
+            }
+            other => panic!("Expected a not fully qualified error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn outdated_releases_keeps_the_newest_of_each_package() {
+        let installed = vec![ident(REDIS_OLD),
+                             ident("core/redis/3.2.4/20170209064044"),
+                             ident(REDIS_NEW),
+                             ident(APP)];
+        assert_eq!(outdated_releases(&ident("core/redis"), &installed, 1),
+                   vec![ident(REDIS_NEW), ident(REDIS_OLD)]);
+        assert_eq!(outdated_releases(&ident("core/redis"), &installed, 3),
+                   Vec::<PackageIdent>::new());
+    }
+
+    #[test]
+    fn dependencies_of_installed_packages_are_kept() {
+        let root = TempDir::new("uninstall").unwrap();
+        let redis = install(root.path(), REDIS_OLD, &[]);
+        let app = install(root.path(), APP, &[REDIS_OLD]);
+        let installed = vec![redis.clone(), app.clone()];
+        let running = HashSet::new();
+
+        match check_removable(&redis, &[redis.clone()], &installed, &running, false, root.path()) {
+            Err(Error::PackageHasDependents(_, ref dependents)) => {
+                assert_eq!(dependents, &vec![app.clone()])
+            }
+            other => panic!("Expected a dependents error, got {:?}", other),
+        }
+        assert!(check_removable(&redis, &[redis.clone()], &installed, &running, true, root.path())
+                    .is_ok());
+        assert!(check_removable(&redis, &installed, &installed, &running, false, root.path())
+                    .is_ok());
+    }
+
+    #[test]
+    fn the_loaded_release_is_running() {
+        let root = TempDir::new("uninstall").unwrap();
+        let old = install(root.path(), REDIS_OLD, &[]);
+        let new = install(root.path(), REDIS_NEW, &[]);
+        let installed = vec![old.clone(), new.clone()];
+
+        // The Supervisor loaded the older release before the newer one was installed.
+        load_service(root.path(), "core/redis", Some(REDIS_OLD));
+        let running = running_packages(root.path()).unwrap();
+        assert!(running.contains(&old));
+        assert!(!running.contains(&new));
+        match check_removable(&old, &[old.clone()], &installed, &running, true, root.path()) {
+            Err(Error::PackageRunning(ref running)) => assert_eq!(running, &old),
+            other => panic!("Expected a running package error, got {:?}", other),
+        }
+        assert!(check_removable(&new, &[new.clone()], &installed, &running, false, root.path())
+                    .is_ok());
+
+        // Until it starts the service, the Supervisor will load the newest release.
+        load_service(root.path(), "core/redis", None);
+        fs::remove_file(root.path().join("hab/sup/default/data/services.dat")).unwrap();
+        let running = running_packages(root.path()).unwrap();
+        assert!(running.contains(&new));
+        assert!(!running.contains(&old));
+    }
+
+    #[test]
+    fn remove_package_removes_empty_parents() {
+        let root = TempDir::new("uninstall").unwrap();
+        let pkgs = root.path().join("hab/pkgs");
+        fs::create_dir_all(pkgs.join("core/redis/3.0.1/20160614125453")).unwrap();
+        fs::create_dir_all(pkgs.join("core/redis/3.2.4/20170103160441")).unwrap();
+        let ident = PackageIdent::from_str("core/redis/3.0.1/20160614125453").unwrap();
+        remove_package(&ident, root.path()).unwrap();
+        assert!(!pkgs.join("core/redis/3.0.1").exists());
+        assert!(pkgs.join("core/redis/3.2.4/20170103160441").is_dir());
+        assert!(Path::new(&pkgs).is_dir());
+    }
+}
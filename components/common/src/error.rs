@@ -19,10 +19,12 @@ use std::result;
 use std::str;
 use std::string;
 
+use serde_json;
 use toml;
 
 use depot_client;
 use hcore;
+use hcore::package::PackageIdent;
 
 pub type Result<T> = result::Result<T, Error>;
 
@@ -37,6 +39,14 @@ pub enum Error {
     HabitatCore(hcore::Error),
     /// Occurs when making lower level IO calls.
     IO(io::Error),
+    InvalidOutputFormat(String),
+    JsonSerialize(serde_json::Error),
+    NoDefaultForPrompt(String),
+    OfflineArtifactMissing(String),
+    PackageHasDependents(PackageIdent, Vec<PackageIdent>),
+    /// A partial identifier, along with the installed releases it matches, newest first.
+    PackageIdentNotFullyQualified(PackageIdent, Vec<PackageIdent>),
+    PackageRunning(PackageIdent),
     RootRequired,
     StrFromUtf8Error(str::Utf8Error),
     StringFromUtf8Error(string::FromUtf8Error),
@@ -63,6 +73,36 @@ impl fmt::Display for Error {
             Error::FileNameError => format!("Failed to extract a filename"),
             Error::HabitatCore(ref e) => format!("{}", e),
             Error::IO(ref err) => format!("{}", err),
+            Error::InvalidOutputFormat(ref f) => {
+                format!("Output format must be one of: plain, json, not {}", f)
+            }
+            Error::JsonSerialize(ref e) => format!("Can't serialize JSON: {}", e),
+            Error::NoDefaultForPrompt(ref q) => {
+                format!("Can't ask `{}' while printing JSON, and there is no default answer", q)
+            }
+            Error::OfflineArtifactMissing(ref ident) => {
+                format!("The artifact for {} is not available offline", ident)
+            }
+            Error::PackageHasDependents(ref ident, ref dependents) => {
+                let dependents: Vec<String> = dependents.iter().map(|d| d.to_string()).collect();
+                format!("Cannot remove {}, it is a dependency of {}",
+                        ident,
+                        dependents.join(", "))
+            }
+            Error::PackageIdentNotFullyQualified(ref ident, ref releases) => {
+                if releases.is_empty() {
+                    format!("No release of {} is installed", ident)
+                } else {
+                    let releases: Vec<String> = releases.iter().map(|r| r.to_string()).collect();
+                    format!("{} is not a fully qualified package identifier, give the release \
+                             to remove, one of: {}",
+                            ident,
+                            releases.join(", "))
+                }
+            }
+            Error::PackageRunning(ref ident) => {
+                format!("Cannot remove {}, it is running under a Supervisor", ident)
+            }
             Error::RootRequired => {
                 "Root or administrator permissions required to complete operation".to_string()
             }
@@ -86,6 +126,15 @@ impl error::Error for Error {
             Error::FileNameError => "Failed to extract a filename from a path",
             Error::HabitatCore(ref err) => err.description(),
             Error::IO(ref err) => err.description(),
+            Error::InvalidOutputFormat(_) => "Output format must be one of: plain, json",
+            Error::JsonSerialize(_) => "Can't serialize JSON",
+            Error::NoDefaultForPrompt(_) => "Can't prompt while printing JSON",
+            Error::OfflineArtifactMissing(_) => "Artifact is not available offline",
+            Error::PackageHasDependents(_, _) => "Package is a dependency of installed packages",
+            Error::PackageIdentNotFullyQualified(_, _) => {
+                "Package identifier is not fully qualified"
+            }
+            Error::PackageRunning(_) => "Package is running under a Supervisor",
             Error::RootRequired => "Root or administrator permissions required to complete operation",
             Error::StrFromUtf8Error(_) => "Failed to convert a string as UTF-8",
             Error::StringFromUtf8Error(_) => "Failed to convert a string as UTF-8",
@@ -113,6 +162,12 @@ impl From<io::Error> for Error {
     }
 }
 
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::JsonSerialize(err)
+    }
+}
+
 impl From<str::Utf8Error> for Error {
     fn from(err: str::Utf8Error) -> Self {
         Error::StrFromUtf8Error(err)
@@ -22,6 +22,10 @@ extern crate log;
 extern crate pbr;
 extern crate regex;
 extern crate retry;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
 #[cfg(test)]
 extern crate tempdir;
 extern crate term;
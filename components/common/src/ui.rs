@@ -15,14 +15,18 @@
 use std::fmt;
 use std::io::{self, BufRead, BufReader, Read, Write};
 use std::process;
+use std::result;
+use std::str::FromStr;
 
 use ansi_term::Colour;
 use depot_client::DisplayProgress;
 use pbr;
+use serde::Serialize;
+use serde_json;
 use term::terminfo::TermInfo;
 use term::{Terminal, TerminfoTerminal};
 
-use error::Result;
+use error::{Error, Result};
 use self::tty::StdStream;
 
 pub const NONINTERACTIVE_ENVVAR: &'static str = "HAB_NONINTERACTIVE";
@@ -71,20 +75,124 @@ impl Status {
     }
 }
 
+/// How a command prints its results: plain lines for people and shell pipelines, or JSON for
+/// other programs.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OutputFormat {
+    Plain,
+    Json,
+}
+
+impl OutputFormat {
+    /// The values accepted on the command line.
+    pub fn variants() -> &'static [&'static str] {
+        &["plain", "json"]
+    }
+}
+
+impl Default for OutputFormat {
+    fn default() -> Self {
+        OutputFormat::Plain
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            OutputFormat::Plain => write!(f, "plain"),
+            OutputFormat::Json => write!(f, "json"),
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = Error;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value {
+            "plain" => Ok(OutputFormat::Plain),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(Error::InvalidOutputFormat(value.to_string())),
+        }
+    }
+}
+
+/// What the UI prints to standard error, one per line, in place of decorated text when the output
+/// format is JSON.
+#[derive(Serialize)]
+struct Event {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    message: String,
+}
+
+impl Event {
+    fn new<T: ToString>(kind: &'static str, message: T) -> Self {
+        Event {
+            kind: kind,
+            status: None,
+            code: None,
+            message: message.to_string(),
+        }
+    }
+}
+
 pub struct UI {
     shell: Shell,
+    format: OutputFormat,
 }
 
 impl UI {
     pub fn default_with(coloring: Coloring, isatty: Option<bool>) -> Self {
-        UI { shell: Shell::default_with(coloring, isatty) }
+        UI {
+            shell: Shell::default_with(coloring, isatty),
+            format: OutputFormat::default(),
+        }
+    }
+
+    /// Sets how the UI prints. With `OutputFormat::Json`, `result` prints the only thing on
+    /// standard output, while statuses, messages, warnings and errors go to standard error as
+    /// one JSON object per line, each with a `type`, and prompts take their default answer.
+    pub fn set_format(&mut self, format: OutputFormat) {
+        self.format = format;
+    }
+
+    pub fn format(&self) -> OutputFormat {
+        self.format
+    }
+
+    /// Prints the result of a command: `value` as JSON, or each of `lines` on its own line.
+    pub fn result<T: Serialize>(&mut self, value: &T, lines: &[String]) -> Result<()> {
+        let ref mut stream = self.shell.out;
+        match self.format {
+            OutputFormat::Plain => {
+                for line in lines.iter() {
+                    try!(write!(stream, "{}\n", line));
+                }
+            }
+            OutputFormat::Json => {
+                try!(write!(stream, "{}\n", try!(serde_json::to_string_pretty(value))))
+            }
+        }
+        try!(stream.flush());
+        Ok(())
     }
 
     pub fn begin<T: ToString>(&mut self, message: T) -> Result<()> {
+        if self.format == OutputFormat::Json {
+            return self.event(Event::new("begin", message));
+        }
         Self::write_heading(&mut self.shell.out, Colour::Yellow, '»', message)
     }
 
     pub fn end<T: ToString>(&mut self, message: T) -> Result<()> {
+        if self.format == OutputFormat::Json {
+            return self.event(Event::new("end", message));
+        }
         Self::write_heading(&mut self.shell.out, Colour::Blue, '★', message)
     }
 
@@ -93,8 +201,13 @@ impl UI {
     }
 
     pub fn status<T: fmt::Display>(&mut self, status: Status, message: T) -> Result<()> {
-        let ref mut stream = self.shell.out;
         let (symbol, status_str, color) = status.parts();
+        if self.format == OutputFormat::Json {
+            let mut event = Event::new("status", message);
+            event.status = Some(status_str.to_lowercase());
+            return self.event(event);
+        }
+        let ref mut stream = self.shell.out;
         match stream.is_colored() {
             true => {
                 try!(write!(stream,
@@ -115,6 +228,9 @@ impl UI {
     }
 
     pub fn warn<T: fmt::Display>(&mut self, message: T) -> Result<()> {
+        if self.format == OutputFormat::Json {
+            return self.event(Event::new("warning", message));
+        }
         let ref mut stream = self.shell.err;
         match stream.is_colored() {
             true => {
@@ -132,7 +248,21 @@ impl UI {
         Ok(())
     }
 
+    /// Prints an error with a stable `code` which programs reading JSON can match on. Plain
+    /// output shows only the message.
+    pub fn fatal_with_code<T: fmt::Display>(&mut self, code: &str, message: T) -> Result<()> {
+        if self.format == OutputFormat::Json {
+            let mut event = Event::new("error", message);
+            event.code = Some(code.to_string());
+            return self.event(event);
+        }
+        self.fatal(message)
+    }
+
     pub fn fatal<T: fmt::Display>(&mut self, message: T) -> Result<()> {
+        if self.format == OutputFormat::Json {
+            return self.event(Event::new("error", message));
+        }
         let ref mut stream = self.shell.err;
         let formatted_message = message
             .to_string()
@@ -159,14 +289,27 @@ impl UI {
     }
 
     pub fn progress(&mut self) -> Option<ProgressBar> {
-        if self.shell.out.is_a_terminal() {
+        if self.shell.out.is_a_terminal() && self.format == OutputFormat::Plain {
             Some(ProgressBar::default())
         } else {
             None
         }
     }
 
+    /// Returns a progress bar for `count` sized events happening together, such as concurrent
+    /// downloads, if progress is shown at all.
+    pub fn combined_progress(&mut self, count: usize) -> Option<CombinedProgress> {
+        if self.shell.out.is_a_terminal() && self.format == OutputFormat::Plain {
+            Some(CombinedProgress::new(count))
+        } else {
+            None
+        }
+    }
+
     pub fn title(&mut self, text: &str) -> Result<()> {
+        if self.format == OutputFormat::Json {
+            return self.message(text);
+        }
         let ref mut stream = self.shell.out;
         match stream.is_colored() {
             true => {
@@ -191,6 +334,9 @@ impl UI {
     }
 
     pub fn heading(&mut self, text: &str) -> Result<()> {
+        if self.format == OutputFormat::Json {
+            return self.message(text);
+        }
         let ref mut stream = self.shell.out;
         match stream.is_colored() {
             true => {
@@ -205,10 +351,16 @@ impl UI {
     }
 
     pub fn para(&mut self, text: &str) -> Result<()> {
+        if self.format == OutputFormat::Json {
+            return self.message(text);
+        }
         Self::print_wrapped(&mut self.shell.out, text, 75, 2)
     }
 
     pub fn br(&mut self) -> Result<()> {
+        if self.format == OutputFormat::Json {
+            return Ok(());
+        }
         let ref mut stream = self.shell.out;
         try!(write!(stream, "\n"));
         try!(stream.flush());
@@ -216,6 +368,9 @@ impl UI {
     }
 
     pub fn prompt_yes_no(&mut self, question: &str, default: Option<bool>) -> Result<bool> {
+        if self.format == OutputFormat::Json {
+            return default.ok_or(Error::NoDefaultForPrompt(question.to_string()));
+        }
         let ref mut stream = self.shell.out;
         let choice = match default {
             Some(yes) => {
@@ -280,6 +435,10 @@ impl UI {
     }
 
     pub fn prompt_ask(&mut self, question: &str, default: Option<&str>) -> Result<String> {
+        if self.format == OutputFormat::Json {
+            return default.map(|d| d.to_string())
+                       .ok_or(Error::NoDefaultForPrompt(question.to_string()));
+        }
         let ref mut stream = self.shell.out;
         let choice = match default {
             Some(d) => {
@@ -324,6 +483,18 @@ impl UI {
         }
     }
 
+    fn message(&mut self, text: &str) -> Result<()> {
+        self.event(Event::new("message", text))
+    }
+
+    /// Prints `event` on its own line of standard error.
+    fn event(&mut self, event: Event) -> Result<()> {
+        let ref mut stream = self.shell.err;
+        try!(write!(stream, "{}\n", try!(serde_json::to_string(&event))));
+        try!(stream.flush());
+        Ok(())
+    }
+
     fn write_heading<T: ToString>(stream: &mut OutputStream,
                                   color: Colour,
                                   symbol: char,
@@ -688,3 +859,90 @@ impl Write for ProgressBar {
         self.bar.flush()
     }
 }
+
+/// A progress bar tracking several sized events together, such as concurrent downloads.
+///
+/// The size of each event is added to the total once it's known, and taken out again for the part
+/// of an event which is given up on, so that a retried download isn't counted twice. The bar's
+/// message shows how many of the events have finished.
+pub struct CombinedProgress {
+    bar: pbr::ProgressBar,
+    total: u64,
+    current: u64,
+    finished: usize,
+    count: usize,
+}
+
+impl CombinedProgress {
+    pub fn new(count: usize) -> Self {
+        let mut progress = CombinedProgress {
+            bar: pbr::ProgressBar::new(0),
+            total: 0,
+            current: 0,
+            finished: 0,
+            count: count,
+        };
+        progress.redraw();
+        progress
+    }
+
+    pub fn add_size(&mut self, size: u64) {
+        self.total += size;
+        self.redraw();
+    }
+
+    pub fn remove_size(&mut self, size: u64) {
+        self.total = self.total.saturating_sub(size);
+        self.redraw();
+    }
+
+    pub fn add(&mut self, n: u64) {
+        self.current += n;
+        self.bar.add(n);
+    }
+
+    pub fn finish_one(&mut self) {
+        self.finished += 1;
+        self.bar.message(&format!("{}/{} ", self.finished, self.count));
+        self.bar.add(0);
+    }
+
+    pub fn finish(&mut self) {
+        println!("");
+        io::stdout().flush().ok().expect("flush() fail");
+    }
+
+    /// The total of a pbr bar can't change, so a new bar is started from where the old one was.
+    fn redraw(&mut self) {
+        self.bar = pbr::ProgressBar::new(self.total);
+        self.bar.set_units(pbr::Units::Bytes);
+        self.bar.show_tick = true;
+        self.bar.message(&format!("{}/{} ", self.finished, self.count));
+        self.bar.add(self.current);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json;
+
+    use super::{Event, OutputFormat};
+
+    #[test]
+    fn events_leave_out_missing_fields() {
+        let mut event = Event::new("status", "core/redis");
+        event.status = Some(String::from("downloading"));
+        assert_eq!(serde_json::to_string(&event).unwrap(),
+                   r#"{"type":"status","status":"downloading","message":"core/redis"}"#);
+        assert_eq!(serde_json::to_string(&Event::new("warning", "careful")).unwrap(),
+                   r#"{"type":"warning","message":"careful"}"#);
+    }
+
+    #[test]
+    fn output_format_round_trips_through_strings() {
+        for name in OutputFormat::variants() {
+            assert_eq!(&name.parse::<OutputFormat>().unwrap().to_string(), name);
+        }
+        assert!("yaml".parse::<OutputFormat>().is_err());
+    }
+}
@@ -12,18 +12,49 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::fs::File;
+//! Signing and verification of Habitat artifacts.
+//!
+//! An artifact is a header followed by the payload, a tarball. A `HART-1` header carries a single
+//! signature:
+//!
+//! ```text
+//! HART-1
+//! <key name with rev>
+//! BLAKE2b
+//! <base64 signature>
+//!
+//! ```
+//!
+//! A `HART-2` header carries one or more signatures of the same payload hash, one per line, with
+//! the signature of the origin key that built the payload first. Further signatures are added by
+//! counter-signing, for example with a release-approval key:
+//!
+//! ```text
+//! HART-2
+//! BLAKE2b
+//! <key name with rev> <base64 signature>
+//! <key name with rev> <base64 signature>
+//!
+//! ```
+
+use std::cell::RefCell;
+use std::fs::{self, File};
 use std::io;
 use std::io::prelude::*;
 use std::io::{BufReader, BufWriter};
 use std::path::Path;
+use std::rc::Rc;
 
 use base64;
 use sodiumoxide::crypto::sign;
 
+use env as henv;
 use error::{Error, Result};
-use super::{HART_FORMAT_VERSION, SIG_HASH_TYPE, SigKeyPair};
+use super::{HART_FORMAT_VERSION, HART_MULTI_SIG_FORMAT_VERSION, REQUIRED_SIGNERS_ENV_VAR,
+            SIG_HASH_TYPE, SigKeyPair};
+use super::hash::HashingReader;
 use super::keys::parse_name_with_rev;
+use super::revocation;
 
 /// Generate and sign a package
 pub fn sign<P1: ?Sized, P2: ?Sized>(src: &P1, dst: &P2, pair: &SigKeyPair) -> Result<()>
@@ -47,39 +78,76 @@ pub fn sign<P1: ?Sized, P2: ?Sized>(src: &P1, dst: &P2, pair: &SigKeyPair) -> Re
     Ok(())
 }
 
+/// Add a signature by `pair` to the already signed artifact `src`, writing a `HART-2` artifact to
+/// `dst`. The payload and the existing signatures are copied as they are. `src` and `dst` may be
+/// the same file.
+pub fn countersign<P1: ?Sized, P2: ?Sized>(src: &P1, dst: &P2, pair: &SigKeyPair) -> Result<()>
+    where P1: AsRef<Path>,
+          P2: AsRef<Path>
+{
+    let mut reader = BufReader::new(try!(File::open(src)));
+    let header = try!(read_header(&mut reader));
+    if header
+           .signatures
+           .iter()
+           .any(|s| s.key_name == pair.name_with_rev()) {
+        return Err(Error::CryptoError(format!("Artifact is already signed with {}",
+                                              pair.name_with_rev())));
+    }
+    let hash = try!(super::hash::hash_reader(&mut reader));
+    debug!("Payload hash for {} = {}", src.as_ref().display(), &hash);
+    let mut signatures = header.signatures;
+    signatures.push(ArtifactSignature {
+                        key_name: pair.name_with_rev(),
+                        signature_raw: base64::encode(&sign::sign(&hash.as_bytes(),
+                                                                  try!(pair.secret()))),
+                    });
+
+    let tmp_dst = dst.as_ref().with_extension("countersign.tmp");
+    {
+        let mut reader = BufReader::new(try!(File::open(src)));
+        try!(read_header(&mut reader));
+        let output_file = try!(File::create(&tmp_dst));
+        let mut writer = BufWriter::new(&output_file);
+        try!(write!(writer, "{}\n{}\n", HART_MULTI_SIG_FORMAT_VERSION, SIG_HASH_TYPE));
+        for signature in signatures.iter() {
+            try!(write!(writer,
+                        "{} {}\n",
+                        signature.key_name,
+                        signature.signature_raw));
+        }
+        try!(write!(writer, "\n"));
+        try!(io::copy(&mut reader, &mut writer));
+    }
+    try!(fs::rename(&tmp_dst, dst));
+    Ok(())
+}
+
 /// return a BufReader to the .tar bytestream, skipping the signed header
 pub fn get_archive_reader<P: AsRef<Path>>(src: &P) -> Result<BufReader<File>> {
     let f = try!(File::open(src));
-    let mut your_format_version = String::new();
-    let mut your_key_name = String::new();
-    let mut your_hash_type = String::new();
-    let mut your_signature_raw = String::new();
-    let mut empty_line = String::new();
-
     let mut reader = BufReader::new(f);
-    if try!(reader.read_line(&mut your_format_version)) <= 0 {
-        return Err(Error::CryptoError("Can't read format version".to_string()));
-    }
-    if try!(reader.read_line(&mut your_key_name)) <= 0 {
-        return Err(Error::CryptoError("Can't read keyname".to_string()));
-    }
-    if try!(reader.read_line(&mut your_hash_type)) <= 0 {
-        return Err(Error::CryptoError("Can't read hash type".to_string()));
-    }
-    if try!(reader.read_line(&mut your_signature_raw)) <= 0 {
-        return Err(Error::CryptoError("Can't read signature".to_string()));
-    }
-    if try!(reader.read_line(&mut empty_line)) <= 0 {
-        return Err(Error::CryptoError("Can't end of header".to_string()));
-    }
+    try!(read_header(&mut reader));
     Ok(reader)
 }
 
+/// A single signature of an artifact's payload hash.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArtifactSignature {
+    /// The name with revision of the key that made the signature.
+    pub key_name: String,
+    /// The base64 encoded signature.
+    pub signature_raw: String,
+}
+
 pub struct ArtifactHeader {
     pub format_version: String,
+    /// The key that signed the payload; the first of `signatures`.
     pub key_name: String,
     pub hash_type: String,
     pub signature_raw: String,
+    /// Every signature of the artifact, the payload signature first.
+    pub signatures: Vec<ArtifactSignature>,
 }
 
 impl ArtifactHeader {
@@ -90,6 +158,10 @@ impl ArtifactHeader {
                -> ArtifactHeader {
         ArtifactHeader {
             format_version: format_version,
+            signatures: vec![ArtifactSignature {
+                                 key_name: key_name.clone(),
+                                 signature_raw: signature_raw.clone(),
+                             }],
             key_name: key_name,
             hash_type: hash_type,
             signature_raw: signature_raw,
@@ -104,48 +176,13 @@ pub fn get_artifact_header<P: ?Sized>(src: &P) -> Result<ArtifactHeader>
     where P: AsRef<Path>
 {
     let f = try!(File::open(src));
-    let mut your_format_version = String::new();
-    let mut your_key_name = String::new();
-    let mut your_hash_type = String::new();
-    let mut your_signature_raw = String::new();
-    let mut empty_line = String::new();
-
     let mut reader = BufReader::new(f);
-    if try!(reader.read_line(&mut your_format_version)) <= 0 {
-        return Err(Error::CryptoError("Can't read format version".to_string()));
-    }
-    if try!(reader.read_line(&mut your_key_name)) <= 0 {
-        return Err(Error::CryptoError("Can't read keyname".to_string()));
-    }
-    if try!(reader.read_line(&mut your_hash_type)) <= 0 {
-        return Err(Error::CryptoError("Can't read hash type".to_string()));
-    }
-    if try!(reader.read_line(&mut your_signature_raw)) <= 0 {
-        return Err(Error::CryptoError("Can't read signature".to_string()));
-    }
-    if try!(reader.read_line(&mut empty_line)) <= 0 {
-        return Err(Error::CryptoError("Can't end of header".to_string()));
-    }
-    let your_format_version = your_format_version.trim().to_string();
-    let your_key_name = your_key_name.trim().to_string();
-    let your_hash_type = your_hash_type.trim().to_string();
-    let your_signature_raw = your_signature_raw.trim().to_string();
-
-    Ok(ArtifactHeader::new(your_format_version,
-                           your_key_name,
-                           your_hash_type,
-                           your_signature_raw))
+    read_header(&mut reader)
 }
 
-/// verify the crypto signature of a .hart file
-pub fn verify<P1: ?Sized, P2: ?Sized>(src: &P1, cache_key_path: &P2) -> Result<(String, String)>
-    where P1: AsRef<Path>,
-          P2: AsRef<Path>
-{
-    let f = try!(File::open(src));
-    let mut reader = BufReader::new(f);
-
-    let _ = {
+/// Read a `HART-1` or `HART-2` header, leaving `reader` at the start of the payload.
+fn read_header<R: BufRead>(reader: &mut R) -> Result<ArtifactHeader> {
+    let format_version = {
         let mut buffer = String::new();
         match reader.read_line(&mut buffer) {
             Ok(0) => {
@@ -153,7 +190,8 @@ pub fn verify<P1: ?Sized, P2: ?Sized>(src: &P1, cache_key_path: &P2) -> Result<(
                                                   .to_string()))
             }
             Ok(_) => {
-                if buffer.trim() != HART_FORMAT_VERSION {
+                if buffer.trim() != HART_FORMAT_VERSION &&
+                   buffer.trim() != HART_MULTI_SIG_FORMAT_VERSION {
                     let msg = format!("Unsupported format version: {}", &buffer.trim());
                     return Err(Error::CryptoError(msg));
                 }
@@ -163,99 +201,279 @@ pub fn verify<P1: ?Sized, P2: ?Sized>(src: &P1, cache_key_path: &P2) -> Result<(
         };
         buffer.trim().to_string()
     };
-    let pair = {
-        let mut buffer = String::new();
-        if try!(reader.read_line(&mut buffer)) <= 0 {
-            return Err(Error::CryptoError("Corrupt payload, can't read origin key name"
-                                              .to_string()));
-        }
-        try!(SigKeyPair::get_pair_for(buffer.trim(), cache_key_path))
-    };
-    let _ = {
-        let mut buffer = String::new();
-        match reader.read_line(&mut buffer) {
-            Ok(0) => {
-                return Err(Error::CryptoError("Corrupt payload, can't read hash type".to_string()))
+    if format_version == HART_FORMAT_VERSION {
+        let key_name = {
+            let mut buffer = String::new();
+            if try!(reader.read_line(&mut buffer)) <= 0 {
+                return Err(Error::CryptoError("Corrupt payload, can't read origin key name"
+                                                  .to_string()));
             }
-            Ok(_) => {
-                if buffer.trim() != SIG_HASH_TYPE {
-                    let msg = format!("Unsupported signature type: {}", &buffer.trim());
-                    return Err(Error::CryptoError(msg));
+            try!(parse_name_with_rev(buffer.trim()));
+            buffer.trim().to_string()
+        };
+        let hash_type = try!(read_hash_type(reader));
+        let signature_raw = {
+            let mut buffer = String::new();
+            match reader.read_line(&mut buffer) {
+                Ok(0) => {
+                    return Err(Error::CryptoError("Corrupt payload, can't read signature"
+                                                      .to_string()))
                 }
+                Ok(_) => try!(decode_signature(buffer.trim())),
+                Err(e) => return Err(Error::from(e)),
+            };
+            buffer.trim().to_string()
+        };
+        let _ = {
+            let mut buffer = String::new();
+            if try!(reader.read_line(&mut buffer)) <= 0 {
+                return Err(Error::CryptoError("Corrupt payload, can't find end of header"
+                                                  .to_string()));
             }
-            Err(e) => return Err(Error::from(e)),
         };
-    };
-    let signature = {
+        return Ok(ArtifactHeader::new(format_version, key_name, hash_type, signature_raw));
+    }
+
+    let hash_type = try!(read_hash_type(reader));
+    let mut signatures = Vec::new();
+    loop {
         let mut buffer = String::new();
-        match reader.read_line(&mut buffer) {
-            Ok(0) => {
-                return Err(Error::CryptoError("Corrupt payload, can't read signature".to_string()))
+        if try!(reader.read_line(&mut buffer)) <= 0 {
+            return Err(Error::CryptoError("Corrupt payload, can't find end of header".to_string()));
+        }
+        if buffer.trim().is_empty() {
+            break;
+        }
+        let mut parts = buffer.trim().splitn(2, ' ');
+        match (parts.next(), parts.next()) {
+            (Some(key_name), Some(signature_raw)) => {
+                try!(parse_name_with_rev(key_name));
+                try!(decode_signature(signature_raw));
+                signatures.push(ArtifactSignature {
+                                    key_name: key_name.to_string(),
+                                    signature_raw: signature_raw.to_string(),
+                                });
             }
-            Ok(_) => {
-                try!(base64::decode(buffer.trim())
-                    .map_err(|e| Error::CryptoError(format!("Can't decode signature: {}", e))))
+            _ => {
+                return Err(Error::CryptoError("Corrupt payload, can't read signature"
+                                                  .to_string()))
             }
-            Err(e) => return Err(Error::from(e)),
         }
-    };
-    let _ = {
-        let mut buffer = String::new();
-        if try!(reader.read_line(&mut buffer)) <= 0 {
-            return Err(Error::CryptoError("Corrupt payload, can't find end of header".to_string()));
+    }
+    if signatures.is_empty() {
+        return Err(Error::CryptoError("Corrupt payload, artifact has no signatures".to_string()));
+    }
+    Ok(ArtifactHeader {
+           format_version: format_version,
+           key_name: signatures[0].key_name.clone(),
+           hash_type: hash_type,
+           signature_raw: signatures[0].signature_raw.clone(),
+           signatures: signatures,
+       })
+}
+
+fn read_hash_type<R: BufRead>(reader: &mut R) -> Result<String> {
+    let mut buffer = String::new();
+    match reader.read_line(&mut buffer) {
+        Ok(0) => {
+            return Err(Error::CryptoError("Corrupt payload, can't read hash type".to_string()))
         }
-    };
-    let expected_hash = match sign::verify(signature.as_slice(), try!(pair.public())) {
-        Ok(signed_data) => {
-            try!(String::from_utf8(signed_data)
-                .map_err(|_| Error::CryptoError("Error parsing artifact signature".to_string())))
+        Ok(_) => {
+            if buffer.trim() != SIG_HASH_TYPE {
+                let msg = format!("Unsupported signature type: {}", &buffer.trim());
+                return Err(Error::CryptoError(msg));
+            }
         }
-        Err(_) => return Err(Error::CryptoError("Verification failed".to_string())),
+        Err(e) => return Err(Error::from(e)),
     };
-    let computed_hash = try!(super::hash::hash_reader(&mut reader));
-    if computed_hash == expected_hash {
-        Ok((pair.name_with_rev(), expected_hash))
-    } else {
-        let msg = format!("Habitat artifact is invalid, \
-                          hashes don't match (expected: {}, computed: {})",
-                          expected_hash,
-                          computed_hash);
-        Err(Error::CryptoError(msg))
+    Ok(buffer.trim().to_string())
+}
+
+fn decode_signature(signature_raw: &str) -> Result<Vec<u8>> {
+    base64::decode(signature_raw)
+        .map_err(|e| Error::CryptoError(format!("Can't decode signature: {}", e)))
+}
+
+/// The names of the keys that must have signed every artifact, as configured by the
+/// `HAB_REQUIRED_SIGNERS` environment variable; a comma separated list of key names without
+/// revisions.
+pub fn required_signers() -> Vec<String> {
+    match henv::var(REQUIRED_SIGNERS_ENV_VAR) {
+        Ok(val) => {
+            val.split(',')
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty())
+                .map(|s| s.to_string())
+                .collect()
+        }
+        Err(_) => Vec::new(),
     }
 }
 
-pub fn artifact_signer<P: AsRef<Path>>(src: &P) -> Result<String> {
+/// verify the crypto signature of a .hart file
+pub fn verify<P1: ?Sized, P2: ?Sized>(src: &P1, cache_key_path: &P2) -> Result<(String, String)>
+    where P1: AsRef<Path>,
+          P2: AsRef<Path>
+{
+    let (mut signers, hash) = try!(verify_signers(src, cache_key_path, &[]));
+    Ok((signers.remove(0), hash))
+}
+
+/// Verify every signature of a .hart file and require a valid signature by each key named in
+/// `required`, by name without revision. Returns the names with revision of the keys whose
+/// signatures were verified, starting with the payload signer, along with the payload hash.
+///
+/// The payload signer's public key must be in the cache. Counter-signatures by keys that are not
+/// in the cache are skipped, and do not count towards `required`. Fails with `Error::KeyRevoked`
+/// if any signer is listed in its origin's cached revocation list.
+pub fn verify_signers<P1: ?Sized, P2: ?Sized>(src: &P1,
+                                              cache_key_path: &P2,
+                                              required: &[String])
+                                              -> Result<(Vec<String>, String)>
+    where P1: AsRef<Path>,
+          P2: AsRef<Path>
+{
+    let reader = try!(get_verifying_reader(src, cache_key_path, required));
+    reader.finish()
+}
+
+/// Check the signatures of a .hart file as `verify_signers` does, and return a reader over its
+/// payload that hashes the bytes as they are read. This lets a caller consume the payload, for
+/// example by unpacking it, in the same pass that verifies it. Nothing read from the payload can
+/// be trusted until `VerifyingReader::finish` succeeds.
+pub fn get_verifying_reader<P1: ?Sized, P2: ?Sized>(src: &P1,
+                                                    cache_key_path: &P2,
+                                                    required: &[String])
+                                                    -> Result<VerifyingReader>
+    where P1: AsRef<Path>,
+          P2: AsRef<Path>
+{
     let f = try!(File::open(src));
     let mut reader = BufReader::new(f);
-
-    let _ = {
-        let mut buffer = String::new();
-        match reader.read_line(&mut buffer) {
-            Ok(0) => {
-                return Err(Error::CryptoError("Corrupt payload, can't read format version"
-                                                  .to_string()))
+    let header = try!(read_header(&mut reader));
+
+    let mut signers = Vec::new();
+    let mut expected_hash: Option<String> = None;
+    for (i, signature) in header.signatures.iter().enumerate() {
+        try!(revocation::check_not_revoked(&signature.key_name, cache_key_path));
+        let pair = match SigKeyPair::get_pair_for(&signature.key_name, cache_key_path) {
+            Ok(pair) => pair,
+            Err(e) => {
+                if i == 0 {
+                    return Err(e);
+                }
+                debug!("Skipping counter-signature by {}, {}", signature.key_name, e);
+                continue;
             }
-            Ok(_) => {
-                if buffer.trim() != HART_FORMAT_VERSION {
-                    let msg = format!("Unsupported format version: {}", &buffer.trim());
-                    return Err(Error::CryptoError(msg));
+        };
+        let public = match pair.public() {
+            Ok(public) => public,
+            Err(e) => {
+                if i == 0 {
+                    return Err(e);
                 }
+                debug!("Skipping counter-signature by {}, {}", signature.key_name, e);
+                continue;
             }
-            Err(e) => return Err(Error::from(e)),
+        };
+        let signature = try!(decode_signature(&signature.signature_raw));
+        let signed_hash = match sign::verify(signature.as_slice(), public) {
+            Ok(signed_data) => {
+                try!(String::from_utf8(signed_data)
+                    .map_err(|_| Error::CryptoError("Error parsing artifact signature".to_string())))
+            }
+            Err(_) => return Err(Error::CryptoError("Verification failed".to_string())),
+        };
+        if let Some(ref hash) = expected_hash {
+            if *hash != signed_hash {
+                let msg = format!("Habitat artifact is invalid, {} signed a different hash \
+                                  (expected: {}, signed: {})",
+                                  pair.name_with_rev(),
+                                  hash,
+                                  signed_hash);
+                return Err(Error::CryptoError(msg));
+            }
+        }
+        if expected_hash.is_none() {
+            expected_hash = Some(signed_hash);
+        }
+        signers.push(pair.name_with_rev());
+    }
+    for name in required.iter() {
+        let signed = signers
+            .iter()
+            .any(|s| match parse_name_with_rev(s) {
+                     Ok((ref signer, _)) => signer == name,
+                     Err(_) => false,
+                 });
+        if !signed {
+            return Err(Error::MissingSignature(name.clone()));
+        }
+    }
 
+    // The payload signature is always checked, so there is an expected hash.
+    Ok(VerifyingReader {
+           payload: Rc::new(RefCell::new(Some(HashingReader::new(reader)))),
+           signers: signers,
+           expected_hash: expected_hash.unwrap(),
+       })
+}
+
+/// The payload of an artifact whose signatures have been checked, hashing every byte as it is
+/// read. Clones share the underlying file and hash, so one clone can be handed to a consumer that
+/// takes ownership of its reader while another is kept to call `finish`.
+#[derive(Clone)]
+pub struct VerifyingReader {
+    payload: Rc<RefCell<Option<HashingReader<BufReader<File>>>>>,
+    signers: Vec<String>,
+    expected_hash: String,
+}
+
+impl VerifyingReader {
+    /// The names with revision of the keys whose signatures were verified, starting with the
+    /// payload signer.
+    pub fn signers(&self) -> &[String] {
+        &self.signers
+    }
+
+    /// Read whatever is left of the payload and check its hash against the signed hash. Returns
+    /// the signers along with the payload hash.
+    pub fn finish(self) -> Result<(Vec<String>, String)> {
+        let hashing = match self.payload.borrow_mut().take() {
+            Some(mut hashing) => {
+                try!(io::copy(&mut hashing, &mut io::sink()));
+                hashing
+            }
+            None => {
+                return Err(Error::CryptoError("Artifact payload was already verified"
+                                                  .to_string()))
+            }
         };
-        buffer.trim().to_string()
-    };
-    let name_with_rev = {
-        let mut buffer = String::new();
-        if try!(reader.read_line(&mut buffer)) <= 0 {
-            return Err(Error::CryptoError("Corrupt payload, can't read origin key name"
-                                              .to_string()));
+        let computed_hash = hashing.finish();
+        if computed_hash == self.expected_hash {
+            Ok((self.signers, self.expected_hash))
+        } else {
+            let msg = format!("Habitat artifact is invalid, \
+                              hashes don't match (expected: {}, computed: {})",
+                              self.expected_hash,
+                              computed_hash);
+            Err(Error::CryptoError(msg))
         }
-        try!(parse_name_with_rev(buffer.trim()));
-        buffer.trim().to_string()
-    };
-    Ok(name_with_rev)
+    }
+}
+
+impl Read for VerifyingReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match *self.payload.borrow_mut() {
+            Some(ref mut hashing) => hashing.read(buf),
+            None => Ok(0),
+        }
+    }
+}
+
+pub fn artifact_signer<P: AsRef<Path>>(src: &P) -> Result<String> {
+    let header = try!(get_artifact_header(src));
+    Ok(header.key_name)
 }
 
 #[cfg(test)]
@@ -266,7 +484,9 @@ mod test {
     use tempdir::TempDir;
 
     use super::*;
-    use super::super::{HART_FORMAT_VERSION, SIG_HASH_TYPE, SigKeyPair};
+    use super::super::{HART_FORMAT_VERSION, HART_MULTI_SIG_FORMAT_VERSION, SIG_HASH_TYPE,
+                       SigKeyPair};
+    use super::super::hash;
     use super::super::test_support::*;
     use super::super::keys::parse_name_with_rev;
 
@@ -281,6 +501,96 @@ mod test {
         assert!(true);
     }
 
+    #[test]
+    fn countersign_and_verify() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let origin = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let release = SigKeyPair::generate_pair_for_origin("release", cache.path()).unwrap();
+        let dst = cache.path().join("signed.dat");
+
+        sign(&fixture("signme.dat"), &dst, &origin).unwrap();
+        countersign(&dst, &dst, &release).unwrap();
+
+        let header = get_artifact_header(&dst).unwrap();
+        assert_eq!(HART_MULTI_SIG_FORMAT_VERSION, header.format_version);
+        assert_eq!(origin.name_with_rev(), header.key_name);
+        assert_eq!(2, header.signatures.len());
+        assert_eq!(release.name_with_rev(), header.signatures[1].key_name);
+
+        let (signers, _) = verify_signers(&dst, cache.path(), &["release".to_string()]).unwrap();
+        assert_eq!(vec![origin.name_with_rev(), release.name_with_rev()], signers);
+        let (signer, _) = verify(&dst, cache.path()).unwrap();
+        assert_eq!(origin.name_with_rev(), signer);
+
+        let mut payload = String::new();
+        get_archive_reader(&dst)
+            .unwrap()
+            .read_to_string(&mut payload)
+            .unwrap();
+        let mut expected = String::new();
+        File::open(fixture("signme.dat"))
+            .unwrap()
+            .read_to_string(&mut expected)
+            .unwrap();
+        assert_eq!(expected, payload);
+    }
+
+    #[test]
+    #[should_panic(expected = "Artifact is already signed with")]
+    fn countersign_twice_with_the_same_key() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let dst = cache.path().join("signed.dat");
+
+        sign(&fixture("signme.dat"), &dst, &pair).unwrap();
+        countersign(&dst, &dst, &pair).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Artifact is not signed by the required key release")]
+    fn verify_signers_missing_required_signer() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let dst = cache.path().join("signed.dat");
+
+        sign(&fixture("signme.dat"), &dst, &pair).unwrap();
+        verify_signers(&dst, cache.path(), &["release".to_string()]).unwrap();
+    }
+
+    #[test]
+    fn verify_skips_counter_signatures_with_unknown_keys() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let other_cache = TempDir::new("other_key_cache").unwrap();
+        let origin = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let release = SigKeyPair::generate_pair_for_origin("release", other_cache.path())
+            .unwrap();
+        let dst = cache.path().join("signed.dat");
+
+        sign(&fixture("signme.dat"), &dst, &origin).unwrap();
+        countersign(&dst, &dst, &release).unwrap();
+
+        let (signers, _) = verify_signers(&dst, cache.path(), &[]).unwrap();
+        assert_eq!(vec![origin.name_with_rev()], signers);
+        assert!(verify_signers(&dst, cache.path(), &["release".to_string()]).is_err());
+    }
+
+    #[test]
+    fn verifying_reader_checks_the_hash_after_a_partial_read() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let dst = cache.path().join("signed.dat");
+        sign(&fixture("signme.dat"), &dst, &pair).unwrap();
+
+        let reader = get_verifying_reader(&dst, cache.path(), &[]).unwrap();
+        let mut consumer = reader.clone();
+        let mut buf = [0u8; 4];
+        consumer.read_exact(&mut buf).unwrap();
+        assert_eq!(reader.signers(), &[pair.name_with_rev()]);
+        let (_, payload_hash) = reader.finish().unwrap();
+        assert_eq!(payload_hash, hash::hash_file(&fixture("signme.dat")).unwrap());
+        assert_eq!(consumer.read(&mut buf).unwrap(), 0);
+    }
+
     #[test]
     #[should_panic(expected = "Secret key is required but not present for")]
     fn sign_missing_private_key() {
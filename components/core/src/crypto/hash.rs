@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use std::fs::File;
-use std::io::{BufReader, Read};
+use std::io::{self, BufReader, Read};
 use std::mem;
 use std::path::Path;
 use std::ptr;
@@ -23,8 +23,6 @@ use libsodium_sys;
 
 use error::Result;
 
-const BUF_SIZE: usize = 1024;
-
 /// Calculate the BLAKE2b hash of a file, return as a hex string
 /// digest size = 32 BYTES
 /// NOTE: the hashing is keyless
@@ -66,43 +64,104 @@ pub fn hash_bytes(data: &[u8]) -> Result<String> {
     Ok(out.to_hex())
 }
 
-pub fn hash_reader(reader: &mut BufReader<File>) -> Result<String> {
-    let mut out = [0u8; libsodium_sys::crypto_generichash_BYTES];
-    let mut st = vec![0u8; (unsafe { libsodium_sys::crypto_generichash_statebytes() })];
+pub fn hash_reader<R: Read>(reader: &mut R) -> Result<String> {
+    let mut hashing = HashingReader::new(reader);
+    try!(io::copy(&mut hashing, &mut io::sink()));
+    Ok(hashing.finish())
+}
+
+/// Calculate the SHA-256 hash of a file, return as a hex string. Habitat itself uses BLAKE2b;
+/// this is for formats of other tools which address content by SHA-256, such as OCI images.
+pub fn sha256_file<P: AsRef<Path>>(filename: &P) -> Result<String> {
+    let mut reader = BufReader::new(try!(File::open(filename.as_ref())));
+    let mut out = [0u8; libsodium_sys::crypto_hash_sha256_BYTES];
+    let mut st = vec![0u8; (unsafe { libsodium_sys::crypto_hash_sha256_statebytes() })];
     let pst =
         unsafe {
-            mem::transmute::<*mut u8,
-                             *mut libsodium_sys::crypto_generichash_state>(st.as_mut_ptr())
+            mem::transmute::<*mut u8, *mut libsodium_sys::crypto_hash_sha256_state>(st.as_mut_ptr())
         };
-
+    let mut buf = [0u8; 8192];
     unsafe {
-        libsodium_sys::crypto_generichash_init(pst, ptr::null_mut(), 0, out.len());
+        libsodium_sys::crypto_hash_sha256_init(pst);
     }
-
-    let mut buf = [0u8; BUF_SIZE];
     loop {
         let bytes_read = try!(reader.read(&mut buf));
         if bytes_read == 0 {
             break;
         }
-        let chunk = &buf[0..bytes_read];
         unsafe {
-            libsodium_sys::crypto_generichash_update(pst, chunk.as_ptr(), chunk.len() as u64);
+            libsodium_sys::crypto_hash_sha256_update(pst, buf.as_ptr(), bytes_read as u64);
         }
     }
     unsafe {
-        libsodium_sys::crypto_generichash_final(pst, out.as_mut_ptr(), out.len());
+        libsodium_sys::crypto_hash_sha256_final(pst, out.as_mut_ptr());
     }
     Ok(out.to_hex())
 }
 
+/// A reader that computes the BLAKE2b hash of every byte read through it, so that a stream can be
+/// hashed while it is being consumed rather than in a separate pass.
+pub struct HashingReader<R> {
+    inner: R,
+    state: Vec<u8>,
+}
+
+impl<R: Read> HashingReader<R> {
+    pub fn new(inner: R) -> Self {
+        let mut state = vec![0u8; (unsafe { libsodium_sys::crypto_generichash_statebytes() })];
+        unsafe {
+            libsodium_sys::crypto_generichash_init(Self::state_ptr(&mut state),
+                                                   ptr::null_mut(),
+                                                   0,
+                                                   libsodium_sys::crypto_generichash_BYTES);
+        }
+        HashingReader {
+            inner: inner,
+            state: state,
+        }
+    }
+
+    /// Return the hex encoded hash of the bytes read so far.
+    pub fn finish(mut self) -> String {
+        let mut out = [0u8; libsodium_sys::crypto_generichash_BYTES];
+        unsafe {
+            libsodium_sys::crypto_generichash_final(Self::state_ptr(&mut self.state),
+                                                    out.as_mut_ptr(),
+                                                    out.len());
+        }
+        out.to_hex()
+    }
+
+    fn state_ptr(state: &mut Vec<u8>) -> *mut libsodium_sys::crypto_generichash_state {
+        unsafe {
+            mem::transmute::<*mut u8, *mut libsodium_sys::crypto_generichash_state>(state
+                                                                                    .as_mut_ptr())
+        }
+    }
+}
+
+impl<R: Read> Read for HashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let bytes_read = try!(self.inner.read(buf));
+        if bytes_read > 0 {
+            let chunk = &buf[0..bytes_read];
+            unsafe {
+                libsodium_sys::crypto_generichash_update(Self::state_ptr(&mut self.state),
+                                                         chunk.as_ptr(),
+                                                         chunk.len() as u64);
+            }
+        }
+        Ok(bytes_read)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::env;
     #[allow(unused_imports)]
     use std::fs::{self, File};
     #[allow(unused_imports)]
-    use std::io;
+    use std::io::{self, Read};
     use std::path::PathBuf;
 
     #[cfg(feature = "functional")]
@@ -148,6 +207,24 @@ mod test {
         assert_eq!(computed, expected);
     }
 
+    #[test]
+    fn sha256_file_working() {
+        // Computed with `sha256sum signme.dat`
+        let computed = sha256_file(&fixture("signme.dat")).unwrap();
+        let expected = "b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c";
+        assert_eq!(computed, expected);
+    }
+
+    #[test]
+    fn hashing_reader_matches_hash_file() {
+        let mut hashing = HashingReader::new(File::open(fixture("signme.dat")).unwrap());
+        let mut buf = Vec::new();
+        hashing.read_to_end(&mut buf).unwrap();
+        assert_eq!(hashing.finish(), hash_file(&fixture("signme.dat")).unwrap());
+        assert_eq!(hash_bytes(&buf).unwrap(),
+                   hash_file(&fixture("signme.dat")).unwrap());
+    }
+
     #[test]
     #[cfg(feature = "functional")]
     fn hash_file_large_binary() {
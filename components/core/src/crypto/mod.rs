@@ -243,6 +243,9 @@ pub static SECRET_BOX_KEY_SUFFIX: &'static str = "box.key";
 /// The suffix on the end of a secret symmetric key file
 pub static SECRET_SYM_KEY_SUFFIX: &'static str = "sym.key";
 
+/// The suffix on the end of an origin's revocation list file
+pub static REVOCATION_LIST_SUFFIX: &'static str = "revoked";
+
 /// The hashing function we're using during sign/verify
 /// See also: https://download.libsodium.org/doc/hashing/generic_hashing.html
 pub static SIG_HASH_TYPE: &'static str = "BLAKE2b";
@@ -251,12 +254,18 @@ pub static SIG_HASH_TYPE: &'static str = "BLAKE2b";
 /// at runtime. This is useful for testing.
 pub static CACHE_KEY_PATH_ENV_VAR: &'static str = "HAB_CACHE_KEY_PATH";
 
+/// This environment variable holds a comma separated list of key names which must have signed an
+/// artifact for it to be installed.
+pub static REQUIRED_SIGNERS_ENV_VAR: &'static str = "HAB_REQUIRED_SIGNERS";
+
 /// Create secret key files with these permissions
 static PUBLIC_KEY_PERMISSIONS: u32 = 0o400;
 static SECRET_KEY_PERMISSIONS: u32 = 0o400;
 
 pub static HART_FORMAT_VERSION: &'static str = "HART-1";
+pub static HART_MULTI_SIG_FORMAT_VERSION: &'static str = "HART-2";
 pub static BOX_FORMAT_VERSION: &'static str = "BOX-1";
+pub static REVOCATION_LIST_FORMAT_VERSION: &'static str = "REVOKE-1";
 
 pub const PUBLIC_SIG_KEY_VERSION: &'static str = "SIG-PUB-1";
 pub const SECRET_SIG_KEY_VERSION: &'static str = "SIG-SEC-1";
@@ -271,6 +280,8 @@ pub use self::keys::sig_key_pair::SigKeyPair;
 pub mod artifact;
 pub mod hash;
 pub mod keys;
+pub mod passphrase;
+pub mod revocation;
 
 pub fn default_cache_key_path(fs_root_path: Option<&Path>) -> PathBuf {
     match henv::var(CACHE_KEY_PATH_ENV_VAR) {
@@ -0,0 +1,101 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Encrypts small secrets, such as auth tokens, with a key derived from a passphrase.
+//!
+//! The key is derived with scrypt from the passphrase and a random salt, and the secret is sealed
+//! with the same secret box as a `SymKey`. The salt, nonce and ciphertext are kept together as
+//! `salt:nonce:ciphertext`, each part in base64.
+
+use base64;
+use sodiumoxide::crypto::pwhash;
+use sodiumoxide::crypto::secretbox;
+
+use error::{Error, Result};
+
+/// Encrypts `data` with `passphrase`, returning the sealed value.
+pub fn encrypt(passphrase: &[u8], data: &[u8]) -> Result<String> {
+    let salt = pwhash::gen_salt();
+    let key = try!(derive_key(passphrase, &salt));
+    let nonce = secretbox::gen_nonce();
+    Ok(format!("{}:{}:{}",
+               base64::encode(&salt.0[..]),
+               base64::encode(&nonce.0[..]),
+               base64::encode(&secretbox::seal(data, &nonce, &key))))
+}
+
+/// Decrypts a value sealed by `encrypt` with `passphrase`.
+///
+/// # Errors
+///
+/// * If the value wasn't made by `encrypt`
+/// * If the passphrase isn't the one the value was encrypted with
+pub fn decrypt(passphrase: &[u8], sealed: &str) -> Result<Vec<u8>> {
+    let parts: Vec<&str> = sealed.trim().split(':').collect();
+    if parts.len() != 3 {
+        return Err(Error::CryptoError("Encrypted value is malformed".to_string()));
+    }
+    let mut decoded = Vec::new();
+    for part in parts.iter() {
+        decoded.push(try!(base64::decode(part).map_err(|e| {
+            Error::CryptoError(format!("Can't decode encrypted value: {}", e))
+        })));
+    }
+    let salt = match pwhash::Salt::from_slice(&decoded[0]) {
+        Some(salt) => salt,
+        None => return Err(Error::CryptoError("Invalid size of salt".to_string())),
+    };
+    let nonce = match secretbox::Nonce::from_slice(&decoded[1]) {
+        Some(nonce) => nonce,
+        None => return Err(Error::CryptoError("Invalid size of nonce".to_string())),
+    };
+    let key = try!(derive_key(passphrase, &salt));
+    secretbox::open(&decoded[2], &nonce, &key)
+        .map_err(|_| Error::CryptoError("Wrong passphrase or corrupt encrypted value".to_string()))
+}
+
+fn derive_key(passphrase: &[u8], salt: &pwhash::Salt) -> Result<secretbox::Key> {
+    let mut key = secretbox::Key([0; secretbox::KEYBYTES]);
+    {
+        let secretbox::Key(ref mut bytes) = key;
+        if pwhash::derive_key(bytes,
+                              passphrase,
+                              salt,
+                              pwhash::OPSLIMIT_INTERACTIVE,
+                              pwhash::MEMLIMIT_INTERACTIVE)
+               .is_err() {
+            return Err(Error::CryptoError("Can't derive a key from the passphrase".to_string()));
+        }
+    }
+    Ok(key)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{decrypt, encrypt};
+
+    #[test]
+    fn encrypt_and_decrypt() {
+        let sealed = encrypt(b"correct horse", b"my-auth-token").unwrap();
+        assert!(!sealed.contains("my-auth-token"));
+        assert_eq!(decrypt(b"correct horse", &sealed).unwrap(), b"my-auth-token".to_vec());
+    }
+
+    #[test]
+    fn decrypt_with_wrong_passphrase() {
+        let sealed = encrypt(b"correct horse", b"my-auth-token").unwrap();
+        assert!(decrypt(b"battery staple", &sealed).is_err());
+        assert!(decrypt(b"correct horse", "not:sealed").is_err());
+    }
+}
@@ -0,0 +1,390 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Origin key revocation lists.
+//!
+//! An origin publishes the revisions of its keys that must no longer be trusted in a revocation
+//! list, signed by one of its keys that is not itself revoked. The list is served by the Depot and
+//! cached as `<origin>.revoked` next to the keys. Artifacts signed by a revoked revision fail
+//! verification.
+//!
+//! ```text
+//! REVOKE-1
+//! <origin>
+//! <signing key name with rev>
+//! BLAKE2b
+//! <base64 signature>
+//!
+//! <revoked key name with rev>
+//! <revoked key name with rev>
+//! ```
+//!
+//! The signature covers the hash of everything following the empty line.
+
+use std::fs::{self, File};
+use std::io::prelude::*;
+use std::path::{Path, PathBuf};
+
+use base64;
+use sodiumoxide::crypto::sign;
+
+use error::{Error, Result};
+use super::{REVOCATION_LIST_FORMAT_VERSION, REVOCATION_LIST_SUFFIX, SIG_HASH_TYPE, SigKeyPair};
+use super::hash;
+use super::keys::parse_name_with_rev;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RevocationList {
+    origin: String,
+    revoked: Vec<String>,
+}
+
+impl RevocationList {
+    /// An empty revocation list for `origin`.
+    pub fn new<T: Into<String>>(origin: T) -> Self {
+        RevocationList {
+            origin: origin.into(),
+            revoked: Vec::new(),
+        }
+    }
+
+    pub fn origin(&self) -> &str {
+        &self.origin
+    }
+
+    /// The names with revision of every revoked key.
+    pub fn revoked(&self) -> &[String] {
+        &self.revoked
+    }
+
+    pub fn is_revoked(&self, name_with_rev: &str) -> bool {
+        self.revoked.iter().any(|r| r == name_with_rev)
+    }
+
+    /// Add a key revision of this origin to the list.
+    pub fn revoke(&mut self, name_with_rev: &str) -> Result<()> {
+        let (name, _) = try!(parse_name_with_rev(name_with_rev));
+        if name != self.origin {
+            return Err(Error::CryptoError(format!("Cannot revoke {}, it is not a key of the \
+                                                   {} origin",
+                                                  name_with_rev,
+                                                  self.origin)));
+        }
+        if !self.is_revoked(name_with_rev) {
+            self.revoked.push(name_with_rev.to_string());
+        }
+        Ok(())
+    }
+
+    /// Sign the list with `pair`, which must be a key of this origin that is not revoked.
+    pub fn to_signed_string(&self, pair: &SigKeyPair) -> Result<String> {
+        if pair.name != self.origin {
+            return Err(Error::CryptoError(format!("Cannot sign the revocation list of {} with \
+                                                   {}",
+                                                  self.origin,
+                                                  pair.name_with_rev())));
+        }
+        if self.is_revoked(&pair.name_with_rev()) {
+            return Err(Error::KeyRevoked(pair.name_with_rev()));
+        }
+        let body = self.body();
+        let hash = try!(hash::hash_string(&body));
+        let signature = sign::sign(&hash.as_bytes(), try!(pair.secret()));
+        Ok(format!("{}\n{}\n{}\n{}\n{}\n\n{}",
+                   REVOCATION_LIST_FORMAT_VERSION,
+                   self.origin,
+                   pair.name_with_rev(),
+                   SIG_HASH_TYPE,
+                   base64::encode(&signature),
+                   body))
+    }
+
+    /// The name with revision of the key that signed `content`, without verifying the signature.
+    pub fn signer(content: &str) -> Result<String> {
+        let (_, signer, _, _) = try!(parse(content));
+        Ok(signer)
+    }
+
+    /// Parse a signed revocation list, verifying its signature with the public keys in the
+    /// cache.
+    pub fn from_signed_str<P: AsRef<Path> + ?Sized>(content: &str,
+                                                    cache_key_path: &P)
+                                                    -> Result<Self> {
+        let (origin, signer, signature, body) = try!(parse(content));
+        let (signer_origin, _) = try!(parse_name_with_rev(&signer));
+        if signer_origin != origin {
+            return Err(Error::CryptoError(format!("Revocation list of {} is signed by {}",
+                                                  origin,
+                                                  signer)));
+        }
+        let pair = try!(SigKeyPair::get_pair_for(&signer, cache_key_path));
+        let signed_hash = match sign::verify(signature.as_slice(), try!(pair.public())) {
+            Ok(signed_data) => {
+                try!(String::from_utf8(signed_data).map_err(|_| {
+                    Error::CryptoError("Error parsing revocation list signature".to_string())
+                }))
+            }
+            Err(_) => return Err(Error::CryptoError("Verification failed".to_string())),
+        };
+        if signed_hash != try!(hash::hash_string(&body)) {
+            return Err(Error::CryptoError(format!("Revocation list of {} is invalid, hashes \
+                                                   don't match",
+                                                  origin)));
+        }
+        let mut list = RevocationList::new(origin);
+        for line in body.lines().map(|l| l.trim()).filter(|l| !l.is_empty()) {
+            try!(list.revoke(line));
+        }
+        if list.is_revoked(&signer) {
+            return Err(Error::KeyRevoked(signer));
+        }
+        Ok(list)
+    }
+
+    /// Verify a signed revocation list and write it to the cache, replacing any list for the same
+    /// origin. Revocations can't be undone, so a list that no longer revokes a key the cached list
+    /// revokes is refused.
+    pub fn write_file_from_str<P: AsRef<Path> + ?Sized>(content: &str,
+                                                        cache_key_path: &P)
+                                                        -> Result<Self> {
+        let list = try!(Self::from_signed_str(content, cache_key_path));
+        if let Some(cached) = try!(Self::get_for(&list.origin, cache_key_path)) {
+            if let Some(dropped) = cached.revoked.iter().find(|r| !list.is_revoked(r)) {
+                return Err(Error::CryptoError(format!("Revocation list of {} no longer revokes \
+                                                       {}",
+                                                      list.origin,
+                                                      dropped)));
+            }
+        }
+        let path = Self::path_for(&list.origin, cache_key_path);
+        let tmp_path = path.with_extension("revoked.tmp");
+        {
+            let mut file = try!(File::create(&tmp_path));
+            try!(file.write_all(content.as_bytes()));
+        }
+        try!(fs::rename(&tmp_path, &path));
+        Ok(list)
+    }
+
+    /// Load the cached revocation list for `origin`, if there is one.
+    pub fn get_for<P: AsRef<Path> + ?Sized>(origin: &str,
+                                            cache_key_path: &P)
+                                            -> Result<Option<Self>> {
+        let path = Self::path_for(origin, cache_key_path);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let mut content = String::new();
+        try!(try!(File::open(&path)).read_to_string(&mut content));
+        Ok(Some(try!(Self::from_signed_str(&content, cache_key_path))))
+    }
+
+    pub fn path_for<P: AsRef<Path> + ?Sized>(origin: &str, cache_key_path: &P) -> PathBuf {
+        cache_key_path
+            .as_ref()
+            .join(format!("{}.{}", origin, REVOCATION_LIST_SUFFIX))
+    }
+
+    fn body(&self) -> String {
+        let mut body = String::new();
+        for name_with_rev in self.revoked.iter() {
+            body.push_str(name_with_rev);
+            body.push('\n');
+        }
+        body
+    }
+}
+
+/// Fail with `Error::KeyRevoked` if the cached revocation list of the key's origin lists it.
+pub fn check_not_revoked<P: AsRef<Path> + ?Sized>(name_with_rev: &str,
+                                                  cache_key_path: &P)
+                                                  -> Result<()> {
+    let (origin, _) = try!(parse_name_with_rev(name_with_rev));
+    match try!(RevocationList::get_for(&origin, cache_key_path)) {
+        Some(ref list) if list.is_revoked(name_with_rev) => {
+            Err(Error::KeyRevoked(name_with_rev.to_string()))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Split a signed revocation list into its origin, signer, decoded signature and body.
+fn parse(content: &str) -> Result<(String, String, Vec<u8>, String)> {
+    let (header, body) = match content.find("\n\n") {
+        Some(i) => (&content[..i], &content[i + 2..]),
+        None => {
+            return Err(Error::CryptoError("Corrupt revocation list, can't find end of header"
+                                              .to_string()))
+        }
+    };
+    let mut lines = header.lines().map(|l| l.trim());
+    match lines.next() {
+        Some(version) if version == REVOCATION_LIST_FORMAT_VERSION => (),
+        Some(version) => {
+            return Err(Error::CryptoError(format!("Unsupported revocation list version: {}",
+                                                  version)))
+        }
+        None => {
+            return Err(Error::CryptoError("Corrupt revocation list, can't read format version"
+                                              .to_string()))
+        }
+    }
+    let origin = match lines.next() {
+        Some(origin) if !origin.is_empty() => origin.to_string(),
+        _ => {
+            return Err(Error::CryptoError("Corrupt revocation list, can't read origin"
+                                              .to_string()))
+        }
+    };
+    let signer = match lines.next() {
+        Some(signer) => {
+            try!(parse_name_with_rev(signer));
+            signer.to_string()
+        }
+        None => {
+            return Err(Error::CryptoError("Corrupt revocation list, can't read signing key name"
+                                              .to_string()))
+        }
+    };
+    match lines.next() {
+        Some(hash_type) if hash_type == SIG_HASH_TYPE => (),
+        Some(hash_type) => {
+            return Err(Error::CryptoError(format!("Unsupported signature type: {}", hash_type)))
+        }
+        None => {
+            return Err(Error::CryptoError("Corrupt revocation list, can't read hash type"
+                                              .to_string()))
+        }
+    }
+    let signature = match lines.next() {
+        Some(signature) => {
+            try!(base64::decode(signature)
+                .map_err(|e| Error::CryptoError(format!("Can't decode signature: {}", e))))
+        }
+        None => {
+            return Err(Error::CryptoError("Corrupt revocation list, can't read signature"
+                                              .to_string()))
+        }
+    };
+    Ok((origin, signer, signature, body.to_string()))
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::File;
+    use std::io::Write;
+
+    use tempdir::TempDir;
+
+    use super::*;
+    use super::super::SigKeyPair;
+    use super::super::artifact;
+    use super::super::test_support::*;
+    use error::Error;
+
+    fn two_revisions(cache: &Path) -> (SigKeyPair, SigKeyPair) {
+        let old = SigKeyPair::generate_pair_for_origin("unicorn", cache).unwrap();
+        // Key revisions have a resolution of one second.
+        ::std::thread::sleep(::std::time::Duration::from_millis(1100));
+        let new = SigKeyPair::generate_pair_for_origin("unicorn", cache).unwrap();
+        (old, new)
+    }
+
+    #[test]
+    fn sign_and_parse() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let (old, new) = two_revisions(cache.path());
+        let mut list = RevocationList::new("unicorn");
+        list.revoke(&old.name_with_rev()).unwrap();
+        let content = list.to_signed_string(&new).unwrap();
+
+        assert_eq!(RevocationList::signer(&content).unwrap(),
+                   new.name_with_rev());
+        let parsed = RevocationList::from_signed_str(&content, cache.path()).unwrap();
+        assert_eq!(list, parsed);
+        assert!(parsed.is_revoked(&old.name_with_rev()));
+        assert!(!parsed.is_revoked(&new.name_with_rev()));
+    }
+
+    #[test]
+    fn tampered_list_fails_verification() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let (old, new) = two_revisions(cache.path());
+        let mut list = RevocationList::new("unicorn");
+        list.revoke(&old.name_with_rev()).unwrap();
+        let content = list.to_signed_string(&new)
+            .unwrap()
+            .replace(&old.name_with_rev(), "");
+
+        assert!(RevocationList::from_signed_str(&content, cache.path()).is_err());
+    }
+
+    #[test]
+    fn cannot_revoke_keys_of_other_origins() {
+        let mut list = RevocationList::new("unicorn");
+        assert!(list.revoke("dragon-20160101010101").is_err());
+    }
+
+    #[test]
+    fn verify_rejects_artifacts_signed_by_revoked_keys() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let (old, new) = two_revisions(cache.path());
+        let dst = cache.path().join("signed.dat");
+        artifact::sign(&fixture("signme.dat"), &dst, &old).unwrap();
+        artifact::verify(&dst, cache.path()).unwrap();
+
+        let mut list = RevocationList::new("unicorn");
+        list.revoke(&old.name_with_rev()).unwrap();
+        let content = list.to_signed_string(&new).unwrap();
+        RevocationList::write_file_from_str(&content, cache.path()).unwrap();
+
+        match artifact::verify(&dst, cache.path()) {
+            Err(Error::KeyRevoked(ref name_with_rev)) => {
+                assert_eq!(name_with_rev, &old.name_with_rev())
+            }
+            other => panic!("Expected a revoked key error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn revocations_cannot_be_dropped() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let (old, new) = two_revisions(cache.path());
+        let mut list = RevocationList::new("unicorn");
+        list.revoke(&old.name_with_rev()).unwrap();
+        let content = list.to_signed_string(&new).unwrap();
+        RevocationList::write_file_from_str(&content, cache.path()).unwrap();
+
+        let empty = RevocationList::new("unicorn")
+            .to_signed_string(&new)
+            .unwrap();
+        assert!(RevocationList::write_file_from_str(&empty, cache.path()).is_err());
+        assert_eq!(RevocationList::get_for("unicorn", cache.path()).unwrap(),
+                   Some(list.clone()));
+
+        // The same list again still replaces the cached list.
+        RevocationList::write_file_from_str(&content, cache.path()).unwrap();
+    }
+
+    #[test]
+    fn get_for_without_a_list() {
+        let cache = TempDir::new("key_cache").unwrap();
+        assert!(RevocationList::get_for("unicorn", cache.path())
+                    .unwrap()
+                    .is_none());
+        let mut f = File::create(RevocationList::path_for("unicorn", cache.path())).unwrap();
+        f.write_all(b"nope").unwrap();
+        assert!(RevocationList::get_for("unicorn", cache.path()).is_err());
+    }
+}
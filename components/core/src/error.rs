@@ -35,6 +35,8 @@ pub enum Error {
     ArchiveError(libarchive::error::ArchiveError),
     /// An invalid path to a keyfile was given.
     BadKeyPath(String),
+    /// Occurs when a package bundle's manifest is missing or invalid.
+    BundleMalformed(String),
     /// Error reading raw contents of configuration file.
     ConfigFileIO(io::Error),
     /// Parsing error while reading a configuration file.
@@ -82,6 +84,8 @@ pub enum Error {
     InvalidPackageIdent(String),
     /// Occurs when a package target string cannot be successfully parsed.
     InvalidPackageTarget(String),
+    /// Occurs when a version requirement string cannot be successfully parsed.
+    InvalidVersionReq(String),
     /// Occurs when validating a package target for an unsupported architecture.
     InvalidArchitecture(String),
     /// Occurs when validating a package target for an unsupported platform.
@@ -96,8 +100,12 @@ pub enum Error {
     MetaFileMalformed(package::metadata::MetaFile),
     /// Occurs when a particular package metadata file is not found.
     MetaFileNotFound(package::metadata::MetaFile),
+    /// Occurs when a key revision is listed in its origin's revocation list.
+    KeyRevoked(String),
     /// When an IO error while accessing a MetaFile.
     MetaFileIO(io::Error),
+    /// Occurs when an artifact is not signed by a key that is required to have signed it.
+    MissingSignature(String),
     /// Occurs when we can't find an outbound IP address
     NoOutboundAddr,
     /// Occurs when a suitable installed package cannot be found.
@@ -138,6 +146,7 @@ impl fmt::Display for Error {
                 format!("Invalid keypath: {}. Specify an absolute path to a file on disk.",
                         e)
             }
+            Error::BundleMalformed(ref e) => format!("Package bundle is malformed: {}", e),
             Error::ConfigFileIO(ref e) => format!("Error reading configuration file: {}", e),
             Error::ConfigFileSyntax(ref e) => {
                 format!("Syntax errors while parsing TOML configuration file:\n\n{}",
@@ -214,6 +223,11 @@ impl fmt::Display for Error {
                          origin/name (example: acme/redis)",
                         e)
             }
+            Error::InvalidVersionReq(ref e) => {
+                format!("Invalid version requirement: {}. A valid requirement is a comma \
+                         separated list of comparisons (example: >=1.2.0, <2.0.0)",
+                        e)
+            }
             Error::InvalidPackageTarget(ref e) => {
                 format!("Invalid package target: {}. A valid target is in the form \
                          architecture-platform (example: x86_64-linux)",
@@ -232,7 +246,14 @@ impl fmt::Display for Error {
                 format!("MetaFile: {:?}, didn't contain a valid UTF-8 string", e)
             }
             Error::MetaFileNotFound(ref e) => format!("Couldn't read MetaFile: {}, not found", e),
+            Error::KeyRevoked(ref e) => {
+                format!("Key {} has been revoked by its origin and can no longer be trusted",
+                        e)
+            }
             Error::MetaFileIO(ref e) => format!("IO error while accessing MetaFile: {:?}", e),
+            Error::MissingSignature(ref e) => {
+                format!("Artifact is not signed by the required key {}", e)
+            }
             Error::NoOutboundAddr => format!("Failed to discover this hosts outbound IP address"),
             Error::PackageNotFound(ref pkg) => {
                 if pkg.fully_qualified() {
@@ -266,6 +287,7 @@ impl error::Error for Error {
         match *self {
             Error::ArchiveError(ref err) => err.description(),
             Error::BadKeyPath(_) => "An absolute path to a file on disk is required",
+            Error::BundleMalformed(_) => "Package bundle manifest is missing or invalid",
             Error::ConfigFileIO(_) => "Unable to read the raw contents of a configuration file",
             Error::ConfigFileSyntax(_) => "Error parsing contents of configuration file",
             Error::ConfigInvalidArraySocketAddr(_) => {
@@ -307,6 +329,7 @@ impl error::Error for Error {
             Error::FileNotFound(_) => "File not found",
             Error::InvalidPackageIdent(_) => "Package identifiers must be in origin/name format (example: acme/redis)",
             Error::InvalidPackageTarget(_) => "Package targets must be in architecture-platform format (example: x86_64-linux)",
+            Error::InvalidVersionReq(_) => "Version requirements must be comma separated comparisons (example: >=1.2.0, <2.0.0)",
             Error::InvalidArchitecture(_) => "Unsupported target architecture supplied.",
             Error::InvalidPlatform(_) => "Unsupported target platform supplied.",
             Error::InvalidServiceGroup(_) => "Service group strings must be in service.group format (example: redis.production)",
@@ -314,7 +337,9 @@ impl error::Error for Error {
             Error::MetaFileBadBind => "Bad value parsed from BIND or BIND_OPTIONAL MetaFile",
             Error::MetaFileMalformed(_) => "MetaFile didn't contain a valid UTF-8 string",
             Error::MetaFileNotFound(_) => "Failed to read an archive's metafile",
+            Error::KeyRevoked(_) => "Key has been revoked by its origin",
             Error::MetaFileIO(_) => "MetaFile could not be read or written to",
+            Error::MissingSignature(_) => "Artifact is not signed by a required key",
             Error::NoOutboundAddr => "Failed to discover the outbound IP address",
             Error::PackageNotFound(_) => "Cannot find a package",
             Error::ParseIntError(_) => "Failed to parse an integer from a string!",
@@ -33,6 +33,8 @@ pub const CACHE_KEY_PATH: &'static str = "hab/cache/keys";
 pub const CACHE_SRC_PATH: &'static str = "hab/cache/src";
 /// The default path where SSL-related artifacts are placed
 pub const CACHE_SSL_PATH: &'static str = "hab/cache/ssl";
+/// The path where package artifacts and bundles are unpacked while they are being verified
+pub const CACHE_UNPACK_PATH: &'static str = "hab/cache/unpack";
 /// The root path containing all locally installed packages
 pub const PKG_PATH: &'static str = "hab/pkgs";
 /// The environment variable pointing to the filesystem root. This exists for internal
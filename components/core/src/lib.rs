@@ -21,6 +21,7 @@ extern crate hyper;
 extern crate lazy_static;
 extern crate libc;
 extern crate libarchive;
+extern crate libarchive3_sys;
 #[macro_use]
 extern crate log;
 extern crate regex;
@@ -30,6 +31,7 @@ extern crate serde_derive;
 extern crate serde_json;
 extern crate sodiumoxide;
 extern crate libsodium_sys;
+extern crate tar;
 #[cfg(test)]
 extern crate tempdir;
 extern crate time;
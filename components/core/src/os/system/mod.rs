@@ -39,6 +39,8 @@ pub struct Uname {
 #[derive(Debug, Hash, Clone, Serialize, Deserialize, Eq, PartialEq)]
 pub enum Architecture {
     X86_64,
+    Aarch64,
+    Armv7,
 }
 
 impl fmt::Display for Architecture {
@@ -51,14 +53,22 @@ impl fmt::Display for Architecture {
 #[derive(Debug, Hash, Clone, Serialize, Deserialize, Eq, PartialEq)]
 pub enum Platform {
     Linux,
+    /// Linux, for packages built to also run on 2.x kernels. Such packages run on any Linux
+    /// system.
+    LinuxKernel2,
     Windows,
     Darwin,
 }
 
 impl fmt::Display for Platform {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let platform_string = format!("{:?}", self);
-        write!(f, "{}", platform_string.to_lowercase())
+        match *self {
+            Platform::LinuxKernel2 => write!(f, "linux-kernel2"),
+            _ => {
+                let platform_string = format!("{:?}", self);
+                write!(f, "{}", platform_string.to_lowercase())
+            }
+        }
     }
 }
 
@@ -68,7 +78,10 @@ impl FromStr for Architecture {
     fn from_str(value: &str) -> result::Result<Self, Self::Err> {
         let architecture = value.trim().to_lowercase();
         match architecture.as_ref() {
-            "x86_64" => Ok(Architecture::X86_64),
+            "x86_64" | "amd64" => Ok(Architecture::X86_64),
+            "aarch64" | "arm64" => Ok(Architecture::Aarch64),
+            // `uname -m` reports `armv7l` on most 32-bit ARM boards
+            "armv7" | "armv7l" | "armhf" => Ok(Architecture::Armv7),
             _ => return Err(Error::InvalidArchitecture(value.to_string())),
         }
     }
@@ -81,6 +94,7 @@ impl FromStr for Platform {
         let platform = value.trim().to_lowercase();
         match platform.as_ref() {
             "linux" => Ok(Platform::Linux),
+            "linux-kernel2" => Ok(Platform::LinuxKernel2),
             "windows" => Ok(Platform::Windows),
             "darwin" => Ok(Platform::Darwin),
             _ => return Err(Error::InvalidPlatform(value.to_string())),
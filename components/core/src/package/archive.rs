@@ -12,25 +12,37 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::any::Any;
+use std::cmp;
 use std::collections::HashMap;
 use std::error;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::mem;
 use std::path::{Path, PathBuf};
 use std::result;
 use std::str::{self, FromStr};
 
 use libarchive::writer;
 use libarchive::reader::{self, Reader};
-use libarchive::archive::{Entry, ReadFilter, ReadFormat, ExtractOption, ExtractOptions};
+use libarchive::archive::{Entry, FileType, ReadFilter, ReadFormat, ExtractOption, ExtractOptions};
+use libarchive3_sys::ffi;
 use regex::Regex;
 
 use super::{Identifiable, PackageIdent, PackageTarget};
 use super::metadata::MetaFile;
 use error::{Error, Result};
 use crypto::{artifact, hash};
+use crypto::artifact::VerifyingReader;
+use crypto::hash::HashingReader;
+use fs::{CACHE_UNPACK_PATH, PKG_PATH};
 
 lazy_static! {
     static ref METAFILE_REGXS: HashMap<MetaFile, Regex> = {
         let mut map = HashMap::new();
+        map.insert(MetaFile::BuildManifest,
+                   Regex::new(&format!(r"^/?hab/pkgs/([^/]+)/([^/]+)/([^/]+)/([^/]+)/{}$",
+                              MetaFile::BuildManifest)).unwrap());
         map.insert(MetaFile::CFlags,
                    Regex::new(&format!(r"^/?hab/pkgs/([^/]+)/([^/]+)/([^/]+)/([^/]+)/{}$",
                               MetaFile::CFlags)).unwrap());
@@ -66,14 +78,45 @@ lazy_static! {
                               MetaFile::Target)).unwrap());
         map
     };
+    static ref PKG_DIR_REGX: Regex =
+        Regex::new(r"^/?hab/pkgs/[^/]+/[^/]+/[^/]+/[^/]+/?").unwrap();
 }
 
 type Metadata = HashMap<MetaFile, String>;
 
+/// The kinds of entries in a package archive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum ArchiveEntryKind {
+    File,
+    Directory,
+    Symlink,
+    Other,
+}
+
+/// An entry of a package archive, with its path relative to the package's install directory so
+/// that the entries of two releases of a package can be compared.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ArchiveEntry {
+    pub path: String,
+    pub kind: ArchiveEntryKind,
+    pub size: i64,
+    pub uid: i64,
+    pub gid: i64,
+    /// The permission bits, including the setuid, setgid and sticky bits.
+    pub mode: u32,
+    /// The BLAKE2b hash of the content of a file.
+    pub checksum: Option<String>,
+    /// The target of a symlink.
+    pub link: Option<String>,
+}
+
 #[derive(Debug)]
 pub struct PackageArchive {
     pub path: PathBuf,
+    /// Every metafile of the archive, once it has been scanned to the end or unpacked.
     metadata: Option<Metadata>,
+    /// The metafiles found so far by scans that stopped at the one they were looking for.
+    scanned: Metadata,
 }
 
 impl PackageArchive {
@@ -81,6 +124,7 @@ impl PackageArchive {
         PackageArchive {
             path: path.into(),
             metadata: None,
+            scanned: Metadata::new(),
         }
     }
 
@@ -93,6 +137,14 @@ impl PackageArchive {
         hash::hash_file(&self.path)
     }
 
+    /// Returns the build manifest, a TOML document recording the inputs of the build.
+    pub fn build_manifest(&mut self) -> Result<Option<String>> {
+        match self.read_metadata(MetaFile::BuildManifest) {
+            Ok(data) => Ok(data.cloned()),
+            Err(e) => Err(e),
+        }
+    }
+
     pub fn cflags(&mut self) -> Result<Option<String>> {
         match self.read_metadata(MetaFile::CFlags) {
             Ok(data) => Ok(data.cloned()),
@@ -189,6 +241,69 @@ impl PackageArchive {
         }
     }
 
+    /// Returns the content of a metafile, if the package has it.
+    pub fn metafile(&mut self, file: MetaFile) -> Result<Option<String>> {
+        match self.read_metadata(file) {
+            Ok(data) => Ok(data.cloned()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns every entry under the package's install directory, in archive order. The content
+    /// of each file is hashed, which means reading the whole archive.
+    ///
+    /// # Failures
+    ///
+    /// * If the archive cannot be read
+    pub fn entries(&self) -> Result<Vec<ArchiveEntry>> {
+        let tar_reader = try!(artifact::get_archive_reader(&self.path));
+        let mut builder = reader::Builder::new();
+        try!(builder.support_format(ReadFormat::Gnutar));
+        try!(builder.support_filter(ReadFilter::Xz));
+        let mut reader = try!(builder.open_stream(tar_reader));
+        let mut entries = Vec::new();
+        loop {
+            let mut entry = match reader.next_header() {
+                Some(header) => {
+                    let path = match PKG_DIR_REGX.find(header.pathname()) {
+                        Some(m) if m.end() < header.pathname().len() => {
+                            header.pathname()[m.end()..].trim_right_matches('/').to_string()
+                        }
+                        _ => continue,
+                    };
+                    let kind = match header.filetype() {
+                        FileType::RegularFile => ArchiveEntryKind::File,
+                        FileType::Directory => ArchiveEntryKind::Directory,
+                        FileType::SymbolicLink => ArchiveEntryKind::Symlink,
+                        _ => ArchiveEntryKind::Other,
+                    };
+                    ArchiveEntry {
+                        path: path,
+                        kind: kind,
+                        size: header.size(),
+                        uid: header.uid(),
+                        gid: header.gid(),
+                        mode: unsafe { ffi::archive_entry_perm(header.entry()) as u32 },
+                        checksum: None,
+                        link: if kind == ArchiveEntryKind::Symlink {
+                            Some(header.symlink().to_string())
+                        } else {
+                            None
+                        },
+                    }
+                }
+                None => break,
+            };
+            if entry.kind == ArchiveEntryKind::File {
+                let mut body = HashingReader::new(EntryBody::new(&mut reader));
+                try!(io::copy(&mut body, &mut io::sink()));
+                entry.checksum = Some(body.finish());
+            }
+            entries.push(entry);
+        }
+        Ok(entries)
+    }
+
     /// A plain string representation of the archive's file name.
     pub fn file_name(&self) -> String {
         self.path
@@ -217,10 +332,105 @@ impl PackageArchive {
     pub fn unpack(&self, fs_root_path: Option<&Path>) -> Result<()> {
         let root = fs_root_path.unwrap_or(Path::new("/"));
         let tar_reader = try!(artifact::get_archive_reader(&self.path));
+        Self::extract(tar_reader, root)
+    }
+
+    /// Verify the archive's signatures and unpack it in a single pass over the file. The payload
+    /// is unpacked into a staging directory while it is being hashed, and the package is only
+    /// moved into place once the hash matches the signed hash. The metafiles of the unpacked
+    /// package are cached, so reading metadata afterwards does not scan the archive again.
+    ///
+    /// Returns the signers and payload hash, as `artifact::verify_signers` does.
+    ///
+    /// # Failures
+    ///
+    /// * If the archive cannot be verified
+    /// * If the package cannot be unpacked
+    pub fn unpack_verified<P: AsRef<Path>>(&mut self,
+                                           fs_root_path: Option<&Path>,
+                                           cache_key_path: &P,
+                                           required: &[String])
+                                           -> Result<(Vec<String>, String)> {
+        let root = fs_root_path.unwrap_or(Path::new("/"));
+        let payload = try!(artifact::get_verifying_reader(&self.path, cache_key_path, required));
+        let staging = root.join(CACHE_UNPACK_PATH).join(self.file_name());
+        if staging.exists() {
+            try!(fs::remove_dir_all(&staging));
+        }
+        try!(fs::create_dir_all(&staging));
+        let result = self.unpack_staged(payload, root, &staging);
+        if let Err(e) = fs::remove_dir_all(&staging) {
+            debug!("Failed to remove staging directory {}, {}",
+                   staging.display(),
+                   e);
+        }
+        result
+    }
+
+    fn unpack_staged(&mut self,
+                     payload: VerifyingReader,
+                     root: &Path,
+                     staging: &Path)
+                     -> Result<(Vec<String>, String)> {
+        try!(Self::extract(payload.clone(), staging));
+        let verified = try!(payload.finish());
+        let staged_pkgs = staging.join(PKG_PATH);
+        for pkg_dir in try!(Self::package_dirs(&staged_pkgs)) {
+            let dst = root.join(PKG_PATH)
+                .join(pkg_dir.strip_prefix(&staged_pkgs).unwrap());
+            if dst.exists() {
+                try!(fs::remove_dir_all(&dst));
+            }
+            try!(fs::create_dir_all(dst.parent().unwrap()));
+            try!(fs::rename(&pkg_dir, &dst));
+            try!(self.cache_metadata(&dst));
+        }
+        Ok(verified)
+    }
+
+    /// The `origin/name/version/release` directories under `pkgs`.
+    fn package_dirs(pkgs: &Path) -> Result<Vec<PathBuf>> {
+        let mut dirs = vec![pkgs.to_path_buf()];
+        for _ in 0..4 {
+            let mut children = Vec::new();
+            for dir in dirs.iter() {
+                for entry in try!(fs::read_dir(dir)) {
+                    let entry = try!(entry);
+                    if try!(entry.file_type()).is_dir() {
+                        children.push(entry.path());
+                    }
+                }
+            }
+            dirs = children;
+        }
+        Ok(dirs)
+    }
+
+    /// Read every metafile of an installed package into the metadata cache.
+    fn cache_metadata(&mut self, pkg_dir: &Path) -> Result<()> {
+        let mut metadata = Metadata::new();
+        for file in METAFILE_REGXS.keys() {
+            let path = pkg_dir.join(file.to_string());
+            if !path.is_file() {
+                continue;
+            }
+            let mut buf = String::new();
+            match File::open(&path).and_then(|mut f| f.read_to_string(&mut buf)) {
+                Ok(_) => {
+                    metadata.insert(file.clone(), String::from(buf.trim()));
+                }
+                Err(e) => return Err(Error::MetaFileIO(e)),
+            }
+        }
+        self.metadata = Some(metadata);
+        Ok(())
+    }
+
+    fn extract<R: Any + Read>(src: R, root: &Path) -> Result<()> {
         let mut builder = reader::Builder::new();
         try!(builder.support_format(ReadFormat::Gnutar));
         try!(builder.support_filter(ReadFilter::Xz));
-        let mut reader = try!(builder.open_stream(tar_reader));
+        let mut reader = try!(builder.open_stream(src));
         let writer = writer::Disk::new();
         let mut extract_options = ExtractOptions::new();
         extract_options.add(ExtractOption::Time);
@@ -257,10 +467,20 @@ impl PackageArchive {
     }
 
     fn read_metadata(&mut self, file: MetaFile) -> Result<Option<&String>> {
-        if let Some(ref files) = self.metadata {
-            return Ok(files.get(&file));
+        if self.metadata.is_none() && !self.scanned.contains_key(&file) {
+            try!(self.scan_metadata(file));
         }
-        let mut metadata = Metadata::new();
+        match self.metadata {
+            Some(ref files) => Ok(files.get(&file)),
+            None => Ok(self.scanned.get(&file)),
+        }
+    }
+
+    /// Read metafiles from the archive until `file` is found, so that looking up the ident or
+    /// target of an artifact doesn't decompress all of it. Every metafile seen on the way is kept
+    /// in `scanned`. A scan which reaches the end of the archive has seen every metafile, and
+    /// fills the metadata cache.
+    fn scan_metadata(&mut self, file: MetaFile) -> Result<()> {
         let mut matched_count = 0u8;
         let tar_reader = try!(artifact::get_archive_reader(&self.path));
         let mut builder = reader::Builder::new();
@@ -281,9 +501,10 @@ impl PackageArchive {
                 break;
             }
 
-            if matched_type.is_none() {
-                continue;
-            }
+            let matched_type = match matched_type {
+                Some(matched_type) => matched_type,
+                None => continue,
+            };
 
             let mut buf = String::new();
             loop {
@@ -296,25 +517,66 @@ impl PackageArchive {
                                 // a newline.
                                 buf.push_str(content);
                             }
-                            Err(_) => return Err(Error::MetaFileMalformed(matched_type.unwrap())),
+                            Err(_) => return Err(Error::MetaFileMalformed(matched_type)),
                         }
                     }
                     Ok(None) => {
                         // Hey, before you go - we are trimming whitespace for you. This
                         // is handy, because later on, you just want the string you want.
-                        metadata.insert(matched_type.unwrap(), String::from(buf.trim()));
+                        self.scanned.insert(matched_type.clone(), String::from(buf.trim()));
                         break;
                     }
-                    Err(_) => return Err(Error::MetaFileMalformed(matched_type.unwrap())),
+                    Err(_) => return Err(Error::MetaFileMalformed(matched_type)),
                 }
             } //inner loop
 
+            if matched_type == file {
+                return Ok(());
+            }
             if matched_count == METAFILE_REGXS.len() as u8 {
                 break;
             }
         }
-        self.metadata = Some(metadata);
-        Ok(self.metadata.as_ref().unwrap().get(&file))
+        self.metadata = Some(mem::replace(&mut self.scanned, Metadata::new()));
+        Ok(())
+    }
+}
+
+/// Reads the body of an archive's current entry a block at a time, so that large files need not
+/// be held in memory.
+struct EntryBody<'a, R: 'a + Reader> {
+    reader: &'a mut R,
+    block: Vec<u8>,
+    pos: usize,
+}
+
+impl<'a, R: Reader> EntryBody<'a, R> {
+    fn new(reader: &'a mut R) -> Self {
+        EntryBody {
+            reader: reader,
+            block: Vec::new(),
+            pos: 0,
+        }
+    }
+}
+
+impl<'a, R: Reader> Read for EntryBody<'a, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.pos == self.block.len() {
+            match self.reader.read_block() {
+                Ok(Some(bytes)) => {
+                    self.block.clear();
+                    self.block.extend_from_slice(bytes);
+                    self.pos = 0;
+                }
+                Ok(None) => return Ok(0),
+                Err(e) => return Err(io::Error::new(io::ErrorKind::Other, e.to_string())),
+            }
+        }
+        let len = cmp::min(buf.len(), self.block.len() - self.pos);
+        buf[..len].copy_from_slice(&self.block[self.pos..self.pos + len]);
+        self.pos += len;
+        Ok(len)
     }
 }
 
@@ -327,6 +589,9 @@ pub trait FromArchive: Sized {
 #[cfg(test)]
 mod test {
     use std::path::PathBuf;
+
+    use tempdir::TempDir;
+
     use os::system::{Architecture, Platform};
     use super::*;
 
@@ -341,6 +606,20 @@ mod test {
         assert_eq!(ident.release, Some("20160427165340".to_string()));
     }
 
+    #[test]
+    fn reading_artifact_metadata_stops_at_the_metafile_asked_for() {
+        let mut hart = PackageArchive::new(fixtures()
+            .join("happyhumans-possums-8.1.4-20160427165340-x86_64-linux.hart"));
+        assert_eq!(hart.ident().unwrap().name, "possums");
+        assert!(hart.metadata.is_none());
+        assert!(!hart.scanned.contains_key(&MetaFile::Manifest));
+        // The archive has no DEPS, so looking for it reads every metafile.
+        assert!(hart.deps().unwrap().is_empty());
+        assert!(hart.metadata.is_some());
+        assert!(hart.manifest().is_ok());
+        assert_eq!(hart.ident().unwrap().name, "possums");
+    }
+
     pub fn root() -> PathBuf {
         PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests")
     }
@@ -349,6 +628,19 @@ mod test {
         root().join("fixtures")
     }
 
+    #[test]
+    fn reading_artifact_entries() {
+        let hart = PackageArchive::new(fixtures()
+            .join("happyhumans-possums-8.1.4-20160427165340-x86_64-linux.hart"));
+        let entries = hart.entries().unwrap();
+        let ident = entries.iter().find(|e| e.path == "IDENT").unwrap();
+        assert_eq!(ident.kind, ArchiveEntryKind::File);
+        let content = b"happyhumans/possums/8.1.4/20160427165340\n";
+        assert_eq!(ident.checksum, Some(hash::hash_bytes(content).unwrap()));
+        assert_eq!(ident.mode & 0o777, 0o644);
+        assert!(entries.iter().all(|e| !e.path.starts_with("hab/")));
+    }
+
     #[test]
     fn reading_artifact_deps() {
         let mut hart = PackageArchive::new(fixtures()
@@ -365,6 +657,21 @@ mod test {
         assert_eq!(1024, tdeps.len());
     }
 
+    #[test]
+    fn unpack_verified_installs_the_package_and_caches_metadata() {
+        let root = TempDir::new("unpack_verified").unwrap();
+        let mut hart = PackageArchive::new(fixtures()
+            .join("happyhumans-possums-8.1.4-20160427165340-x86_64-linux.hart"));
+        let (signers, _) = hart.unpack_verified(Some(root.path()), &fixtures(), &[]).unwrap();
+        assert_eq!(signers, vec!["happyhumans-20160424223347".to_string()]);
+        assert!(root.path()
+                    .join("hab/pkgs/happyhumans/possums/8.1.4/20160427165340/IDENT")
+                    .is_file());
+        assert!(!root.path().join("hab/cache/unpack").join(hart.file_name()).exists());
+        assert!(hart.metadata.is_some());
+        assert_eq!(hart.ident().unwrap().name, "possums");
+    }
+
     #[test]
     fn reading_artifact_target() {
         let mut hart = PackageArchive::new(fixtures()
@@ -0,0 +1,335 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Package bundles, for installing packages on hosts that can't reach a Depot.
+//!
+//! A bundle holds the artifact of a package, the artifacts of its transitive dependencies and the
+//! public origin keys needed to verify them. It is a tarball signed like any other artifact, so
+//! it can be verified with `artifact::verify`. The tarball has a `MANIFEST` file:
+//!
+//! ```text
+//! BUNDLE-1
+//! <package ident>
+//! artifact <artifact file name>
+//! key <public key file name>
+//! ```
+//!
+//! followed by the artifacts under `artifacts/` and the keys under `keys/`.
+
+use std::fs::{self, File};
+use std::io::{BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use tar;
+
+use crypto::{artifact, SigKeyPair};
+use crypto::keys::PairType;
+use error::{Error, Result};
+use super::PackageIdent;
+
+pub const BUNDLE_FORMAT_VERSION: &'static str = "BUNDLE-1";
+pub const BUNDLE_EXT: &'static str = "bundle";
+
+const MANIFEST: &'static str = "MANIFEST";
+const ARTIFACTS_DIR: &'static str = "artifacts";
+const KEYS_DIR: &'static str = "keys";
+
+/// What a verified bundle unpacked to.
+#[derive(Debug)]
+pub struct BundleContents {
+    /// The package the bundle was made for.
+    pub ident: PackageIdent,
+    /// The name with revision of the key that signed the bundle.
+    pub signer: String,
+    /// The artifact of `ident`.
+    pub artifact: PathBuf,
+    /// Every artifact in the bundle, including the one of `ident`.
+    pub artifacts: Vec<PathBuf>,
+    /// The public origin keys in the bundle.
+    pub keys: Vec<PathBuf>,
+}
+
+impl BundleContents {
+    /// Write the public keys in the bundle into `cache_key_path`. The keys are covered by the
+    /// bundle's signature, so once it is verified they are trusted as much as its signer, whatever
+    /// their origin: a bundle carries the keys of every origin its artifacts are signed with.
+    /// Anything but a public key is skipped. Returns the names with revision of the keys written
+    /// and of the keys skipped.
+    ///
+    /// # Failures
+    ///
+    /// * If a key cannot be read or parsed
+    /// * If a key cannot be written to the cache
+    pub fn import_keys<P: AsRef<Path> + ?Sized>(&self,
+                                                cache_key_path: &P)
+                                                -> Result<(Vec<String>, Vec<String>)> {
+        let mut imported = Vec::new();
+        let mut skipped = Vec::new();
+        for key in self.keys.iter() {
+            let mut content = String::new();
+            try!(try!(File::open(key)).read_to_string(&mut content));
+            let (pair_type, name_with_rev, _) = try!(SigKeyPair::parse_key_str(&content));
+            if pair_type != PairType::Public {
+                skipped.push(name_with_rev);
+                continue;
+            }
+            if SigKeyPair::get_public_key_path(&name_with_rev, cache_key_path).is_ok() {
+                continue;
+            }
+            try!(SigKeyPair::write_file_from_str(&content, cache_key_path));
+            imported.push(name_with_rev);
+        }
+        Ok((imported, skipped))
+    }
+}
+
+#[derive(Debug)]
+pub struct PackageBundle {
+    pub path: PathBuf,
+}
+
+impl PackageBundle {
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        PackageBundle { path: path.into() }
+    }
+
+    /// The file name of the bundle for `ident` (ex: `core-redis-3.2.4-20170103160441.bundle`).
+    pub fn file_name_for(ident: &PackageIdent) -> Result<String> {
+        if !ident.fully_qualified() {
+            return Err(Error::InvalidPackageIdent(ident.to_string()));
+        }
+        Ok(format!("{}-{}-{}-{}.{}",
+                   ident.origin,
+                   ident.name,
+                   ident.version.as_ref().unwrap(),
+                   ident.release.as_ref().unwrap(),
+                   BUNDLE_EXT))
+    }
+
+    /// Write a bundle of `artifacts` and public `keys` for `ident` to `dst`, signed with `pair`.
+    /// `artifact` is the artifact of `ident` itself, and must not be repeated in `artifacts`.
+    ///
+    /// # Failures
+    ///
+    /// * If any of the files cannot be read
+    /// * If the bundle cannot be written or signed
+    pub fn create<P: AsRef<Path>>(dst: P,
+                                  ident: &PackageIdent,
+                                  artifact: &Path,
+                                  artifacts: &[PathBuf],
+                                  keys: &[PathBuf],
+                                  pair: &SigKeyPair)
+                                  -> Result<Self> {
+        let dst = dst.as_ref();
+        let tarball = dst.with_extension("tar.tmp");
+        let result = Self::write_tarball(&tarball, ident, artifact, artifacts, keys)
+            .and_then(|_| artifact::sign(&tarball, dst, pair));
+        let _ = fs::remove_file(&tarball);
+        try!(result);
+        Ok(Self::new(dst))
+    }
+
+    /// Verify the bundle's signature with the keys in `cache_key_path` and unpack it into `dir`.
+    /// The bundle's signer must already be trusted: its public key is not taken from the bundle.
+    ///
+    /// The whole bundle is verified before anything is unpacked, and the payload is checked
+    /// again while it's unpacked in case the file changed in between. `dir` is removed if the
+    /// bundle fails either check.
+    ///
+    /// # Failures
+    ///
+    /// * If the bundle cannot be verified
+    /// * If the bundle cannot be unpacked or its manifest is invalid
+    pub fn unpack_verified<P: AsRef<Path>>(&self,
+                                           dir: &Path,
+                                           cache_key_path: &P)
+                                           -> Result<BundleContents> {
+        try!(artifact::verify(&self.path, cache_key_path));
+        let payload = try!(artifact::get_verifying_reader(&self.path, cache_key_path, &[]));
+        try!(fs::create_dir_all(dir));
+        let unpacked = tar::Archive::new(payload.clone())
+            .unpack(dir)
+            .map_err(Error::from)
+            .and_then(|_| payload.finish());
+        let (mut signers, _) = match unpacked {
+            Ok(verified) => verified,
+            Err(e) => {
+                let _ = fs::remove_dir_all(dir);
+                return Err(e);
+            }
+        };
+
+        let mut manifest = String::new();
+        try!(try!(File::open(dir.join(MANIFEST))).read_to_string(&mut manifest));
+        let (ident, artifacts, keys) = try!(parse_manifest(&manifest));
+        let artifacts: Vec<PathBuf> =
+            artifacts.iter().map(|a| dir.join(ARTIFACTS_DIR).join(a)).collect();
+        let keys: Vec<PathBuf> = keys.iter().map(|k| dir.join(KEYS_DIR).join(k)).collect();
+        for path in artifacts.iter().chain(keys.iter()) {
+            if !path.is_file() {
+                return Err(Error::BundleMalformed(format!("{} is listed in the manifest but \
+                                                           missing",
+                                                          path.display())));
+            }
+        }
+        Ok(BundleContents {
+               ident: ident,
+               signer: signers.remove(0),
+               artifact: artifacts[0].clone(),
+               artifacts: artifacts,
+               keys: keys,
+           })
+    }
+
+    fn write_tarball(tarball: &Path,
+                     ident: &PackageIdent,
+                     artifact: &Path,
+                     artifacts: &[PathBuf],
+                     keys: &[PathBuf])
+                     -> Result<()> {
+        let mut all = vec![artifact.to_path_buf()];
+        all.extend(artifacts.iter().cloned());
+        let mut manifest = format!("{}\n{}\n", BUNDLE_FORMAT_VERSION, ident);
+        for path in all.iter() {
+            manifest.push_str(&format!("artifact {}\n", try!(file_name(path))));
+        }
+        for path in keys.iter() {
+            manifest.push_str(&format!("key {}\n", try!(file_name(path))));
+        }
+
+        let mut builder = tar::Builder::new(BufWriter::new(try!(File::create(tarball))));
+        let mut header = tar::Header::new_gnu();
+        header.set_size(manifest.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        try!(builder.append_data(&mut header, MANIFEST, manifest.as_bytes()));
+        for path in all.iter() {
+            try!(builder.append_path_with_name(path,
+                                               Path::new(ARTIFACTS_DIR)
+                                                   .join(try!(file_name(path)))));
+        }
+        for path in keys.iter() {
+            try!(builder.append_path_with_name(path,
+                                               Path::new(KEYS_DIR).join(try!(file_name(path)))));
+        }
+        let mut writer = try!(builder.into_inner());
+        try!(writer.flush());
+        Ok(())
+    }
+}
+
+fn file_name(path: &Path) -> Result<String> {
+    match path.file_name() {
+        Some(name) => Ok(name.to_string_lossy().into_owned()),
+        None => Err(Error::BundleMalformed(format!("{} is not a file", path.display()))),
+    }
+}
+
+/// Parse a manifest into the bundle's package ident, artifact names, starting with the one of the
+/// package, and key names. Names may not contain path separators.
+fn parse_manifest(manifest: &str) -> Result<(PackageIdent, Vec<String>, Vec<String>)> {
+    let mut lines = manifest.lines();
+    if lines.next().map(|l| l.trim()) != Some(BUNDLE_FORMAT_VERSION) {
+        return Err(Error::BundleMalformed(String::from("unsupported format version")));
+    }
+    let ident = match lines.next() {
+        Some(line) => try!(PackageIdent::from_str(line.trim())),
+        None => return Err(Error::BundleMalformed(String::from("missing package ident"))),
+    };
+    let mut artifacts = Vec::new();
+    let mut keys = Vec::new();
+    for line in lines.map(|l| l.trim()).filter(|l| !l.is_empty()) {
+        let mut parts = line.splitn(2, ' ');
+        let (kind, name) = (parts.next().unwrap(), parts.next().unwrap_or(""));
+        if name.is_empty() || name.contains('/') || name.contains('\\') || name.starts_with('.') {
+            return Err(Error::BundleMalformed(format!("invalid file name `{}'", name)));
+        }
+        match kind {
+            "artifact" => artifacts.push(String::from(name)),
+            "key" => keys.push(String::from(name)),
+            _ => return Err(Error::BundleMalformed(format!("unknown entry `{}'", line))),
+        }
+    }
+    if artifacts.is_empty() {
+        return Err(Error::BundleMalformed(String::from("no artifacts")));
+    }
+    Ok((ident, artifacts, keys))
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::File;
+    use std::io::{Read, Write};
+    use std::str::FromStr;
+
+    use tempdir::TempDir;
+
+    use crypto::SigKeyPair;
+    use package::PackageIdent;
+    use super::{parse_manifest, PackageBundle};
+    use super::super::test_support::fixture_path;
+
+    #[test]
+    fn create_and_unpack_verified() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let out = TempDir::new("bundle").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let ident = PackageIdent::from_str("happyhumans/possums/8.1.4/20160427165340").unwrap();
+        let artifact =
+            fixture_path("happyhumans-possums-8.1.4-20160427165340-x86_64-linux.hart");
+        let key = fixture_path("happyhumans-20160424223347.pub");
+        let dst = out.path().join(PackageBundle::file_name_for(&ident).unwrap());
+
+        let bundle = PackageBundle::create(&dst, &ident, &artifact, &[], &[key], &pair).unwrap();
+        let contents = bundle
+            .unpack_verified(&out.path().join("unpacked"), cache.path())
+            .unwrap();
+        assert_eq!(contents.ident, ident);
+        assert_eq!(contents.signer, pair.name_with_rev());
+        assert_eq!(contents.artifacts.len(), 1);
+        assert!(contents.artifact.is_file());
+        assert!(contents.keys[0].ends_with("happyhumans-20160424223347.pub"));
+    }
+
+    #[test]
+    fn tampered_bundle_is_not_unpacked() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let out = TempDir::new("bundle").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let ident = PackageIdent::from_str("happyhumans/possums/8.1.4/20160427165340").unwrap();
+        let artifact =
+            fixture_path("happyhumans-possums-8.1.4-20160427165340-x86_64-linux.hart");
+        let dst = out.path().join(PackageBundle::file_name_for(&ident).unwrap());
+        let bundle = PackageBundle::create(&dst, &ident, &artifact, &[], &[], &pair).unwrap();
+
+        let mut bytes = Vec::new();
+        File::open(&dst).unwrap().read_to_end(&mut bytes).unwrap();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xff;
+        File::create(&dst).unwrap().write_all(&bytes).unwrap();
+
+        let unpacked = out.path().join("unpacked");
+        assert!(bundle.unpack_verified(&unpacked, cache.path()).is_err());
+        assert!(!unpacked.exists());
+    }
+
+    #[test]
+    fn parse_manifest_rejects_paths() {
+        let manifest = "BUNDLE-1\ncore/redis/3.2.4/20170103160441\nartifact ../../etc/passwd\n";
+        assert!(parse_manifest(manifest).is_err());
+        let manifest = "BUNDLE-1\ncore/redis/3.2.4/20170103160441\nkey core-1.pub\n";
+        assert!(parse_manifest(manifest).is_err());
+    }
+}
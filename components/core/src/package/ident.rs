@@ -19,7 +19,7 @@ use std::str::FromStr;
 
 use regex::Regex;
 
-use package::PackageTarget;
+use package::{PackageTarget, Version, VersionReq};
 use error::{Error, Result};
 
 #[derive(Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Hash)]
@@ -84,16 +84,26 @@ impl PackageIdent {
         }
     }
 
+    /// Returns `true` if the ident has a version and it satisfies `req`.
+    pub fn matches_req(&self, req: &VersionReq) -> bool {
+        self.version.as_ref().map_or(false, |v| req.matches_str(v))
+    }
+
     pub fn archive_name(&self) -> Option<String> {
+        self.archive_name_with_target(&PackageTarget::default())
+    }
+
+    /// The file name of the artifact of this package for `target`, if the ident is fully
+    /// qualified.
+    pub fn archive_name_with_target(&self, target: &PackageTarget) -> Option<String> {
         if self.fully_qualified() {
-            let default_target = PackageTarget::default();
             Some(format!("{}-{}-{}-{}-{}-{}.hart",
                          self.origin,
                          self.name,
                          self.version.as_ref().unwrap(),
                          self.release.as_ref().unwrap(),
-                         default_target.architecture,
-                         default_target.platform))
+                         target.architecture,
+                         target.platform))
         } else {
             None
         }
@@ -200,13 +210,8 @@ impl PartialOrd for PackageIdent {
         }
         match version_sort(self.version.as_ref().unwrap(),
                            other.version.as_ref().unwrap()) {
-            ord @ Ok(Ordering::Greater) |
-            ord @ Ok(Ordering::Less) => ord.ok(),
             Ok(Ordering::Equal) => Some(self.release.cmp(&other.release)),
-            Err(e) => {
-                error!("This was a very bad version number: {:?}", e);
-                return None;
-            }
+            ord => ord.ok(),
         }
     }
 }
@@ -225,118 +230,28 @@ impl Ord for PackageIdent {
         if self.name != other.name {
             return self.name.cmp(&other.name);
         }
-        match version_sort(self.version.as_ref().unwrap(),
-                           other.version.as_ref().unwrap()) {
-            ord @ Ok(Ordering::Greater) |
-            ord @ Ok(Ordering::Less) => ord.unwrap(),
-            Ok(Ordering::Equal) => self.release.cmp(&other.release),
-            Err(_) => Ordering::Less,
+        let a_version = self.version.as_ref().map(|v| Version::parse(v));
+        let b_version = other.version.as_ref().map(|v| Version::parse(v));
+        match a_version.cmp(&b_version) {
+            Ordering::Equal => self.release.cmp(&other.release),
+            ord => ord,
         }
     }
 }
 
 /// Sorts two packages according to their version.
 ///
-/// We are a bit more strict than your average package management solution on versioning.
-/// What we support is the "some number of digits or dots" (the version number),
-/// followed by an optional "-" and any alphanumeric string (the extension). When determining sort
-/// order, we:
-///
-/// * Separate the version numbers from the extensions
-/// * Split the version numbers into an array of digits on any '.' characters. Digits are converted
-///   into <u64>.
-/// * Compare the version numbers by iterating over them. If 'a' is greater or lesser than 'b', we
-///   return that as the result. If it is equal, we move to the next digit and repeat. If one of
-///   the version numbers is exhausted before the other, it gains 0's for the missing slot.
-/// * If the version numbers are equal, but either A or B has an extension (but not both) than the
-///   version without the extension is greater. (1.0.0 is greater than 1.0.0-alpha6)
-/// * If both have an extension, it is compared lexicographically, with the result as the final
-///   ordering.
-///
-/// Returns a Error if we fail to match for any reason.
+/// Versions are ordered as described in the `version` module: semantic versions, including
+/// pre-releases and build metadata, sort as Semantic Versioning says, and any other upstream
+/// version string still gets a total order. Sorting never fails, the `Result` only remains for
+/// the callers that match on it.
 pub fn version_sort(a_version: &str, b_version: &str) -> Result<Ordering> {
-    let (a_parts, a_extension) = try!(split_version(a_version));
-    let (b_parts, b_extension) = try!(split_version(b_version));
-    let mut a_iter = a_parts.iter();
-    let mut b_iter = b_parts.iter();
-    loop {
-        let mut a_exhausted = false;
-        let mut b_exhausted = false;
-        let a_num = match a_iter.next() {
-            Some(i) => try!(i.parse::<u64>()),
-            None => {
-                a_exhausted = true;
-                0u64
-            }
-        };
-        let b_num = match b_iter.next() {
-            Some(i) => try!(i.parse::<u64>()),
-            None => {
-                b_exhausted = true;
-                0u64
-            }
-        };
-        if a_exhausted && b_exhausted {
-            break;
-        }
-        match a_num.cmp(&b_num) {
-            Ordering::Greater => {
-                return Ok(Ordering::Greater);
-            }
-            Ordering::Equal => {
-                continue;
-            }
-            Ordering::Less => {
-                return Ok(Ordering::Less);
-            }
-        }
-    }
-
-    // If you have equal digits, and one has an extension, it is
-    // the plain digits who win.
-    // 1.0.0-alpha1 vs 1.0.0
-    if a_extension.is_some() && b_extension.is_none() {
-        return Ok(Ordering::Less);
-    } else if a_extension.is_none() && b_extension.is_some() {
-        return Ok(Ordering::Greater);
-    } else if a_extension.is_none() && b_extension.is_none() {
-        return Ok(Ordering::Equal);
-    } else {
-        let a = match a_extension {
-            Some(a) => a,
-            None => String::new(),
-        };
-        let b = match b_extension {
-            Some(b) => b,
-            None => String::new(),
-        };
-        return Ok(a.cmp(&b));
-    }
-}
-
-fn split_version(version: &str) -> Result<(Vec<&str>, Option<String>)> {
-    let re = try!(Regex::new(r"([\d\.]+)(-.+)?"));
-    let caps = match re.captures(version) {
-        Some(caps) => caps,
-        None => return Err(Error::InvalidPackageIdent(version.to_string())),
-    };
-    let version_number = caps.get(1).unwrap();
-    let extension = match caps.get(2) {
-        Some(e) => {
-            let mut estr: String = e.as_str().to_string();
-            estr.remove(0);
-            Some(estr)
-        }
-        None => None,
-    };
-    let version_parts: Vec<&str> = version_number.as_str().split('.').collect();
-    Ok((version_parts, extension))
+    Ok(Version::parse(a_version).cmp(&Version::parse(b_version)))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use super::split_version;
     use std::cmp::Ordering;
     use std::cmp::PartialOrd;
 
@@ -418,14 +333,17 @@ mod tests {
     }
 
     #[test]
-    fn split_version_returns_both_parts() {
-        let svr = split_version("1.2.3-beta16");
-        match svr {
-            Ok((version_parts, Some(extension))) => {
-                assert_eq!(vec!["1", "2", "3"], version_parts);
-                assert_eq!("beta16", extension);
-            }
-            Ok((_, None)) => panic!("Has an extension"),
+    fn version_sort_semver_and_arbitrary_versions() {
+        match version_sort("1.0.0-rc.1+build5", "1.0.0-rc.1") {
+            Ok(compare) => assert_eq!(compare, Ordering::Greater),
+            Err(e) => panic!("{:?}", e),
+        }
+        match version_sort("1.0.0-rc.2", "1.0.0-rc.10") {
+            Ok(compare) => assert_eq!(compare, Ordering::Less),
+            Err(e) => panic!("{:?}", e),
+        }
+        match version_sort("2017.01.17b", "2017.01.17a") {
+            Ok(compare) => assert_eq!(compare, Ordering::Greater),
             Err(e) => panic!("{:?}", e),
         }
     }
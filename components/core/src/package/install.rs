@@ -26,7 +26,7 @@ use std::str::FromStr;
 use toml;
 use toml::Value;
 
-use super::{Identifiable, PackageIdent, Target, PackageTarget};
+use super::{Identifiable, PackageIdent, Target, PackageTarget, VersionReq};
 use super::metadata::{Bind, MetaFile};
 use error::{Error, Result};
 use fs;
@@ -60,20 +60,54 @@ impl PackageInstall {
         }
     }
 
-    /// Verifies an installation of a package that is equal or newer to a given ident and returns
-    /// a Result of a `PackageIdent` if one exists.
+    /// Verifies an installation of the newest release of a package whose version is equal or
+    /// newer to the version of `ident`, if it has one, and returns a struct representing that
+    /// package installation. The release of `ident` is not considered.
     ///
     /// An optional `fs_root` path may be provided to search for a package that is mounted on a
     /// filesystem not currently rooted at `/`.
     pub fn load_at_least(ident: &PackageIdent,
                          fs_root_path: Option<&Path>)
                          -> Result<PackageInstall> {
-        let package_install = try!(Self::resolve_package_install_min(ident, fs_root_path));
-        let package_target = try!(package_install.target());
-        match package_target.validate() {
-            Ok(()) => Ok(package_install),
-            Err(e) => Err(e),
+        let req = match ident.version {
+            Some(ref version) => VersionReq::at_least(version),
+            None => VersionReq::any(),
+        };
+        Self::load_matching(ident, &req, fs_root_path)
+    }
+
+    /// Verifies an installation of the newest release of a package whose version satisfies `req`
+    /// and returns a struct representing that package installation. Only the origin and name of
+    /// `ident` are considered.
+    ///
+    /// An optional `fs_root` path may be provided to search for a package that is mounted on a
+    /// filesystem not currently rooted at `/`.
+    pub fn load_matching(ident: &PackageIdent,
+                         req: &VersionReq,
+                         fs_root_path: Option<&Path>)
+                         -> Result<PackageInstall> {
+        let fs_root_path = fs_root_path.map_or(PathBuf::from("/"), |p| p.into());
+        let package_root_path = fs::pkg_root_path(Some(&fs_root_path));
+        if !package_root_path.exists() {
+            return Err(Error::PackageNotFound(ident.clone()));
         }
+        let latest = try!(Self::package_list(&package_root_path))
+            .into_iter()
+            .filter(|p| p.origin == ident.origin && p.name == ident.name && p.matches_req(req))
+            .max();
+        let package_install = match latest {
+            Some(id) => {
+                PackageInstall {
+                    installed_path: fs::pkg_install_path(&id, Some(&fs_root_path)),
+                    fs_root_path: fs_root_path,
+                    package_root_path: package_root_path,
+                    ident: id,
+                }
+            }
+            None => return Err(Error::PackageNotFound(ident.clone())),
+        };
+        try!(try!(package_install.target()).validate());
+        Ok(package_install)
     }
 
     fn resolve_package_install<T>(ident: &PackageIdent,
@@ -125,56 +159,16 @@ impl PackageInstall {
         }
     }
 
-    /// Find an installed package that is at minimum the version of the given ident.
-    fn resolve_package_install_min<T>(ident: &PackageIdent,
-                                      fs_root_path: Option<T>)
-                                      -> Result<PackageInstall>
-        where T: AsRef<Path>
-    {
-        // If the PackageIndent is does not have a version, use a reasonable minimum version that
-        // will be satisfied by any installed package with the same origin/name
-        let ident = if None == ident.version {
-            PackageIdent::new(ident.origin.clone(),
-                              ident.name.clone(),
-                              Some("0".into()),
-                              Some("0".into()))
-        } else {
-            ident.clone()
-        };
-        let fs_root_path = fs_root_path.map_or(PathBuf::from("/"), |p| p.as_ref().into());
-        let package_root_path = fs::pkg_root_path(Some(&fs_root_path));
+    /// Returns the identifiers of every package installed under the package path.
+    ///
+    /// An optional `fs_root` path may be provided to list packages on a filesystem not currently
+    /// rooted at `/`.
+    pub fn list_installed(fs_root_path: Option<&Path>) -> Result<Vec<PackageIdent>> {
+        let package_root_path = fs::pkg_root_path(fs_root_path);
         if !package_root_path.exists() {
-            return Err(Error::PackageNotFound(ident.clone()));
-        }
-
-        let pl = try!(Self::package_list(&package_root_path));
-        let latest: Option<PackageIdent> = pl.iter()
-            .filter(|ref p| p.origin == ident.origin && p.name == ident.name)
-            .fold(None, |winner, b| match winner {
-                Some(a) => {
-                    match a.cmp(&b) {
-                        Ordering::Greater | Ordering::Equal => Some(a),
-                        Ordering::Less => Some(b.clone()),
-                    }
-                }
-                None => {
-                    match b.cmp(&ident) {
-                        Ordering::Greater | Ordering::Equal => Some(b.clone()),
-                        Ordering::Less => None,
-                    }
-                }
-            });
-        match latest {
-            Some(id) => {
-                Ok(PackageInstall {
-                       installed_path: fs::pkg_install_path(&id, Some(&fs_root_path)),
-                       fs_root_path: fs_root_path,
-                       package_root_path: package_root_path,
-                       ident: id.clone(),
-                   })
-            }
-            None => Err(Error::PackageNotFound(ident.clone())),
+            return Ok(vec![]);
         }
+        Self::package_list(&package_root_path)
     }
 
     pub fn new_from_parts(ident: PackageIdent,
@@ -245,7 +239,8 @@ impl PackageInstall {
         }
     }
 
-    fn deps(&self) -> Result<Vec<PackageIdent>> {
+    /// Returns the direct runtime dependencies of the package, read from its `DEPS` metafile.
+    pub fn deps(&self) -> Result<Vec<PackageIdent>> {
         self.read_deps(MetaFile::Deps)
     }
 
@@ -253,6 +248,11 @@ impl PackageInstall {
         self.read_deps(MetaFile::TDeps)
     }
 
+    /// Returns the package's `MANIFEST`, which records the metadata of its plan.
+    pub fn manifest(&self) -> Result<String> {
+        self.read_metafile(MetaFile::Manifest)
+    }
+
     /// Returns a Rust representation of the mappings defined by the `pkg_env` plan variable.
     ///
     /// # Failures
@@ -723,12 +723,17 @@ impl fmt::Display for PackageInstall {
 
 #[cfg(test)]
 mod test {
+    use std::fs::{self, File};
+    use std::io::Write;
     use std::str::FromStr;
     use std::path::PathBuf;
+    use tempdir::TempDir;
     use toml;
-    use super::super::PackageIdent;
+    use super::super::{PackageIdent, PackageTarget, VersionReq};
+    use super::super::metadata::MetaFile;
     use super::PackageInstall;
     use super::super::test_support::*;
+    use fs::pkg_install_path;
 
     #[test]
     fn can_serialize_default_config() {
@@ -748,4 +753,55 @@ mod test {
             Err(e) => assert!(false, format!("{:?}", e)),
         }
     }
+
+    fn install_redis_releases(root: &TempDir, releases: &[(&str, &str)]) {
+        for &(version, release) in releases.iter() {
+            let ident = PackageIdent::new("core", "redis", Some(version), Some(release));
+            let path = pkg_install_path(&ident, Some(root.path()));
+            fs::create_dir_all(&path).unwrap();
+            File::create(path.join(MetaFile::Target.to_string()))
+                .unwrap()
+                .write_all(PackageTarget::default().to_string().as_bytes())
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn load_matching_picks_newest_satisfying_release() {
+        let root = TempDir::new("load-matching").unwrap();
+        install_redis_releases(&root,
+                               &[("1.9.2", "20170101010101"),
+                                 ("1.10.0", "20170101010102"),
+                                 ("2.0.0", "20170101010103")]);
+        let ident = PackageIdent::from_str("core/redis").unwrap();
+
+        let req = VersionReq::from_str(">=1.2,<2").unwrap();
+        let package_install = PackageInstall::load_matching(&ident, &req, Some(root.path()))
+            .unwrap();
+        assert_eq!(package_install.ident().to_string(),
+                   "core/redis/1.10.0/20170101010102");
+
+        let req = VersionReq::from_str(">=3").unwrap();
+        assert!(PackageInstall::load_matching(&ident, &req, Some(root.path())).is_err());
+    }
+
+    #[test]
+    fn load_at_least_picks_newest_release_of_at_least_the_version() {
+        let root = TempDir::new("load-at-least").unwrap();
+        install_redis_releases(&root,
+                               &[("1.9.2", "20170101010101"), ("1.10.0", "20170101010102")]);
+
+        let ident = PackageIdent::from_str("core/redis/1.9.10").unwrap();
+        let package_install = PackageInstall::load_at_least(&ident, Some(root.path())).unwrap();
+        assert_eq!(package_install.ident().to_string(),
+                   "core/redis/1.10.0/20170101010102");
+
+        let ident = PackageIdent::from_str("core/redis").unwrap();
+        let package_install = PackageInstall::load_at_least(&ident, Some(root.path())).unwrap();
+        assert_eq!(package_install.ident().to_string(),
+                   "core/redis/1.10.0/20170101010102");
+
+        let ident = PackageIdent::from_str("core/redis/1.11").unwrap();
+        assert!(PackageInstall::load_at_least(&ident, Some(root.path())).is_err());
+    }
 }
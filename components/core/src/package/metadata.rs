@@ -47,6 +47,7 @@ impl FromStr for Bind {
 pub enum MetaFile {
     Binds,
     BindsOptional,
+    BuildManifest,
     CFlags,
     Config,
     Deps,
@@ -70,6 +71,7 @@ impl fmt::Display for MetaFile {
         let id = match *self {
             MetaFile::Binds => "BINDS",
             MetaFile::BindsOptional => "BINDS_OPTIONAL",
+            MetaFile::BuildManifest => "BUILD_MANIFEST",
             MetaFile::CFlags => "CFLAGS",
             MetaFile::Config => "default.toml",
             MetaFile::Deps => "DEPS",
@@ -13,17 +13,21 @@
 // limitations under the License.
 
 pub mod archive;
+pub mod bundle;
 pub mod ident;
 pub mod install;
 pub mod metadata;
 pub mod plan;
 pub mod target;
+pub mod version;
 
-pub use self::archive::{FromArchive, PackageArchive};
+pub use self::archive::{ArchiveEntry, ArchiveEntryKind, FromArchive, PackageArchive};
+pub use self::bundle::PackageBundle;
 pub use self::ident::{Identifiable, PackageIdent};
 pub use self::install::PackageInstall;
 pub use self::plan::Plan;
 pub use self::target::{Target, PackageTarget};
+pub use self::version::{Version, VersionReq};
 
 #[cfg(test)]
 pub mod test_support {
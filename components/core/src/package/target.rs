@@ -68,16 +68,31 @@ impl PackageTarget {
     pub fn current_architecture() -> Architecture {
         if cfg!(target_arch = "x86_64") {
             Architecture::X86_64
+        } else if cfg!(target_arch = "aarch64") {
+            Architecture::Aarch64
+        } else if cfg!(target_arch = "arm") {
+            Architecture::Armv7
         } else {
             unreachable!("binary built for an unknown architecture")
         }
     }
+
+    /// Returns the targets whose packages can run on this target, starting with this target
+    /// itself. Packages built for `linux-kernel2` run on any Linux system of the same
+    /// architecture.
+    pub fn compatible_targets(&self) -> Vec<PackageTarget> {
+        let mut targets = vec![self.clone()];
+        if self.platform == Platform::Linux {
+            targets.push(PackageTarget::new(Platform::LinuxKernel2, self.architecture.clone()));
+        }
+        targets
+    }
 }
 
 impl Target for PackageTarget {
     fn validate(&self) -> Result<()> {
         let default = PackageTarget::default();
-        if self.architecture == default.architecture && self.platform == default.platform {
+        if default.compatible_targets().contains(self) {
             Ok(())
         } else {
             Err(Error::TargetMatchError(format!("Package target ({}-{}) does not match system \
@@ -109,7 +124,8 @@ impl FromStr for PackageTarget {
     type Err = Error;
 
     fn from_str(value: &str) -> result::Result<Self, Self::Err> {
-        let items: Vec<&str> = value.split("-").collect();
+        // The architecture never contains a dash, but the platform may (ex: `linux-kernel2`).
+        let items: Vec<&str> = value.splitn(2, "-").collect();
         let (architecture, platform) = match items.len() {
             2 => {
                 (try!(Architecture::from_str(items[0].into())),
@@ -150,6 +166,24 @@ mod tests {
         assert_eq!(target.architecture, Architecture::X86_64);
     }
 
+    #[test]
+    fn package_target_with_dashed_platform() {
+        let target = PackageTarget::from_str("armv7-linux-kernel2").unwrap();
+        assert_eq!(target.platform, Platform::LinuxKernel2);
+        assert_eq!(target.architecture, Architecture::Armv7);
+        assert_eq!(target.to_string(), "armv7-linux-kernel2");
+        let target = PackageTarget::from_str("aarch64-linux").unwrap();
+        assert_eq!(target.architecture, Architecture::Aarch64);
+    }
+
+    #[test]
+    fn linux_kernel2_packages_run_on_linux() {
+        let linux = PackageTarget::from_str("aarch64-linux").unwrap();
+        let kernel2 = PackageTarget::from_str("aarch64-linux-kernel2").unwrap();
+        assert_eq!(linux.compatible_targets(), vec![linux.clone(), kernel2.clone()]);
+        assert_eq!(kernel2.compatible_targets(), vec![kernel2]);
+    }
+
     #[test]
     #[should_panic]
     fn package_target_with_reversed_target_string() {
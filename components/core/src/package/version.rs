@@ -0,0 +1,416 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Package versions and requirements on them.
+//!
+//! A package's version is whatever its upstream project uses, so any string is a valid `Version`
+//! and every two versions can be ordered. Where the string allows it, the order is the one of
+//! [Semantic Versioning](http://semver.org):
+//!
+//! * A version is split into `<release>[-<pre-release>][+<build>]`.
+//! * The dot separated parts of the releases are compared in turn, a missing part counting as
+//!   `0`. Parts are compared as runs of digits and of other characters, so `1.10` is newer than
+//!   `1.9` and `2.0b` is newer than `2.0a`. Runs of digits are compared as numbers of any length
+//!   and are newer than other runs.
+//! * With equal releases, a version without a pre-release is newer (`1.0.0` > `1.0.0-rc.1`).
+//!   Pre-releases are compared part by part: numeric parts as numbers and older than any other
+//!   part, and a pre-release that runs out of parts first is older.
+//! * Build metadata does not take part in precedence. It, and then the version string itself,
+//!   only break ties so that two versions are equal only if their strings are.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::result;
+use std::str::FromStr;
+
+use error::Error;
+
+#[derive(Clone, Debug)]
+pub struct Version {
+    raw: String,
+    release: Vec<String>,
+    pre: Option<Vec<String>>,
+    build: Option<String>,
+}
+
+impl Version {
+    pub fn parse(version: &str) -> Self {
+        let (rest, build) = match version.find('+') {
+            Some(i) => (&version[..i], Some(version[i + 1..].to_string())),
+            None => (version, None),
+        };
+        let (release, pre) = match rest.find('-') {
+            Some(i) => (&rest[..i], Some(split_parts(&rest[i + 1..]))),
+            None => (rest, None),
+        };
+        Version {
+            raw: version.to_string(),
+            release: split_parts(release),
+            pre: pre,
+            build: build,
+        }
+    }
+
+    /// Compares the precedence of two versions, ignoring their build metadata.
+    pub fn precedence_cmp(&self, other: &Version) -> Ordering {
+        let len = self.release.len().max(other.release.len());
+        for i in 0..len {
+            let a = self.release.get(i).map_or("0", |p| p.as_str());
+            let b = other.release.get(i).map_or("0", |p| p.as_str());
+            match cmp_runs(a, b) {
+                Ordering::Equal => continue,
+                ord => return ord,
+            }
+        }
+        match (self.pre.as_ref(), other.pre.as_ref()) {
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(_), None) => Ordering::Less,
+            (Some(a), Some(b)) => {
+                for (x, y) in a.iter().zip(b.iter()) {
+                    match cmp_pre_part(x, y) {
+                        Ordering::Equal => continue,
+                        ord => return ord,
+                    }
+                }
+                a.len().cmp(&b.len())
+            }
+        }
+    }
+
+    /// The lowest version above every version sharing the first `index + 1` release parts of
+    /// this one, ignoring their pre-releases (ex: `1.3.0-0` for `1.2.7` and index `1`).
+    fn bump(&self, index: usize) -> Option<Version> {
+        let mut parts = Vec::new();
+        for i in 0..(index + 1) {
+            match self.release.get(i).map_or(Ok(0), |p| p.parse::<u64>()) {
+                Ok(n) if i == index => parts.push((n + 1).to_string()),
+                Ok(n) => parts.push(n.to_string()),
+                Err(_) => return None,
+            }
+        }
+        while parts.len() < self.release.len().max(3) {
+            parts.push(String::from("0"));
+        }
+        Some(Version::parse(&format!("{}-0", parts.join("."))))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
+impl FromStr for Version {
+    type Err = Error;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        Ok(Version::parse(value))
+    }
+}
+
+impl PartialEq for Version {
+    fn eq(&self, other: &Version) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl Eq for Version {}
+
+impl Hash for Version {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Version) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Version) -> Ordering {
+        self.precedence_cmp(other)
+            .then_with(|| self.build.cmp(&other.build))
+            .then_with(|| self.raw.cmp(&other.raw))
+    }
+}
+
+/// A requirement on versions, made of comma separated comparisons that a version must all
+/// satisfy (ex: `>=1.2.0, <2.0.0`). The comparisons are:
+///
+/// * `=1.2.3`, or just `1.2.3`: the same precedence as `1.2.3`
+/// * `>1.2.3`, `>=1.2.3`, `<1.2.3` and `<=1.2.3`: a newer or older precedence than `1.2.3`
+/// * `~1.2.3`: at least `1.2.3` but older than `1.3.0`, or than `2.0.0` for `~1`
+/// * `^1.2.3`: at least `1.2.3` but older than `2.0.0`, or than `0.3.0` for `^0.2.3`
+/// * `*`: any version
+///
+/// The upper bounds of `~` and `^` exclude the pre-releases of the bound itself.
+#[derive(Clone, Debug)]
+pub struct VersionReq {
+    raw: String,
+    comparators: Vec<(Op, Version)>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Op {
+    Exact,
+    Greater,
+    GreaterEq,
+    Less,
+    LessEq,
+}
+
+impl VersionReq {
+    /// Returns a requirement that every version satisfies.
+    pub fn any() -> Self {
+        VersionReq {
+            raw: String::from("*"),
+            comparators: Vec::new(),
+        }
+    }
+
+    /// Returns a requirement for versions at least as new as `version`.
+    pub fn at_least(version: &str) -> Self {
+        VersionReq {
+            raw: format!(">={}", version),
+            comparators: vec![(Op::GreaterEq, Version::parse(version))],
+        }
+    }
+
+    pub fn matches(&self, version: &Version) -> bool {
+        self.comparators
+            .iter()
+            .all(|&(op, ref bound)| {
+                let ord = version.precedence_cmp(bound);
+                match op {
+                    Op::Exact => ord == Ordering::Equal,
+                    Op::Greater => ord == Ordering::Greater,
+                    Op::GreaterEq => ord != Ordering::Less,
+                    Op::Less => ord == Ordering::Less,
+                    Op::LessEq => ord != Ordering::Greater,
+                }
+            })
+    }
+
+    /// Returns `true` if `version` is a version string that satisfies the requirement.
+    pub fn matches_str(&self, version: &str) -> bool {
+        self.matches(&Version::parse(version))
+    }
+}
+
+impl fmt::Display for VersionReq {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
+impl PartialEq for VersionReq {
+    fn eq(&self, other: &VersionReq) -> bool {
+        self.comparators == other.comparators
+    }
+}
+
+impl FromStr for VersionReq {
+    type Err = Error;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        let mut comparators = Vec::new();
+        for item in value.split(',').map(|i| i.trim()) {
+            if item == "*" {
+                continue;
+            }
+            let (op, version) = if item.starts_with(">=") {
+                (">=", &item[2..])
+            } else if item.starts_with("<=") {
+                ("<=", &item[2..])
+            } else if item.starts_with(|c: char| c == '>' || c == '<' || c == '=' || c == '~' ||
+                                           c == '^') {
+                (&item[..1], &item[1..])
+            } else {
+                ("=", item)
+            };
+            let version = version.trim();
+            if version.is_empty() || version.contains(char::is_whitespace) {
+                return Err(Error::InvalidVersionReq(value.to_string()));
+            }
+            let version = Version::parse(version);
+            match op {
+                "=" => comparators.push((Op::Exact, version)),
+                ">" => comparators.push((Op::Greater, version)),
+                ">=" => comparators.push((Op::GreaterEq, version)),
+                "<" => comparators.push((Op::Less, version)),
+                "<=" => comparators.push((Op::LessEq, version)),
+                _ => {
+                    let index = if op == "~" {
+                        if version.release.len() > 1 { 1 } else { 0 }
+                    } else {
+                        version
+                            .release
+                            .iter()
+                            .position(|p| p != "0")
+                            .unwrap_or(version.release.len() - 1)
+                    };
+                    let upper = match version.bump(index) {
+                        Some(upper) => upper,
+                        None => return Err(Error::InvalidVersionReq(value.to_string())),
+                    };
+                    comparators.push((Op::GreaterEq, version));
+                    comparators.push((Op::Less, upper));
+                }
+            }
+        }
+        Ok(VersionReq {
+               raw: value.trim().to_string(),
+               comparators: comparators,
+           })
+    }
+}
+
+fn split_parts(value: &str) -> Vec<String> {
+    value.split('.').map(|p| p.to_string()).collect()
+}
+
+/// Splits a version part into its runs of digits and of other characters.
+fn runs(part: &str) -> Vec<&str> {
+    let mut runs = Vec::new();
+    let mut start = 0;
+    let mut digits = None;
+    for (i, c) in part.char_indices() {
+        let is_digit = c.is_digit(10);
+        if digits.is_some() && digits != Some(is_digit) {
+            runs.push(&part[start..i]);
+            start = i;
+        }
+        digits = Some(is_digit);
+    }
+    if start < part.len() {
+        runs.push(&part[start..]);
+    }
+    runs
+}
+
+fn is_numeric(value: &str) -> bool {
+    !value.is_empty() && value.chars().all(|c| c.is_digit(10))
+}
+
+/// Compares two strings of digits as numbers, whatever their length.
+fn cmp_numeric(a: &str, b: &str) -> Ordering {
+    let a = a.trim_left_matches('0');
+    let b = b.trim_left_matches('0');
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+fn cmp_runs(a: &str, b: &str) -> Ordering {
+    let a_runs = runs(a);
+    let b_runs = runs(b);
+    for (x, y) in a_runs.iter().zip(b_runs.iter()) {
+        let ord = match (is_numeric(x), is_numeric(y)) {
+            (true, true) => cmp_numeric(x, y),
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => x.cmp(y),
+        };
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+    a_runs.len().cmp(&b_runs.len())
+}
+
+fn cmp_pre_part(a: &str, b: &str) -> Ordering {
+    match (is_numeric(a), is_numeric(b)) {
+        (true, true) => cmp_numeric(a, b),
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (false, false) => cmp_runs(a, b),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::{Version, VersionReq};
+
+    fn sorted(versions: &[&str]) -> Vec<String> {
+        let mut versions: Vec<Version> = versions.iter().map(|v| Version::parse(v)).collect();
+        versions.sort();
+        versions.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn semver_ordering() {
+        assert_eq!(sorted(&["1.0.0", "1.0.0-rc.1", "1.0.0-beta.11", "1.0.0-beta.2",
+                            "1.0.0-beta", "1.0.0-alpha.beta", "1.0.0-alpha.1", "1.0.0-alpha",
+                            "0.9.10", "0.9.9"]),
+                   vec!["0.9.9", "0.9.10", "1.0.0-alpha", "1.0.0-alpha.1", "1.0.0-alpha.beta",
+                        "1.0.0-beta", "1.0.0-beta.2", "1.0.0-beta.11", "1.0.0-rc.1", "1.0.0"]);
+    }
+
+    #[test]
+    fn build_metadata_only_breaks_ties() {
+        let a = Version::parse("1.0.0-rc.1+build5");
+        let b = Version::parse("1.0.0-rc.1+build6");
+        assert!(a < b);
+        assert!(a.precedence_cmp(&b) == ::std::cmp::Ordering::Equal);
+        assert!(Version::parse("1.0.0+build5") > Version::parse("1.0.0-rc.2"));
+    }
+
+    #[test]
+    fn arbitrary_versions_are_ordered() {
+        assert_eq!(sorted(&["2017.01.17b", "2017.01.17", "2017.1.9", "r20", "r3", "1.2.3.4",
+                            "20150521131347", "1.2.3"]),
+                   vec!["r3", "r20", "1.2.3", "1.2.3.4", "2017.1.9", "2017.01.17",
+                        "2017.01.17b", "20150521131347"]);
+        assert!(Version::parse("99999999999999999999999") > Version::parse("1"));
+    }
+
+    #[test]
+    fn version_req_comparisons() {
+        let req = VersionReq::from_str(">=1.2.0, <2.0.0").unwrap();
+        assert!(req.matches_str("1.2.0"));
+        assert!(req.matches_str("1.10.3"));
+        assert!(!req.matches_str("1.2.0-rc.1"));
+        assert!(!req.matches_str("2.0.0"));
+        assert!(VersionReq::from_str("1.2").unwrap().matches_str("1.2.0+build1"));
+        assert!(VersionReq::from_str("*").unwrap().matches_str("anything"));
+        assert!(VersionReq::at_least("3.0").matches_str("3.2.4"));
+    }
+
+    #[test]
+    fn version_req_tilde_and_caret() {
+        let tilde = VersionReq::from_str("~1.2.3").unwrap();
+        assert!(tilde.matches_str("1.2.9"));
+        assert!(!tilde.matches_str("1.3.0-rc.1"));
+        assert!(!tilde.matches_str("1.3.0"));
+        let caret = VersionReq::from_str("^1.2.3").unwrap();
+        assert!(caret.matches_str("1.9.0"));
+        assert!(!caret.matches_str("2.0.0"));
+        let caret = VersionReq::from_str("^0.2.3").unwrap();
+        assert!(caret.matches_str("0.2.9"));
+        assert!(!caret.matches_str("0.3.0"));
+    }
+
+    #[test]
+    fn version_req_rejects_invalid_requirements() {
+        assert!(VersionReq::from_str(">=").is_err());
+        assert!(VersionReq::from_str("1.2, ").is_err());
+        assert!(VersionReq::from_str(">= 1 2").is_err());
+        assert!(VersionReq::from_str("~r20").is_err());
+    }
+}
@@ -19,10 +19,20 @@ use error::Result;
 pub use os::system::{uname, Uname};
 
 static GOOGLE_DNS: &'static str = "8.8.8.8:53";
+static GOOGLE_DNS_V6: &'static str = "[2001:4860:4860::8888]:53";
 
+/// Returns the address this host uses to reach the internet. The IPv4 address is preferred, so
+/// the IPv6 address is only returned on hosts without a route to the IPv4 internet.
 pub fn ip() -> Result<IpAddr> {
-    let socket = try!(UdpSocket::bind("0.0.0.0:0"));
-    let _ = try!(socket.connect(GOOGLE_DNS));
+    match ip_for_route("0.0.0.0:0", GOOGLE_DNS) {
+        Ok(ip) => Ok(ip),
+        Err(_) => ip_for_route("[::]:0", GOOGLE_DNS_V6),
+    }
+}
+
+fn ip_for_route(bind: &str, remote: &str) -> Result<IpAddr> {
+    let socket = try!(UdpSocket::bind(bind));
+    let _ = try!(socket.connect(remote));
     let addr = try!(socket.local_addr());
     Ok(addr.ip())
 }
@@ -20,6 +20,8 @@ pub mod upload {
     use std::time;
 
     use butterfly::client::Client;
+    use butterfly::error::Error as ButterflyError;
+    use butterfly::rumor::chunk::{CHUNK_SIZE, MAX_CHUNKED_FILE_BYTES};
     use common::ui::{Status, UI};
     use hcore::crypto::{SymKey, BoxKeyPair};
     use hcore::service::ServiceGroup;
@@ -62,6 +64,18 @@ pub mod upload {
             encrypted = true;
         }
 
+        // Refuse oversized files here, rather than after we have connected to the first peer.
+        if body.len() > MAX_CHUNKED_FILE_BYTES {
+            let err = ButterflyError::RumorTooLarge(body.len(), MAX_CHUNKED_FILE_BYTES);
+            return Err(Error::ButterflyError(format!("{}", err)));
+        }
+        if body.len() > CHUNK_SIZE {
+            try!(ui.status(Status::Creating,
+                           format!("{} chunks of up to {} bytes",
+                                   (body.len() + CHUNK_SIZE - 1) / CHUNK_SIZE,
+                                   CHUNK_SIZE)));
+        }
+
         for peer in peers.iter() {
             try!(ui.status(Status::Applying, format!("to peer {}", peer)));
             let mut client = try!(Client::new(peer, ring_key.map(|k| k.clone()))
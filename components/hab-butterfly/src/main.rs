@@ -26,6 +26,7 @@ extern crate log;
 
 use std::env;
 use std::ffi::OsString;
+use std::net::{IpAddr, SocketAddr};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::thread;
@@ -101,13 +102,7 @@ fn start(ui: &mut UI) -> Result<()> {
 
 fn sub_config_apply(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let peers_str = m.value_of("PEER").unwrap_or("127.0.0.1");
-    let mut peers: Vec<String> = peers_str.split(",").map(|p| p.into()).collect();
-    for p in peers.iter_mut() {
-        if p.find(':').is_none() {
-            p.push(':');
-            p.push_str(&HABITAT_BUTTERFLY_PORT.to_string());
-        }
-    }
+    let peers: Vec<String> = peers_str.split(",").map(peer_with_port).collect();
     let number = value_t!(m, "VERSION_NUMBER", u64).unwrap_or_else(|e| e.exit());
     let file_path = match m.value_of("FILE") {
         Some("-") | None => None,
@@ -146,13 +141,7 @@ fn sub_config_apply(ui: &mut UI, m: &ArgMatches) -> Result<()> {
 
 fn sub_file_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let peers_str = m.value_of("PEER").unwrap_or("127.0.0.1");
-    let mut peers: Vec<String> = peers_str.split(",").map(|p| p.into()).collect();
-    for p in peers.iter_mut() {
-        if p.find(':').is_none() {
-            p.push(':');
-            p.push_str(&HABITAT_BUTTERFLY_PORT.to_string());
-        }
-    }
+    let peers: Vec<String> = peers_str.split(",").map(peer_with_port).collect();
     let number = value_t!(m, "VERSION_NUMBER", u64).unwrap_or_else(|e| e.exit());
     let file_path = Path::new(m.value_of("FILE").unwrap()); // Required via clap
     match file_path.metadata() {
@@ -263,3 +252,16 @@ fn user_param_or_env(m: &ArgMatches) -> Option<String> {
         }
     }
 }
+
+/// Append the default port to a peer given without one. IPv6 addresses may be given bare, in
+/// brackets, or in brackets followed by a port.
+fn peer_with_port(peer: &str) -> String {
+    if let Ok(ip) = IpAddr::from_str(peer.trim_left_matches('[').trim_right_matches(']')) {
+        return SocketAddr::new(ip, HABITAT_BUTTERFLY_PORT as u16).to_string();
+    }
+    if peer.find(':').is_none() {
+        format!("{}:{}", peer, HABITAT_BUTTERFLY_PORT)
+    } else {
+        peer.to_string()
+    }
+}
@@ -17,7 +17,11 @@ use std::result;
 use std::str::FromStr;
 
 use clap::{App, AppSettings, Arg};
+use command::pkg::sbom::SbomSpec;
+use common::ui::OutputFormat;
+use credentials::TokenStore;
 use hcore::crypto::keys::PairType;
+use hcore::package::{PackageIdent, VersionReq};
 use regex::Regex;
 use url::Url;
 
@@ -42,12 +46,16 @@ pub fn get() -> App<'static, 'static> {
         (author: "\nAuthors: The Habitat Maintainers <humans@habitat.sh>\n")
         (@setting VersionlessSubcommands)
         (@setting ArgRequiredElseHelp)
+        (@arg OUTPUT_FORMAT: --format +takes_value +global {valid_output_format}
+            "Output format: plain, or json for programs (default: plain)")
         (@subcommand cli =>
             (about: "Commands relating to Habitat runtime config")
             (aliases: &["cl"])
             (@setting ArgRequiredElseHelp)
             (subcommand: sub_cli_setup().aliases(&["s", "se", "set", "setu"]))
             (subcommand: sub_cli_completers().aliases(&["c", "co", "com", "comp"]))
+            (subcommand: sub_cli_profile().aliases(&["p", "pr", "pro", "prof", "profi", "profil"]))
+            (subcommand: sub_cli_token_store().aliases(&["t", "to", "tok", "toke", "token"]))
         )
         (@subcommand config =>
             (about: "Commands relating to Habitat runtime config")
@@ -109,6 +117,15 @@ pub fn get() -> App<'static, 'static> {
                         contents and writes the key to disk")
                     (aliases: &["i", "im", "imp", "impo", "impor"])
                 )
+                (@subcommand revoke =>
+                    (about: "Revokes an origin key revision, so that artifacts signed with it are \
+                        no longer trusted")
+                    (@arg KEY_WITH_REV: +required +takes_value
+                        "The origin key name with revision (ex: core-20160810182414)")
+                    (@arg DEPOT_URL: -u --url +takes_value {valid_url}
+                        "Use a specific Depot URL (ex: http://depot.example.com/v1/depot)")
+                    (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for the Depot")
+                )
                 (@subcommand upload =>
                     (@group upload =>
                         (@attributes +required)
@@ -149,6 +166,38 @@ pub fn get() -> App<'static, 'static> {
                     "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
             )
             (subcommand: sub_pkg_build())
+            (@subcommand bundle =>
+                (about: "Creates a signed bundle of a package, its dependencies and their origin \
+                    keys, for installing it without a Depot")
+                (@arg PKG_IDENT: +required +takes_value
+                    "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+                (@arg ORIGIN: --origin +takes_value "Origin key used to sign the bundle")
+                (@arg DEST_DIR: -d --dest +takes_value
+                    "Directory to write the bundle to (default: current directory)")
+                (@arg DEPOT_URL: -u --url +takes_value {valid_url}
+                    "Use a specific Depot URL (ex: http://depot.example.com/v1/depot)")
+            )
+            (@subcommand dependencies =>
+                (about: "Prints the dependencies of an installed package")
+                (aliases: &["deps"])
+                (@arg PKG_IDENT: +required +takes_value
+                    "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+                (@arg TRANSITIVE: -t --transitive
+                    "Include the dependencies of dependencies")
+                (@arg REVERSE: -r --reverse
+                    "Print the installed packages that depend on the package instead")
+                (@arg TREE: --tree conflicts_with[TRANSITIVE]
+                    "Print the whole tree of installed dependencies, or of dependents with \
+                    --reverse")
+            )
+            (@subcommand diff =>
+                (about: "Compares two Habitat Artifacts file-by-file and metafile-by-metafile")
+                (@arg SOURCE_A: +required {file_exists}
+                    "A path to a Habitat Artifact \
+                    (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
+                (@arg SOURCE_B: +required {file_exists}
+                    "A path to the Habitat Artifact to compare it with")
+            )
             (@subcommand env =>
                 (about: "Prints the runtime environment of a specific installed package")
                 (@arg PKG_IDENT: +required +takes_value
@@ -168,7 +217,8 @@ pub fn get() -> App<'static, 'static> {
                 (about: "Exports the package to the specified format")
                 (aliases: &["exp"])
                 (@arg FORMAT: +required +takes_value
-                    "The export format (ex: docker, aci, mesos, or tar)")
+                    "The export format \
+                    (ex: docker, oci, aci, mesos, kubernetes, systemd, or tar)")
                 (@arg PKG_IDENT: +required +takes_value
                     "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
             )
@@ -179,11 +229,21 @@ pub fn get() -> App<'static, 'static> {
             )
             (subcommand: sub_pkg_install().aliases(
                 &["i", "in", "ins", "inst", "insta", "instal"]))
+            (@subcommand list =>
+                (about: "Lists installed packages")
+                (aliases: &["l", "li", "ls"])
+                (@arg PKG_IDENT: +takes_value
+                    "Only list packages of an origin or matching a package identifier \
+                    (ex: core, core/redis, core/busybox-static/1.42.2)")
+            )
             (@subcommand path =>
                 (about: "Prints the path to a specific installed release of a package")
                 (aliases: &["p", "pa", "pat"])
                 (@arg PKG_IDENT: +required +takes_value
                     "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+                (@arg VERSION_REQ: --("version-req") +takes_value {valid_version_req}
+                    "Print the newest installed release whose version satisfies a requirement \
+                    (ex: '>=3.2, <4')")
             )
             (@subcommand provides =>
                 (about: "Search installed Habitat packages for a given file")
@@ -194,6 +254,14 @@ pub fn get() -> App<'static, 'static> {
                     (ex: core/busybox-static/1.24.2/20160708162350)")
                 (@arg FULL_PATHS: -p "Show full path to file")
             )
+            (@subcommand sbom =>
+                (about: "Prints a software bill of materials for a package and its dependencies")
+                (@arg PKG_IDENT_OR_ARTIFACT: +required +takes_value
+                    "An installed package identifier or a path to a Habitat Artifact \
+                    (ex: core/redis, /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
+                (@arg SPEC: --spec +takes_value {valid_sbom_spec}
+                    "SBOM standard: spdx or cyclonedx (default: spdx)")
+            )
             (@subcommand search =>
                 (about: "Search for a package on a Depot")
                 (@arg SEARCH_TERM: +required +takes_value "Search term")
@@ -204,19 +272,37 @@ pub fn get() -> App<'static, 'static> {
                 (about: "Signs an archive with an origin key, generating a Habitat Artifact")
                 (aliases: &["s", "si", "sig"])
                 (@arg ORIGIN: --origin +takes_value "Origin key used to create signature")
+                (@arg COUNTERSIGN: --countersign
+                    "Add a signature to an already signed Habitat Artifact, keeping its payload \
+                    and existing signatures")
                 (@arg SOURCE: +required {file_exists}
                     "A path to a source archive file \
-                    (ex: /home/acme-redis-3.0.7-21120102031201.tar.xz)")
+                    (ex: /home/acme-redis-3.0.7-21120102031201.tar.xz), or to a Habitat Artifact \
+                    when counter-signing")
                 (@arg DEST: +required
                     "The destination path to the signed Habitat Artifact \
                     (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
             )
+            (@subcommand uninstall =>
+                (about: "Removes an installed Habitat package")
+                (@arg PKG_IDENT: +required +takes_value
+                    "A fully qualified package identifier, or any package identifier with \
+                    --keep-latest (ex: core/redis/3.2.4/20170103160441, core/redis)")
+                (@arg FORCE: -f --force
+                    "Remove the package even if other installed packages depend on it")
+                (@arg KEEP_LATEST: --("keep-latest") +takes_value {valid_numeric}
+                    "Remove every installed release matching PKG_IDENT except the newest N, \
+                    skipping releases that are still needed")
+            )
             (@subcommand upload =>
                 (about: "Uploads a local Habitat Artifact to a Depot")
                 (aliases: &["u", "up", "upl", "uplo", "uploa"])
                 (@arg DEPOT_URL: -u --url +takes_value {valid_url}
                     "Use a specific Depot URL (ex: http://depot.example.com/v1/depot)")
                 (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for the Depot")
+                (@arg CHANNEL: -c --channel +takes_value
+                    "Promote the uploaded packages to a channel (default: the channel of the \
+                    profile in use, if any)")
                 (@arg HART_FILE: +required +multiple {file_exists}
                     "One or more filepaths to a Habitat Artifact \
                     (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
@@ -224,6 +310,9 @@ pub fn get() -> App<'static, 'static> {
             (@subcommand verify =>
                 (about: "Verifies a Habitat Artifact with an origin key")
                 (aliases: &["v", "ve", "ver", "veri", "verif"])
+                (@arg SIGNER: --signer +takes_value +multiple
+                    "The name of a key that must have signed the artifact, in addition to any \
+                    named in HAB_REQUIRED_SIGNERS")
                 (@arg SOURCE: +required {file_exists}
                     "A path to a Habitat Artifact \
                     (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
@@ -253,6 +342,17 @@ pub fn get() -> App<'static, 'static> {
                 (@arg NO_CALLBACKS: --nocallbacks -f
                     "Do not include callback functions in template")
             )
+            (@subcommand lint =>
+                (about: "Checks a plan, its `default.toml` and its hooks and config templates \
+                    for mistakes without building it")
+                (aliases: &["l", "li", "lin"])
+                (@arg PLAN_CONTEXT: +takes_value
+                    "A directory containing a `plan.sh` file \
+                    or a `habitat/` directory which contains the `plan.sh` file (default: .)")
+                (@arg BIND: -b --bind +takes_value +multiple {valid_bind_pkg}
+                    "Checks a bind against the exports of an installed package \
+                    (ex: database=core/postgresql)")
+            )
         )
         (@subcommand ring =>
             (about: "Commands relating to Habitat rings")
@@ -369,6 +469,50 @@ fn alias_start() -> App<'static, 'static> {
     )
 }
 
+fn sub_cli_profile() -> App<'static, 'static> {
+    let sub = clap_app!(@subcommand profile =>
+        (about: "Commands relating to CLI profiles, each with a Depot URL, an origin, an auth \
+            token and a channel for the commands which need them")
+        (@setting ArgRequiredElseHelp)
+        (@subcommand create =>
+            (about: "Creates a profile, replacing any profile of the same name")
+            (aliases: &["c", "cr", "cre", "crea", "creat"])
+            (@arg PROFILE: +required +takes_value "Name of the profile (ex: internal)")
+            (@arg DEPOT_URL: -u --url +takes_value {valid_url}
+                "Depot URL of the profile (ex: http://depot.example.com/v1/depot)")
+            (@arg ORIGIN: -o --origin +takes_value "Origin of the profile")
+            (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for the Depot")
+            (@arg CHANNEL: -c --channel +takes_value
+                    "Default channel of the profile, which `hab pkg upload' promotes to")
+        )
+        (@subcommand list =>
+            (about: "Lists the profiles, marking the one in use with `*'")
+            (aliases: &["l", "li", "lis"])
+        )
+    );
+    // `use` can't be the name of a subcommand made with `clap_app!`
+    sub.subcommand(App::new("use")
+                       .about("Uses a profile for the commands that follow, unless \
+                               HAB_PROFILE names another one")
+                       .aliases(&["u", "us"])
+                       .arg(Arg::with_name("PROFILE")
+                                .help("Name of the profile (ex: internal)")
+                                .required(true)
+                                .takes_value(true)))
+}
+
+fn sub_cli_token_store() -> App<'static, 'static> {
+    // A subcommand made with `clap_app!` can't have a `-` in its name
+    App::new("token-store")
+        .about("Shows where auth tokens are kept, or moves them to another store: the keyring \
+                of the desktop session, a file encrypted with a passphrase or the CLI config \
+                in plaintext")
+        .arg(Arg::with_name("STORE")
+                 .help("Store to move auth tokens to: keyring, encrypted-file or plaintext")
+                 .takes_value(true)
+                 .validator(valid_token_store))
+}
+
 fn sub_cli_setup() -> App<'static, 'static> {
     clap_app!(@subcommand setup =>
         (about: "Sets up the CLI with reasonable defaults.")
@@ -448,6 +592,11 @@ fn sub_pkg_install() -> App<'static, 'static> {
             "One or more Habitat package identifiers (ex: acme/redis) and/or filepaths \
             to a Habitat Artifact (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
         (@arg BINLINK: -b --binlink "Binlink all binaries from installed package(s)")
+        (@arg BUNDLE: --bundle
+            "Install from one or more bundles made with `hab pkg bundle`, without contacting a \
+            Depot")
+        (@arg VERSION_REQ: --("version-req") +takes_value {valid_version_req}
+            "Install the newest release whose version satisfies a requirement (ex: '>=3.2, <4')")
     );
     sub.arg(Arg::with_name("IGNORE_TARGET")
                 .help("Skips target validation for package installation.")
@@ -468,6 +617,48 @@ fn file_exists_or_stdin(val: String) -> result::Result<(), String> {
     if val == "-" { Ok(()) } else { file_exists(val) }
 }
 
+fn valid_output_format(val: String) -> result::Result<(), String> {
+    match OutputFormat::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => {
+            Err(format!("FORMAT: value must be one of: {}",
+                        OutputFormat::variants().join(", ")))
+        }
+    }
+}
+
+fn valid_token_store(val: String) -> result::Result<(), String> {
+    match TokenStore::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => {
+            Err(format!("STORE: value must be one of: {}",
+                        TokenStore::variants().join(", ")))
+        }
+    }
+}
+
+fn valid_sbom_spec(val: String) -> result::Result<(), String> {
+    match SbomSpec::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("SPEC: value must be one of: {}", SbomSpec::variants().join(", "))),
+    }
+}
+
+fn valid_version_req(val: String) -> result::Result<(), String> {
+    match VersionReq::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("VERSION_REQ: {} is not a valid version requirement", &val)),
+    }
+}
+
+fn valid_bind_pkg(val: String) -> result::Result<(), String> {
+    let mut parts = val.splitn(2, '=');
+    match (parts.next(), parts.next().map(PackageIdent::from_str)) {
+        (Some(bind), Some(Ok(_))) if !bind.is_empty() => Ok(()),
+        _ => Err(format!("BIND: {} is invalid, must be BIND=PKG_IDENT", &val)),
+    }
+}
+
 fn valid_pair_type(val: String) -> result::Result<(), String> {
     match PairType::from_str(&val) {
         Ok(_) => Ok(()),
@@ -487,6 +678,13 @@ fn valid_service_group(val: String) -> result::Result<(), String> {
     }
 }
 
+fn valid_numeric(val: String) -> result::Result<(), String> {
+    match val.parse::<usize>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("'{}' is not a number", &val)),
+    }
+}
+
 fn valid_url(val: String) -> result::Result<(), String> {
     match Url::parse(&val) {
         Ok(_) => Ok(()),
@@ -0,0 +1,111 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Manages the named profiles of the CLI config, each with a Depot URL, an origin, an auth token
+//! and a channel.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab cli profile create internal --url https://depot.example.com/v1/depot --origin acme
+//! $ hab cli profile use internal
+//! $ hab cli profile list
+//! ```
+//!
+//! Commands which take `--url`, `--auth` or `--origin` fall back to the values of the profile in
+//! use when neither the option nor its environment variable is given. `HAB_PROFILE` selects
+//! another profile for a single command.
+
+use common::ui::{Status, UI};
+
+use config::{self, Profile};
+use error::{Error, Result};
+
+#[derive(Debug, Serialize)]
+struct ProfileSummary {
+    name: String,
+    active: bool,
+    depot_url: Option<String>,
+    origin: Option<String>,
+    channel: Option<String>,
+    /// Whether the profile has an auth token, which is never printed.
+    auth_token: bool,
+}
+
+/// Saves `profile` under `name`, replacing any profile of that name.
+pub fn create(ui: &mut UI, name: &str, profile: Profile) -> Result<()> {
+    if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '-' || c == '_') {
+        return Err(Error::ArgumentError("Profile names may only have letters, digits, `-' \
+                                         and `_'"));
+    }
+    let mut config = try!(config::load());
+    if config.profiles.contains_key(name) {
+        try!(ui.status(Status::Custom('↻', String::from("Replacing")),
+                       format!("profile {}", name)));
+    } else {
+        try!(ui.status(Status::Creating, format!("profile {}", name)));
+    }
+    config.profiles.insert(name.to_string(), profile);
+    try!(config::save(&config));
+    try!(ui.para(&format!("Switch to it with `hab cli profile use {}'.", name)));
+    Ok(())
+}
+
+/// Makes `name` the profile in use.
+pub fn activate(ui: &mut UI, name: &str) -> Result<()> {
+    let mut config = try!(config::load());
+    if !config.profiles.contains_key(name) {
+        return Err(Error::ProfileNotFound(name.to_string()));
+    }
+    config.profile = Some(name.to_string());
+    try!(config::save(&config));
+    try!(ui.status(Status::Using, format!("profile {}", name)));
+    Ok(())
+}
+
+/// Prints the profiles, marking the one in use.
+pub fn list(ui: &mut UI) -> Result<()> {
+    let config = try!(config::load());
+    // A profile which no longer exists is simply not marked.
+    let active = config.active_profile().unwrap_or(None).map(|(name, _)| name);
+    let mut summaries = Vec::new();
+    for (name, profile) in config.profiles.iter() {
+        summaries.push(ProfileSummary {
+                           name: name.clone(),
+                           active: active.as_ref() == Some(name),
+                           depot_url: profile.depot_url.clone(),
+                           origin: profile.origin.clone(),
+                           channel: profile.channel.clone(),
+                           auth_token: try!(config.profile_has_auth_token(name)),
+                       });
+    }
+    let lines: Vec<String> = summaries.iter()
+        .map(|s| {
+            let mut line = format!("{} {}", if s.active { "*" } else { " " }, s.name);
+            for &(key, value) in [("url", &s.depot_url),
+                                  ("origin", &s.origin),
+                                  ("channel", &s.channel)]
+                        .iter() {
+                if let Some(ref value) = *value {
+                    line.push_str(&format!(" {}={}", key, value));
+                }
+            }
+            if s.auth_token {
+                line.push_str(" auth=yes");
+            }
+            line
+        })
+        .collect();
+    Ok(try!(ui.result(&summaries, &lines)))
+}
@@ -22,6 +22,7 @@ use {AUTH_TOKEN_ENVVAR, ORIGIN_ENVVAR};
 use analytics;
 use command;
 use config;
+use credentials;
 use error::Result;
 
 pub fn start(ui: &mut UI, cache_path: &Path, analytics_path: &Path) -> Result<()> {
@@ -175,7 +176,7 @@ fn ask_default_auth_token(ui: &mut UI) -> Result<bool> {
 
 fn prompt_auth_token(ui: &mut UI) -> Result<String> {
     let config = try!(config::load());
-    let default = match config.auth_token {
+    let token = match try!(config.default_auth_token()) {
         Some(o) => {
             try!(ui.para("You already have a default auth token set up, but feel free to change it \
                        if you wish."));
@@ -183,7 +184,13 @@ fn prompt_auth_token(ui: &mut UI) -> Result<String> {
         }
         None => env::var(AUTH_TOKEN_ENVVAR).ok(),
     };
-    Ok(try!(ui.prompt_ask("GitHub access token", default.as_ref().map(|x| &**x))))
+    // The token is shown redacted, so taking the default means keeping the token itself.
+    let default = token.as_ref().map(|t| credentials::redact(t));
+    let answer = try!(ui.prompt_ask("GitHub access token", default.as_ref().map(|x| &**x)));
+    match (token, default) {
+        (Some(token), Some(default)) => Ok(if answer == default { token } else { answer }),
+        _ => Ok(answer),
+    }
 }
 
 fn ask_enable_analytics(ui: &mut UI, analytics_path: &Path) -> Result<bool> {
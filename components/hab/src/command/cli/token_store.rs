@@ -0,0 +1,54 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Shows or changes where the auth tokens of the CLI config are kept.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab cli token-store
+//! $ hab cli token-store encrypted-file
+//! ```
+//!
+//! Changing the store moves every token already saved into the new one.
+
+use common::ui::{Status, UI};
+
+use config;
+use credentials::TokenStore;
+use error::Result;
+
+pub fn start(ui: &mut UI, store: Option<TokenStore>) -> Result<()> {
+    let mut config = try!(config::load());
+    match store {
+        Some(store) => {
+            try!(ui.status(Status::Custom('↻', String::from("Moving")),
+                           format!("auth tokens to the {} store", store)));
+            let saved = try!(config.saved_token_store());
+            config.token_store = Some(store.to_string());
+            try!(config::save_from_store(&config, saved));
+            let saved = try!(try!(config::load()).saved_token_store());
+            if saved != store {
+                try!(ui.warn(format!("Auth tokens are kept in the {} store, as chosen by \
+                                      HAB_TOKEN_STORE",
+                                     saved)));
+            }
+        }
+        None => {
+            let saved = try!(config.saved_token_store());
+            try!(ui.status(Status::Using, format!("the {} store for auth tokens", saved)));
+        }
+    }
+    Ok(())
+}
@@ -12,16 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::io;
+use std::io::{self, Read};
 use std::fs::File;
 use std::path::Path;
 
+use common::ui::{OutputFormat, UI};
 use hcore::crypto::SigKeyPair;
 use hcore::crypto::keys::PairType;
 
 use error::Result;
+use super::OriginKey;
 
-pub fn start(origin: &str, pair_type: PairType, cache: &Path) -> Result<()> {
+pub fn start(ui: &mut UI, origin: &str, pair_type: PairType, cache: &Path) -> Result<()> {
     let latest = try!(SigKeyPair::get_latest_pair_for(origin, cache));
     let path = match pair_type {
         PairType::Public => try!(SigKeyPair::get_public_key_path(&latest.name_with_rev(), cache)),
@@ -31,6 +33,14 @@ pub fn start(origin: &str, pair_type: PairType, cache: &Path) -> Result<()> {
     debug!("Streaming file contents of {} {} to standard out",
            &pair_type,
            &path.display());
-    try!(io::copy(&mut file, &mut io::stdout()));
+    if ui.format() == OutputFormat::Json {
+        let mut key = OriginKey::new(&latest.name, &latest.rev, &pair_type);
+        let mut content = String::new();
+        try!(file.read_to_string(&mut content));
+        key.key = Some(content);
+        try!(ui.result(&key, &[]));
+    } else {
+        try!(io::copy(&mut file, &mut io::stdout()));
+    }
     Ok(())
 }
@@ -18,10 +18,12 @@ use common::ui::UI;
 use hcore::crypto::SigKeyPair;
 
 use error::Result;
+use super::OriginKey;
 
 pub fn start(ui: &mut UI, origin: &str, cache: &Path) -> Result<()> {
     try!(ui.begin(format!("Generating origin key for {}", &origin)));
     let pair = try!(SigKeyPair::generate_pair_for_origin(origin, cache));
     try!(ui.end(format!("Generated origin key pair {}.", &pair.name_with_rev())));
+    try!(ui.result(&OriginKey::new(&pair.name, &pair.rev, "pair"), &[]));
     Ok(())
 }
@@ -18,6 +18,7 @@ use common::ui::UI;
 use hcore::crypto::SigKeyPair;
 
 use error::Result;
+use super::OriginKey;
 
 pub fn start(ui: &mut UI, content: &str, cache: &Path) -> Result<()> {
     try!(ui.begin("Importing origin key from standard input"));
@@ -25,5 +26,6 @@ pub fn start(ui: &mut UI, content: &str, cache: &Path) -> Result<()> {
     try!(ui.end(format!("Imported {} origin key {}.",
                         &pair_type,
                         &pair.name_with_rev())));
+    try!(ui.result(&OriginKey::new(&pair.name, &pair.rev, &pair_type), &[]));
     Ok(())
 }
@@ -16,6 +16,7 @@ pub mod download;
 pub mod export;
 pub mod generate;
 pub mod import;
+pub mod revoke;
 pub mod upload_latest;
 pub mod upload;
 
@@ -26,6 +27,28 @@ use std::path::Path;
 use error::{Error, Result};
 use hcore;
 
+/// The result of a command which creates or reads an origin key, for `--format json`.
+#[derive(Debug, Serialize)]
+struct OriginKey {
+    name: String,
+    revision: String,
+    pair_type: String,
+    /// The content of the key, for commands which print it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    key: Option<String>,
+}
+
+impl OriginKey {
+    fn new<T: ToString>(name: &str, revision: &str, pair_type: T) -> Self {
+        OriginKey {
+            name: name.to_string(),
+            revision: revision.to_string(),
+            pair_type: pair_type.to_string(),
+            key: None,
+        }
+    }
+}
+
 // shared between origin::key::upload and origin::key::upload_latest
 fn get_name_with_rev(keyfile: &Path, expected_vsn: &str) -> Result<String> {
     let f = try!(File::open(&keyfile));
@@ -0,0 +1,74 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+
+use common::ui::{Status, UI};
+use depot_client::Client;
+use error::{Error, Result};
+use hcore;
+use hcore::crypto::SigKeyPair;
+use hcore::crypto::keys::parse_name_with_rev;
+use hcore::crypto::revocation::RevocationList;
+
+use {PRODUCT, VERSION};
+
+pub fn start(ui: &mut UI,
+             depot: &str,
+             token: &str,
+             name_with_rev: &str,
+             cache: &Path)
+             -> Result<()> {
+    let (origin, _) = try!(parse_name_with_rev(name_with_rev));
+    let depot_client = try!(Client::new(depot, PRODUCT, VERSION, None));
+    try!(ui.begin(format!("Revoking origin key {}", name_with_rev)));
+
+    // Start from the list the Depot already has, so earlier revocations are kept.
+    let mut list = match try!(depot_client.fetch_origin_revocations(&origin)) {
+        Some(content) => {
+            let signer = try!(RevocationList::signer(&content));
+            if let Err(_) = SigKeyPair::get_public_key_path(&signer, cache) {
+                try!(ui.status(Status::Downloading,
+                               format!("{} public origin key", &signer)));
+                let (name, rev) = try!(parse_name_with_rev(&signer));
+                try!(depot_client.fetch_origin_key(&name, &rev, cache, ui.progress()));
+            }
+            try!(RevocationList::from_signed_str(&content, cache))
+        }
+        None => RevocationList::new(origin.clone()),
+    };
+    try!(list.revoke(name_with_rev));
+
+    let pair = match try!(SigKeyPair::get_pairs_for(&origin, cache))
+              .into_iter()
+              .find(|p| p.secret().is_ok() && !list.is_revoked(&p.name_with_rev())) {
+        Some(pair) => pair,
+        None => {
+            let msg = format!("No secret key for {} that has not been revoked. Generate a new \
+                               origin key and upload it first.",
+                              &origin);
+            return Err(Error::HabitatCore(hcore::Error::CryptoError(msg)));
+        }
+    };
+    try!(ui.status(Status::Signing,
+                   format!("revocation list for {} with {}", &origin, pair.name_with_rev())));
+    let content = try!(list.to_signed_string(&pair));
+
+    try!(ui.status(Status::Uploading, format!("revocation list for {}", &origin)));
+    try!(depot_client.put_origin_revocations(&origin, &content, token));
+    try!(RevocationList::write_file_from_str(&content, cache));
+    try!(ui.status(Status::Cached, format!("revocation list for {}", &origin)));
+    try!(ui.end(format!("Revoked origin key {}.", name_with_rev)));
+    Ok(())
+}
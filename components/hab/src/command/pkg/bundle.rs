@@ -0,0 +1,120 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Creates a bundle of a package for installing it on a host that can't reach a Depot.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg bundle core/redis --origin acme
+//! $ hab pkg install --bundle core-redis-3.2.4-20170103160441.bundle
+//! ```
+//!
+//! The bundle holds the artifacts of `core/redis` and of all of its transitive dependencies,
+//! along with the public origin keys that signed them, and is signed with the `acme` origin key.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use common::ui::{Status, UI};
+use depot_client::Client;
+use hcore::crypto::{artifact, SigKeyPair};
+use hcore::crypto::keys::parse_name_with_rev;
+use hcore::package::{PackageArchive, PackageBundle, PackageIdent};
+
+use {PRODUCT, VERSION};
+use error::{Error, Result};
+
+pub fn start(ui: &mut UI,
+             url: &str,
+             ident: &PackageIdent,
+             dst_dir: &Path,
+             pair: &SigKeyPair,
+             fs_root_path: &Path,
+             cache_artifact_path: &Path,
+             cache_key_path: &Path)
+             -> Result<()> {
+    let depot_client = try!(Client::new(url, PRODUCT, VERSION, Some(fs_root_path)));
+    try!(ui.begin(format!("Bundling {}", ident)));
+    let ident = if ident.fully_qualified() {
+        ident.clone()
+    } else {
+        try!(depot_client.show_package(ident)).into()
+    };
+
+    let artifact = try!(cached_artifact(ui, &depot_client, &ident, cache_artifact_path));
+    let mut artifacts = Vec::new();
+    for dep in try!(PackageArchive::new(artifact.clone()).tdeps()) {
+        artifacts.push(try!(cached_artifact(ui, &depot_client, &dep, cache_artifact_path)));
+    }
+
+    let mut keys: Vec<PathBuf> = Vec::new();
+    for path in Some(&artifact).into_iter().chain(artifacts.iter()) {
+        let header = try!(artifact::get_artifact_header(path));
+        for (i, signature) in header.signatures.iter().enumerate() {
+            let nwr = &signature.key_name;
+            if let Err(_) = SigKeyPair::get_public_key_path(nwr, cache_key_path) {
+                try!(ui.status(Status::Downloading, format!("{} public origin key", nwr)));
+                let (name, rev) = try!(parse_name_with_rev(nwr));
+                if let Err(e) = depot_client.fetch_origin_key(&name,
+                                                              &rev,
+                                                              cache_key_path,
+                                                              ui.progress()) {
+                    // A counter-signature can't be checked without its key, but the package can
+                    // still be installed.
+                    if i == 0 {
+                        return Err(Error::from(e));
+                    }
+                    try!(ui.warn(format!("Skipping counter-signing key {}, {}", nwr, e)));
+                    continue;
+                }
+            }
+            let key = try!(SigKeyPair::get_public_key_path(nwr, cache_key_path));
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+        try!(artifact::verify(path, cache_key_path));
+        try!(ui.status(Status::Verified, path.display()));
+    }
+
+    try!(fs::create_dir_all(dst_dir));
+    let dst = dst_dir.join(try!(PackageBundle::file_name_for(&ident)));
+    try!(ui.status(Status::Signing,
+                   format!("bundle with {}", pair.name_with_rev())));
+    try!(PackageBundle::create(&dst, &ident, &artifact, &artifacts, &keys, pair));
+    try!(ui.end(format!("Bundle of {} with {} packages written to {}",
+                        &ident,
+                        artifacts.len() + 1,
+                        dst.display())));
+    Ok(())
+}
+
+/// The path of the artifact for `ident` in the artifact cache, downloading it if needed.
+fn cached_artifact(ui: &mut UI,
+                   depot_client: &Client,
+                   ident: &PackageIdent,
+                   cache_artifact_path: &Path)
+                   -> Result<PathBuf> {
+    if let Some(name) = ident.archive_name() {
+        let path = cache_artifact_path.join(name);
+        if path.is_file() {
+            try!(ui.status(Status::Using, ident));
+            return Ok(path);
+        }
+    }
+    try!(ui.status(Status::Downloading, ident));
+    let archive = try!(depot_client.fetch_package(ident, cache_artifact_path, ui.progress()));
+    Ok(archive.path)
+}
@@ -0,0 +1,234 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::iter;
+use std::path::Path;
+
+use common::ui::UI;
+use hcore::fs::{pkg_install_path, pkg_root_path};
+use hcore::package::{PackageIdent, PackageInstall};
+
+use error::Result;
+
+#[derive(Debug, Serialize)]
+struct Dependencies {
+    ident: PackageIdent,
+    transitive: bool,
+    reverse: bool,
+    dependencies: Vec<PackageIdent>,
+}
+
+/// A package and the tree of packages beneath it: those it depends on or, in reverse, those that
+/// depend on it.
+#[derive(Debug, PartialEq, Serialize)]
+struct DependencyNode {
+    ident: PackageIdent,
+    dependencies: Vec<DependencyNode>,
+}
+
+#[derive(Debug, Serialize)]
+struct DependencyTree {
+    reverse: bool,
+    tree: DependencyNode,
+}
+
+/// Print the dependencies of an installed package, read from its `DEPS` metafile or, if
+/// `transitive` is set, from its `TDEPS` metafile. With `reverse`, print the installed packages
+/// that depend on it instead. With `tree`, print the package and every installed package beneath
+/// it, each indented under the one that depends on it or, in reverse, that it depends on.
+pub fn start(ui: &mut UI,
+             ident: &PackageIdent,
+             fs_root_path: &Path,
+             transitive: bool,
+             reverse: bool,
+             tree: bool)
+             -> Result<()> {
+    let package = try!(PackageInstall::load(ident, Some(fs_root_path)));
+    if tree {
+        let graph = try!(installed_dependencies(fs_root_path));
+        let result = DependencyTree {
+            reverse: reverse,
+            tree: dependency_tree(package.ident(), &graph, reverse),
+        };
+        let mut lines = Vec::new();
+        tree_lines(&result.tree, 0, &mut lines);
+        return Ok(try!(ui.result(&result, &lines)));
+    }
+    let dependencies = if reverse {
+        try!(dependents(package.ident(), fs_root_path, transitive))
+    } else {
+        try!(dependencies_of(&package, transitive))
+    };
+    let lines: Vec<String> = dependencies.iter().map(|d| d.to_string()).collect();
+    let result = Dependencies {
+        ident: package.ident().clone(),
+        transitive: transitive,
+        reverse: reverse,
+        dependencies: dependencies,
+    };
+    Ok(try!(ui.result(&result, &lines)))
+}
+
+fn dependencies_of(package: &PackageInstall, transitive: bool) -> Result<Vec<PackageIdent>> {
+    if transitive {
+        Ok(try!(package.tdeps()))
+    } else {
+        Ok(try!(package.deps()))
+    }
+}
+
+/// Returns the installed packages that depend on `ident`, directly or, if `transitive` is set,
+/// through any of their dependencies.
+fn dependents(ident: &PackageIdent,
+              fs_root_path: &Path,
+              transitive: bool)
+              -> Result<Vec<PackageIdent>> {
+    let mut dependents = Vec::new();
+    for other in try!(PackageInstall::list_installed(Some(fs_root_path))) {
+        if &other == ident {
+            continue;
+        }
+        if try!(dependencies_of(&installed(&other, fs_root_path), transitive)).contains(ident) {
+            dependents.push(other);
+        }
+    }
+    dependents.sort();
+    Ok(dependents)
+}
+
+/// Returns the direct dependencies of every installed package.
+fn installed_dependencies(fs_root_path: &Path)
+                          -> Result<HashMap<PackageIdent, Vec<PackageIdent>>> {
+    let mut graph = HashMap::new();
+    for ident in try!(PackageInstall::list_installed(Some(fs_root_path))) {
+        let deps = try!(installed(&ident, fs_root_path).deps());
+        graph.insert(ident, deps);
+    }
+    Ok(graph)
+}
+
+/// Builds the tree beneath `ident` from the direct dependencies in `graph`. A dependency which
+/// isn't installed is a leaf.
+fn dependency_tree(ident: &PackageIdent,
+                   graph: &HashMap<PackageIdent, Vec<PackageIdent>>,
+                   reverse: bool)
+                   -> DependencyNode {
+    let children = if reverse {
+        let mut dependents: Vec<PackageIdent> = graph
+            .iter()
+            .filter(|&(_, deps)| deps.contains(ident))
+            .map(|(other, _)| other.clone())
+            .collect();
+        dependents.sort();
+        dependents
+    } else {
+        graph.get(ident).cloned().unwrap_or(vec![])
+    };
+    DependencyNode {
+        ident: ident.clone(),
+        dependencies: children
+            .iter()
+            .map(|child| dependency_tree(child, graph, reverse))
+            .collect(),
+    }
+}
+
+fn tree_lines(node: &DependencyNode, depth: usize, lines: &mut Vec<String>) {
+    let indent: String = iter::repeat("  ").take(depth).collect();
+    lines.push(format!("{}{}", indent, node.ident));
+    for child in node.dependencies.iter() {
+        tree_lines(child, depth + 1, lines);
+    }
+}
+
+fn installed(ident: &PackageIdent, fs_root_path: &Path) -> PackageInstall {
+    PackageInstall::new_from_parts(ident.clone(),
+                                   fs_root_path.to_path_buf(),
+                                   pkg_root_path(Some(fs_root_path)),
+                                   pkg_install_path(ident, Some(fs_root_path)))
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::{self, File};
+    use std::io::Write;
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use hcore::package::PackageIdent;
+    use tempdir::TempDir;
+
+    use super::{dependency_tree, dependents, installed_dependencies, tree_lines};
+
+    fn install(root: &Path, ident: &str, deps: &[&str], tdeps: &[&str]) {
+        let path = root.join("hab/pkgs").join(ident);
+        fs::create_dir_all(&path).unwrap();
+        let mut f = File::create(path.join("DEPS")).unwrap();
+        f.write_all(deps.join("\n").as_bytes()).unwrap();
+        let mut f = File::create(path.join("TDEPS")).unwrap();
+        f.write_all(tdeps.join("\n").as_bytes()).unwrap();
+    }
+
+    #[test]
+    fn dependents_direct_and_transitive() {
+        let root = TempDir::new("pkg_dependencies").unwrap();
+        let glibc = "core/glibc/2.22/20160612063629";
+        let openssl = "core/openssl/1.0.2j/20161214010106";
+        let redis = "core/redis/3.2.4/20170103160441";
+        install(root.path(), glibc, &[], &[]);
+        install(root.path(), openssl, &[glibc], &[glibc]);
+        install(root.path(), redis, &[openssl], &[openssl, glibc]);
+
+        let glibc = PackageIdent::from_str(glibc).unwrap();
+        let direct = dependents(&glibc, root.path(), false).unwrap();
+        assert_eq!(direct, vec![PackageIdent::from_str(openssl).unwrap()]);
+        let transitive = dependents(&glibc, root.path(), true).unwrap();
+        assert_eq!(transitive,
+                   vec![PackageIdent::from_str(openssl).unwrap(),
+                        PackageIdent::from_str(redis).unwrap()]);
+    }
+
+    #[test]
+    fn dependency_tree_both_ways() {
+        let root = TempDir::new("pkg_dependencies").unwrap();
+        let glibc = "core/glibc/2.22/20160612063629";
+        let openssl = "core/openssl/1.0.2j/20161214010106";
+        let redis = "core/redis/3.2.4/20170103160441";
+        install(root.path(), glibc, &[], &[]);
+        install(root.path(), openssl, &[glibc], &[glibc]);
+        install(root.path(), redis, &[openssl, glibc], &[openssl, glibc]);
+        let graph = installed_dependencies(root.path()).unwrap();
+
+        let mut lines = Vec::new();
+        tree_lines(&dependency_tree(&PackageIdent::from_str(redis).unwrap(), &graph, false),
+                   0,
+                   &mut lines);
+        assert_eq!(lines,
+                   vec![redis.to_string(),
+                        format!("  {}", openssl),
+                        format!("    {}", glibc),
+                        format!("  {}", glibc)]);
+
+        let mut lines = Vec::new();
+        tree_lines(&dependency_tree(&PackageIdent::from_str(glibc).unwrap(), &graph, true),
+                   0,
+                   &mut lines);
+        assert_eq!(lines,
+                   vec![glibc.to_string(),
+                        format!("  {}", openssl),
+                        format!("    {}", redis),
+                        format!("  {}", redis)]);
+    }
+}
@@ -0,0 +1,285 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Compares two package artifacts file-by-file and metafile-by-metafile.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg diff core-redis-3.2.4-20170103160441-x86_64-linux.hart \
+//!     results/core-redis-3.2.4-20170103160441-x86_64-linux.hart
+//! ```
+//!
+//! Paths are compared relative to each package's install directory, so that two releases built
+//! from the same plan can be compared too; their `IDENT`, `FILES` and `BUILD_MANIFEST` metafiles
+//! will then differ by the release.
+
+use std::cmp;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use common::ui::UI;
+use hcore::package::{ArchiveEntry, PackageArchive};
+use hcore::package::metadata::MetaFile;
+
+use error::Result;
+
+/// The metafiles compared line by line rather than as files.
+const METAFILES: &'static [MetaFile] = &[MetaFile::BuildManifest,
+                                         MetaFile::CFlags,
+                                         MetaFile::Config,
+                                         MetaFile::Deps,
+                                         MetaFile::TDeps,
+                                         MetaFile::Exposes,
+                                         MetaFile::Ident,
+                                         MetaFile::LdRunPath,
+                                         MetaFile::LdFlags,
+                                         MetaFile::Manifest,
+                                         MetaFile::Path,
+                                         MetaFile::Target];
+
+#[derive(Debug, Serialize)]
+struct Diff {
+    a: String,
+    b: String,
+    identical: bool,
+    files: Vec<FileDiff>,
+    metafiles: Vec<MetafileDiff>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct FileDiff {
+    path: String,
+    change: &'static str,
+    details: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct MetafileDiff {
+    name: String,
+    removed: Vec<String>,
+    added: Vec<String>,
+}
+
+pub fn start(ui: &mut UI, a: &Path, b: &Path) -> Result<()> {
+    let mut a_archive = PackageArchive::new(a);
+    let mut b_archive = PackageArchive::new(b);
+    let metafile_names: Vec<String> = METAFILES.iter().map(|m| m.to_string()).collect();
+
+    let files = diff_entries(try!(a_archive.entries()),
+                             try!(b_archive.entries()),
+                             &metafile_names);
+    let mut metafiles = Vec::new();
+    for metafile in METAFILES.iter() {
+        let a_content = try!(a_archive.metafile(metafile.clone())).unwrap_or_default();
+        let b_content = try!(b_archive.metafile(metafile.clone())).unwrap_or_default();
+        if let Some(diff) = diff_metafile(&metafile.to_string(), &a_content, &b_content) {
+            metafiles.push(diff);
+        }
+    }
+
+    let mut lines = Vec::new();
+    for file in files.iter() {
+        if file.details.is_empty() {
+            lines.push(format!("{} {}", file.change, file.path));
+        } else {
+            lines.push(format!("{} {} ({})", file.change, file.path, file.details.join(", ")));
+        }
+    }
+    for metafile in metafiles.iter() {
+        lines.push(format!("changed {}", metafile.name));
+        lines.extend(metafile.removed.iter().map(|l| format!("  - {}", l)));
+        lines.extend(metafile.added.iter().map(|l| format!("  + {}", l)));
+    }
+    let identical = files.is_empty() && metafiles.is_empty();
+    if identical {
+        lines.push(String::from("Artifacts are identical"));
+    }
+    let diff = Diff {
+        a: a.display().to_string(),
+        b: b.display().to_string(),
+        identical: identical,
+        files: files,
+        metafiles: metafiles,
+    };
+    Ok(try!(ui.result(&diff, &lines)))
+}
+
+/// Compares the entries of two archives by path, leaving out the given metafiles.
+fn diff_entries(a: Vec<ArchiveEntry>,
+                b: Vec<ArchiveEntry>,
+                metafile_names: &[String])
+                -> Vec<FileDiff> {
+    let a: BTreeMap<String, ArchiveEntry> = a.into_iter().map(|e| (e.path.clone(), e)).collect();
+    let mut b: BTreeMap<String, ArchiveEntry> =
+        b.into_iter().map(|e| (e.path.clone(), e)).collect();
+    let mut diffs = Vec::new();
+    for (path, a_entry) in a.into_iter() {
+        match b.remove(&path) {
+            Some(b_entry) => {
+                let details = entry_changes(&a_entry, &b_entry);
+                if !details.is_empty() {
+                    diffs.push(FileDiff {
+                                   path: path,
+                                   change: "changed",
+                                   details: details,
+                               });
+                }
+            }
+            None => {
+                diffs.push(FileDiff {
+                               path: path,
+                               change: "removed",
+                               details: vec![],
+                           })
+            }
+        }
+    }
+    for (path, _) in b.into_iter() {
+        diffs.push(FileDiff {
+                       path: path,
+                       change: "added",
+                       details: vec![],
+                   });
+    }
+    diffs.retain(|d| !metafile_names.contains(&d.path));
+    diffs.sort_by(|x, y| x.path.cmp(&y.path));
+    diffs
+}
+
+fn entry_changes(a: &ArchiveEntry, b: &ArchiveEntry) -> Vec<String> {
+    let mut details = Vec::new();
+    if a.kind != b.kind {
+        details.push(format!("kind {:?} -> {:?}", a.kind, b.kind));
+    }
+    if a.checksum != b.checksum {
+        details.push(String::from("content"));
+    }
+    if a.size != b.size {
+        details.push(format!("size {} -> {}", a.size, b.size));
+    }
+    if a.link != b.link {
+        details.push(format!("link {} -> {}",
+                             a.link.as_ref().map_or("", |l| l.as_str()),
+                             b.link.as_ref().map_or("", |l| l.as_str())));
+    }
+    if a.uid != b.uid || a.gid != b.gid {
+        details.push(format!("owner {}:{} -> {}:{}", a.uid, a.gid, b.uid, b.gid));
+    }
+    if a.mode != b.mode {
+        details.push(format!("mode {:04o} -> {:04o}", a.mode, b.mode));
+    }
+    details
+}
+
+/// Returns the lines of `a` and of `b` left out of their longest common subsequence, if the two
+/// differ. A line which only moved is therefore listed as both removed and added.
+fn diff_metafile(name: &str, a: &str, b: &str) -> Option<MetafileDiff> {
+    if a == b {
+        return None;
+    }
+    let a_lines: Vec<&str> = a.lines().collect();
+    let b_lines: Vec<&str> = b.lines().collect();
+    // common[i][j] is the length of the longest common subsequence of a_lines[i..] and
+    // b_lines[j..].
+    let mut common = vec![vec![0usize; b_lines.len() + 1]; a_lines.len() + 1];
+    for i in (0..a_lines.len()).rev() {
+        for j in (0..b_lines.len()).rev() {
+            common[i][j] = if a_lines[i] == b_lines[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                cmp::max(common[i + 1][j], common[i][j + 1])
+            };
+        }
+    }
+    let mut removed = Vec::new();
+    let mut added = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a_lines.len() || j < b_lines.len() {
+        if i < a_lines.len() && j < b_lines.len() && a_lines[i] == b_lines[j] {
+            i += 1;
+            j += 1;
+        } else if j == b_lines.len() ||
+                  (i < a_lines.len() && common[i + 1][j] >= common[i][j + 1]) {
+            removed.push(a_lines[i].to_string());
+            i += 1;
+        } else {
+            added.push(b_lines[j].to_string());
+            j += 1;
+        }
+    }
+    Some(MetafileDiff {
+             name: name.to_string(),
+             removed: removed,
+             added: added,
+         })
+}
+
+#[cfg(test)]
+mod test {
+    use hcore::package::{ArchiveEntry, ArchiveEntryKind};
+
+    use super::{diff_entries, diff_metafile};
+
+    fn file(path: &str, checksum: &str) -> ArchiveEntry {
+        ArchiveEntry {
+            path: path.to_string(),
+            kind: ArchiveEntryKind::File,
+            size: 4,
+            uid: 0,
+            gid: 0,
+            mode: 0o644,
+            checksum: Some(checksum.to_string()),
+            link: None,
+        }
+    }
+
+    #[test]
+    fn diff_entries_reports_changes_by_path() {
+        let a = vec![file("bin/redis-server", "aaaa"), file("IDENT", "1111"), file("old", "x")];
+        let b = vec![file("bin/redis-server", "bbbb"), file("IDENT", "2222"), file("new", "y")];
+        let diffs = diff_entries(a, b, &[String::from("IDENT")]);
+        let summary: Vec<(&str, &str)> =
+            diffs.iter().map(|d| (d.path.as_str(), d.change)).collect();
+        assert_eq!(summary,
+                   vec![("bin/redis-server", "changed"), ("new", "added"), ("old", "removed")]);
+        assert_eq!(diffs[0].details, vec![String::from("content")]);
+    }
+
+    #[test]
+    fn diff_entries_reports_permission_changes() {
+        let mut script = file("bin/run", "aaaa");
+        script.mode = 0o755;
+        let diffs = diff_entries(vec![file("bin/run", "aaaa")], vec![script], &[]);
+        assert_eq!(diffs[0].details, vec![String::from("mode 0644 -> 0755")]);
+    }
+
+    #[test]
+    fn diff_metafile_lists_lines_on_each_side() {
+        assert!(diff_metafile("TDEPS", "core/glibc\n", "core/glibc\n").is_none());
+        let diff = diff_metafile("TDEPS",
+                                 "core/glibc/2.22/1\ncore/zlib/1.2.8/1",
+                                 "core/glibc/2.22/1\ncore/zlib/1.2.8/2")
+                .unwrap();
+        assert_eq!(diff.removed, vec![String::from("core/zlib/1.2.8/1")]);
+        assert_eq!(diff.added, vec![String::from("core/zlib/1.2.8/2")]);
+    }
+
+    #[test]
+    fn diff_metafile_catches_reordered_lines() {
+        let diff = diff_metafile("PATH", "/a/bin\n/b/bin\n", "/b/bin\n/a/bin\n").unwrap();
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!(diff.added, diff.removed);
+    }
+}
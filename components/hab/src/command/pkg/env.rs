@@ -14,16 +14,17 @@
 
 use std::path::Path;
 
+use common::ui::UI;
 use hcore::package::{PackageIdent, PackageInstall};
 
 use error::Result;
 
 // TODO: This needs a windows compatible version
-pub fn start(ident: &PackageIdent, fs_root_path: &Path) -> Result<()> {
+pub fn start(ui: &mut UI, ident: &PackageIdent, fs_root_path: &Path) -> Result<()> {
     let pkg_install = PackageInstall::load(ident, Some(fs_root_path))?;
     let env = pkg_install.runtime_environment()?;
-    for (key, value) in env.into_iter() {
-        println!("export {}=\"{}\"", key, value);
-    }
-    Ok(())
+    let lines: Vec<String> = env.iter()
+        .map(|(key, value)| format!("export {}=\"{}\"", key, value))
+        .collect();
+    Ok(ui.result(&env, &lines)?)
 }
@@ -0,0 +1,587 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Exports a package to Kubernetes manifests generated from the package's metadata.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg export kubernetes core/redis
+//! $ kubectl apply -f core-redis.yaml
+//! ```
+//!
+//! The manifests run the image written by `hab pkg export oci`, tagged
+//! `<origin>/<name>:<version>-<release>`. They are:
+//!
+//! * a StatefulSet, or a Deployment if `HAB_EXPORT_KUBERNETES_KIND` is `deployment`, running the
+//!   Supervisor with the package
+//! * a Service for the ports in the package's `EXPOSES`, if it has any
+//! * a ConfigMap with the package's `default.toml`, mounted as the service's `user.toml`
+//! * a headless Service named `<name>-gossip`, through which the Supervisors find each other
+//!
+//! Each required bind is bound to the `default` group of a service of the same name, whose
+//! Supervisors are found through its own `<bind>-gossip` Service.
+
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use common::ui::{Status, UI};
+use hcore::env as henv;
+use hcore::package::{PackageIdent, PackageInstall};
+use hcore::package::install::DEFAULT_CFG_FILE;
+use serde::Serialize;
+use serde_json::{self, Value};
+
+use error::{Error, Result};
+use super::rootfs;
+
+/// Chooses the kind of workload: `statefulset` (the default) or `deployment`.
+pub const KIND_ENVVAR: &'static str = "HAB_EXPORT_KUBERNETES_KIND";
+
+const SUP_HTTP_PORT: u16 = 9631;
+const SUP_GOSSIP_PORT: u16 = 9638;
+const DATA_VOLUME_SIZE: &'static str = "1Gi";
+
+/// What the manifests are generated from.
+#[derive(Debug, Default)]
+struct Service {
+    ident: PackageIdent,
+    exposes: Vec<u16>,
+    binds: Vec<String>,
+    binds_optional: Vec<String>,
+    exports: BTreeMap<String, String>,
+    default_toml: Option<String>,
+    svc_user: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct Metadata {
+    name: String,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    labels: BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    annotations: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Serialize)]
+struct Object<T> {
+    #[serde(rename = "apiVersion")]
+    api_version: &'static str,
+    kind: &'static str,
+    metadata: Metadata,
+    spec: T,
+}
+
+#[derive(Debug, Serialize)]
+struct ConfigMap {
+    #[serde(rename = "apiVersion")]
+    api_version: &'static str,
+    kind: &'static str,
+    metadata: Metadata,
+    data: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Serialize)]
+struct ServiceSpec {
+    #[serde(rename = "clusterIP", skip_serializing_if = "Option::is_none")]
+    cluster_ip: Option<&'static str>,
+    #[serde(rename = "publishNotReadyAddresses", skip_serializing_if = "Option::is_none")]
+    publish_not_ready_addresses: Option<bool>,
+    selector: BTreeMap<String, String>,
+    ports: Vec<ServicePort>,
+}
+
+#[derive(Debug, Serialize)]
+struct ServicePort {
+    name: String,
+    port: u16,
+    #[serde(rename = "targetPort")]
+    target_port: u16,
+    protocol: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+struct WorkloadSpec {
+    #[serde(rename = "serviceName", skip_serializing_if = "Option::is_none")]
+    service_name: Option<String>,
+    replicas: u32,
+    selector: LabelSelector,
+    template: PodTemplate,
+    #[serde(rename = "volumeClaimTemplates", skip_serializing_if = "Vec::is_empty")]
+    volume_claim_templates: Vec<Object<ClaimSpec>>,
+}
+
+#[derive(Debug, Serialize)]
+struct LabelSelector {
+    #[serde(rename = "matchLabels")]
+    match_labels: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Serialize)]
+struct PodTemplate {
+    metadata: Metadata,
+    spec: PodSpec,
+}
+
+#[derive(Debug, Serialize)]
+struct PodSpec {
+    containers: Vec<Container>,
+    volumes: Vec<Volume>,
+}
+
+#[derive(Debug, Serialize)]
+struct Container {
+    name: String,
+    image: String,
+    args: Vec<String>,
+    ports: Vec<ContainerPort>,
+    #[serde(rename = "volumeMounts")]
+    volume_mounts: Vec<VolumeMount>,
+}
+
+#[derive(Debug, Serialize)]
+struct ContainerPort {
+    name: String,
+    #[serde(rename = "containerPort")]
+    container_port: u16,
+    protocol: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+struct VolumeMount {
+    name: &'static str,
+    #[serde(rename = "mountPath")]
+    mount_path: String,
+    #[serde(rename = "subPath", skip_serializing_if = "Option::is_none")]
+    sub_path: Option<&'static str>,
+}
+
+#[derive(Debug, Serialize)]
+struct Volume {
+    name: &'static str,
+    #[serde(rename = "configMap", skip_serializing_if = "Option::is_none")]
+    config_map: Option<ConfigMapVolume>,
+    #[serde(rename = "emptyDir", skip_serializing_if = "Option::is_none")]
+    empty_dir: Option<BTreeMap<String, String>>,
+}
+
+#[derive(Debug, Serialize)]
+struct ConfigMapVolume {
+    name: String,
+}
+
+#[derive(Debug, Serialize)]
+struct ClaimSpec {
+    #[serde(rename = "accessModes")]
+    access_modes: Vec<&'static str>,
+    resources: ClaimResources,
+}
+
+#[derive(Debug, Serialize)]
+struct ClaimResources {
+    requests: BTreeMap<String, String>,
+}
+
+/// Writes the manifests for `ident` to `dst_dir`, installing the package first if it is missing,
+/// and returns the path of the manifests.
+pub fn start(ui: &mut UI,
+             ident: &PackageIdent,
+             fs_root_path: &Path,
+             dst_dir: &Path)
+             -> Result<PathBuf> {
+    try!(ui.begin(format!("Exporting {} to Kubernetes manifests", ident)));
+    let package = try!(rootfs::installed(ui, ident, fs_root_path));
+    let stateful = match henv::var(KIND_ENVVAR) {
+        Ok(ref kind) if kind == "deployment" => false,
+        Ok(ref kind) if kind == "statefulset" => true,
+        Ok(_) => {
+            return Err(Error::ArgumentError("HAB_EXPORT_KUBERNETES_KIND must be one of: \
+                                             statefulset, deployment"))
+        }
+        Err(_) => true,
+    };
+    let service = try!(service_for(&package));
+    let manifests = try!(render(&service, stateful));
+
+    try!(fs::create_dir_all(dst_dir));
+    let dst = dst_dir.join(format!("{}-{}.yaml", service.ident.origin, service.ident.name));
+    try!(ui.status(Status::Creating, dst.display()));
+    let mut file = try!(File::create(&dst));
+    try!(file.write_all(manifests.as_bytes()));
+    try!(ui.end(format!("Kubernetes manifests for {} written to {}",
+                        service.ident,
+                        dst.display())));
+    Ok(dst)
+}
+
+fn service_for(package: &PackageInstall) -> Result<Service> {
+    let mut default_toml = None;
+    if let Ok(mut file) = File::open(package.installed_path().join(DEFAULT_CFG_FILE)) {
+        let mut content = String::new();
+        try!(file.read_to_string(&mut content));
+        default_toml = Some(content);
+    }
+    Ok(Service {
+           ident: package.ident().clone(),
+           exposes: try!(package.exposes())
+               .iter()
+               .filter_map(|p| p.trim().parse().ok())
+               .collect(),
+           binds: try!(package.binds()).into_iter().map(|b| b.service).collect(),
+           binds_optional: try!(package.binds_optional()).into_iter().map(|b| b.service).collect(),
+           exports: try!(package.exports()).into_iter().collect(),
+           default_toml: default_toml,
+           svc_user: try!(package.svc_user()),
+       })
+}
+
+/// Renders the manifests of `service` as a stream of YAML documents.
+fn render(service: &Service, stateful: bool) -> Result<String> {
+    let name = resource_name(&service.ident.name);
+    let gossip_name = format!("{}-gossip", name);
+    let mut labels = BTreeMap::new();
+    labels.insert(String::from("app"), name.clone());
+    labels.insert(String::from("habitat.sh/origin"), resource_name(&service.ident.origin));
+
+    let mut annotations = BTreeMap::new();
+    annotations.insert(String::from("habitat.sh/ident"), service.ident.to_string());
+    if !service.exports.is_empty() {
+        let exports: Vec<String> =
+            service.exports.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+        annotations.insert(String::from("habitat.sh/exports"), exports.join(","));
+    }
+    if !service.binds_optional.is_empty() {
+        annotations.insert(String::from("habitat.sh/optional-binds"),
+                           service.binds_optional.join(","));
+    }
+    if let Some(ref user) = service.svc_user {
+        annotations.insert(String::from("habitat.sh/svc-user"), user.clone());
+    }
+    let metadata = |name: &str| {
+        Metadata {
+            name: name.to_string(),
+            labels: labels.clone(),
+            annotations: annotations.clone(),
+        }
+    };
+
+    let mut documents = Vec::new();
+    let gossip_ports = vec![ServicePort {
+                                name: String::from("gossip"),
+                                port: SUP_GOSSIP_PORT,
+                                target_port: SUP_GOSSIP_PORT,
+                                protocol: "TCP",
+                            },
+                            ServicePort {
+                                name: String::from("gossip-udp"),
+                                port: SUP_GOSSIP_PORT,
+                                target_port: SUP_GOSSIP_PORT,
+                                protocol: "UDP",
+                            }];
+    documents.push(try!(to_value(&Object {
+                                      api_version: "v1",
+                                      kind: "Service",
+                                      metadata: metadata(&gossip_name),
+                                      spec: ServiceSpec {
+                                          cluster_ip: Some("None"),
+                                          publish_not_ready_addresses: Some(true),
+                                          selector: labels.clone(),
+                                          ports: gossip_ports,
+                                      },
+                                  })));
+    if !service.exposes.is_empty() {
+        let ports = service
+            .exposes
+            .iter()
+            .map(|p| {
+                     ServicePort {
+                         name: format!("port-{}", p),
+                         port: *p,
+                         target_port: *p,
+                         protocol: "TCP",
+                     }
+                 })
+            .collect();
+        documents.push(try!(to_value(&Object {
+                                          api_version: "v1",
+                                          kind: "Service",
+                                          metadata: metadata(&name),
+                                          spec: ServiceSpec {
+                                              cluster_ip: None,
+                                              publish_not_ready_addresses: None,
+                                              selector: labels.clone(),
+                                              ports: ports,
+                                          },
+                                      })));
+    }
+
+    let svc_path = format!("/hab/svc/{}", service.ident.name);
+    let mut volumes = Vec::new();
+    let mut volume_mounts = vec![VolumeMount {
+                                     name: "data",
+                                     mount_path: format!("{}/data", svc_path),
+                                     sub_path: None,
+                                 }];
+    if let Some(ref default_toml) = service.default_toml {
+        let config_name = format!("{}-config", name);
+        let mut data = BTreeMap::new();
+        data.insert(String::from("user.toml"), default_toml.clone());
+        documents.push(try!(to_value(&ConfigMap {
+                                          api_version: "v1",
+                                          kind: "ConfigMap",
+                                          metadata: metadata(&config_name),
+                                          data: data,
+                                      })));
+        volumes.push(Volume {
+                         name: "config",
+                         config_map: Some(ConfigMapVolume { name: config_name }),
+                         empty_dir: None,
+                     });
+        volume_mounts.push(VolumeMount {
+                               name: "config",
+                               mount_path: format!("{}/user.toml", svc_path),
+                               sub_path: Some("user.toml"),
+                           });
+    }
+    let mut claims = Vec::new();
+    if stateful {
+        let mut requests = BTreeMap::new();
+        requests.insert(String::from("storage"), String::from(DATA_VOLUME_SIZE));
+        claims.push(Object {
+                        api_version: "v1",
+                        kind: "PersistentVolumeClaim",
+                        metadata: Metadata {
+                            name: String::from("data"),
+                            labels: BTreeMap::new(),
+                            annotations: BTreeMap::new(),
+                        },
+                        spec: ClaimSpec {
+                            access_modes: vec!["ReadWriteOnce"],
+                            resources: ClaimResources { requests: requests },
+                        },
+                    });
+    } else {
+        volumes.push(Volume {
+                         name: "data",
+                         config_map: None,
+                         empty_dir: Some(BTreeMap::new()),
+                     });
+    }
+
+    let mut args = vec![service.ident.to_string(), String::from("--peer"), gossip_name.clone()];
+    for bind in service.binds.iter() {
+        args.push(String::from("--bind"));
+        args.push(format!("{}:{}.default", bind, bind));
+        args.push(String::from("--peer"));
+        args.push(format!("{}-gossip", resource_name(bind)));
+    }
+    let mut ports = vec![ContainerPort {
+                             name: String::from("http"),
+                             container_port: SUP_HTTP_PORT,
+                             protocol: "TCP",
+                         },
+                         ContainerPort {
+                             name: String::from("gossip"),
+                             container_port: SUP_GOSSIP_PORT,
+                             protocol: "TCP",
+                         },
+                         ContainerPort {
+                             name: String::from("gossip-udp"),
+                             container_port: SUP_GOSSIP_PORT,
+                             protocol: "UDP",
+                         }];
+    for port in service.exposes.iter() {
+        ports.push(ContainerPort {
+                       name: format!("port-{}", port),
+                       container_port: *port,
+                       protocol: "TCP",
+                   });
+    }
+    let workload = Object {
+        api_version: "apps/v1",
+        kind: if stateful { "StatefulSet" } else { "Deployment" },
+        metadata: metadata(&name),
+        spec: WorkloadSpec {
+            service_name: if stateful {
+                Some(gossip_name.clone())
+            } else {
+                None
+            },
+            replicas: 1,
+            selector: LabelSelector { match_labels: labels.clone() },
+            template: PodTemplate {
+                metadata: metadata(&name),
+                spec: PodSpec {
+                    containers: vec![Container {
+                                         name: name.clone(),
+                                         image: format!("{}/{}:{}-{}",
+                                                        service.ident.origin,
+                                                        service.ident.name,
+                                                        service.ident.version.as_ref().unwrap(),
+                                                        service.ident.release.as_ref().unwrap()),
+                                         args: args,
+                                         ports: ports,
+                                         volume_mounts: volume_mounts,
+                                     }],
+                    volumes: volumes,
+                },
+            },
+            volume_claim_templates: claims,
+        },
+    };
+    documents.push(try!(to_value(&workload)));
+
+    let mut yaml = String::new();
+    for document in documents.iter() {
+        yaml.push_str("---\n");
+        to_yaml(document, 0, &mut yaml);
+    }
+    Ok(yaml)
+}
+
+fn to_value<T: Serialize>(object: &T) -> Result<Value> {
+    Ok(try!(serde_json::to_value(object)))
+}
+
+/// Makes a name valid for a Kubernetes resource: lowercase alphanumerics and dashes.
+fn resource_name(name: &str) -> String {
+    name.chars()
+        .flat_map(|c| if c.is_alphanumeric() {
+                      c.to_lowercase().collect::<Vec<char>>()
+                  } else {
+                      vec!['-']
+                  })
+        .collect::<String>()
+        .trim_matches('-')
+        .to_string()
+}
+
+/// Writes `value` as block YAML indented by `indent` spaces. Strings are written as JSON strings,
+/// which are valid YAML, except multi-line strings, which are written as literal blocks.
+fn to_yaml(value: &Value, indent: usize, out: &mut String) {
+    let pad = " ".repeat(indent);
+    match *value {
+        Value::Object(ref map) => {
+            for (key, value) in map.iter() {
+                out.push_str(&format!("{}{}:", pad, key));
+                to_yaml_child(value, indent, out);
+            }
+        }
+        Value::Array(ref items) => {
+            for item in items.iter() {
+                match *item {
+                    Value::Object(_) | Value::Array(_) if !is_empty(item) => {
+                        let mut child = String::new();
+                        to_yaml(item, indent + 2, &mut child);
+                        out.push_str(&format!("{}- {}", pad, &child[indent + 2..]));
+                    }
+                    _ => {
+                        out.push_str(&format!("{}-", pad));
+                        to_yaml_child(item, indent, out);
+                    }
+                }
+            }
+        }
+        _ => out.push_str(&format!("{}{}\n", pad, scalar(value))),
+    }
+}
+
+/// Writes `value` after a key or a dash at `indent`.
+fn to_yaml_child(value: &Value, indent: usize, out: &mut String) {
+    match *value {
+        Value::Object(_) | Value::Array(_) if !is_empty(value) => {
+            out.push('\n');
+            to_yaml(value, indent + 2, out);
+        }
+        Value::String(ref s) if s.contains('\n') && !s.starts_with(' ') => {
+            out.push_str(if s.ends_with('\n') { " |\n" } else { " |-\n" });
+            for line in s.lines() {
+                if line.is_empty() {
+                    out.push('\n');
+                } else {
+                    out.push_str(&format!("{}{}\n", " ".repeat(indent + 2), line));
+                }
+            }
+        }
+        _ => out.push_str(&format!(" {}\n", scalar(value))),
+    }
+}
+
+fn is_empty(value: &Value) -> bool {
+    match *value {
+        Value::Object(ref map) => map.is_empty(),
+        Value::Array(ref items) => items.is_empty(),
+        _ => false,
+    }
+}
+
+fn scalar(value: &Value) -> String {
+    match *value {
+        Value::Object(_) => String::from("{}"),
+        Value::Array(_) => String::from("[]"),
+        _ => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use hcore::package::PackageIdent;
+    use serde_json;
+
+    use super::{render, resource_name, to_yaml, Service};
+
+    #[test]
+    fn to_yaml_writes_block_style() {
+        let value = serde_json::from_str(r#"{"a": {"b": [1, {"c": "d", "e": []}]},
+                                             "f": "x\ny\n", "g": {}}"#)
+            .unwrap();
+        let mut yaml = String::new();
+        to_yaml(&value, 0, &mut yaml);
+        assert_eq!(yaml,
+                   "a:\n  b:\n    - 1\n    - c: \"d\"\n      e: []\nf: |\n  x\n  y\ng: {}\n");
+    }
+
+    #[test]
+    fn resource_name_is_dns_safe() {
+        assert_eq!(resource_name("Redis_Server.2"), "redis-server-2");
+    }
+
+    #[test]
+    fn render_binds_to_peer_services() {
+        let service = Service {
+            ident: PackageIdent::from_str("core/ruby-rails-sample/0.1.0/20170101000000").unwrap(),
+            exposes: vec![3000],
+            binds: vec![String::from("database")],
+            default_toml: Some(String::from("port = 3000\n")),
+            ..Default::default()
+        };
+        let yaml = render(&service, true).unwrap();
+        assert!(yaml.contains("kind: \"StatefulSet\"\n"));
+        assert!(yaml.contains("name: \"ruby-rails-sample-gossip\"\n"));
+        assert!(yaml.contains("clusterIP: \"None\"\n"));
+        assert!(yaml.contains("- \"--bind\"\n"));
+        assert!(yaml.contains("- \"database:database.default\"\n"));
+        assert!(yaml.contains("- \"database-gossip\"\n"));
+        assert!(yaml.contains("user.toml: |\n"));
+        assert!(yaml.contains("image: \"core/ruby-rails-sample:0.1.0-20170101000000\"\n"));
+
+        let yaml = render(&service, false).unwrap();
+        assert!(yaml.contains("kind: \"Deployment\"\n"));
+        assert!(!yaml.contains("volumeClaimTemplates"));
+    }
+}
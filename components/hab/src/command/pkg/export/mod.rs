@@ -12,26 +12,29 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod kubernetes;
+pub mod oci;
+pub mod rootfs;
+pub mod systemd;
+pub mod tarball;
+
 use common::ui::UI;
 use hcore::package::PackageIdent;
 
 use error::Result;
 
 #[allow(dead_code)]
-pub struct ExportFormat {
-    pkg_ident: PackageIdent,
-    cmd: String,
-}
-
-#[allow(dead_code)]
-impl ExportFormat {
-    pub fn pkg_ident(&self) -> &PackageIdent {
-        &self.pkg_ident
-    }
-
-    pub fn cmd(&self) -> &str {
-        &self.cmd
-    }
+pub enum ExportFormat {
+    /// Exported by running the command `cmd` of the package `pkg_ident`.
+    External { pkg_ident: PackageIdent, cmd: String },
+    /// Exported by `hab` itself to Kubernetes manifests.
+    Kubernetes,
+    /// Exported by `hab` itself to an OCI image layout tarball.
+    Oci,
+    /// Exported by `hab` itself to a systemd unit.
+    Systemd,
+    /// Exported by `hab` itself to a root filesystem tarball.
+    Tar,
 }
 
 pub fn start(ui: &mut UI, ident: &PackageIdent, format: &ExportFormat) -> Result<()> {
@@ -44,58 +47,93 @@ pub fn format_for(ui: &mut UI, value: &str) -> Result<ExportFormat> {
 
 #[cfg(target_os = "linux")]
 mod inner {
+    use std::env;
     use std::ffi::OsString;
-    use std::path::Path;
+    use std::path::{Path, PathBuf};
     use std::str::FromStr;
 
     use common::command::package::install;
     use common::ui::{Status, UI};
-    use hcore::fs::{cache_artifact_path, FS_ROOT_PATH};
+    use hcore::fs::{am_i_root, cache_artifact_path, FS_ROOT_PATH};
     use hcore::package::{PackageIdent, PackageInstall};
     use hcore::url::default_depot_url;
 
     use {PRODUCT, VERSION};
     use command::pkg::exec;
     use error::{Error, Result};
-    use super::ExportFormat;
+    use super::{kubernetes, oci, systemd, tarball, ExportFormat};
 
     pub fn format_for(_ui: &mut UI, value: &str) -> Result<ExportFormat> {
         match value {
             "docker" => {
-                let format = ExportFormat {
+                let format = ExportFormat::External {
                     pkg_ident: try!(PackageIdent::from_str("core/hab-pkg-dockerize")),
                     cmd: "hab-pkg-dockerize".to_string(),
                 };
                 Ok(format)
             }
             "aci" => {
-                let format = ExportFormat {
+                let format = ExportFormat::External {
                     pkg_ident: try!(PackageIdent::from_str("core/hab-pkg-aci")),
                     cmd: "hab-pkg-aci".to_string(),
                 };
                 Ok(format)
             }
             "mesos" => {
-                let format = ExportFormat {
+                let format = ExportFormat::External {
                     pkg_ident: try!(PackageIdent::from_str("core/hab-pkg-mesosize")),
                     cmd: "hab-pkg-mesosize".to_string(),
                 };
                 Ok(format)
             }
-            "tar" => {
-                let format = ExportFormat {
-                    pkg_ident: try!(PackageIdent::from_str("core/hab-pkg-tarize")),
-                    cmd: "hab-pkg-tarize".to_string(),
-                };
-                Ok(format)
-            }
+            "kubernetes" => Ok(ExportFormat::Kubernetes),
+            "oci" => Ok(ExportFormat::Oci),
+            "systemd" => Ok(ExportFormat::Systemd),
+            "tar" => Ok(ExportFormat::Tar),
             _ => Err(Error::UnsupportedExportFormat(value.to_string())),
         }
     }
 
+    /// The filesystem root the native exporters of images and manifests take packages from,
+    /// installing those that are missing. Only root may install into the system's root, so
+    /// anyone else exports from a root of their own under `~/.hab/export`, which keeps what was
+    /// installed for earlier exports.
+    fn export_root() -> PathBuf {
+        if am_i_root() {
+            return FS_ROOT_PATH.clone();
+        }
+        match env::home_dir() {
+            Some(home) => home.join(".hab").join("export"),
+            None => FS_ROOT_PATH.clone(),
+        }
+    }
+
     pub fn start(ui: &mut UI, ident: &PackageIdent, format: &ExportFormat) -> Result<()> {
-        let format_ident = format.pkg_ident();
-        match PackageInstall::load(format.pkg_ident(), None) {
+        let (format_ident, cmd) = match *format {
+            ExportFormat::External { ref pkg_ident, ref cmd } => (pkg_ident, cmd),
+            ExportFormat::Kubernetes => {
+                let dst = try!(env::current_dir());
+                try!(kubernetes::start(ui, ident, &export_root(), &dst));
+                return Ok(());
+            }
+            ExportFormat::Oci => {
+                let dst = try!(env::current_dir());
+                try!(oci::start(ui, ident, &export_root(), &dst));
+                return Ok(());
+            }
+            ExportFormat::Systemd => {
+                let dst = try!(env::current_dir());
+                // The unit runs the packages installed on this system.
+                try!(systemd::start(ui, ident, Path::new(&*FS_ROOT_PATH), &dst));
+                return Ok(());
+            }
+            ExportFormat::Tar => {
+                let dst = try!(env::current_dir());
+                try!(tarball::start(ui, ident, &export_root(), &dst));
+                return Ok(());
+            }
+        };
+        match PackageInstall::load(format_ident, None) {
             Ok(_) => {}
             _ => {
                 try!(ui.status(Status::Missing, format!("package for {}", &format_ident)));
@@ -110,7 +148,7 @@ mod inner {
             }
         }
         let pkg_arg = OsString::from(&ident.to_string());
-        exec::start(&format_ident, &format.cmd(), vec![pkg_arg])
+        exec::start(format_ident, cmd, vec![pkg_arg])
     }
 }
 
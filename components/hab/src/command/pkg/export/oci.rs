@@ -0,0 +1,318 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Exports a package to an OCI image, without the need for a container runtime.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg export oci core/redis
+//! $ skopeo copy oci-archive:core-redis-3.2.4-20170103160441.oci.tar docker-daemon:core/redis:3.2.4
+//! ```
+//!
+//! The image is written as a tarball of an [OCI image
+//! layout](https://github.com/opencontainers/image-spec/blob/master/image-layout.md). Each
+//! package of the closure of the Supervisor and of the exported package gets a layer of its own,
+//! whose content depends only on the package, so that registries and runtimes can share layers
+//! between images. A last layer adds the `hab` user and group, a `/tmp`, and `/bin/sh` and
+//! `/bin/bash` from `core/busybox-static`. The image starts the Supervisor with the exported
+//! package.
+
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use common::ui::{Status, UI};
+use hcore::crypto::hash;
+use hcore::fs::pkg_install_path;
+use hcore::os::system::Architecture;
+use hcore::package::{PackageIdent, PackageInstall, PackageTarget};
+use serde_json;
+use tar;
+use time;
+
+use error::Result;
+use super::rootfs;
+
+/// The port of the Supervisor's HTTP gateway.
+const SUP_HTTP_PORT: &'static str = "9631";
+
+const LAYOUT_VERSION: &'static str = "1.0.0";
+const MEDIA_TYPE_MANIFEST: &'static str = "application/vnd.oci.image.manifest.v1+json";
+const MEDIA_TYPE_CONFIG: &'static str = "application/vnd.oci.image.config.v1+json";
+const MEDIA_TYPE_LAYER: &'static str = "application/vnd.oci.image.layer.v1.tar";
+const REF_NAME_ANNOTATION: &'static str = "org.opencontainers.image.ref.name";
+
+#[derive(Debug, Serialize)]
+struct Descriptor {
+    #[serde(rename = "mediaType")]
+    media_type: &'static str,
+    digest: String,
+    size: u64,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    annotations: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Serialize)]
+struct ImageLayout {
+    #[serde(rename = "imageLayoutVersion")]
+    image_layout_version: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+struct ImageIndex {
+    #[serde(rename = "schemaVersion")]
+    schema_version: u32,
+    manifests: Vec<Descriptor>,
+}
+
+#[derive(Debug, Serialize)]
+struct ImageManifest {
+    #[serde(rename = "schemaVersion")]
+    schema_version: u32,
+    config: Descriptor,
+    layers: Vec<Descriptor>,
+}
+
+#[derive(Debug, Serialize)]
+struct ImageConfig {
+    created: String,
+    architecture: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    variant: Option<&'static str>,
+    os: &'static str,
+    config: ContainerConfig,
+    rootfs: RootFs,
+}
+
+#[derive(Debug, Serialize)]
+struct ContainerConfig {
+    #[serde(rename = "Env")]
+    env: Vec<String>,
+    #[serde(rename = "Entrypoint")]
+    entrypoint: Vec<String>,
+    #[serde(rename = "Cmd")]
+    cmd: Vec<String>,
+    #[serde(rename = "ExposedPorts")]
+    exposed_ports: BTreeMap<String, Empty>,
+    #[serde(rename = "Volumes")]
+    volumes: BTreeMap<String, Empty>,
+    #[serde(rename = "WorkingDir")]
+    working_dir: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+struct RootFs {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    diff_ids: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct Empty {}
+
+/// Writes the OCI image of `ident` to `dst_dir`, installing the package and the Supervisor first
+/// if they are missing, and returns the path of the image tarball.
+pub fn start(ui: &mut UI,
+             ident: &PackageIdent,
+             fs_root_path: &Path,
+             dst_dir: &Path)
+             -> Result<PathBuf> {
+    try!(ui.begin(format!("Exporting {} to an OCI image", ident)));
+    let (package, roots) = try!(rootfs::install_with_supervisor(ui, ident, fs_root_path));
+    let closure = try!(rootfs::closure(&roots));
+
+    let ident = package.ident();
+    let name = format!("{}-{}-{}-{}.oci.tar",
+                       ident.origin,
+                       ident.name,
+                       ident.version.as_ref().unwrap(),
+                       ident.release.as_ref().unwrap());
+    try!(fs::create_dir_all(dst_dir));
+    let dst = dst_dir.join(name);
+    let layout_dir = dst.with_extension("tmp");
+    let result = write_layout(ui, &layout_dir, &package, &roots, &closure, fs_root_path)
+        .and_then(|_| write_tarball(&layout_dir, &dst));
+    let _ = fs::remove_dir_all(&layout_dir);
+    try!(result);
+    try!(ui.end(format!("OCI image of {} with {} packages written to {}",
+                        ident,
+                        closure.len(),
+                        dst.display())));
+    Ok(dst)
+}
+
+fn write_layout(ui: &mut UI,
+                layout_dir: &Path,
+                package: &PackageInstall,
+                roots: &[PackageInstall],
+                closure: &[PackageIdent],
+                fs_root_path: &Path)
+                -> Result<()> {
+    let blobs_dir = layout_dir.join("blobs").join("sha256");
+    try!(fs::create_dir_all(&blobs_dir));
+
+    let mut layers = Vec::new();
+    for dep in closure.iter() {
+        try!(ui.status(Status::Creating, format!("layer for {}", dep)));
+        let src = pkg_install_path(dep, Some(fs_root_path));
+        let layer = try!(write_blob(&blobs_dir, MEDIA_TYPE_LAYER, |w| {
+            let mut builder = tar::Builder::new(w);
+            try!(rootfs::append_pkg(&mut builder, dep, &src));
+            try!(builder.finish());
+            Ok(())
+        }));
+        layers.push(layer);
+    }
+    let busybox = roots.iter()
+        .find(|r| r.ident().name == "busybox-static")
+        .expect("busybox-static is one of the base packages");
+    layers.push(try!(write_blob(&blobs_dir, MEDIA_TYPE_LAYER, |w| {
+        let mut builder = tar::Builder::new(w);
+        try!(rootfs::append_system_files(&mut builder));
+        try!(rootfs::append_busybox_links(&mut builder, busybox.ident()));
+        try!(builder.finish());
+        Ok(())
+    })));
+
+    let image_config = try!(image_config(package, roots, &layers));
+    let config = try!(write_blob(&blobs_dir, MEDIA_TYPE_CONFIG, |w| {
+        try!(serde_json::to_writer(w, &image_config));
+        Ok(())
+    }));
+    let image_manifest = ImageManifest {
+        schema_version: 2,
+        config: config,
+        layers: layers,
+    };
+    let mut manifest = try!(write_blob(&blobs_dir, MEDIA_TYPE_MANIFEST, |w| {
+        try!(serde_json::to_writer(w, &image_manifest));
+        Ok(())
+    }));
+    let ident = package.ident();
+    manifest.annotations.insert(String::from(REF_NAME_ANNOTATION),
+                                format!("{}/{}:{}-{}",
+                                        ident.origin,
+                                        ident.name,
+                                        ident.version.as_ref().unwrap(),
+                                        ident.release.as_ref().unwrap()));
+    let index = ImageIndex {
+        schema_version: 2,
+        manifests: vec![manifest],
+    };
+    let layout = ImageLayout { image_layout_version: LAYOUT_VERSION };
+    try!(serde_json::to_writer(try!(File::create(layout_dir.join("index.json"))), &index));
+    try!(serde_json::to_writer(try!(File::create(layout_dir.join("oci-layout"))), &layout));
+    Ok(())
+}
+
+fn image_config(package: &PackageInstall,
+                roots: &[PackageInstall],
+                layers: &[Descriptor])
+                -> Result<ImageConfig> {
+    let ident = package.ident();
+    let hab = roots.iter()
+        .find(|r| r.ident().name == "hab")
+        .expect("hab is one of the base packages");
+    let mut paths: Vec<String> = Vec::new();
+    for root in roots.iter().filter(|r| r.ident().name != "hab-sup") {
+        for path in try!(root.paths()) {
+            paths.push(path.to_string_lossy().into_owned());
+        }
+    }
+
+    let mut exposed_ports = BTreeMap::new();
+    exposed_ports.insert(format!("{}/tcp", SUP_HTTP_PORT), Empty {});
+    for port in try!(package.exposes()).into_iter().filter(|p| !p.is_empty()) {
+        exposed_ports.insert(format!("{}/tcp", port), Empty {});
+    }
+    let mut volumes = BTreeMap::new();
+    for dir in &["config", "data"] {
+        volumes.insert(format!("/hab/svc/{}/{}", ident.name, dir), Empty {});
+    }
+
+    let (architecture, variant) = match PackageTarget::current_architecture() {
+        Architecture::X86_64 => ("amd64", None),
+        Architecture::Aarch64 => ("arm64", None),
+        Architecture::Armv7 => ("arm", Some("v7")),
+    };
+    Ok(ImageConfig {
+           created: time::now_utc().rfc3339().to_string(),
+           architecture: architecture,
+           variant: variant,
+           os: "linux",
+           config: ContainerConfig {
+               env: vec![format!("PATH={}", paths.join(":"))],
+               entrypoint: vec![pkg_install_path(hab.ident(), None)
+                                    .join("bin")
+                                    .join("hab")
+                                    .to_string_lossy()
+                                    .into_owned(),
+                                String::from("sup"),
+                                String::from("start")],
+               cmd: vec![ident.to_string()],
+               exposed_ports: exposed_ports,
+               volumes: volumes,
+               working_dir: "/",
+           },
+           rootfs: RootFs {
+               kind: "layers",
+               // Layers are not compressed, so their digests are also their diff IDs.
+               diff_ids: layers.iter().map(|l| l.digest.clone()).collect(),
+           },
+       })
+}
+
+/// Writes a blob with `write` and names it after its digest.
+fn write_blob<F>(blobs_dir: &Path, media_type: &'static str, write: F) -> Result<Descriptor>
+    where F: FnOnce(&mut BufWriter<File>) -> Result<()>
+{
+    let tmp = blobs_dir.join("blob.tmp");
+    {
+        let mut writer = BufWriter::new(try!(File::create(&tmp)));
+        try!(write(&mut writer));
+        try!(writer.flush());
+    }
+    let digest = try!(hash::sha256_file(&tmp));
+    let size = try!(fs::metadata(&tmp)).len();
+    try!(fs::rename(&tmp, blobs_dir.join(&digest)));
+    Ok(Descriptor {
+           media_type: media_type,
+           digest: format!("sha256:{}", digest),
+           size: size,
+           annotations: BTreeMap::new(),
+       })
+}
+
+/// Writes the image layout at `layout_dir` to the tarball `dst`.
+fn write_tarball(layout_dir: &Path, dst: &Path) -> Result<()> {
+    let mut builder = tar::Builder::new(BufWriter::new(try!(File::create(dst))));
+    try!(builder.append_path_with_name(layout_dir.join("oci-layout"), "oci-layout"));
+    try!(builder.append_path_with_name(layout_dir.join("index.json"), "index.json"));
+    try!(rootfs::append_dir(&mut builder, Path::new("blobs"), 0o755));
+    try!(rootfs::append_dir(&mut builder, Path::new("blobs/sha256"), 0o755));
+    let mut blobs = Vec::new();
+    for entry in try!(fs::read_dir(layout_dir.join("blobs").join("sha256"))) {
+        blobs.push(try!(entry).file_name());
+    }
+    blobs.sort();
+    for blob in blobs {
+        try!(builder.append_path_with_name(layout_dir.join("blobs").join("sha256").join(&blob),
+                                           Path::new("blobs").join("sha256").join(&blob)));
+    }
+    let mut writer = try!(builder.into_inner());
+    try!(writer.flush());
+    Ok(())
+}
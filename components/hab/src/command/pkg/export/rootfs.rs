@@ -0,0 +1,203 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The parts shared by the exporters that lay out a root filesystem for a package.
+
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::Path;
+use std::str::FromStr;
+
+use common::command::package::install;
+use common::ui::{Status, UI};
+use hcore::fs::pkg_install_path;
+use hcore::package::{PackageIdent, PackageInstall};
+use hcore::url::default_depot_url;
+use tar;
+
+use {PRODUCT, VERSION};
+use error::Result;
+
+/// The packages every root filesystem needs to run the Supervisor.
+pub const BASE_PKGS: &'static [&'static str] = &["core/busybox-static",
+                                                 "core/hab",
+                                                 "core/hab-sup"];
+
+const ETC_PASSWD: &'static str = "root:x:0:0:root:/:/bin/sh\nhab:x:42:42:root:/tmp:/bin/sh\n";
+const ETC_GROUP: &'static str = "root:x:0:\nhab:x:42:hab\n";
+
+/// The programs of `core/busybox-static` linked into `/bin`, as in the images made by
+/// `hab pkg export docker`, so that the shell of the users in `/etc/passwd` exists.
+const BUSYBOX_BINLINKS: &'static [&'static str] = &["bash", "sh"];
+
+/// Loads the installed package for `ident` and the base packages, installing any of them that
+/// are missing. Returns the package and all of them, the package last.
+pub fn install_with_supervisor(ui: &mut UI,
+                               ident: &PackageIdent,
+                               fs_root_path: &Path)
+                               -> Result<(PackageInstall, Vec<PackageInstall>)> {
+    let mut roots = Vec::new();
+    for pkg in BASE_PKGS.iter() {
+        roots.push(try!(installed(ui, &try!(PackageIdent::from_str(pkg)), fs_root_path)));
+    }
+    let package = try!(installed(ui, ident, fs_root_path));
+    roots.push(package.clone());
+    Ok((package, roots))
+}
+
+/// Loads the installed package for `ident`, installing it first if it is missing. Packages are
+/// installed into `fs_root_path` itself, which needs no privileges when it is a root of the
+/// user's own.
+pub fn installed(ui: &mut UI, ident: &PackageIdent, fs_root_path: &Path) -> Result<PackageInstall> {
+    if let Ok(package) = PackageInstall::load(ident, Some(fs_root_path)) {
+        return Ok(package);
+    }
+    try!(ui.status(Status::Missing, format!("package for {}", ident)));
+    let ident = try!(install::start_in_root(ui,
+                                            &default_depot_url(),
+                                            ident,
+                                            PRODUCT,
+                                            VERSION,
+                                            fs_root_path));
+    Ok(try!(PackageInstall::load(&ident, Some(fs_root_path))))
+}
+
+/// Returns the packages of `roots` and their transitive dependencies, each package after its
+/// dependencies and without repeats.
+pub fn closure(roots: &[PackageInstall]) -> Result<Vec<PackageIdent>> {
+    let mut closure: Vec<PackageIdent> = Vec::new();
+    for root in roots.iter() {
+        for dep in try!(root.tdeps()).into_iter().chain(Some(root.ident().clone())) {
+            if !closure.contains(&dep) {
+                closure.push(dep);
+            }
+        }
+    }
+    Ok(closure)
+}
+
+/// Appends the installed package at `src` under `hab/pkgs`. Entries are appended in name order
+/// and with fixed owners and times, so that a package always gives the same entries.
+pub fn append_pkg<W: Write>(builder: &mut tar::Builder<W>,
+                            ident: &PackageIdent,
+                            src: &Path)
+                            -> Result<()> {
+    let dst = pkg_install_path(ident, None);
+    let dst = dst.strip_prefix("/").unwrap_or(&dst).to_path_buf();
+    let mut parents = Vec::new();
+    let mut parent = dst.parent();
+    while let Some(path) = parent {
+        if path.as_os_str().is_empty() {
+            break;
+        }
+        parents.push(path);
+        parent = path.parent();
+    }
+    for path in parents.into_iter().rev() {
+        try!(append_dir(builder, path, 0o755));
+    }
+    append_tree(builder, src, &dst)
+}
+
+/// Appends `/etc/passwd` and `/etc/group` with the `hab` user and group, and a `/tmp`.
+pub fn append_system_files<W: Write>(builder: &mut tar::Builder<W>) -> Result<()> {
+    try!(append_dir(builder, Path::new("etc"), 0o755));
+    try!(append_file(builder, Path::new("etc/passwd"), ETC_PASSWD.as_bytes(), 0o644));
+    try!(append_file(builder, Path::new("etc/group"), ETC_GROUP.as_bytes(), 0o644));
+    try!(append_dir(builder, Path::new("tmp"), 0o1777));
+    Ok(())
+}
+
+/// Appends `/bin` with `BUSYBOX_BINLINKS` linked to the installed `busybox` package.
+pub fn append_busybox_links<W: Write>(builder: &mut tar::Builder<W>,
+                                      busybox: &PackageIdent)
+                                      -> Result<()> {
+    let bin = pkg_install_path(busybox, None).join("bin");
+    try!(append_dir(builder, Path::new("bin"), 0o755));
+    for program in BUSYBOX_BINLINKS.iter() {
+        try!(append_symlink(builder, &Path::new("bin").join(program), &bin.join(program)));
+    }
+    Ok(())
+}
+
+pub fn append_tree<W: Write>(builder: &mut tar::Builder<W>, src: &Path, dst: &Path) -> Result<()> {
+    let metadata = try!(fs::symlink_metadata(src));
+    let mut header = tar::Header::new_gnu();
+    header.set_metadata(&metadata);
+    header.set_uid(0);
+    header.set_gid(0);
+    header.set_mtime(0);
+    if metadata.file_type().is_symlink() {
+        header.set_size(0);
+        try!(header.set_link_name(try!(fs::read_link(src))));
+        header.set_cksum();
+        try!(builder.append_data(&mut header, dst, io::empty()));
+    } else if metadata.is_dir() {
+        header.set_size(0);
+        header.set_cksum();
+        try!(builder.append_data(&mut header, dst, io::empty()));
+        let mut children = Vec::new();
+        for entry in try!(fs::read_dir(src)) {
+            children.push(try!(entry).file_name());
+        }
+        children.sort();
+        for child in children {
+            try!(append_tree(builder, &src.join(&child), &dst.join(&child)));
+        }
+    } else {
+        header.set_cksum();
+        try!(builder.append_data(&mut header, dst, try!(File::open(src))));
+    }
+    Ok(())
+}
+
+pub fn append_dir<W: Write>(builder: &mut tar::Builder<W>, path: &Path, mode: u32) -> Result<()> {
+    let mut header = tar::Header::new_gnu();
+    header.set_entry_type(tar::EntryType::Directory);
+    header.set_size(0);
+    header.set_mode(mode);
+    header.set_mtime(0);
+    header.set_cksum();
+    try!(builder.append_data(&mut header, path, io::empty()));
+    Ok(())
+}
+
+pub fn append_file<W: Write>(builder: &mut tar::Builder<W>,
+                             path: &Path,
+                             content: &[u8],
+                             mode: u32)
+                             -> Result<()> {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(content.len() as u64);
+    header.set_mode(mode);
+    header.set_mtime(0);
+    header.set_cksum();
+    try!(builder.append_data(&mut header, path, content));
+    Ok(())
+}
+
+pub fn append_symlink<W: Write>(builder: &mut tar::Builder<W>,
+                                path: &Path,
+                                target: &Path)
+                                -> Result<()> {
+    let mut header = tar::Header::new_gnu();
+    header.set_entry_type(tar::EntryType::Symlink);
+    header.set_size(0);
+    header.set_mode(0o777);
+    header.set_mtime(0);
+    try!(header.set_link_name(target));
+    header.set_cksum();
+    try!(builder.append_data(&mut header, path, io::empty()));
+    Ok(())
+}
@@ -0,0 +1,147 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Exports a package to a systemd unit which runs the Supervisor with the package.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg export systemd core/redis
+//! $ cp core-redis.service /etc/systemd/system/
+//! $ systemctl enable --now core-redis
+//! ```
+//!
+//! The unit runs the Supervisor as root, with the package's runtime environment, and restarts it
+//! when it fails. The Supervisor runs the service as the package's service user and group itself.
+
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use common::ui::{Status, UI};
+use hcore::fs::pkg_install_path;
+use hcore::package::{PackageIdent, PackageInstall};
+
+use error::Result;
+use super::rootfs;
+
+/// Seconds to wait before restarting a failed Supervisor.
+const RESTART_SEC: u32 = 5;
+
+/// Writes the unit for `ident` to `dst_dir`, installing the package and the Supervisor first if
+/// they are missing, and returns the path of the unit.
+pub fn start(ui: &mut UI,
+             ident: &PackageIdent,
+             fs_root_path: &Path,
+             dst_dir: &Path)
+             -> Result<PathBuf> {
+    try!(ui.begin(format!("Exporting {} to a systemd unit", ident)));
+    let (package, roots) = try!(rootfs::install_with_supervisor(ui, ident, fs_root_path));
+    let hab = roots.iter()
+        .find(|r| r.ident().name == "hab")
+        .expect("hab is one of the base packages");
+    let unit = try!(unit_for(&package, hab.ident()));
+
+    try!(fs::create_dir_all(dst_dir));
+    let dst = dst_dir.join(format!("{}-{}.service", package.ident().origin, package.ident().name));
+    try!(ui.status(Status::Creating, dst.display()));
+    let mut file = try!(File::create(&dst));
+    try!(file.write_all(unit.as_bytes()));
+    try!(ui.end(format!("systemd unit for {} written to {}", package.ident(), dst.display())));
+    Ok(dst)
+}
+
+fn unit_for(package: &PackageInstall, hab: &PackageIdent) -> Result<String> {
+    let mut environment = BTreeMap::new();
+    for (key, value) in try!(package.runtime_environment()) {
+        environment.insert(key, value);
+    }
+    Ok(render_unit(package.ident(),
+                   &pkg_install_path(hab, None).join("bin").join("hab"),
+                   &environment))
+}
+
+fn render_unit(ident: &PackageIdent,
+               hab_bin: &Path,
+               environment: &BTreeMap<String, String>)
+               -> String {
+    let mut unit = String::new();
+    unit.push_str("[Unit]\n");
+    unit.push_str(&format!("Description=Habitat Supervisor running {}\n", ident));
+    unit.push_str("Wants=network-online.target\n");
+    unit.push_str("After=network-online.target\n");
+    unit.push_str("\n[Service]\n");
+    for (key, value) in environment.iter() {
+        unit.push_str(&format!("Environment={}\n", quote(&format!("{}={}", key, value))));
+    }
+    unit.push_str(&format!("ExecStart={} sup start {}\n",
+                           quote(&hab_bin.to_string_lossy()),
+                           ident));
+    unit.push_str("Restart=on-failure\n");
+    unit.push_str(&format!("RestartSec={}\n", RESTART_SEC));
+    unit.push_str("\n[Install]\n");
+    unit.push_str("WantedBy=multi-user.target\n");
+    unit
+}
+
+/// Quotes a value for a unit file, escaping what systemd would otherwise expand.
+fn quote(value: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '%' => quoted.push_str("%%"),
+            '$' => quoted.push_str("$$"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use hcore::package::PackageIdent;
+
+    use super::{quote, render_unit};
+
+    #[test]
+    fn quote_escapes_systemd_specials() {
+        assert_eq!(quote("PATH=/bin"), "\"PATH=/bin\"");
+        assert_eq!(quote("A=\"50%\" $HOME \\"),
+                   "\"A=\\\"50%%\\\" $$HOME \\\\\"");
+    }
+
+    #[test]
+    fn render_unit_runs_the_supervisor() {
+        let ident = PackageIdent::from_str("core/redis/3.2.4/20170103160441").unwrap();
+        let mut environment = BTreeMap::new();
+        environment.insert(String::from("PATH"), String::from("/hab/pkgs/core/redis/bin"));
+        let unit = render_unit(&ident,
+                               Path::new("/hab/pkgs/core/hab/0.20.0/20170326090935/bin/hab"),
+                               &environment);
+        assert!(!unit.contains("User="));
+        assert!(!unit.contains("Group="));
+        assert!(unit.contains("Environment=\"PATH=/hab/pkgs/core/redis/bin\"\n"));
+        assert!(unit.contains("ExecStart=\"/hab/pkgs/core/hab/0.20.0/20170326090935/bin/hab\" \
+                               sup start core/redis/3.2.4/20170103160441\n"));
+        assert!(unit.contains("Restart=on-failure\n"));
+    }
+}
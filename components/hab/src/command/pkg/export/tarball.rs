@@ -0,0 +1,118 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Exports a package to a tarball of a root filesystem which can be chrooted into.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg export tar core/redis
+//! $ mkdir /srv/redis && tar -xf core-redis-3.2.4-20170103160441.tar -C /srv/redis
+//! $ chroot /srv/redis /hab/bin/hab sup run
+//! ```
+//!
+//! The root filesystem holds the package, the Supervisor and their transitive dependencies, the
+//! public keys of their origins found in the key cache, `/hab/bin/hab`, `/bin/sh` and `/bin/bash`
+//! from `core/busybox-static`, and a Supervisor state
+//! directory with a spec for the package, so that `hab sup run` starts it.
+
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use common::ui::{Status, UI};
+use hcore::crypto::SigKeyPair;
+use hcore::fs::{cache_key_path, pkg_install_path};
+use hcore::package::PackageIdent;
+use tar;
+
+use error::Result;
+use super::rootfs;
+
+/// Where the Supervisor keeps its state, relative to the root filesystem.
+const SUP_STATE_PATH: &'static str = "hab/sup/default";
+
+/// Writes the root filesystem tarball for `ident` to `dst_dir`, installing the package and the
+/// Supervisor first if they are missing, and returns the path of the tarball.
+pub fn start(ui: &mut UI,
+             ident: &PackageIdent,
+             fs_root_path: &Path,
+             dst_dir: &Path)
+             -> Result<PathBuf> {
+    try!(ui.begin(format!("Exporting {} to a root filesystem tarball", ident)));
+    let (package, roots) = try!(rootfs::install_with_supervisor(ui, ident, fs_root_path));
+    let closure = try!(rootfs::closure(&roots));
+    let ident = package.ident();
+    let hab = roots.iter()
+        .find(|r| r.ident().name == "hab")
+        .expect("hab is one of the base packages");
+    let busybox = roots.iter()
+        .find(|r| r.ident().name == "busybox-static")
+        .expect("busybox-static is one of the base packages");
+
+    let mut keys = Vec::new();
+    let mut origins: Vec<&str> = closure.iter().map(|i| i.origin.as_str()).collect();
+    origins.sort();
+    origins.dedup();
+    let key_cache = cache_key_path(Some(fs_root_path));
+    for origin in origins {
+        for pair in SigKeyPair::get_pairs_for(origin, &key_cache).unwrap_or_default() {
+            if let Ok(path) = SigKeyPair::get_public_key_path(&pair.name_with_rev(), &key_cache) {
+                keys.push(path);
+            }
+        }
+    }
+
+    try!(fs::create_dir_all(dst_dir));
+    let dst = dst_dir.join(format!("{}-{}-{}-{}.tar",
+                                   ident.origin,
+                                   ident.name,
+                                   ident.version.as_ref().unwrap(),
+                                   ident.release.as_ref().unwrap()));
+    let mut builder = tar::Builder::new(BufWriter::new(try!(File::create(&dst))));
+    try!(rootfs::append_system_files(&mut builder));
+    try!(rootfs::append_busybox_links(&mut builder, busybox.ident()));
+    for dep in closure.iter() {
+        try!(ui.status(Status::Using, dep));
+        try!(rootfs::append_pkg(&mut builder, dep, &pkg_install_path(dep, Some(fs_root_path))));
+    }
+    try!(rootfs::append_dir(&mut builder, Path::new("hab/bin"), 0o755));
+    try!(rootfs::append_symlink(&mut builder,
+                                Path::new("hab/bin/hab"),
+                                &pkg_install_path(hab.ident(), None).join("bin").join("hab")));
+    try!(rootfs::append_dir(&mut builder, Path::new("hab/cache"), 0o755));
+    try!(rootfs::append_dir(&mut builder, Path::new("hab/cache/keys"), 0o755));
+    for key in keys.iter() {
+        let name = Path::new("hab/cache/keys").join(key.file_name().unwrap());
+        try!(rootfs::append_tree(&mut builder, key, &name));
+    }
+    let state_path = Path::new(SUP_STATE_PATH);
+    try!(rootfs::append_dir(&mut builder, Path::new("hab/sup"), 0o755));
+    try!(rootfs::append_dir(&mut builder, state_path, 0o755));
+    try!(rootfs::append_dir(&mut builder, &state_path.join("data"), 0o755));
+    try!(rootfs::append_dir(&mut builder, &state_path.join("specs"), 0o755));
+    let spec = format!("ident = \"{}\"\n", ident);
+    try!(rootfs::append_file(&mut builder,
+                             &state_path.join("specs").join(format!("{}.spec", ident.name)),
+                             spec.as_bytes(),
+                             0o644));
+    let mut writer = try!(builder.into_inner());
+    try!(writer.flush());
+    try!(ui.end(format!("Root filesystem of {} with {} packages and {} keys written to {}",
+                        ident,
+                        closure.len(),
+                        keys.len(),
+                        dst.display())));
+    Ok(dst)
+}
@@ -12,12 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common::ui::UI;
 use hcore::crypto::hash;
 
 use error::Result;
 
-pub fn start(src: &str) -> Result<()> {
+#[derive(Debug, Serialize)]
+struct FileHash<'a> {
+    file: &'a str,
+    hash: String,
+}
+
+pub fn start(ui: &mut UI, src: &str) -> Result<()> {
     let h = try!(hash::hash_file(&src));
-    println!("{}  {}", h, src);
-    Ok(())
+    let lines = vec![format!("{}  {}", h, src)];
+    Ok(try!(ui.result(&FileHash { file: src, hash: h }, &lines)))
 }
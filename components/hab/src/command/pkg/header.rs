@@ -30,6 +30,12 @@ pub fn start(ui: &mut UI, src: &Path) -> Result<()> {
         try!(io::stdout().write(format!("Key Name       : {}\n", header.key_name).as_bytes()));
         try!(io::stdout().write(format!("Hash Type      : {}\n", header.hash_type).as_bytes()));
         try!(io::stdout().write(format!("Raw Signature  : {}\n", header.signature_raw).as_bytes()));
+        for signature in header.signatures.iter().skip(1) {
+            try!(io::stdout().write(format!("Counter-signed : {} {}\n",
+                                            signature.key_name,
+                                            signature.signature_raw)
+                                            .as_bytes()));
+        }
     } else {
         try!(ui.warn("Failed to read package header."));
     }
@@ -0,0 +1,43 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+use std::str::FromStr;
+
+use common::ui::UI;
+use hcore::package::{Identifiable, PackageIdent, PackageInstall};
+
+use error::Result;
+
+/// Print every installed package, optionally limited to an origin (ex: `core`) or to any part of
+/// a package identifier (ex: `core/redis`, `core/redis/3.2.4`).
+pub fn start(ui: &mut UI, filter: Option<&str>, fs_root_path: &Path) -> Result<()> {
+    let packages = try!(installed(filter, fs_root_path));
+    let lines: Vec<String> = packages.iter().map(|p| p.to_string()).collect();
+    Ok(try!(ui.result(&packages, &lines)))
+}
+
+fn installed(filter: Option<&str>, fs_root_path: &Path) -> Result<Vec<PackageIdent>> {
+    let mut packages = try!(PackageInstall::list_installed(Some(fs_root_path)));
+    match filter {
+        Some(filter) if filter.contains('/') => {
+            let ident = try!(PackageIdent::from_str(filter));
+            packages.retain(|p| p.satisfies(&ident));
+        }
+        Some(origin) => packages.retain(|p| p.origin == origin),
+        None => (),
+    }
+    packages.sort();
+    Ok(packages)
+}
@@ -14,13 +14,18 @@
 
 pub mod binlink;
 pub mod build;
+pub mod bundle;
+pub mod dependencies;
+pub mod diff;
 pub mod env;
 pub mod exec;
 pub mod export;
 pub mod hash;
 pub mod header;
+pub mod list;
 pub mod path;
 pub mod provides;
+pub mod sbom;
 pub mod search;
 pub mod sign;
 pub mod upload;
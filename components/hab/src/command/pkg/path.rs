@@ -14,12 +14,31 @@
 
 use std::path::Path;
 
-use hcore::package::{PackageIdent, PackageInstall};
+use common::ui::UI;
+use hcore::package::{PackageIdent, PackageInstall, VersionReq};
 
 use error::Result;
 
-pub fn start(ident: &PackageIdent, fs_root_path: &Path) -> Result<()> {
-    let pkg_install = try!(PackageInstall::load(ident, Some(fs_root_path)));
-    println!("{}", pkg_install.installed_path().display());
-    Ok(())
+#[derive(Debug, Serialize)]
+struct InstalledPath {
+    ident: String,
+    path: String,
+}
+
+pub fn start(ui: &mut UI,
+             ident: &PackageIdent,
+             version_req: Option<&VersionReq>,
+             fs_root_path: &Path)
+             -> Result<()> {
+    let pkg_install = match version_req {
+        Some(req) => try!(PackageInstall::load_matching(ident, req, Some(fs_root_path))),
+        None => try!(PackageInstall::load(ident, Some(fs_root_path))),
+    };
+    let path = pkg_install.installed_path().display().to_string();
+    let lines = vec![path.clone()];
+    let result = InstalledPath {
+        ident: pkg_install.ident().to_string(),
+        path: path,
+    };
+    Ok(try!(ui.result(&result, &lines)))
 }
@@ -15,12 +15,21 @@
 use std::collections::HashSet;
 use std::path::Path;
 
+use common::ui::UI;
 use walkdir::WalkDir;
 
 use error::{Error, Result};
 use hcore::fs::PKG_PATH;
 
-pub fn start(filename: &str,
+#[derive(Debug, Serialize)]
+struct Provider {
+    package: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+}
+
+pub fn start(ui: &mut UI,
+             filename: &str,
              fs_root_path: &Path,
              full_releases: bool,
              full_path: bool)
@@ -33,6 +42,7 @@ pub fn start(filename: &str,
     let pkg_root = fs_root_path.join(PKG_PATH);
 
     let mut found_any = false;
+    let mut providers = Vec::new();
 
     // recursively walk the directories in pkg_root looking for matches
     for entry in WalkDir::new(pkg_root).into_iter().filter_map(|e| e.ok()) {
@@ -66,7 +76,10 @@ pub fn start(filename: &str,
                 // the result into the found HashSet, as we want to
                 // print out each path we find.
                 if full_path {
-                    println!("{}: {}", &pkg_name, &entry.path().to_string_lossy());
+                    providers.push(Provider {
+                                       package: pkg_name,
+                                       path: Some(entry.path().to_string_lossy().into_owned()),
+                                   });
                 } else {
                     found.insert(pkg_name);
                 }
@@ -75,11 +88,20 @@ pub fn start(filename: &str,
     }
     // if we're not using full_path, then using a set will filter out
     // duplicates. This shows the filtered set of matches
-    for entry in &found {
-        println!("{}", entry);
+    for entry in found.into_iter() {
+        providers.push(Provider {
+                           package: entry,
+                           path: None,
+                       });
     }
     if found_any {
-        Ok(())
+        let lines: Vec<String> = providers.iter()
+            .map(|p| match p.path {
+                     Some(ref path) => format!("{}: {}", p.package, path),
+                     None => p.package.clone(),
+                 })
+            .collect();
+        Ok(try!(ui.result(&providers, &lines)))
     } else {
         Err(Error::ProvidesError(filename.to_string()))
     }
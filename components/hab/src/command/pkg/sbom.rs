@@ -0,0 +1,805 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Prints a software bill of materials for a package and its transitive dependencies.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg sbom core/redis
+//! $ hab pkg sbom --spec cyclonedx ./results/core-redis-3.2.4-20170103160441-x86_64-linux.hart
+//! ```
+//!
+//! The licenses and sources of each package are read from its `MANIFEST`. Dependencies are read
+//! from installed packages, or else from artifacts in the artifact cache; a dependency found in
+//! neither is listed by its identifier alone. A package's checksums are those of its artifact,
+//! when the artifact is at hand. The upstream source a package was built from is listed as a
+//! component of its own, with the checksum the plan verified it against.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::result;
+use std::str::FromStr;
+
+use common::ui::UI;
+use hcore::crypto::hash;
+use hcore::package::{PackageArchive, PackageIdent, PackageInstall, PackageTarget};
+use serde_json;
+use time;
+use uuid::Uuid;
+
+use {PRODUCT, VERSION};
+use error::{Error, Result};
+
+const NOASSERTION: &'static str = "NOASSERTION";
+
+/// The SBOM standards we can write.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SbomSpec {
+    Spdx,
+    CycloneDx,
+}
+
+impl SbomSpec {
+    /// The values accepted on the command line.
+    pub fn variants() -> &'static [&'static str] {
+        &["spdx", "cyclonedx"]
+    }
+}
+
+impl Default for SbomSpec {
+    fn default() -> Self {
+        SbomSpec::Spdx
+    }
+}
+
+impl fmt::Display for SbomSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SbomSpec::Spdx => write!(f, "spdx"),
+            SbomSpec::CycloneDx => write!(f, "cyclonedx"),
+        }
+    }
+}
+
+impl FromStr for SbomSpec {
+    type Err = Error;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value {
+            "spdx" => Ok(SbomSpec::Spdx),
+            "cyclonedx" => Ok(SbomSpec::CycloneDx),
+            _ => Err(Error::ArgumentError("SBOM spec must be one of: spdx, cyclonedx")),
+        }
+    }
+}
+
+/// The checksums of a package's artifact, the `.hart` file.
+#[derive(Debug, PartialEq)]
+struct ArtifactHashes {
+    blake2b: String,
+    sha256: String,
+}
+
+impl ArtifactHashes {
+    fn of(path: &Path) -> Option<Self> {
+        match (hash::hash_file(&path), hash::sha256_file(&path)) {
+            (Ok(blake2b), Ok(sha256)) => {
+                Some(ArtifactHashes {
+                         blake2b: blake2b,
+                         sha256: sha256,
+                     })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// What the SBOM says about one package.
+#[derive(Debug, Default, PartialEq)]
+struct Component {
+    ident: PackageIdent,
+    licenses: Vec<String>,
+    /// Where the plan downloaded the upstream source from.
+    source: Option<String>,
+    /// The SHA-256 checksum of the upstream source, as `pkg_shasum` in the plan.
+    source_shasum: Option<String>,
+    upstream_url: Option<String>,
+    artifact: Option<ArtifactHashes>,
+    /// The direct dependencies, or `None` if the package's metadata could not be found.
+    deps: Option<Vec<PackageIdent>>,
+}
+
+impl Component {
+    fn new(ident: PackageIdent, manifest: &str, deps: Vec<PackageIdent>) -> Self {
+        let mut component = Component {
+            ident: ident,
+            deps: Some(deps),
+            ..Default::default()
+        };
+        for line in manifest.lines() {
+            let (key, value) = match manifest_field(line) {
+                Some(field) => field,
+                None => continue,
+            };
+            match key {
+                "License" => {
+                    component.licenses = value.split_whitespace().map(|l| l.to_string()).collect()
+                }
+                "Source" => component.source = markdown_link(value),
+                "Upstream URL" => component.upstream_url = markdown_link(value),
+                "SHA" if is_sha256(value.trim_matches('`')) => {
+                    component.source_shasum = Some(value.trim_matches('`').to_string())
+                }
+                _ => (),
+            }
+        }
+        component
+    }
+
+    fn unknown(ident: PackageIdent) -> Self {
+        Component { ident: ident, ..Default::default() }
+    }
+
+    fn purl(&self) -> String {
+        format!("pkg:generic/{}/{}@{}?release={}",
+                self.ident.origin,
+                self.ident.name,
+                self.ident.version.as_ref().map_or("", |v| v.as_str()),
+                self.ident.release.as_ref().map_or("", |r| r.as_str()))
+    }
+
+    fn spdx_id(&self) -> String {
+        spdx_id(&self.ident)
+    }
+
+    fn source_spdx_id(&self) -> String {
+        self.spdx_id().replace("SPDXRef-Package-", "SPDXRef-Source-")
+    }
+}
+
+pub fn start(ui: &mut UI,
+             source: &str,
+             fs_root_path: &Path,
+             cache_artifact_path: &Path,
+             spec: SbomSpec)
+             -> Result<()> {
+    let (root, tdeps) = if Path::new(source).is_file() {
+        let mut archive = PackageArchive::new(Path::new(source));
+        let ident = try!(archive.ident());
+        let mut root = Component::new(ident,
+                                      &try!(archive.manifest()),
+                                      try!(archive.deps()));
+        root.artifact = ArtifactHashes::of(Path::new(source));
+        (root, try!(archive.tdeps()))
+    } else {
+        let ident = try!(PackageIdent::from_str(source));
+        let package = try!(PackageInstall::load(&ident, Some(fs_root_path)));
+        let mut root = Component::new(package.ident().clone(),
+                                      &try!(package.manifest()),
+                                      try!(package.deps()));
+        root.artifact = cached_artifact(package.ident(), cache_artifact_path)
+            .and_then(|path| ArtifactHashes::of(&path));
+        (root, try!(package.tdeps()))
+    };
+
+    let mut components = vec![root];
+    for dep in tdeps.into_iter() {
+        let component = load_component(dep.clone(), fs_root_path, cache_artifact_path);
+        if component.deps.is_none() {
+            try!(ui.warn(format!("{} is neither installed nor cached, listing it without its \
+                                  licenses and sources",
+                                 dep)));
+        }
+        components.push(component);
+    }
+
+    let document = match spec {
+        SbomSpec::Spdx => try!(serde_json::to_value(&spdx_document(&components))),
+        SbomSpec::CycloneDx => try!(serde_json::to_value(&cyclonedx_bom(&components))),
+    };
+    let text = try!(serde_json::to_string_pretty(&document));
+    try!(ui.result(&document, &[text]));
+    Ok(())
+}
+
+/// Reads what it can about a dependency from its install or from its cached artifact.
+fn load_component(ident: PackageIdent,
+                  fs_root_path: &Path,
+                  cache_artifact_path: &Path)
+                  -> Component {
+    let cached = cached_artifact(&ident, cache_artifact_path);
+    let artifact = cached.as_ref().and_then(|path| ArtifactHashes::of(path));
+    let mut component = Component::unknown(ident.clone());
+    if let Ok(package) = PackageInstall::load(&ident, Some(fs_root_path)) {
+        if let (Ok(manifest), Ok(deps)) = (package.manifest(), package.deps()) {
+            component = Component::new(ident, &manifest, deps);
+        }
+    } else if let Some(path) = cached {
+        let mut archive = PackageArchive::new(path);
+        if let (Ok(manifest), Ok(deps)) = (archive.manifest(), archive.deps()) {
+            component = Component::new(ident, &manifest, deps);
+        }
+    }
+    component.artifact = artifact;
+    component
+}
+
+/// Returns the path of the artifact of `ident` in the artifact cache, if it's there. The Depot
+/// may serve an artifact for any target compatible with this system, so the first compatible one
+/// in the cache is returned.
+fn cached_artifact(ident: &PackageIdent, cache_artifact_path: &Path) -> Option<PathBuf> {
+    PackageTarget::default()
+        .compatible_targets()
+        .iter()
+        .filter_map(|target| ident.archive_name_with_target(target))
+        .map(|name| cache_artifact_path.join(name))
+        .find(|path| path.is_file())
+}
+
+/// Whether `value` is a hex SHA-256 checksum. Plans without a `pkg_shasum` have a sentence there.
+fn is_sha256(value: &str) -> bool {
+    value.len() == 64 && value.chars().all(|c| c.is_digit(16))
+}
+
+/// Splits a `* __Key__: value` line of a `MANIFEST`.
+fn manifest_field(line: &str) -> Option<(&str, &str)> {
+    if !line.starts_with("* __") {
+        return None;
+    }
+    let rest = &line[4..];
+    rest.find("__: ")
+        .map(|i| (&rest[..i], rest[i + 4..].trim()))
+}
+
+/// Returns the URL of a `[text](url)` Markdown link; plans without the value have a sentence
+/// there instead.
+fn markdown_link(value: &str) -> Option<String> {
+    if !value.starts_with('[') || !value.ends_with(')') {
+        return None;
+    }
+    value.rfind("](").map(|i| value[i + 2..value.len() - 1].to_string())
+}
+
+fn spdx_id(ident: &PackageIdent) -> String {
+    let id: String = ident
+        .to_string()
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '.' || c == '-' { c } else { '-' })
+        .collect();
+    format!("SPDXRef-Package-{}", id)
+}
+
+/// Plans name licenses freely, so anything that isn't a plain identifier becomes a reference.
+fn spdx_license(licenses: &[String]) -> String {
+    if licenses.is_empty() {
+        return NOASSERTION.to_string();
+    }
+    licenses
+        .iter()
+        .map(|l| if l.chars().all(|c| c.is_alphanumeric() || c == '.' || c == '-' || c == '+') {
+                 l.clone()
+             } else {
+                 let id: String = l.chars()
+                     .map(|c| if c.is_alphanumeric() || c == '.' || c == '-' { c } else { '-' })
+                     .collect();
+                 format!("LicenseRef-{}", id)
+             })
+        .collect::<Vec<String>>()
+        .join(" AND ")
+}
+
+#[derive(Debug, Serialize)]
+struct SpdxDocument {
+    #[serde(rename = "spdxVersion")]
+    spdx_version: &'static str,
+    #[serde(rename = "dataLicense")]
+    data_license: &'static str,
+    #[serde(rename = "SPDXID")]
+    spdx_id: &'static str,
+    name: String,
+    #[serde(rename = "documentNamespace")]
+    document_namespace: String,
+    #[serde(rename = "creationInfo")]
+    creation_info: SpdxCreationInfo,
+    packages: Vec<SpdxPackage>,
+    relationships: Vec<SpdxRelationship>,
+}
+
+#[derive(Debug, Serialize)]
+struct SpdxCreationInfo {
+    created: String,
+    creators: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct SpdxPackage {
+    #[serde(rename = "SPDXID")]
+    spdx_id: String,
+    name: String,
+    #[serde(rename = "versionInfo")]
+    version_info: String,
+    #[serde(rename = "downloadLocation")]
+    download_location: String,
+    #[serde(rename = "homepage", skip_serializing_if = "Option::is_none")]
+    homepage: Option<String>,
+    #[serde(rename = "licenseConcluded")]
+    license_concluded: &'static str,
+    #[serde(rename = "licenseDeclared")]
+    license_declared: String,
+    #[serde(rename = "copyrightText")]
+    copyright_text: &'static str,
+    /// Always false: the SBOM lists packages, not the files in them, so SPDX requires no
+    /// `packageVerificationCode`.
+    #[serde(rename = "filesAnalyzed")]
+    files_analyzed: bool,
+    checksums: Vec<SpdxChecksum>,
+    #[serde(rename = "externalRefs", skip_serializing_if = "Vec::is_empty")]
+    external_refs: Vec<SpdxExternalRef>,
+}
+
+#[derive(Debug, Serialize)]
+struct SpdxChecksum {
+    algorithm: &'static str,
+    #[serde(rename = "checksumValue")]
+    checksum_value: String,
+}
+
+#[derive(Debug, Serialize)]
+struct SpdxExternalRef {
+    #[serde(rename = "referenceCategory")]
+    reference_category: &'static str,
+    #[serde(rename = "referenceType")]
+    reference_type: &'static str,
+    #[serde(rename = "referenceLocator")]
+    reference_locator: String,
+}
+
+#[derive(Debug, Serialize)]
+struct SpdxRelationship {
+    #[serde(rename = "spdxElementId")]
+    spdx_element_id: String,
+    #[serde(rename = "relationshipType")]
+    relationship_type: &'static str,
+    #[serde(rename = "relatedSpdxElement")]
+    related_spdx_element: String,
+}
+
+fn spdx_package(component: &Component) -> SpdxPackage {
+    let ident = &component.ident;
+    let mut checksums = Vec::new();
+    if let Some(ref artifact) = component.artifact {
+        checksums.push(SpdxChecksum {
+                           algorithm: "SHA256",
+                           checksum_value: artifact.sha256.clone(),
+                       });
+        checksums.push(SpdxChecksum {
+                           algorithm: "BLAKE2b-256",
+                           checksum_value: artifact.blake2b.clone(),
+                       });
+    }
+    SpdxPackage {
+        spdx_id: component.spdx_id(),
+        name: format!("{}/{}", ident.origin, ident.name),
+        version_info: format!("{}/{}",
+                              ident.version.as_ref().map_or("", |v| v.as_str()),
+                              ident.release.as_ref().map_or("", |r| r.as_str())),
+        download_location: NOASSERTION.to_string(),
+        homepage: component.upstream_url.clone(),
+        license_concluded: NOASSERTION,
+        license_declared: spdx_license(&component.licenses),
+        copyright_text: NOASSERTION,
+        files_analyzed: false,
+        checksums: checksums,
+        external_refs: vec![SpdxExternalRef {
+                                reference_category: "PACKAGE-MANAGER",
+                                reference_type: "purl",
+                                reference_locator: component.purl(),
+                            }],
+    }
+}
+
+/// The upstream source `component` was built from, if its plan downloaded one.
+fn spdx_source_package(component: &Component) -> Option<SpdxPackage> {
+    component.source.as_ref().map(|source| {
+        SpdxPackage {
+            spdx_id: component.source_spdx_id(),
+            name: format!("{}-source", component.ident.name),
+            version_info: component.ident.version.clone().unwrap_or_default(),
+            download_location: source.clone(),
+            homepage: component.upstream_url.clone(),
+            license_concluded: NOASSERTION,
+            license_declared: spdx_license(&component.licenses),
+            copyright_text: NOASSERTION,
+            files_analyzed: false,
+            checksums: component.source_shasum
+                .iter()
+                .map(|s| {
+                         SpdxChecksum {
+                             algorithm: "SHA256",
+                             checksum_value: s.clone(),
+                         }
+                     })
+                .collect(),
+            external_refs: Vec::new(),
+        }
+    })
+}
+
+fn spdx_document(components: &[Component]) -> SpdxDocument {
+    let root = &components[0];
+    let mut packages = Vec::new();
+    let mut relationships = vec![SpdxRelationship {
+                                     spdx_element_id: String::from("SPDXRef-DOCUMENT"),
+                                     relationship_type: "DESCRIBES",
+                                     related_spdx_element: root.spdx_id(),
+                                 }];
+    for component in components.iter() {
+        packages.push(spdx_package(component));
+        if let Some(source) = spdx_source_package(component) {
+            relationships.push(SpdxRelationship {
+                                   spdx_element_id: component.spdx_id(),
+                                   relationship_type: "GENERATED_FROM",
+                                   related_spdx_element: source.spdx_id.clone(),
+                               });
+            packages.push(source);
+        }
+        for dep in component.deps.iter().flat_map(|d| d.iter()) {
+            relationships.push(SpdxRelationship {
+                                   spdx_element_id: component.spdx_id(),
+                                   relationship_type: "DEPENDS_ON",
+                                   related_spdx_element: spdx_id(dep),
+                               });
+        }
+    }
+    SpdxDocument {
+        spdx_version: "SPDX-2.3",
+        data_license: "CC0-1.0",
+        spdx_id: "SPDXRef-DOCUMENT",
+        name: root.ident.to_string(),
+        document_namespace: format!("https://habitat.sh/spdx/{}-{}",
+                                    root.ident,
+                                    Uuid::new_v4()),
+        creation_info: SpdxCreationInfo {
+            created: time::now_utc().rfc3339().to_string(),
+            creators: vec![format!("Tool: {}-{}", PRODUCT, VERSION)],
+        },
+        packages: packages,
+        relationships: relationships,
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct CycloneDxBom {
+    #[serde(rename = "bomFormat")]
+    bom_format: &'static str,
+    #[serde(rename = "specVersion")]
+    spec_version: &'static str,
+    #[serde(rename = "serialNumber")]
+    serial_number: String,
+    version: u32,
+    metadata: CycloneDxMetadata,
+    components: Vec<CycloneDxComponent>,
+    dependencies: Vec<CycloneDxDependency>,
+}
+
+#[derive(Debug, Serialize)]
+struct CycloneDxMetadata {
+    timestamp: String,
+    tools: Vec<CycloneDxTool>,
+    component: CycloneDxComponent,
+}
+
+#[derive(Debug, Serialize)]
+struct CycloneDxTool {
+    vendor: &'static str,
+    name: &'static str,
+    version: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+struct CycloneDxComponent {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    #[serde(rename = "bom-ref")]
+    bom_ref: String,
+    group: String,
+    name: String,
+    version: String,
+    purl: String,
+    licenses: Vec<CycloneDxLicenseChoice>,
+    hashes: Vec<CycloneDxHash>,
+    #[serde(rename = "externalReferences")]
+    external_references: Vec<CycloneDxReference>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pedigree: Option<CycloneDxPedigree>,
+}
+
+/// Records the upstream source a package was built from as its ancestor.
+#[derive(Debug, Serialize)]
+struct CycloneDxPedigree {
+    ancestors: Vec<CycloneDxSource>,
+}
+
+#[derive(Debug, Serialize)]
+struct CycloneDxSource {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    name: String,
+    version: String,
+    hashes: Vec<CycloneDxHash>,
+    #[serde(rename = "externalReferences")]
+    external_references: Vec<CycloneDxReference>,
+}
+
+#[derive(Debug, Serialize)]
+struct CycloneDxLicenseChoice {
+    license: CycloneDxLicense,
+}
+
+#[derive(Debug, Serialize)]
+struct CycloneDxLicense {
+    name: String,
+}
+
+#[derive(Debug, Serialize)]
+struct CycloneDxHash {
+    alg: &'static str,
+    content: String,
+}
+
+#[derive(Debug, Serialize)]
+struct CycloneDxReference {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    url: String,
+}
+
+#[derive(Debug, Serialize)]
+struct CycloneDxDependency {
+    #[serde(rename = "ref")]
+    reference: String,
+    #[serde(rename = "dependsOn")]
+    depends_on: Vec<String>,
+}
+
+fn cyclonedx_component(component: &Component, kind: &'static str) -> CycloneDxComponent {
+    let mut references = Vec::new();
+    if let Some(ref url) = component.upstream_url {
+        references.push(CycloneDxReference {
+                            kind: "website",
+                            url: url.clone(),
+                        });
+    }
+    CycloneDxComponent {
+        kind: kind,
+        bom_ref: component.purl(),
+        group: component.ident.origin.clone(),
+        name: component.ident.name.clone(),
+        version: component.ident.version.clone().unwrap_or_default(),
+        purl: component.purl(),
+        licenses: component
+            .licenses
+            .iter()
+            .map(|l| CycloneDxLicenseChoice { license: CycloneDxLicense { name: l.clone() } })
+            .collect(),
+        hashes: component
+            .artifact
+            .iter()
+            .flat_map(|a| {
+                          vec![CycloneDxHash {
+                                   alg: "SHA-256",
+                                   content: a.sha256.clone(),
+                               },
+                               CycloneDxHash {
+                                   alg: "BLAKE2b-256",
+                                   content: a.blake2b.clone(),
+                               }]
+                      })
+            .collect(),
+        external_references: references,
+        pedigree: component.source.as_ref().map(|source| {
+            CycloneDxPedigree {
+                ancestors: vec![CycloneDxSource {
+                                    kind: "file",
+                                    name: format!("{}-source", component.ident.name),
+                                    version: component.ident.version.clone().unwrap_or_default(),
+                                    hashes: component.source_shasum
+                                        .iter()
+                                        .map(|s| {
+                                                 CycloneDxHash {
+                                                     alg: "SHA-256",
+                                                     content: s.clone(),
+                                                 }
+                                             })
+                                        .collect(),
+                                    external_references: vec![CycloneDxReference {
+                                                                  kind: "distribution",
+                                                                  url: source.clone(),
+                                                              }],
+                                }],
+            }
+        }),
+    }
+}
+
+fn cyclonedx_bom(components: &[Component]) -> CycloneDxBom {
+    CycloneDxBom {
+        bom_format: "CycloneDX",
+        spec_version: "1.4",
+        serial_number: format!("urn:uuid:{}", Uuid::new_v4()),
+        version: 1,
+        metadata: CycloneDxMetadata {
+            timestamp: time::now_utc().rfc3339().to_string(),
+            tools: vec![CycloneDxTool {
+                            vendor: "Habitat",
+                            name: PRODUCT,
+                            version: VERSION,
+                        }],
+            component: cyclonedx_component(&components[0], "application"),
+        },
+        components: components[1..]
+            .iter()
+            .map(|c| cyclonedx_component(c, "library"))
+            .collect(),
+        dependencies: components
+            .iter()
+            .filter_map(|c| {
+                c.deps
+                    .as_ref()
+                    .map(|deps| {
+                             CycloneDxDependency {
+                                 reference: c.purl(),
+                                 depends_on: deps.iter()
+                                     .map(|d| Component::unknown(d.clone()).purl())
+                                     .collect(),
+                             }
+                         })
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::File;
+    use std::str::FromStr;
+
+    use hcore::package::{PackageIdent, PackageTarget};
+    use tempdir::TempDir;
+
+    use serde_json::{self, Value};
+
+    use super::{cached_artifact, cyclonedx_bom, spdx_document, spdx_license, ArtifactHashes,
+                Component};
+
+    const SHASUM: &'static str = "2ad042c5a6c508223adeb9f6af5c3a2e0b0b9ed7a2d2ebf7ba2b6f0b8b3f5d2a";
+
+    const MANIFEST: &'static str = "# core / redis
+Persistent key-value database, with built-in net interface
+
+* __Maintainer__: The Habitat Maintainers <humans@habitat.sh>
+* __Version__: 3.2.4
+* __Release__: 20170103160441
+* __Upstream URL__: [http://redis.io](http://redis.io)
+* __License__: BSD-3-Clause
+* __Source__: [http://download.redis.io/releases/redis-3.2.4.tar.gz](http://download.redis.io/releases/redis-3.2.4.tar.gz)
+* __SHA__: `2ad042c5a6c508223adeb9f6af5c3a2e0b0b9ed7a2d2ebf7ba2b6f0b8b3f5d2a`
+* __Build Dependencies__: no build dependencies or undefined
+";
+
+    #[test]
+    fn component_reads_the_manifest() {
+        let ident = PackageIdent::from_str("core/redis/3.2.4/20170103160441").unwrap();
+        let component = Component::new(ident, MANIFEST, vec![]);
+        assert_eq!(component.licenses, vec![String::from("BSD-3-Clause")]);
+        assert_eq!(component.source,
+                   Some(String::from("http://download.redis.io/releases/redis-3.2.4.tar.gz")));
+        assert_eq!(component.upstream_url, Some(String::from("http://redis.io")));
+        assert_eq!(component.source_shasum, Some(String::from(SHASUM)));
+        assert_eq!(component.purl(),
+                   "pkg:generic/core/redis@3.2.4?release=20170103160441");
+    }
+
+    #[test]
+    fn component_without_recorded_source() {
+        let manifest = "* __Source__: source URL not provided or required\n\
+                        * __SHA__: SHA256 checksum not provided or required\n";
+        let ident = PackageIdent::from_str("core/hab-sup/0.20.0/20170326090935").unwrap();
+        let component = Component::new(ident, manifest, vec![]);
+        assert_eq!(component.source, None);
+        assert_eq!(component.source_shasum, None);
+    }
+
+    #[test]
+    fn cached_artifact_falls_back_to_compatible_targets() {
+        let cache = TempDir::new("sbom").unwrap();
+        let ident = PackageIdent::from_str("core/redis/3.2.4/20170103160441").unwrap();
+        assert_eq!(cached_artifact(&ident, cache.path()), None);
+
+        let target = PackageTarget::default().compatible_targets().pop().unwrap();
+        let path = cache.path().join(ident.archive_name_with_target(&target).unwrap());
+        File::create(&path).unwrap();
+        assert_eq!(cached_artifact(&ident, cache.path()), Some(path));
+    }
+
+    #[test]
+    fn spdx_license_references_free_form_names() {
+        assert_eq!(spdx_license(&[]), "NOASSERTION");
+        assert_eq!(spdx_license(&[String::from("Apache-2.0"), String::from("GPLv2+")]),
+                   "Apache-2.0 AND GPLv2+");
+        assert_eq!(spdx_license(&[String::from("Public Domain")]),
+                   "LicenseRef-Public-Domain");
+    }
+
+    #[test]
+    fn component_ignores_a_shasum_which_isnt_sha256() {
+        let manifest = "* __SHA__: `2ad042c5a6c508223adeb9f6af5c3a2e0b0b9ed7`\n";
+        let ident = PackageIdent::from_str("core/redis/3.2.4/20170103160441").unwrap();
+        assert_eq!(Component::new(ident, manifest, vec![]).source_shasum, None);
+    }
+
+    fn redis() -> Component {
+        let ident = PackageIdent::from_str("core/redis/3.2.4/20170103160441").unwrap();
+        let mut component = Component::new(ident, MANIFEST, vec![]);
+        component.artifact = Some(ArtifactHashes {
+                                      blake2b: String::from("b2"),
+                                      sha256: String::from("s256"),
+                                  });
+        component
+    }
+
+    #[test]
+    fn spdx_lists_the_source_as_a_package_of_its_own() {
+        let document = serde_json::to_value(&spdx_document(&[redis()])).unwrap();
+        let packages = document["packages"].as_array().unwrap();
+        assert_eq!(packages.len(), 2);
+        for package in packages.iter() {
+            assert_eq!(package["filesAnalyzed"], Value::Bool(false));
+            assert!(package.get("packageVerificationCode").is_none());
+        }
+        let package = &packages[0];
+        assert_eq!(package["downloadLocation"], "NOASSERTION");
+        assert_eq!(package["checksums"][0]["algorithm"], "SHA256");
+        assert_eq!(package["checksums"][0]["checksumValue"], "s256");
+        assert_eq!(package["checksums"][1]["algorithm"], "BLAKE2b-256");
+        assert_eq!(package["checksums"][1]["checksumValue"], "b2");
+        let source = &packages[1];
+        assert_eq!(source["downloadLocation"],
+                   "http://download.redis.io/releases/redis-3.2.4.tar.gz");
+        assert_eq!(source["checksums"][0]["checksumValue"], SHASUM);
+        let generated = document["relationships"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|r| r["relationshipType"] == "GENERATED_FROM")
+            .cloned()
+            .unwrap();
+        assert_eq!(generated["spdxElementId"], package["SPDXID"]);
+        assert_eq!(generated["relatedSpdxElement"], source["SPDXID"]);
+    }
+
+    #[test]
+    fn cyclonedx_lists_the_source_as_an_ancestor() {
+        let bom = serde_json::to_value(&cyclonedx_bom(&[redis()])).unwrap();
+        let component = &bom["metadata"]["component"];
+        assert_eq!(component["hashes"][0]["content"], "s256");
+        assert_eq!(component["hashes"][1]["alg"], "BLAKE2b-256");
+        let ancestor = &component["pedigree"]["ancestors"][0];
+        assert_eq!(ancestor["hashes"][0]["alg"], "SHA-256");
+        assert_eq!(ancestor["hashes"][0]["content"], SHASUM);
+        assert_eq!(ancestor["externalReferences"][0]["url"],
+                   "http://download.redis.io/releases/redis-3.2.4.tar.gz");
+    }
+}
@@ -12,28 +12,43 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use error::Result;
+use common::ui::UI;
 use depot_client::Client;
+use hcore::package::PackageIdent;
+
+use error::Result;
 use {PRODUCT, VERSION};
 
-pub fn start(st: &str, url: &str) -> Result<()> {
+#[derive(Debug, Serialize)]
+struct SearchResults {
+    packages: Vec<PackageIdent>,
+    /// Whether the Depot found more packages than it returned.
+    truncated: bool,
+}
+
+pub fn start(ui: &mut UI, st: &str, url: &str) -> Result<()> {
     let depot_client = try!(Client::new(url, PRODUCT, VERSION, None));
     let (packages, more) = try!(depot_client.search_package(st.to_string()));
+    let mut lines = Vec::new();
     match packages.len() {
-        0 => println!("No packages found that match '{}'", st),
+        0 => lines.push(format!("No packages found that match '{}'", st)),
         _ => {
             for p in &packages {
                 if let (&Some(ref version), &Some(ref release)) = (&p.version, &p.release) {
-                    println!("{}/{}/{}/{}", p.origin, p.name, version, release);
+                    lines.push(format!("{}/{}/{}/{}", p.origin, p.name, version, release));
                 } else {
-                    println!("{}/{}", p.origin, p.name);
+                    lines.push(format!("{}/{}", p.origin, p.name));
                 }
             }
             if more {
-                println!("Search returned too many items, only showing the first {}",
-                         packages.len());
+                lines.push(format!("Search returned too many items, only showing the first {}",
+                                   packages.len()));
             }
         }
     }
-    Ok(())
+    let results = SearchResults {
+        packages: packages,
+        truncated: more,
+    };
+    Ok(try!(ui.result(&results, &lines)))
 }
@@ -30,3 +30,15 @@ pub fn start(ui: &mut UI, origin: &SigKeyPair, src: &Path, dst: &Path) -> Result
     try!(ui.end(format!("Signed artifact {}.", dst.display())));
     Ok(())
 }
+
+pub fn countersign(ui: &mut UI, pair: &SigKeyPair, src: &Path, dst: &Path) -> Result<()> {
+    try!(ui.begin(format!("Counter-signing {}", src.display())));
+    try!(ui.status(Status::Signing,
+                   format!("{} with {} to create {}",
+                           src.display(),
+                           &pair.name_with_rev(),
+                           dst.display())));
+    try!(artifact::countersign(src, dst, pair));
+    try!(ui.end(format!("Counter-signed artifact {}.", dst.display())));
+    Ok(())
+}
@@ -23,6 +23,12 @@
 //!
 //! Will upload a package to the Depot.
 //!
+//! ```bash
+//! $ hab pkg upload /path/to/acme-redis-2.0.7-2112010203120101-x86_64-linux.hart -c stable
+//! ```
+//!
+//! Will also promote it to the `stable` channel of its origin.
+//!
 //! # Notes
 //!
 //! This should be extended to cover uploading specific packages, and finding them by ways more
@@ -56,7 +62,8 @@ pub fn start<P: AsRef<Path>>(ui: &mut UI,
                              url: &str,
                              token: &str,
                              archive_path: &P,
-                             key_path: &P)
+                             key_path: &P,
+                             channel: Option<&str>)
                              -> Result<()> {
     let mut archive = PackageArchive::new(PathBuf::from(archive_path.as_ref()));
 
@@ -116,10 +123,7 @@ pub fn start<P: AsRef<Path>>(ui: &mut UI,
     }
     let ident = try!(archive.ident());
     match depot_client.show_package(&ident) {
-        Ok(_) => {
-            try!(ui.status(Status::Using, format!("existing {}", &ident)));
-            Ok(())
-        }
+        Ok(_) => try!(ui.status(Status::Using, format!("existing {}", &ident))),
         Err(depot_client::Error::APIError(StatusCode::NotFound, _)) => {
             if retry(RETRIES,
                      RETRY_WAIT,
@@ -136,10 +140,15 @@ pub fn start<P: AsRef<Path>>(ui: &mut UI,
                                                                                  &ident))));
             }
             try!(ui.end(format!("Upload of {} complete.", &ident)));
-            Ok(())
         }
-        Err(e) => Err(Error::from(e)),
+        Err(e) => return Err(Error::from(e)),
+    }
+    if let Some(channel) = channel {
+        try!(depot_client.promote_package(&mut archive, channel, token));
+        try!(ui.status(Status::Custom('✓', String::from("Promoted")),
+                       format!("{} to the {} channel", &ident, channel)));
     }
+    Ok(())
 }
 
 fn upload_into_depot(ui: &mut UI,
@@ -152,12 +161,15 @@ fn upload_into_depot(ui: &mut UI,
     match depot_client.put_package(&mut archive, token, ui.progress()) {
         Ok(_) => (),
         Err(depot_client::Error::APIError(StatusCode::Conflict, _)) => {
-            println!("Package already exists on remote; skipping.");
+            try!(ui.warn("Package already exists on remote; skipping."));
         }
         Err(depot_client::Error::APIError(StatusCode::UnprocessableEntity, _)) => {
             return Err(Error::PackageArchiveMalformed(format!("{}", archive.path.display())));
         }
-        Err(depot_client::Error::APIError(StatusCode::NotImplemented, _)) => println!("Package platform or architecture not supported by the targted depot; skipping."),
+        Err(depot_client::Error::APIError(StatusCode::NotImplemented, _)) => {
+            try!(ui.warn("Package platform or architecture not supported by the targted depot; \
+                          skipping."));
+        }
         Err(e) => return Err(Error::from(e)),
     };
     try!(ui.status(Status::Uploaded, ident));
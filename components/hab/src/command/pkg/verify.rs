@@ -19,11 +19,11 @@ use hcore::crypto::artifact;
 
 use error::Result;
 
-pub fn start(ui: &mut UI, src: &Path, cache: &Path) -> Result<()> {
+pub fn start(ui: &mut UI, src: &Path, cache: &Path, required: &[String]) -> Result<()> {
     try!(ui.begin(format!("Verifying artifact {}", &src.display())));
-    let (name_with_rev, hash) = try!(artifact::verify(src, cache));
+    let (signers, hash) = try!(artifact::verify_signers(src, cache, required));
     try!(ui.status(Status::Verified,
-                   format!("checksum {} signed with {}", &hash, &name_with_rev)));
+                   format!("checksum {} signed with {}", &hash, signers.join(", "))));
     try!(ui.end(format!("Verified artifact {}.", &src.display())));
     Ok(())
 }
@@ -0,0 +1,729 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Checks a plan for mistakes before it is built.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab plan lint
+//! $ hab plan lint habitat --bind database=core/postgresql --format json
+//! ```
+//!
+//! The variables and callbacks of `plan.sh` are read without running it, so values made from
+//! other variables or commands are checked as written. The checks are:
+//!
+//! * `pkg_name` and `pkg_origin` are set
+//! * `pkg_license` is set, and a `pkg_source` has a `pkg_shasum` which isn't a placeholder,
+//!   unless the plan downloads its source itself in `do_download`
+//! * every `pkg_exports` value is a key of `default.toml`, and every `pkg_exposes` entry is a key
+//!   of `pkg_exports`
+//! * every export a bind wants is exported by the bound package, when that package is known: a
+//!   dependency with the same name as the bind, or one given with `--bind`, installed locally
+//! * the `cfg` keys used by the templates in `hooks/` and `config/` are set in `default.toml`,
+//!   and their `bind` names are binds of the plan. A `cfg` key used only within an `{{#if}}` or
+//!   `{{#unless}}` block may be left to the user, so it is a warning
+//! * callbacks named like build phases are build phases
+//!
+//! Each problem is printed as `path:line: severity: message [rule]`, or as JSON, and the command
+//! fails if any of them is an error.
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Read;
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use common::ui::UI;
+use hcore::package::{PackageIdent, PackageInstall};
+use regex::Regex;
+use toml;
+use walkdir::WalkDir;
+
+use error::{Error, Result};
+
+const ERROR: &'static str = "error";
+const WARNING: &'static str = "warning";
+
+/// The callbacks run by `hab-plan-build`, in the order of the build phases.
+const CALLBACKS: &'static [&'static str] = &["do_begin",
+                                             "do_setup_environment",
+                                             "do_download",
+                                             "do_verify",
+                                             "do_clean",
+                                             "do_unpack",
+                                             "do_prepare",
+                                             "do_build",
+                                             "do_check",
+                                             "do_install",
+                                             "do_strip",
+                                             "do_end"];
+
+/// The directories of templates rendered by the Supervisor.
+const TEMPLATE_DIRS: &'static [&'static str] = &["hooks", "config"];
+
+#[derive(Debug, Serialize)]
+struct Report {
+    plan: String,
+    errors: usize,
+    warnings: usize,
+    findings: Vec<Finding>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct Finding {
+    rule: &'static str,
+    severity: &'static str,
+    path: String,
+    line: Option<usize>,
+    message: String,
+}
+
+#[derive(Debug, PartialEq)]
+enum Value {
+    Scalar(String),
+    Array(Vec<String>),
+    Map(BTreeMap<String, String>),
+}
+
+impl Value {
+    fn is_empty(&self) -> bool {
+        match *self {
+            Value::Scalar(ref s) => s.is_empty(),
+            Value::Array(ref a) => a.is_empty(),
+            Value::Map(ref m) => m.is_empty(),
+        }
+    }
+
+    fn items(&self) -> Vec<String> {
+        match *self {
+            Value::Scalar(ref s) => vec![s.clone()],
+            Value::Array(ref a) => a.clone(),
+            Value::Map(ref m) => m.values().cloned().collect(),
+        }
+    }
+}
+
+/// The variables of a plan, with the line setting each of them, and its callbacks.
+#[derive(Debug, Default)]
+struct Plan {
+    vars: BTreeMap<String, (usize, Value)>,
+    callbacks: BTreeMap<String, usize>,
+}
+
+pub fn start(ui: &mut UI,
+             plan_context: &Path,
+             fs_root_path: &Path,
+             bind_pkgs: &[(String, PackageIdent)])
+             -> Result<()> {
+    let plan_dir = if plan_context.join("plan.sh").is_file() {
+        plan_context.to_path_buf()
+    } else if plan_context.join("habitat").join("plan.sh").is_file() {
+        plan_context.join("habitat")
+    } else {
+        let missing = plan_context.join("plan.sh");
+        return Err(Error::FileNotFound(missing.to_string_lossy().into_owned()));
+    };
+    let plan_path = plan_dir.join("plan.sh");
+    let plan = parse_plan(&try!(read_file(&plan_path)));
+
+    let mut findings = Vec::new();
+    let default_toml_path = plan_dir.join("default.toml");
+    let default_toml = if default_toml_path.is_file() {
+        match toml::from_str(&try!(read_file(&default_toml_path))) {
+            Ok(value) => Some(value),
+            Err(e) => {
+                findings.push(finding("invalid-default-toml",
+                                      ERROR,
+                                      &default_toml_path,
+                                      None,
+                                      format!("default.toml can't be read: {}", e)));
+                None
+            }
+        }
+    } else {
+        Some(toml::Value::Table(BTreeMap::new()))
+    };
+
+    let mut templates = Vec::new();
+    for dir in TEMPLATE_DIRS.iter() {
+        for entry in WalkDir::new(plan_dir.join(dir)).into_iter().filter_map(|e| e.ok()) {
+            if entry.file_type().is_file() {
+                // Templates which aren't text can't use any keys.
+                if let Ok(content) = read_file(entry.path()) {
+                    templates.push((entry.path().to_path_buf(), content));
+                }
+            }
+        }
+    }
+    templates.sort();
+
+    let mut bound_exports = BTreeMap::new();
+    for dep in plan.items("pkg_deps").iter().chain(plan.items("pkg_build_deps").iter()) {
+        if let Ok(ident) = PackageIdent::from_str(dep) {
+            if let Ok(pkg) = PackageInstall::load(&ident, Some(fs_root_path)) {
+                bound_exports.insert(ident.name.clone(), try!(exported(&pkg)));
+            }
+        }
+    }
+    for &(ref bind, ref ident) in bind_pkgs.iter() {
+        let pkg = try!(PackageInstall::load(ident, Some(fs_root_path)));
+        bound_exports.insert(bind.clone(), try!(exported(&pkg)));
+    }
+
+    findings.extend(lint(&plan_path,
+                         &plan,
+                         default_toml.as_ref(),
+                         &templates,
+                         &bound_exports));
+    let errors = findings.iter().filter(|f| f.severity == ERROR).count();
+    let warnings = findings.len() - errors;
+
+    let mut lines: Vec<String> = findings.iter()
+        .map(|f| match f.line {
+                 Some(line) => {
+                     format!("{}:{}: {}: {} [{}]", f.path, line, f.severity, f.message, f.rule)
+                 }
+                 None => format!("{}: {}: {} [{}]", f.path, f.severity, f.message, f.rule),
+             })
+        .collect();
+    lines.push(format!("{} errors, {} warnings in {}", errors, warnings, plan_path.display()));
+    let report = Report {
+        plan: plan_path.display().to_string(),
+        errors: errors,
+        warnings: warnings,
+        findings: findings,
+    };
+    try!(ui.result(&report, &lines));
+    if errors > 0 {
+        return Err(Error::PlanLintFailed(errors));
+    }
+    Ok(())
+}
+
+impl Plan {
+    fn get(&self, name: &str) -> Option<&(usize, Value)> {
+        match self.vars.get(name) {
+            Some(&(_, ref value)) if value.is_empty() => None,
+            var => var,
+        }
+    }
+
+    fn items(&self, name: &str) -> Vec<String> {
+        self.vars.get(name).map(|&(_, ref value)| value.items()).unwrap_or_default()
+    }
+}
+
+/// Reads the variables set and the functions defined at the top level of `plan.sh`.
+fn parse_plan(content: &str) -> Plan {
+    let mut plan = Plan::default();
+    let mut in_function = false;
+    let mut lines = content.lines().enumerate();
+    while let Some((i, line)) = lines.next() {
+        let trimmed = line.trim();
+        if in_function {
+            // Plans close their functions at the start of a line.
+            in_function = !line.starts_with('}');
+            continue;
+        }
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if let Some(name) = function_name(trimmed) {
+            plan.callbacks.insert(name, i + 1);
+            in_function = !trimmed.ends_with('}');
+            continue;
+        }
+        let (name, raw) = match trimmed.find('=') {
+            Some(eq) if is_identifier(&trimmed[..eq]) => (&trimmed[..eq], &trimmed[eq + 1..]),
+            _ => continue,
+        };
+        let value = if raw.starts_with('(') {
+            let mut raw = String::from(&raw[1..]);
+            let (mut words, mut closed) = shell_words(&raw);
+            while !closed {
+                match lines.next() {
+                    Some((_, next)) => {
+                        raw.push('\n');
+                        raw.push_str(next);
+                        let (w, c) = shell_words(&raw);
+                        words = w;
+                        closed = c;
+                    }
+                    None => break,
+                }
+            }
+            array_value(words)
+        } else {
+            Value::Scalar(shell_words(raw).0.into_iter().next().unwrap_or_default())
+        };
+        plan.vars.insert(name.to_string(), (i + 1, value));
+    }
+    plan
+}
+
+fn lint(plan_path: &Path,
+        plan: &Plan,
+        default_toml: Option<&toml::Value>,
+        templates: &[(PathBuf, String)],
+        bound_exports: &BTreeMap<String, (String, Vec<String>)>)
+        -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let line_of = |name: &str| plan.vars.get(name).map(|&(line, _)| line);
+
+    for name in ["pkg_name", "pkg_origin"].iter().cloned() {
+        if plan.get(name).is_none() {
+            findings.push(finding("required-variable",
+                                  ERROR,
+                                  plan_path,
+                                  line_of(name),
+                                  format!("{} must be set", name)));
+        }
+    }
+    if plan.get("pkg_license").is_none() {
+        findings.push(finding("missing-license",
+                              ERROR,
+                              plan_path,
+                              line_of("pkg_license"),
+                              String::from("pkg_license must name the licenses of the software")));
+    }
+    if let Some(&(source_line, _)) = plan.get("pkg_source") {
+        match plan.get("pkg_shasum") {
+            Some(&(line, Value::Scalar(ref shasum))) if shasum == "TODO" => {
+                findings.push(finding("missing-shasum",
+                                      ERROR,
+                                      plan_path,
+                                      Some(line),
+                                      String::from("pkg_shasum is still a placeholder")));
+            }
+            Some(&(line, Value::Scalar(ref shasum))) => {
+                if !shasum.contains('$') &&
+                   (shasum.len() != 64 || !shasum.chars().all(|c| c.is_digit(16))) {
+                    findings.push(finding("invalid-shasum",
+                                          ERROR,
+                                          plan_path,
+                                          Some(line),
+                                          format!("pkg_shasum must be the SHA-256 sum of \
+                                                   pkg_source, not {}",
+                                                  shasum)));
+                }
+            }
+            _ if plan.callbacks.contains_key("do_download") => (),
+            _ => {
+                findings.push(finding("missing-shasum",
+                                      ERROR,
+                                      plan_path,
+                                      Some(source_line),
+                                      String::from("pkg_source is set but pkg_shasum is not")));
+            }
+        }
+    }
+
+    let no_exports = BTreeMap::new();
+    let exports = match plan.get("pkg_exports") {
+        Some(&(_, Value::Map(ref exports))) => exports,
+        _ => &no_exports,
+    };
+    if let Some(cfg) = default_toml {
+        for (key, cfg_key) in exports.iter() {
+            if !has_key(cfg, cfg_key) {
+                findings.push(finding("export-not-in-default-toml",
+                                      ERROR,
+                                      plan_path,
+                                      line_of("pkg_exports"),
+                                      format!("pkg_exports[{}] is {}, which default.toml \
+                                               doesn't set",
+                                              key,
+                                              cfg_key)));
+            }
+        }
+    }
+    for expose in plan.items("pkg_exposes") {
+        if !exports.contains_key(&expose) {
+            findings.push(finding("expose-unknown-export",
+                                  ERROR,
+                                  plan_path,
+                                  line_of("pkg_exposes"),
+                                  format!("pkg_exposes has {}, which isn't a key of \
+                                           pkg_exports",
+                                          expose)));
+        }
+    }
+
+    let mut binds = Vec::new();
+    for var in &["pkg_binds", "pkg_binds_optional"] {
+        if let Some(&(line, Value::Map(ref wanted))) = plan.get(var) {
+            for (bind, keys) in wanted.iter() {
+                binds.push(bind.clone());
+                let &(ref ident, ref exported) = match bound_exports.get(bind) {
+                    Some(bound) => bound,
+                    None => continue,
+                };
+                for key in keys.split_whitespace().filter(|k| !exported.iter().any(|e| e == k)) {
+                    findings.push(finding("bind-unknown-export",
+                                          ERROR,
+                                          plan_path,
+                                          Some(line),
+                                          format!("{}[{}] wants {}, which {} doesn't export",
+                                                  var,
+                                                  bind,
+                                                  key,
+                                                  ident)));
+                }
+            }
+        }
+    }
+
+    let mustache = Regex::new(r"\{\{[^}]*\}\}").unwrap();
+    let key = Regex::new(r"\b(cfg|bind)((?:\.[A-Za-z0-9_-]+)+)").unwrap();
+    for &(ref path, ref content) in templates.iter() {
+        // The block helpers open at each expression, to tell conditional keys from others.
+        let mut blocks: Vec<String> = Vec::new();
+        for (i, line) in content.lines().enumerate() {
+            for expr in mustache.find_iter(line) {
+                let expr = &line[expr.start()..expr.end()];
+                let inner = expr.trim_matches(|c| c == '{' || c == '}' || c == '~').trim();
+                if inner.starts_with('#') {
+                    blocks.push(inner[1..].split_whitespace().next().unwrap_or("").to_string());
+                } else if inner.starts_with('/') {
+                    blocks.pop();
+                }
+                let conditional = blocks.iter().any(|b| b == "if" || b == "unless");
+                for caps in key.captures_iter(expr) {
+                    let path_in = &caps[2][1..];
+                    if &caps[1] == "cfg" {
+                        if default_toml.map_or(true, |cfg| has_key(cfg, path_in)) {
+                            continue;
+                        }
+                        if conditional {
+                            findings.push(finding("template-conditional-key",
+                                                  WARNING,
+                                                  path,
+                                                  Some(i + 1),
+                                                  format!("cfg.{} isn't set in default.toml, \
+                                                           so it's only used if the user sets \
+                                                           it",
+                                                          path_in)));
+                            continue;
+                        }
+                        findings.push(finding("template-undefined-key",
+                                              ERROR,
+                                              path,
+                                              Some(i + 1),
+                                              format!("cfg.{} isn't set in default.toml",
+                                                      path_in)));
+                    } else {
+                        let bind = path_in.split('.').next().unwrap();
+                        if binds.iter().any(|b| b == bind) {
+                            continue;
+                        }
+                        findings.push(finding("template-undefined-key",
+                                              ERROR,
+                                              path,
+                                              Some(i + 1),
+                                              format!("bind.{} isn't in pkg_binds or \
+                                                       pkg_binds_optional",
+                                                      bind)));
+                    }
+                }
+            }
+        }
+    }
+
+    for (name, line) in plan.callbacks.iter() {
+        if name.starts_with("do_") && !name.starts_with("do_default_") &&
+           !CALLBACKS.iter().any(|c| c == name) {
+            findings.push(finding("unknown-callback",
+                                  WARNING,
+                                  plan_path,
+                                  Some(*line),
+                                  format!("{} isn't a build phase callback, so it is only run \
+                                           if the plan calls it",
+                                          name)));
+        }
+    }
+    findings
+}
+
+fn finding(rule: &'static str,
+           severity: &'static str,
+           path: &Path,
+           line: Option<usize>,
+           message: String)
+           -> Finding {
+    Finding {
+        rule: rule,
+        severity: severity,
+        path: path.display().to_string(),
+        line: line,
+        message: message,
+    }
+}
+
+/// Returns the names of the exports of `pkg`.
+fn exported(pkg: &PackageInstall) -> Result<(String, Vec<String>)> {
+    let mut keys: Vec<String> = try!(pkg.exports()).into_iter().map(|(k, _)| k).collect();
+    keys.sort();
+    Ok((pkg.ident().to_string(), keys))
+}
+
+/// Returns whether the dotted `key` is set in the TOML `value`.
+fn has_key(value: &toml::Value, key: &str) -> bool {
+    let mut value = value;
+    for part in key.split('.') {
+        let next = match *value {
+            toml::Value::Table(ref table) => table.get(part),
+            toml::Value::Array(ref array) => part.parse::<usize>().ok().and_then(|i| array.get(i)),
+            _ => None,
+        };
+        value = match next {
+            Some(next) => next,
+            None => return false,
+        };
+    }
+    true
+}
+
+/// Returns the name of the function whose definition starts `line`.
+fn function_name(line: &str) -> Option<String> {
+    let (keyword, line) = if line.starts_with("function ") {
+        (true, line["function ".len()..].trim_left())
+    } else {
+        (false, line)
+    };
+    let end = match line.find("()") {
+        Some(end) => end,
+        None if keyword => {
+            line.find(|c: char| c.is_whitespace() || c == '{').unwrap_or(line.len())
+        }
+        None => return None,
+    };
+    let name = line[..end].trim();
+    if is_identifier(name) {
+        Some(name.to_string())
+    } else {
+        None
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    !name.is_empty() && !name.starts_with(|c: char| c.is_digit(10)) &&
+    name.chars().all(|c| c == '_' || c.is_alphanumeric())
+}
+
+/// Makes the value of an array from its words, which is associative if its words are all
+/// `[key]=value`.
+fn array_value(words: Vec<String>) -> Value {
+    let mut map = BTreeMap::new();
+    for word in words.iter() {
+        match word.find("]=") {
+            Some(end) if word.starts_with('[') => {
+                map.insert(word[1..end].to_string(), word[end + 2..].to_string());
+            }
+            _ => return Value::Array(words),
+        }
+    }
+    if map.is_empty() {
+        Value::Array(words)
+    } else {
+        Value::Map(map)
+    }
+}
+
+/// Splits `s` into words the way the shell would, without their quotes and leaving out comments,
+/// up to an unquoted `)`. Returns the words and whether the `)` was found.
+fn shell_words(s: &str) -> (Vec<String>, bool) {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut in_word = false;
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                in_word = true;
+                while let Some(c) = chars.next() {
+                    if c == '\'' {
+                        break;
+                    }
+                    word.push(c);
+                }
+            }
+            '"' => {
+                in_word = true;
+                while let Some(c) = chars.next() {
+                    match c {
+                        '"' => break,
+                        '\\' => word.extend(chars.next()),
+                        _ => word.push(c),
+                    }
+                }
+            }
+            '\\' => {
+                in_word = true;
+                match chars.next() {
+                    Some('\n') | None => {}
+                    Some(c) => word.push(c),
+                }
+            }
+            '#' if !in_word => {
+                while chars.peek().map_or(false, |&c| c != '\n') {
+                    chars.next();
+                }
+            }
+            ')' => {
+                if in_word {
+                    words.push(word);
+                }
+                return (words, true);
+            }
+            c if c.is_whitespace() => {
+                if in_word {
+                    words.push(mem::replace(&mut word, String::new()));
+                    in_word = false;
+                }
+            }
+            c => {
+                in_word = true;
+                word.push(c);
+            }
+        }
+    }
+    if in_word {
+        words.push(word);
+    }
+    (words, false)
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    let mut content = String::new();
+    try!(try!(File::open(path)).read_to_string(&mut content));
+    Ok(content)
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+    use std::path::{Path, PathBuf};
+
+    use toml;
+
+    use super::{lint, parse_plan, Value};
+
+    const PLAN: &'static str = r#"pkg_name=redis
+pkg_origin=core
+pkg_version="3.2.4"
+pkg_license=('BSD-3-Clause')
+pkg_source="http://download.redis.io/releases/${pkg_name}-${pkg_version}.tar.gz"
+pkg_shasum=TODO
+pkg_deps=(core/glibc # the C library
+  core/postgresql)
+pkg_exports=(
+  [port]=port
+  [host]="network.host"
+)
+pkg_exposes=(port tls-port)
+pkg_binds=(
+  [postgresql]="port superuser_name"
+)
+
+do_build() {
+  pkg_name=other
+  make
+}
+
+do_biuld() { make; }
+"#;
+
+    fn rules(findings: &[super::Finding]) -> Vec<(&'static str, Option<usize>)> {
+        findings.iter().map(|f| (f.rule, f.line)).collect()
+    }
+
+    #[test]
+    fn parse_plan_reads_variables_and_callbacks() {
+        let plan = parse_plan(PLAN);
+        assert_eq!(plan.vars["pkg_name"], (1, Value::Scalar(String::from("redis"))));
+        assert_eq!(plan.vars["pkg_license"],
+                   (4, Value::Array(vec![String::from("BSD-3-Clause")])));
+        assert_eq!(plan.vars["pkg_deps"],
+                   (7,
+                    Value::Array(vec![String::from("core/glibc"),
+                                      String::from("core/postgresql")])));
+        let mut exports = BTreeMap::new();
+        exports.insert(String::from("port"), String::from("port"));
+        exports.insert(String::from("host"), String::from("network.host"));
+        assert_eq!(plan.vars["pkg_exports"], (9, Value::Map(exports)));
+        assert_eq!(plan.callbacks.keys().collect::<Vec<_>>(), vec!["do_biuld", "do_build"]);
+    }
+
+    #[test]
+    fn lint_flags_plan_problems() {
+        let plan = parse_plan(PLAN);
+        let cfg: toml::Value = toml::from_str("port = 6379\n[network]\nbind = \"0.0.0.0\"\n")
+            .unwrap();
+        let mut bound = BTreeMap::new();
+        bound.insert(String::from("postgresql"),
+                     (String::from("core/postgresql/9.6.1/20170110214106"),
+                      vec![String::from("port")]));
+        let findings = lint(Path::new("plan.sh"), &plan, Some(&cfg), &[], &bound);
+        assert_eq!(rules(&findings),
+                   vec![("missing-shasum", Some(6)),
+                        ("export-not-in-default-toml", Some(9)),
+                        ("expose-unknown-export", Some(13)),
+                        ("bind-unknown-export", Some(14)),
+                        ("unknown-callback", Some(23))]);
+        assert!(findings[1].message.contains("network.host"));
+        assert!(findings[3].message.contains("superuser_name"));
+    }
+
+    #[test]
+    fn lint_flags_undefined_template_keys() {
+        let plan = parse_plan("pkg_name=redis\npkg_origin=core\npkg_license=('MIT')\n\
+                               pkg_binds_optional=([db]=port)\n");
+        let cfg: toml::Value = toml::from_str("[server]\nport = 6379\n").unwrap();
+        let templates = vec![(PathBuf::from("hooks/run"),
+                              String::from("#!/bin/sh\nexec redis --port {{cfg.server.port}} \
+                                            {{#if bind.db}}--db {{bind.db.first.sys.ip}}{{/if}}\
+                                            \n{{toJson cfg.server.host}} {{bind.cache.first}}\n\
+                                            {{#if cfg.tls}}\n--tls {{cfg.tls.cert}}\n{{/if}}\
+                                            {{~#unless cfg.quiet}}-v{{/unless}}\n\
+                                            {{cfg.server.name}}\n"))];
+        let findings = lint(Path::new("plan.sh"),
+                            &plan,
+                            Some(&cfg),
+                            &templates,
+                            &BTreeMap::new());
+        assert_eq!(rules(&findings),
+                   vec![("template-undefined-key", Some(3)),
+                        ("template-undefined-key", Some(3)),
+                        ("template-conditional-key", Some(4)),
+                        ("template-conditional-key", Some(5)),
+                        ("template-conditional-key", Some(6)),
+                        ("template-undefined-key", Some(7))]);
+        assert!(findings[0].message.contains("cfg.server.host"));
+        assert!(findings[1].message.contains("bind.cache"));
+        assert_eq!(findings[2].severity, "warning");
+        assert!(findings[4].message.contains("cfg.quiet"));
+        assert!(findings[5].message.contains("cfg.server.name"));
+    }
+
+    #[test]
+    fn lint_allows_a_plan_downloading_its_own_source() {
+        let plan = parse_plan("pkg_name=redis\npkg_origin=core\npkg_license=('MIT')\n\
+                               pkg_source=git://github.com/antirez/redis\n\
+                               do_download() {\n  git clone $pkg_source\n}\n");
+        let findings = lint(Path::new("plan.sh"), &plan, None, &[], &BTreeMap::new());
+        assert!(findings.is_empty());
+    }
+}
@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(target_os = "linux")]
+mod rootless;
+
 use std::env;
-use std::ffi::OsString;
+use std::ffi::{OsStr, OsString};
 
 use common::ui::UI;
 use hcore::crypto::CACHE_KEY_PATH_ENV_VAR;
@@ -27,16 +30,25 @@ use error::Result;
 const STUDIO_CMD: &'static str = "hab-studio";
 const STUDIO_CMD_ENVVAR: &'static str = "HAB_STUDIO_BINARY";
 const STUDIO_PACKAGE_IDENT: &'static str = "core/hab-studio";
-
-pub fn start(ui: &mut UI, args: Vec<OsString>) -> Result<()> {
-    try!(inner::rerun_with_sudo_if_needed(ui));
+const ROOTLESS_FLAG: &'static str = "--rootless";
+const ROOTLESS_ENVVAR: &'static str = "HAB_STUDIO_ROOTLESS";
+const STUDIO_SUBCOMMANDS: &'static [&'static str] = &["build", "enter", "help", "new", "rm",
+                                                      "run", "version"];
+
+pub fn start(ui: &mut UI, mut args: Vec<OsString>) -> Result<()> {
+    let rootless = take_rootless_flag(&mut args) || henv::var(ROOTLESS_ENVVAR).is_ok();
+    if rootless {
+        env::set_var(ROOTLESS_ENVVAR, "true");
+    } else {
+        try!(inner::rerun_with_sudo_if_needed(ui));
+    }
 
     // If the `$HAB_ORIGIN` environment variable is not present, then see if a default is set in
     // the CLI config. If so, set it as the `$HAB_ORIGIN` environment variable for the `hab-studio`
     // or `docker` execv call.
     if henv::var("HAB_ORIGIN").is_err() {
         let config = try!(config::load_with_sudo_user());
-        if let Some(default_origin) = config.origin {
+        if let Some(default_origin) = try!(config.origin()) {
             debug!("Setting default origin {} via CLI config", &default_origin);
             env::set_var("HAB_ORIGIN", default_origin);
         }
@@ -62,7 +74,26 @@ pub fn start(ui: &mut UI, args: Vec<OsString>) -> Result<()> {
         }
     }
 
-    inner::start(ui, args)
+    if rootless {
+        inner::start_rootless(ui, args)
+    } else {
+        inner::start(ui, args)
+    }
+}
+
+/// Removes `--rootless` from the options given before the `hab-studio` subcommand and returns
+/// whether it was there, so that the flag isn't mistaken for an argument of the subcommand.
+fn take_rootless_flag(args: &mut Vec<OsString>) -> bool {
+    let end = args.iter()
+        .position(|a| STUDIO_SUBCOMMANDS.iter().any(|s| a.as_os_str() == OsStr::new(s)))
+        .unwrap_or(args.len());
+    match args[..end].iter().position(|a| a.as_os_str() == OsStr::new(ROOTLESS_FLAG)) {
+        Some(i) => {
+            args.remove(i);
+            true
+        }
+        None => false,
+    }
 }
 
 #[cfg(target_os = "linux")]
@@ -73,35 +104,60 @@ mod inner {
     use std::str::FromStr;
 
     use common::ui::UI;
-    use hcore::crypto::{init, default_cache_key_path};
+    use hcore::crypto::{init, default_cache_key_path, CACHE_KEY_PATH_ENV_VAR};
     use hcore::env as henv;
-    use hcore::fs::{am_i_root, find_command};
+    use hcore::fs::{am_i_root, find_command, CACHE_KEY_PATH};
     use hcore::os::process;
     use hcore::package::PackageIdent;
 
     use error::{Error, Result};
     use exec;
+    use super::rootless;
     use VERSION;
 
     const SUDO_CMD: &'static str = "sudo";
+    const STUDIOS_HOME_ENVVAR: &'static str = "HAB_STUDIOS_HOME";
 
     pub fn start(ui: &mut UI, args: Vec<OsString>) -> Result<()> {
         let command = match henv::var(super::STUDIO_CMD_ENVVAR) {
             Ok(command) => PathBuf::from(command),
             Err(_) => {
                 init();
-                let version: Vec<&str> = VERSION.split("/").collect();
-                let ident = try!(PackageIdent::from_str(&format!("{}/{}",
-                                                                 super::STUDIO_PACKAGE_IDENT,
-                                                                 version[0])));
                 try!(exec::command_from_min_pkg(ui,
                                                 super::STUDIO_CMD,
-                                                &ident,
+                                                &try!(studio_ident()),
                                                 &default_cache_key_path(None),
                                                 0))
             }
         };
+        become_studio(command, args)
+    }
 
+    /// Runs the Studio as root of a user namespace, keeping the Studios and the key cache in
+    /// the home directory of the user unless they were set elsewhere.
+    pub fn start_rootless(ui: &mut UI, args: Vec<OsString>) -> Result<()> {
+        if !am_i_root() {
+            let home = try!(rootless::home());
+            if henv::var(STUDIOS_HOME_ENVVAR).is_err() {
+                env::set_var(STUDIOS_HOME_ENVVAR, home.join(".hab").join("studios"));
+            }
+            if henv::var(CACHE_KEY_PATH_ENV_VAR).is_err() {
+                env::set_var(CACHE_KEY_PATH_ENV_VAR, home.join(format!(".{}", CACHE_KEY_PATH)));
+            }
+            return rootless::reexec_in_namespaces(args);
+        }
+
+        let command = match henv::var(super::STUDIO_CMD_ENVVAR) {
+            Ok(command) => PathBuf::from(command),
+            Err(_) => {
+                init();
+                try!(rootless::studio_command(ui, super::STUDIO_CMD, &try!(studio_ident())))
+            }
+        };
+        become_studio(command, args)
+    }
+
+    fn become_studio(command: PathBuf, args: Vec<OsString>) -> Result<()> {
         if let Some(cmd) = find_command(command.to_string_lossy().as_ref()) {
             try!(process::become_command(cmd, args));
         } else {
@@ -110,6 +166,12 @@ mod inner {
         Ok(())
     }
 
+    /// Returns the identifier of the Studio package with the same version as this program.
+    fn studio_ident() -> Result<PackageIdent> {
+        let version: Vec<&str> = VERSION.split("/").collect();
+        Ok(try!(PackageIdent::from_str(&format!("{}/{}", super::STUDIO_PACKAGE_IDENT, version[0]))))
+    }
+
     pub fn rerun_with_sudo_if_needed(ui: &mut UI) -> Result<()> {
         // If I have root permissions, early return, we are done.
         if am_i_root() {
@@ -336,6 +398,10 @@ mod inner {
         Ok(try!(process::become_command(cmd, cmd_args)))
     }
 
+    pub fn start_rootless(_ui: &mut UI, _args: Vec<OsString>) -> Result<()> {
+        Err(Error::SubcommandNotSupported(String::from("studio --rootless")))
+    }
+
     pub fn rerun_with_sudo_if_needed(_ui: &mut UI) -> Result<()> {
         // No sudo calls necessary here--we are calling `docker` commands instead
         Ok(())
@@ -359,3 +425,28 @@ mod inner {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::ffi::OsString;
+
+    use super::take_rootless_flag;
+
+    fn args(args: &[&str]) -> Vec<OsString> {
+        args.iter().map(OsString::from).collect()
+    }
+
+    #[test]
+    fn take_rootless_flag_before_subcommand() {
+        let mut studio_args = args(&["-k", "core", "--rootless", "enter"]);
+        assert!(take_rootless_flag(&mut studio_args));
+        assert_eq!(studio_args, args(&["-k", "core", "enter"]));
+    }
+
+    #[test]
+    fn take_rootless_flag_leaves_subcommand_args() {
+        let mut studio_args = args(&["run", "./configure", "--rootless"]);
+        assert!(!take_rootless_flag(&mut studio_args));
+        assert_eq!(studio_args, args(&["run", "./configure", "--rootless"]));
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runs a Studio without root privileges.
+//!
+//! An unprivileged user may create a user namespace in which they are root, together with mount
+//! and PID namespaces owned by it. `hab studio --rootless` runs itself again inside such
+//! namespaces, where `hab-studio` lays out the same `/hab` tree, mounts `/proc`, bind mounts
+//! `/dev` and `/sys` and `chroot`s into the Studio as it would with real root privileges. The
+//! mounts go away with the namespaces when the Studio exits.
+//!
+//! Only the user's own UID and GID are mapped into the namespace, as root, so files the Studio
+//! creates belong to the user and giving a file to any other owner fails.
+
+use std::env;
+use std::ffi::{CString, OsString};
+use std::io;
+use std::mem;
+use std::os::unix::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::ptr;
+
+use common::command::package::install;
+use common::ui::{Status, UI};
+use hcore::fs::{cache_artifact_path, find_command_in_pkg};
+use hcore::package::{PackageIdent, PackageInstall};
+use hcore::url::default_depot_url;
+use libc;
+
+use {PRODUCT, VERSION};
+use error::{Error, Result};
+
+/// Where the Studio package is installed for the user when it isn't installed system-wide,
+/// relative to their home directory.
+const FS_ROOT_PATH: &'static str = ".hab/rootless";
+
+/// Returns the home directory of the user, under which their Studios and keys live.
+pub fn home() -> Result<PathBuf> {
+    env::home_dir().ok_or(Error::ArgumentError("A rootless Studio requires a home directory, \
+                                                please set HOME"))
+}
+
+/// Runs `hab studio` with `args` in new user, mount and PID namespaces, as their root.
+///
+/// Returns only if the namespaces can't be created; otherwise exits with the exit code of the
+/// Studio.
+pub fn reexec_in_namespaces(args: Vec<OsString>) -> Result<()> {
+    // Everything the children need is prepared up front, as a child of a process with several
+    // threads should do no more than make system calls.
+    let exe = try!(env::current_exe());
+    let mut argv = vec![try!(cstring(exe.as_os_str().as_bytes())), try!(cstring(b"studio"))];
+    for arg in args.iter() {
+        argv.push(try!(cstring(arg.as_bytes())));
+    }
+    let mut argv_ptrs: Vec<*const libc::c_char> = argv.iter().map(|a| a.as_ptr()).collect();
+    argv_ptrs.push(ptr::null());
+    let (uid, gid) = unsafe { (libc::getuid(), libc::getgid()) };
+    let id_maps = vec![(try!(cstring(b"/proc/self/setgroups")), b"deny".to_vec()),
+                       (try!(cstring(b"/proc/self/uid_map")),
+                        format!("0 {} 1", uid).into_bytes()),
+                       (try!(cstring(b"/proc/self/gid_map")),
+                        format!("0 {} 1", gid).into_bytes())];
+
+    // A child which fails to set up the namespaces writes its `errno` to this pipe.
+    let mut fds = [0; 2];
+    if unsafe { libc::pipe2(fds.as_mut_ptr(), libc::O_CLOEXEC) } != 0 {
+        return Err(Error::from(io::Error::last_os_error()));
+    }
+    match unsafe { libc::fork() } {
+        -1 => Err(Error::from(io::Error::last_os_error())),
+        0 => unsafe {
+            libc::close(fds[0]);
+            enter_namespaces(fds[1], &id_maps, &argv_ptrs)
+        },
+        pid => {
+            unsafe { libc::close(fds[1]) };
+            ignore_interrupts();
+            let mut errno: libc::c_int = 0;
+            let read = unsafe {
+                libc::read(fds[0],
+                           &mut errno as *mut libc::c_int as *mut libc::c_void,
+                           mem::size_of::<libc::c_int>())
+            };
+            unsafe { libc::close(fds[0]) };
+            let status = wait_for(pid);
+            if read == mem::size_of::<libc::c_int>() as isize {
+                let err = io::Error::from_raw_os_error(errno);
+                return Err(Error::UserNamespaceUnavailable(err.to_string()));
+            }
+            process::exit(status)
+        }
+    }
+}
+
+/// Returns the path to `command` in the Studio package, installing the package for the user if
+/// it is installed neither system-wide nor for them.
+pub fn studio_command(ui: &mut UI, command: &str, ident: &PackageIdent) -> Result<PathBuf> {
+    if let Ok(pi) = PackageInstall::load_at_least(ident, None) {
+        if let Some(cmd) = try!(find_command_in_pkg(command, &pi, Path::new("/"))) {
+            return Ok(cmd);
+        }
+    }
+
+    let fs_root_path = try!(home()).join(FS_ROOT_PATH);
+    let pi = match PackageInstall::load_at_least(ident, Some(&fs_root_path)) {
+        Ok(pi) => pi,
+        Err(_) => {
+            try!(ui.status(Status::Missing, format!("package for {}", ident)));
+            let installed = try!(install::start(ui,
+                                                &default_depot_url(),
+                                                &ident.to_string(),
+                                                PRODUCT,
+                                                VERSION,
+                                                &fs_root_path,
+                                                &cache_artifact_path(Some(&fs_root_path)),
+                                                false));
+            try!(PackageInstall::load(&installed, Some(&fs_root_path)))
+        }
+    };
+    match try!(find_command_in_pkg(command, &pi, &fs_root_path)) {
+        Some(cmd) => Ok(fs_root_path.join(cmd.strip_prefix("/").unwrap_or(&cmd))),
+        None => Err(Error::ExecCommandNotFound(command.to_string())),
+    }
+}
+
+/// Moves into new namespaces and execs `argv` in a child which is the first process of the PID
+/// namespace, then exits with its exit code.
+unsafe fn enter_namespaces(err_fd: libc::c_int,
+                           id_maps: &[(CString, Vec<u8>)],
+                           argv: &[*const libc::c_char])
+                           -> ! {
+    if libc::unshare(libc::CLONE_NEWUSER | libc::CLONE_NEWNS | libc::CLONE_NEWPID) != 0 {
+        fail(err_fd);
+    }
+    for &(ref path, ref content) in id_maps.iter() {
+        let fd = libc::open(path.as_ptr(), libc::O_WRONLY);
+        if fd < 0 ||
+           libc::write(fd, content.as_ptr() as *const libc::c_void, content.len()) < 0 {
+            fail(err_fd);
+        }
+        libc::close(fd);
+    }
+    // Keep the Studio's mounts from propagating back to the host.
+    if libc::mount(ptr::null(),
+                   b"/\0".as_ptr() as *const libc::c_char,
+                   ptr::null(),
+                   libc::MS_REC | libc::MS_PRIVATE,
+                   ptr::null()) != 0 {
+        fail(err_fd);
+    }
+    match libc::fork() {
+        -1 => fail(err_fd),
+        0 => {
+            libc::execv(argv[0], argv.as_ptr());
+            fail(err_fd)
+        }
+        pid => {
+            libc::close(err_fd);
+            ignore_interrupts();
+            libc::_exit(wait_for(pid))
+        }
+    }
+}
+
+unsafe fn fail(err_fd: libc::c_int) -> ! {
+    let errno = io::Error::last_os_error().raw_os_error().unwrap_or(0);
+    libc::write(err_fd,
+                &errno as *const libc::c_int as *const libc::c_void,
+                mem::size_of::<libc::c_int>());
+    libc::_exit(1)
+}
+
+/// Leaves interrupts from the terminal to the Studio, which decides what to do with them.
+fn ignore_interrupts() {
+    unsafe {
+        libc::signal(libc::SIGINT, libc::SIG_IGN);
+        libc::signal(libc::SIGQUIT, libc::SIG_IGN);
+    }
+}
+
+/// Waits for the child `pid` and returns its exit code, or `128` plus the signal which killed it.
+fn wait_for(pid: libc::pid_t) -> i32 {
+    let mut status: libc::c_int = 0;
+    while unsafe { libc::waitpid(pid, &mut status, 0) } != pid {
+        if io::Error::last_os_error().kind() != io::ErrorKind::Interrupted {
+            return 1;
+        }
+    }
+    if libc::WIFEXITED(status) {
+        libc::WEXITSTATUS(status)
+    } else if libc::WIFSIGNALED(status) {
+        128 + libc::WTERMSIG(status)
+    } else {
+        1
+    }
+}
+
+fn cstring(bytes: &[u8]) -> Result<CString> {
+    Ok(try!(CString::new(bytes)))
+}
@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::env;
+use std::fmt;
 use std::fs::{self, File};
 use std::io::Write;
 use std::path::PathBuf;
@@ -21,16 +23,138 @@ use hcore::config::ConfigFile;
 use hcore::env as henv;
 use hcore::fs::{am_i_root, FS_ROOT_PATH};
 use hcore::os::users;
+use hcore::url::DEFAULT_DEPOT_URL;
 use toml;
 
+use credentials::{self, TokenStore, DEFAULT_SLOT};
 use error::{Error, Result};
 
 const CLI_CONFIG_PATH: &'static str = "hab/etc/cli.toml";
+const TOKEN_FILE_NAME: &'static str = "tokens.toml";
 
-#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+/// Selects a profile in place of the one in use, for a single command.
+pub const PROFILE_ENVVAR: &'static str = "HAB_PROFILE";
+/// Chooses where the encrypted token file is kept, in place of beside the CLI config.
+pub const TOKEN_FILE_ENVVAR: &'static str = "HAB_TOKEN_FILE";
+
+#[derive(Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub struct Config {
+    /// The default auth token, unless it is kept in the token store.
     pub auth_token: Option<String>,
     pub origin: Option<String>,
+    /// The name of the profile in use, if any.
+    #[serde(default)]
+    pub profile: Option<String>,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+    /// The store which holds the auth tokens which aren't in this file, if any.
+    #[serde(default)]
+    pub token_store: Option<String>,
+}
+
+/// A named set of defaults for talking to a Depot, so that one can switch between Depots,
+/// origins and tokens with `hab cli profile use`. Its values take precedence over the top-level
+/// `auth_token` and `origin`.
+#[derive(Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Profile {
+    pub depot_url: Option<String>,
+    pub origin: Option<String>,
+    pub auth_token: Option<String>,
+    pub channel: Option<String>,
+}
+
+impl Config {
+    /// Returns the name and the values of the profile in use: the one named by `HAB_PROFILE`,
+    /// else the one chosen with `hab cli profile use`.
+    pub fn active_profile(&self) -> Result<Option<(String, &Profile)>> {
+        let name = match henv::var(PROFILE_ENVVAR) {
+            Ok(name) => name,
+            Err(_) => {
+                match self.profile {
+                    Some(ref name) => name.clone(),
+                    None => return Ok(None),
+                }
+            }
+        };
+        match self.profiles.get(&name) {
+            Some(profile) => Ok(Some((name, profile))),
+            None => Err(Error::ProfileNotFound(name)),
+        }
+    }
+
+    /// Returns the Depot URL of the profile in use.
+    pub fn depot_url(&self) -> Result<Option<String>> {
+        Ok(try!(self.active_profile()).and_then(|(_, p)| p.depot_url.clone()))
+    }
+
+    /// Returns the origin of the profile in use, else the default origin.
+    pub fn origin(&self) -> Result<Option<String>> {
+        Ok(try!(self.active_profile())
+               .and_then(|(_, p)| p.origin.clone())
+               .or(self.origin.clone()))
+    }
+
+    /// Returns the auth token of the profile in use, else the default auth token. The default
+    /// token is for the default Depot, so a profile with a Depot URL of its own never falls back
+    /// to it.
+    pub fn auth_token(&self) -> Result<Option<String>> {
+        if let Some((name, profile)) = try!(self.active_profile()) {
+            let slot = credentials::profile_slot(&name);
+            if let Some(token) = try!(self.stored_token(&slot, &profile.auth_token)) {
+                return Ok(Some(token));
+            }
+            if profile.depot_url.is_some() {
+                return Ok(None);
+            }
+        }
+        self.default_auth_token()
+    }
+
+    /// Returns the auth token for the Depot at `url`: the one `auth_token` returns, but only if
+    /// `url` is the Depot of the profile in use, or the default Depot when it has none. A token
+    /// is never sent to a Depot it wasn't meant for, such as one given with `--url`.
+    pub fn auth_token_for(&self, url: &str) -> Result<Option<String>> {
+        let depot_url = try!(self.depot_url()).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+        if depot_url.trim_right_matches('/') != url.trim_right_matches('/') {
+            return Ok(None);
+        }
+        self.auth_token()
+    }
+
+    /// Returns the default auth token, ignoring profiles.
+    pub fn default_auth_token(&self) -> Result<Option<String>> {
+        self.stored_token(DEFAULT_SLOT, &self.auth_token)
+    }
+
+    /// Returns whether the profile `name` has an auth token, without reading it.
+    pub fn profile_has_auth_token(&self, name: &str) -> Result<bool> {
+        match self.profiles.get(name) {
+            Some(&Profile { auth_token: Some(_), .. }) => Ok(true),
+            Some(_) => try!(self.saved_token_store()).contains(&credentials::profile_slot(name)),
+            None => Ok(false),
+        }
+    }
+
+    /// Returns the store which the auth tokens of this file were saved to.
+    pub fn saved_token_store(&self) -> Result<TokenStore> {
+        match self.token_store {
+            Some(ref store) => store.parse(),
+            None => Ok(TokenStore::Plaintext),
+        }
+    }
+
+    /// Returns `plaintext`, else the token in `slot` of the store this file was saved with.
+    fn stored_token(&self, slot: &str, plaintext: &Option<String>) -> Result<Option<String>> {
+        match *plaintext {
+            Some(ref token) => Ok(Some(token.clone())),
+            None => try!(self.saved_token_store()).get(slot),
+        }
+    }
+
+    /// Returns the channel of the profile in use.
+    pub fn channel(&self) -> Result<Option<String>> {
+        Ok(try!(self.active_profile()).and_then(|(_, p)| p.channel.clone()))
+    }
 }
 
 impl ConfigFile for Config {
@@ -42,10 +166,36 @@ impl Default for Config {
         Config {
             auth_token: None,
             origin: None,
+            profile: None,
+            profiles: BTreeMap::new(),
+            token_store: None,
         }
     }
 }
 
+impl fmt::Debug for Config {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Config")
+            .field("auth_token", &self.auth_token.as_ref().map(|t| credentials::redact(t)))
+            .field("origin", &self.origin)
+            .field("profile", &self.profile)
+            .field("profiles", &self.profiles)
+            .field("token_store", &self.token_store)
+            .finish()
+    }
+}
+
+impl fmt::Debug for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Profile")
+            .field("depot_url", &self.depot_url)
+            .field("origin", &self.origin)
+            .field("auth_token", &self.auth_token.as_ref().map(|t| credentials::redact(t)))
+            .field("channel", &self.channel)
+            .finish()
+    }
+}
+
 pub fn load() -> Result<Config> {
     common_load(false)
 }
@@ -54,20 +204,69 @@ pub fn load_with_sudo_user() -> Result<Config> {
     common_load(true)
 }
 
+/// Saves `config`, moving its auth tokens into the token store in use.
 pub fn save(config: &Config) -> Result<()> {
+    save_from_store(config, try!(config.saved_token_store()))
+}
+
+/// Saves `config` like `save`, where `saved` is the store its auth tokens were last saved to. Use
+/// it once `config.token_store` was changed, so that the tokens `saved` keeps are moved as well.
+pub fn save_from_store(config: &Config, saved: TokenStore) -> Result<()> {
     let config_path = cli_config_path(false);
     let parent_path = match config_path.parent() {
         Some(p) => p,
         None => return Err(Error::FileNotFound(config_path.to_string_lossy().into_owned())),
     };
     try!(fs::create_dir_all(&parent_path));
-    let raw = try!(toml::ser::to_string(config));
-    debug!("Raw config toml:\n---\n{}\n---", &raw);
+    let mut config = config.clone();
+    let store = try!(TokenStore::configured(config.token_store.as_ref().map(|s| s.as_str())));
+    try!(move_tokens(&mut config, saved, store));
+    // The raw TOML may hold tokens, while the `Debug` form never does.
+    debug!("Saving CLI config: {:?}", &config);
+    let raw = try!(toml::ser::to_string(&config));
     let mut file = try!(File::create(&config_path));
     try!(file.write_all(raw.as_bytes()));
     Ok(())
 }
 
+/// Returns the path of the encrypted token file, `HAB_TOKEN_FILE` or else beside the CLI config.
+pub fn token_file_path() -> PathBuf {
+    match henv::var(TOKEN_FILE_ENVVAR) {
+        Ok(path) => PathBuf::from(path),
+        Err(_) => cli_config_path(false).with_file_name(TOKEN_FILE_NAME),
+    }
+}
+
+/// Moves the auth tokens of `config` into `store`, along with those still kept by `saved`, the
+/// store it was last saved with, and records `store` in `config`.
+fn move_tokens(config: &mut Config, saved: TokenStore, store: TokenStore) -> Result<()> {
+    {
+        let mut tokens = vec![(DEFAULT_SLOT.to_string(), &mut config.auth_token)];
+        for (name, profile) in config.profiles.iter_mut() {
+            tokens.push((credentials::profile_slot(name), &mut profile.auth_token));
+        }
+        for (slot, token) in tokens {
+            let moving = store != saved && token.is_none();
+            if moving {
+                *token = try!(saved.get(&slot));
+            }
+            if store != TokenStore::Plaintext {
+                if let Some(value) = token.take() {
+                    try!(store.set(&slot, &value));
+                }
+            }
+            if moving {
+                try!(saved.remove(&slot));
+            }
+        }
+    }
+    config.token_store = match store {
+        TokenStore::Plaintext => None,
+        _ => Some(store.to_string()),
+    };
+    Ok(())
+}
+
 fn common_load(use_sudo_user: bool) -> Result<Config> {
     let cli_config_path = cli_config_path(use_sudo_user);
     if cli_config_path.exists() {
@@ -99,3 +298,137 @@ fn cli_config_path(use_sudo_user: bool) -> PathBuf {
 
     PathBuf::from(&*FS_ROOT_PATH).join(CLI_CONFIG_PATH)
 }
+
+#[cfg(test)]
+mod test {
+    use tempdir::TempDir;
+    use toml;
+
+    use hcore::url::DEFAULT_DEPOT_URL;
+
+    use super::{move_tokens, Config, Profile};
+    use credentials::{self, TokenFile, TokenStore, DEFAULT_SLOT};
+
+    #[test]
+    fn profile_values_take_precedence() {
+        let mut config = Config::default();
+        config.origin = Some(String::from("core"));
+        config.auth_token = Some(String::from("public-token"));
+        let depot_url = String::from("http://depot.example.com/v1/depot");
+        config.profiles.insert(String::from("internal"),
+                               Profile {
+                                   depot_url: Some(depot_url.clone()),
+                                   origin: Some(String::from("acme")),
+                                   ..Profile::default()
+                               });
+        assert_eq!(config.origin().unwrap(), Some(String::from("core")));
+        assert_eq!(config.depot_url().unwrap(), None);
+
+        config.profiles.insert(String::from("acme"),
+                               Profile {
+                                   origin: Some(String::from("acme")),
+                                   channel: Some(String::from("unstable")),
+                                   ..Profile::default()
+                               });
+        assert_eq!(config.channel().unwrap(), None);
+
+        config.profile = Some(String::from("internal"));
+        assert_eq!(config.origin().unwrap(), Some(String::from("acme")));
+        // The default token is never sent to the Depot of a profile.
+        assert_eq!(config.auth_token().unwrap(), None);
+        assert_eq!(config.depot_url().unwrap(), Some(depot_url));
+
+        config.profile = Some(String::from("acme"));
+        assert_eq!(config.auth_token().unwrap(), Some(String::from("public-token")));
+        assert_eq!(config.channel().unwrap(), Some(String::from("unstable")));
+
+        config.profile = Some(String::from("missing"));
+        assert!(config.origin().is_err());
+    }
+
+    #[test]
+    fn profile_tokens_are_only_for_their_depot() {
+        let mut config = Config::default();
+        config.auth_token = Some(String::from("public-token"));
+        let depot_url = String::from("http://depot.example.com/v1/depot");
+        config.profiles.insert(String::from("internal"),
+                               Profile {
+                                   depot_url: Some(depot_url.clone()),
+                                   auth_token: Some(String::from("internal-token")),
+                                   ..Profile::default()
+                               });
+        assert_eq!(config.auth_token_for(DEFAULT_DEPOT_URL).unwrap(),
+                   Some(String::from("public-token")));
+        assert_eq!(config.auth_token_for(&depot_url).unwrap(), None);
+
+        config.profile = Some(String::from("internal"));
+        assert_eq!(config.auth_token_for(&format!("{}/", depot_url)).unwrap(),
+                   Some(String::from("internal-token")));
+        assert_eq!(config.auth_token_for("http://elsewhere.example.com/v1/depot").unwrap(),
+                   None);
+        assert_eq!(config.auth_token_for(DEFAULT_DEPOT_URL).unwrap(), None);
+    }
+
+    #[test]
+    fn debug_output_redacts_tokens() {
+        let mut config = Config::default();
+        config.auth_token = Some(String::from("0123456789abcdef0123"));
+        config.profiles.insert(String::from("internal"),
+                               Profile {
+                                   auth_token: Some(String::from("internal-token")),
+                                   ..Profile::default()
+                               });
+        let debug = format!("{:?}", config);
+        assert!(!debug.contains("0123456789abcdef0123"));
+        assert!(!debug.contains("internal-token"));
+        assert!(debug.contains("********0123"));
+    }
+
+    #[test]
+    fn config_with_profiles_round_trips() {
+        let mut config = Config::default();
+        config.origin = Some(String::from("core"));
+        config.profile = Some(String::from("internal"));
+        config.profiles.insert(String::from("internal"),
+                               Profile {
+                                   channel: Some(String::from("unstable")),
+                                   ..Profile::default()
+                               });
+        let raw = toml::ser::to_string(&config).unwrap();
+        assert_eq!(toml::from_str::<Config>(&raw).unwrap(), config);
+        assert_eq!(toml::from_str::<Config>("origin = \"core\"\n").unwrap().profiles.len(),
+                   0);
+    }
+
+    #[test]
+    fn tokens_move_between_stores() {
+        let dir = TempDir::new("tokens").unwrap();
+        let passphrase = String::from("correct horse battery staple");
+        let encrypted = TokenStore::EncryptedFile(TokenFile::new(dir.path().join("tokens.toml"),
+                                                                 Some(passphrase)));
+        let mut config = Config::default();
+        config.auth_token = Some(String::from("default-token"));
+        config.profiles.insert(String::from("internal"),
+                               Profile {
+                                   auth_token: Some(String::from("internal-token")),
+                                   ..Profile::default()
+                               });
+        let plaintext = config.clone();
+        let internal = credentials::profile_slot("internal");
+
+        move_tokens(&mut config, TokenStore::Plaintext, encrypted.clone()).unwrap();
+        assert_eq!(config.token_store, Some(String::from("encrypted-file")));
+        assert_eq!(config.auth_token, None);
+        assert_eq!(config.profiles["internal"].auth_token, None);
+        assert_eq!(encrypted.get(DEFAULT_SLOT).unwrap(),
+                   Some(String::from("default-token")));
+        assert_eq!(encrypted.get(&internal).unwrap(),
+                   Some(String::from("internal-token")));
+
+        // Moving out of a store takes the tokens out of it, even though the config names it.
+        move_tokens(&mut config, encrypted.clone(), TokenStore::Plaintext).unwrap();
+        assert_eq!(config, plaintext);
+        assert!(!encrypted.contains(DEFAULT_SLOT).unwrap());
+        assert!(!encrypted.contains(&internal).unwrap());
+    }
+}
@@ -0,0 +1,454 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Keeps the Depot auth tokens of the CLI config out of the config file.
+//!
+//! Tokens live in one of three stores:
+//!
+//! * `keyring`: the keyring of the desktop session, reached through the Secret Service D-Bus
+//!   API with `secret-tool` (Linux only)
+//! * `encrypted-file`: `tokens.toml` beside the CLI config, each token encrypted with a key
+//!   derived from a passphrase, which is read from `HAB_TOKEN_PASSPHRASE` or asked for
+//! * `plaintext`: the CLI config itself
+//!
+//! The keyring is used when `secret-tool` and a session bus are available, and the CLI config
+//! otherwise, unless `hab cli token-store` or `HAB_TOKEN_STORE` chooses another store. Each
+//! token has a slot in the store: `default` for the top-level token of the CLI config and
+//! `profile:<NAME>` for the token of a profile.
+
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+use std::result;
+use std::str::FromStr;
+use std::sync::Mutex;
+
+use hcore::crypto::passphrase;
+use hcore::env as henv;
+use hcore::fs::find_command;
+use toml;
+
+use config;
+use error::{Error, Result};
+
+/// Chooses the store which auth tokens are moved to the next time the CLI config is saved.
+pub const TOKEN_STORE_ENVVAR: &'static str = "HAB_TOKEN_STORE";
+/// Holds the passphrase of the encrypted token file, so that it isn't asked for.
+pub const TOKEN_PASSPHRASE_ENVVAR: &'static str = "HAB_TOKEN_PASSPHRASE";
+/// The slot of the top-level auth token of the CLI config.
+pub const DEFAULT_SLOT: &'static str = "default";
+
+const SECRET_TOOL: &'static str = "secret-tool";
+const KEYRING_SERVICE: &'static str = "habitat";
+const REDACTED: &'static str = "********";
+
+lazy_static! {
+    /// The passphrase of the encrypted token file, asked for at most once per command.
+    static ref PASSPHRASE: Mutex<Option<String>> = Mutex::new(None);
+}
+
+/// Where the auth tokens of the CLI config are kept.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TokenStore {
+    Plaintext,
+    Keyring,
+    EncryptedFile(TokenFile),
+}
+
+/// The encrypted token file, along with the passphrase its tokens are sealed with when it is known
+/// up front.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TokenFile {
+    path: PathBuf,
+    passphrase: Option<String>,
+}
+
+impl TokenFile {
+    /// Returns the token file at `path`. Without a `passphrase`, it is read from
+    /// `HAB_TOKEN_PASSPHRASE` or asked for the first time a token is sealed or unsealed.
+    pub fn new<P: Into<PathBuf>>(path: P, passphrase: Option<String>) -> TokenFile {
+        TokenFile {
+            path: path.into(),
+            passphrase: passphrase,
+        }
+    }
+
+    fn passphrase(&self) -> Result<String> {
+        match self.passphrase {
+            Some(ref passphrase) => Ok(passphrase.clone()),
+            None => passphrase(),
+        }
+    }
+
+    fn read(&self) -> Result<BTreeMap<String, String>> {
+        if !self.path.exists() {
+            return Ok(BTreeMap::new());
+        }
+        let mut raw = String::new();
+        try!(try!(File::open(&self.path)).read_to_string(&mut raw));
+        Ok(try!(toml::from_str(&raw)))
+    }
+
+    fn write(&self, tokens: &BTreeMap<String, String>) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            try!(fs::create_dir_all(parent));
+        }
+        let raw = try!(toml::ser::to_string(tokens));
+        let mut file = try!(create_for_owner(&self.path));
+        try!(file.write_all(raw.as_bytes()));
+        Ok(())
+    }
+}
+
+impl fmt::Debug for TokenFile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TokenFile {{ path: {:?} }}", self.path)
+    }
+}
+
+impl TokenStore {
+    /// The values accepted on the command line.
+    pub fn variants() -> &'static [&'static str] {
+        &["keyring", "encrypted-file", "plaintext"]
+    }
+
+    /// Returns the store named by `HAB_TOKEN_STORE`, else `config_store`, else the keyring if
+    /// it is available and the CLI config if not.
+    pub fn configured(config_store: Option<&str>) -> Result<TokenStore> {
+        if let Ok(name) = henv::var(TOKEN_STORE_ENVVAR) {
+            return name.parse();
+        }
+        match config_store {
+            Some(name) => name.parse(),
+            None if keyring_available() => Ok(TokenStore::Keyring),
+            None => Ok(TokenStore::Plaintext),
+        }
+    }
+
+    /// Returns the token in `slot`, if any. Tokens kept in plaintext are in the CLI config
+    /// itself, so this store never has any.
+    pub fn get(&self, slot: &str) -> Result<Option<String>> {
+        match *self {
+            TokenStore::Plaintext => Ok(None),
+            TokenStore::Keyring => {
+                let output = try!(secret_tool(&["lookup", "service", KEYRING_SERVICE, "slot", slot],
+                                              None));
+                // `secret-tool lookup` fails without a word when there is no such secret.
+                if !output.status.success() {
+                    return match output.stderr.is_empty() {
+                               true => Ok(None),
+                               false => Err(secret_tool_error(&output)),
+                           };
+                }
+                let token = try!(utf8(output.stdout));
+                Ok(Some(token.trim_right_matches('\n').to_string()))
+            }
+            TokenStore::EncryptedFile(ref file) => {
+                match try!(file.read()).get(slot) {
+                    Some(sealed) => {
+                        let passphrase = try!(file.passphrase());
+                        let token = try!(passphrase::decrypt(passphrase.as_bytes(), sealed));
+                        Ok(Some(try!(utf8(token))))
+                    }
+                    None => Ok(None),
+                }
+            }
+        }
+    }
+
+    /// Keeps `token` in `slot`, replacing any token it had.
+    pub fn set(&self, slot: &str, token: &str) -> Result<()> {
+        match *self {
+            TokenStore::Plaintext => Ok(()),
+            TokenStore::Keyring => {
+                let label = format!("--label=Habitat auth token ({})", slot);
+                let output = try!(secret_tool(&["store",
+                                                &label,
+                                                "service",
+                                                KEYRING_SERVICE,
+                                                "slot",
+                                                slot],
+                                              Some(token)));
+                match output.status.success() {
+                    true => Ok(()),
+                    false => Err(secret_tool_error(&output)),
+                }
+            }
+            TokenStore::EncryptedFile(ref file) => {
+                let mut tokens = try!(file.read());
+                let passphrase = try!(file.passphrase());
+                tokens.insert(slot.to_string(),
+                              try!(passphrase::encrypt(passphrase.as_bytes(), token.as_bytes())));
+                file.write(&tokens)
+            }
+        }
+    }
+
+    /// Removes the token in `slot`, if any.
+    pub fn remove(&self, slot: &str) -> Result<()> {
+        match *self {
+            TokenStore::Plaintext => Ok(()),
+            TokenStore::Keyring => {
+                let output = try!(secret_tool(&["clear", "service", KEYRING_SERVICE, "slot", slot],
+                                              None));
+                match output.status.success() || output.stderr.is_empty() {
+                    true => Ok(()),
+                    false => Err(secret_tool_error(&output)),
+                }
+            }
+            TokenStore::EncryptedFile(ref file) => {
+                let mut tokens = try!(file.read());
+                match tokens.remove(slot) {
+                    Some(_) => file.write(&tokens),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+
+    /// Returns whether `slot` has a token, without asking for the passphrase of the encrypted
+    /// token file.
+    pub fn contains(&self, slot: &str) -> Result<bool> {
+        match *self {
+            TokenStore::EncryptedFile(ref file) => Ok(try!(file.read()).contains_key(slot)),
+            _ => Ok(try!(self.get(slot)).is_some()),
+        }
+    }
+}
+
+impl fmt::Display for TokenStore {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TokenStore::Plaintext => write!(f, "plaintext"),
+            TokenStore::Keyring => write!(f, "keyring"),
+            TokenStore::EncryptedFile(_) => write!(f, "encrypted-file"),
+        }
+    }
+}
+
+impl FromStr for TokenStore {
+    type Err = Error;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value {
+            "plaintext" => Ok(TokenStore::Plaintext),
+            "keyring" => Ok(TokenStore::Keyring),
+            "encrypted-file" => {
+                Ok(TokenStore::EncryptedFile(TokenFile::new(config::token_file_path(), None)))
+            }
+            _ => {
+                Err(Error::ArgumentError("Token store must be one of: keyring, encrypted-file, \
+                                          plaintext"))
+            }
+        }
+    }
+}
+
+/// Returns the slot of the auth token of the profile `name`.
+pub fn profile_slot(name: &str) -> String {
+    format!("profile:{}", name)
+}
+
+/// Masks `token` for showing in output and logs, keeping only its last four characters when it
+/// is long enough that they give nothing away.
+pub fn redact(token: &str) -> String {
+    let chars: Vec<char> = token.chars().collect();
+    if chars.len() < 16 {
+        return REDACTED.to_string();
+    }
+    let tail: String = chars[chars.len() - 4..].iter().cloned().collect();
+    format!("{}{}", REDACTED, tail)
+}
+
+/// Returns `args` for logging, with the value of any `-z` or `--auth` option redacted.
+pub fn redact_args(args: &[OsString]) -> Vec<String> {
+    let mut redacted = Vec::with_capacity(args.len());
+    let mut takes_token = false;
+    for arg in args.iter().map(|a| a.to_string_lossy()) {
+        if takes_token {
+            redacted.push(redact(&arg));
+            takes_token = false;
+        } else if arg == "-z" || arg == "--auth" {
+            redacted.push(arg.into_owned());
+            takes_token = true;
+        } else if arg.starts_with("--auth=") {
+            redacted.push(format!("--auth={}", redact(&arg["--auth=".len()..])));
+        } else if arg.starts_with("-z") {
+            redacted.push(format!("-z{}", redact(&arg[2..])));
+        } else {
+            redacted.push(arg.into_owned());
+        }
+    }
+    redacted
+}
+
+fn keyring_available() -> bool {
+    cfg!(target_os = "linux") && henv::var("DBUS_SESSION_BUS_ADDRESS").is_ok() &&
+    find_command(SECRET_TOOL).is_some()
+}
+
+fn secret_tool(args: &[&str], input: Option<&str>) -> Result<Output> {
+    if !cfg!(target_os = "linux") {
+        return Err(Error::TokenStoreError(String::from("the keyring is only supported on Linux, \
+                                                        choose another store with \
+                                                        `hab cli token-store'")));
+    }
+    let mut child = try!(Command::new(SECRET_TOOL)
+                             .args(args)
+                             .stdin(Stdio::piped())
+                             .stdout(Stdio::piped())
+                             .stderr(Stdio::piped())
+                             .spawn()
+                             .map_err(|e| {
+                                          Error::TokenStoreError(format!("can't run {}: {}",
+                                                                         SECRET_TOOL,
+                                                                         e))
+                                      }));
+    if let Some(input) = input {
+        if let Some(ref mut stdin) = child.stdin {
+            try!(stdin.write_all(input.as_bytes()));
+        }
+    }
+    Ok(try!(child.wait_with_output()))
+}
+
+fn secret_tool_error(output: &Output) -> Error {
+    Error::TokenStoreError(format!("{} failed: {}",
+                                   SECRET_TOOL,
+                                   String::from_utf8_lossy(&output.stderr).trim()))
+}
+
+fn utf8(bytes: Vec<u8>) -> Result<String> {
+    String::from_utf8(bytes)
+        .map_err(|_| Error::TokenStoreError(String::from("stored token isn't valid UTF-8")))
+}
+
+/// Creates or truncates `path`, readable and writable by its owner only from the start.
+#[cfg(unix)]
+fn create_for_owner(path: &Path) -> Result<File> {
+    use std::fs::OpenOptions;
+    use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+
+    let file = try!(OpenOptions::new()
+                        .write(true)
+                        .create(true)
+                        .truncate(true)
+                        .mode(0o600)
+                        .open(path));
+    // The mode is only applied to a new file.
+    try!(file.set_permissions(fs::Permissions::from_mode(0o600)));
+    Ok(file)
+}
+
+#[cfg(not(unix))]
+fn create_for_owner(path: &Path) -> Result<File> {
+    Ok(try!(File::create(path)))
+}
+
+fn passphrase() -> Result<String> {
+    let mut cached = PASSPHRASE.lock().expect("Token passphrase lock poisoned");
+    if let Some(ref passphrase) = *cached {
+        return Ok(passphrase.clone());
+    }
+    let passphrase = match henv::var(TOKEN_PASSPHRASE_ENVVAR) {
+        Ok(passphrase) => passphrase,
+        Err(_) => try!(prompt_passphrase()),
+    };
+    if passphrase.is_empty() {
+        return Err(Error::TokenStoreError(String::from("the passphrase of the encrypted token \
+                                                        file can't be empty")));
+    }
+    *cached = Some(passphrase.clone());
+    Ok(passphrase)
+}
+
+/// Asks for the passphrase on the terminal, without echoing it.
+#[cfg(unix)]
+fn prompt_passphrase() -> Result<String> {
+    use std::fs::OpenOptions;
+    use std::io::{BufRead, BufReader};
+    use std::mem;
+    use std::os::unix::io::AsRawFd;
+
+    use libc;
+
+    let mut tty = try!(OpenOptions::new()
+                           .read(true)
+                           .write(true)
+                           .open("/dev/tty")
+                           .map_err(|e| {
+        Error::TokenStoreError(format!("can't ask for the passphrase of the encrypted token \
+                                        file, set {}: {}",
+                                       TOKEN_PASSPHRASE_ENVVAR,
+                                       e))
+    }));
+    try!(write!(tty, "Passphrase for the auth token file: "));
+    try!(tty.flush());
+    let fd = tty.as_raw_fd();
+    let mut term: libc::termios = unsafe { mem::zeroed() };
+    let echo_off = unsafe { libc::tcgetattr(fd, &mut term) } == 0;
+    if echo_off {
+        let mut quiet = term;
+        quiet.c_lflag &= !libc::ECHO;
+        unsafe { libc::tcsetattr(fd, libc::TCSANOW, &quiet) };
+    }
+    let mut line = String::new();
+    let read = BufReader::new(&tty).read_line(&mut line);
+    if echo_off {
+        unsafe { libc::tcsetattr(fd, libc::TCSANOW, &term) };
+    }
+    try!(writeln!(tty, ""));
+    try!(read);
+    Ok(line.trim_right_matches(|c| c == '\r' || c == '\n').to_string())
+}
+
+#[cfg(not(unix))]
+fn prompt_passphrase() -> Result<String> {
+    Err(Error::TokenStoreError(format!("set {} to unlock the encrypted token file",
+                                       TOKEN_PASSPHRASE_ENVVAR)))
+}
+
+#[cfg(test)]
+mod test {
+    use std::ffi::OsString;
+
+    use super::{redact, redact_args, TokenStore};
+
+    #[test]
+    fn redact_hides_tokens() {
+        assert_eq!(redact("short"), "********");
+        assert_eq!(redact("0123456789abcdef0123"), "********0123");
+
+        let args: Vec<OsString> = vec!["hab", "pkg", "upload", "-z", "0123456789abcdef0123",
+                                       "--auth=secret", "-zsecret", "pkg.hart"]
+            .into_iter()
+            .map(OsString::from)
+            .collect();
+        assert_eq!(redact_args(&args),
+                   vec!["hab", "pkg", "upload", "-z", "********0123", "--auth=********",
+                        "-z********", "pkg.hart"]);
+    }
+
+    #[test]
+    fn token_store_round_trips_through_strings() {
+        for name in TokenStore::variants() {
+            assert_eq!(&name.parse::<TokenStore>().unwrap().to_string(), name);
+        }
+        assert!("vault".parse::<TokenStore>().is_err());
+    }
+}
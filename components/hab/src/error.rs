@@ -23,6 +23,8 @@ use depot_client;
 use common;
 use hcore;
 use handlebars;
+use hyper::status::StatusCode;
+use serde_json;
 use toml;
 
 pub type Result<T> = result::Result<T, Error>;
@@ -46,17 +48,105 @@ pub enum Error {
     HabitatCore(hcore::Error),
     HandlebarsRenderError(handlebars::TemplateRenderError),
     IO(io::Error),
+    JsonSerialize(serde_json::Error),
     PackageArchiveMalformed(String),
     PathPrefixError(path::StripPrefixError),
+    PlanLintFailed(usize),
+    ProfileNotFound(String),
     ProvidesError(String),
     RootRequired,
     SubcommandNotSupported(String),
     UnsupportedExportFormat(String),
+    TokenStoreError(String),
     TomlDeserializeError(toml::de::Error),
     TomlSerializeError(toml::ser::Error),
+    UserNamespaceUnavailable(String),
     Utf8Error(String),
 }
 
+impl Error {
+    /// Returns a code for the kind of error which stays the same across releases, unlike the
+    /// message, for programs reading `--format json` output.
+    pub fn code(&self) -> &'static str {
+        match *self {
+            Error::ArgumentError(_) => "argument-error",
+            Error::ButterflyError(_) => "butterfly-error",
+            Error::CommandNotFoundInPkg(_) => "command-not-found-in-pkg",
+            Error::CryptoCLI(_) => "crypto-error",
+            Error::DepotClient(ref e) => depot_client_code(e),
+            Error::DockerDaemonDown => "docker-daemon-down",
+            Error::DockerFileSharingNotEnabled => "docker-file-sharing-not-enabled",
+            Error::DockerImageNotFound(_) => "docker-image-not-found",
+            Error::DockerNetworkDown(_) => "docker-network-down",
+            Error::ExecCommandNotFound(_) => "exec-command-not-found",
+            Error::FFINulError(_) => "nul-in-string",
+            Error::FileNotFound(_) => "file-not-found",
+            Error::HabitatCommon(ref e) => common_code(e),
+            Error::HabitatCore(ref e) => core_code(e),
+            Error::HandlebarsRenderError(_) => "template-render-error",
+            Error::IO(_) => "io-error",
+            Error::JsonSerialize(_) => "json-serialize-error",
+            Error::PackageArchiveMalformed(_) => "package-archive-malformed",
+            Error::PathPrefixError(_) => "path-prefix-error",
+            Error::PlanLintFailed(_) => "plan-lint-failed",
+            Error::ProfileNotFound(_) => "profile-not-found",
+            Error::ProvidesError(_) => "provides-not-found",
+            Error::RootRequired => "root-required",
+            Error::SubcommandNotSupported(_) => "subcommand-not-supported",
+            Error::UnsupportedExportFormat(_) => "unsupported-export-format",
+            Error::TokenStoreError(_) => "token-store-error",
+            Error::TomlDeserializeError(_) => "toml-deserialize-error",
+            Error::TomlSerializeError(_) => "toml-serialize-error",
+            Error::UserNamespaceUnavailable(_) => "user-namespace-unavailable",
+            Error::Utf8Error(_) => "utf8-error",
+        }
+    }
+}
+
+fn common_code(err: &common::Error) -> &'static str {
+    match *err {
+        common::Error::ArtifactIdentMismatch(_) => "artifact-ident-mismatch",
+        common::Error::CryptoKeyError(_) => "crypto-key-error",
+        common::Error::DepotClient(ref e) => depot_client_code(e),
+        common::Error::HabitatCore(ref e) => core_code(e),
+        common::Error::OfflineArtifactMissing(_) => "offline-artifact-missing",
+        common::Error::PackageHasDependents(_, _) => "package-has-dependents",
+        common::Error::PackageRunning(_) => "package-running",
+        common::Error::RootRequired => "root-required",
+        _ => "habitat-common-error",
+    }
+}
+
+fn core_code(err: &hcore::Error) -> &'static str {
+    match *err {
+        hcore::Error::CryptoError(_) => "crypto-error",
+        hcore::Error::FileNotFound(_) => "file-not-found",
+        hcore::Error::InvalidPackageIdent(_) => "invalid-package-ident",
+        hcore::Error::KeyRevoked(_) => "key-revoked",
+        hcore::Error::MissingSignature(_) => "missing-signature",
+        hcore::Error::PackageNotFound(_) => "package-not-found",
+        hcore::Error::TargetMatchError(_) => "target-mismatch",
+        _ => "habitat-core-error",
+    }
+}
+
+fn depot_client_code(err: &depot_client::Error) -> &'static str {
+    match *err {
+        depot_client::Error::APIError(StatusCode::NotFound, _) => "depot-not-found",
+        depot_client::Error::APIError(StatusCode::Unauthorized, _) => "depot-unauthorized",
+        depot_client::Error::APIError(StatusCode::Forbidden, _) => "depot-forbidden",
+        depot_client::Error::APIError(StatusCode::Conflict, _) => "depot-conflict",
+        depot_client::Error::APIError(StatusCode::NotImplemented, _) => "depot-unsupported-target",
+        depot_client::Error::APIError(_, _) => "depot-api-error",
+        depot_client::Error::DownloadFailed(_) => "download-failed",
+        depot_client::Error::HabitatCore(ref e) => core_code(e),
+        depot_client::Error::HabitatHttpClient(_) |
+        depot_client::Error::HyperError(_) => "depot-unreachable",
+        depot_client::Error::UploadFailed(_) => "upload-failed",
+        _ => "depot-client-error",
+    }
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
@@ -103,11 +193,18 @@ impl fmt::Display for Error {
             Error::HabitatCore(ref e) => format!("{}", e),
             Error::HandlebarsRenderError(ref e) => format!("{}", e),
             Error::IO(ref err) => format!("{}", err),
+            Error::JsonSerialize(ref e) => format!("Can't serialize JSON: {}", e),
             Error::PackageArchiveMalformed(ref e) => {
                 format!("Package archive was unreadable or contained unexpected contents: {:?}",
                         e)
             }
             Error::PathPrefixError(ref err) => format!("{}", err),
+            Error::PlanLintFailed(n) => format!("Plan lint found {} error(s)", n),
+            Error::ProfileNotFound(ref p) => {
+                format!("No CLI profile named {}, create it with `hab cli profile create {}'",
+                        p,
+                        p)
+            }
             Error::ProvidesError(ref err) => format!("Can't find {}", err),
             Error::RootRequired => {
                 "Root or administrator permissions required to complete operation".to_string()
@@ -116,8 +213,14 @@ impl fmt::Display for Error {
                 format!("Subcommand `{}' not supported on this operating system", e)
             }
             Error::UnsupportedExportFormat(ref e) => format!("Unsupported export format: {}", e),
+            Error::TokenStoreError(ref e) => format!("Can't access the auth token store: {}", e),
             Error::TomlDeserializeError(ref e) => format!("Can't deserialize TOML: {}", e),
             Error::TomlSerializeError(ref e) => format!("Can't serialize TOML: {}", e),
+            Error::UserNamespaceUnavailable(ref e) => {
+                format!("Can't create the namespaces of a rootless Studio: {}\nUnprivileged \
+                         user namespaces must be enabled, see `sysctl user.max_user_namespaces'.",
+                        e)
+            }
             Error::Utf8Error(ref e) => format!("Error processing a string as UTF-8: {}", e),
         };
         write!(f, "{}", msg)
@@ -143,14 +246,19 @@ impl error::Error for Error {
             Error::HabitatCore(ref err) => err.description(),
             Error::HandlebarsRenderError(ref err) => err.description(),
             Error::IO(ref err) => err.description(),
+            Error::JsonSerialize(_) => "Can't serialize JSON",
             Error::PackageArchiveMalformed(_) => "Package archive was unreadable or had unexpected contents",
             Error::PathPrefixError(ref err) => err.description(),
+            Error::PlanLintFailed(_) => "Plan lint found errors",
+            Error::ProfileNotFound(_) => "CLI profile not found",
             Error::ProvidesError(_) => "Can't find a package that provides the given search parameter",
             Error::RootRequired => "Root or administrator permissions required to complete operation",
             Error::SubcommandNotSupported(_) => "Subcommand not supported on this operating system",
             Error::UnsupportedExportFormat(_) => "Unsupported export format",
+            Error::TokenStoreError(_) => "Can't access the auth token store",
             Error::TomlDeserializeError(_) => "Can't deserialize TOML",
             Error::TomlSerializeError(_) => "Can't serialize TOML",
+            Error::UserNamespaceUnavailable(_) => "Can't create a user namespace",
             Error::Utf8Error(_) => "Error processing string as UTF-8",
         }
     }
@@ -192,6 +300,12 @@ impl From<io::Error> for Error {
     }
 }
 
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Error {
+        Error::JsonSerialize(err)
+    }
+}
+
 impl From<path::StripPrefixError> for Error {
     fn from(err: path::StripPrefixError) -> Error {
         Error::PathPrefixError(err)
@@ -23,6 +23,9 @@ extern crate ansi_term;
 extern crate clap;
 extern crate hyper;
 #[macro_use]
+extern crate lazy_static;
+extern crate libc;
+#[macro_use]
 extern crate log;
 extern crate pbr;
 extern crate regex;
@@ -30,6 +33,11 @@ extern crate retry;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
+extern crate serde_json;
+extern crate tar;
+#[cfg(test)]
+extern crate tempdir;
+extern crate time;
 extern crate toml;
 extern crate url;
 extern crate uuid;
@@ -39,6 +47,7 @@ pub mod analytics;
 pub mod cli;
 pub mod command;
 pub mod config;
+pub mod credentials;
 pub mod error;
 mod exec;
 
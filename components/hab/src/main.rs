@@ -33,17 +33,19 @@ use std::thread;
 
 use clap::{ArgMatches, Shell};
 
-use common::ui::{Coloring, UI, NOCOLORING_ENVVAR, NONINTERACTIVE_ENVVAR};
+use common::ui::{Coloring, OutputFormat, UI, NOCOLORING_ENVVAR, NONINTERACTIVE_ENVVAR};
 use hcore::env as henv;
-use hcore::crypto::{init, default_cache_key_path, SigKeyPair};
+use hcore::crypto::{artifact, init, default_cache_key_path, SigKeyPair};
 use hcore::crypto::keys::PairType;
 use hcore::fs::{cache_artifact_path, cache_analytics_path, cache_key_path};
 use hcore::service::ServiceGroup;
-use hcore::package::PackageIdent;
+use hcore::package::{PackageIdent, VersionReq};
 use hcore::url::{DEFAULT_DEPOT_URL, DEPOT_URL_ENVVAR};
 
-use hab::{analytics, cli, command, config, AUTH_TOKEN_ENVVAR, ORIGIN_ENVVAR, PRODUCT, VERSION};
+use hab::{analytics, cli, command, config, credentials, AUTH_TOKEN_ENVVAR, ORIGIN_ENVVAR, PRODUCT,
+          VERSION};
 use hab::error::{Error, Result};
+use hab::command::pkg::sbom::SbomSpec;
 
 /// Makes the --org CLI param optional when this env var is set
 const HABITAT_ORG_ENVVAR: &'static str = "HAB_ORG";
@@ -68,7 +70,7 @@ fn main() {
     let mut ui = ui();
     thread::spawn(|| analytics::instrument_subcommand());
     if let Err(e) = start(&mut ui) {
-        ui.fatal(e).unwrap();
+        ui.fatal_with_code(e.code(), e).unwrap();
         std::process::exit(1)
     }
 }
@@ -77,19 +79,29 @@ fn start(ui: &mut UI) -> Result<()> {
     try!(exec_subcommand_if_called(ui));
 
     let (args, remaining_args) = raw_parse_args();
-    debug!("clap cli args: {:?}", &args);
-    debug!("remaining cli args: {:?}", &remaining_args);
+    debug!("clap cli args: {:?}", credentials::redact_args(&args));
+    debug!("remaining cli args: {:?}", credentials::redact_args(&remaining_args));
     let app_matches = cli::get()
         .get_matches_from_safe_borrow(&mut args.iter())
         .unwrap_or_else(|e| {
                             analytics::instrument_clap_error(&e);
-                            e.exit();
+                            exit_with_clap_error(ui, &args, e);
                         });
+    ui.set_format(try!(output_format_param(&app_matches)));
     match app_matches.subcommand() {
         ("cli", Some(matches)) => {
             match matches.subcommand() {
                 ("setup", Some(_)) => try!(sub_cli_setup(ui)),
                 ("completers", Some(m)) => try!(sub_cli_completers(m)),
+                ("profile", Some(m)) => {
+                    match m.subcommand() {
+                        ("create", Some(sc)) => try!(sub_cli_profile_create(ui, sc)),
+                        ("list", Some(_)) => try!(sub_cli_profile_list(ui)),
+                        ("use", Some(sc)) => try!(sub_cli_profile_use(ui, sc)),
+                        _ => unreachable!(),
+                    }
+                }
+                ("token-store", Some(m)) => try!(sub_cli_token_store(ui, m)),
                 _ => unreachable!(),
             }
         }
@@ -99,9 +111,10 @@ fn start(ui: &mut UI) -> Result<()> {
                 ("key", Some(m)) => {
                     match m.subcommand() {
                         ("download", Some(sc)) => try!(sub_origin_key_download(ui, sc)),
-                        ("export", Some(sc)) => try!(sub_origin_key_export(sc)),
+                        ("export", Some(sc)) => try!(sub_origin_key_export(ui, sc)),
                         ("generate", Some(sc)) => try!(sub_origin_key_generate(ui, sc)),
                         ("import", Some(_)) => try!(sub_origin_key_import(ui)),
+                        ("revoke", Some(sc)) => try!(sub_origin_key_revoke(ui, sc)),
                         ("upload", Some(sc)) => try!(sub_origin_key_upload(ui, sc)),
                         _ => unreachable!(),
                     }
@@ -113,16 +126,22 @@ fn start(ui: &mut UI) -> Result<()> {
             match matches.subcommand() {
                 ("binlink", Some(m)) => try!(sub_pkg_binlink(ui, m)),
                 ("build", Some(m)) => try!(sub_pkg_build(ui, m)),
-                ("config", Some(m)) => try!(sub_pkg_config(m)),
-                ("env", Some(m)) => try!(sub_pkg_env(m)),
+                ("bundle", Some(m)) => try!(sub_pkg_bundle(ui, m)),
+                ("config", Some(m)) => try!(sub_pkg_config(ui, m)),
+                ("dependencies", Some(m)) => try!(sub_pkg_dependencies(ui, m)),
+                ("diff", Some(m)) => try!(sub_pkg_diff(ui, m)),
+                ("env", Some(m)) => try!(sub_pkg_env(ui, m)),
                 ("exec", Some(m)) => try!(sub_pkg_exec(m, remaining_args)),
                 ("export", Some(m)) => try!(sub_pkg_export(ui, m)),
-                ("hash", Some(m)) => try!(sub_pkg_hash(m)),
+                ("hash", Some(m)) => try!(sub_pkg_hash(ui, m)),
                 ("install", Some(m)) => try!(sub_pkg_install(ui, m)),
-                ("path", Some(m)) => try!(sub_pkg_path(m)),
-                ("provides", Some(m)) => try!(sub_pkg_provides(m)),
-                ("search", Some(m)) => try!(sub_pkg_search(m)),
+                ("list", Some(m)) => try!(sub_pkg_list(ui, m)),
+                ("path", Some(m)) => try!(sub_pkg_path(ui, m)),
+                ("provides", Some(m)) => try!(sub_pkg_provides(ui, m)),
+                ("sbom", Some(m)) => try!(sub_pkg_sbom(ui, m)),
+                ("search", Some(m)) => try!(sub_pkg_search(ui, m)),
                 ("sign", Some(m)) => try!(sub_pkg_sign(ui, m)),
+                ("uninstall", Some(m)) => try!(sub_pkg_uninstall(ui, m)),
                 ("upload", Some(m)) => try!(sub_pkg_upload(ui, m)),
                 ("verify", Some(m)) => try!(sub_pkg_verify(ui, m)),
                 ("header", Some(m)) => try!(sub_pkg_header(ui, m)),
@@ -132,6 +151,7 @@ fn start(ui: &mut UI) -> Result<()> {
         ("plan", Some(matches)) => {
             match matches.subcommand() {
                 ("init", Some(m)) => try!(sub_plan_init(ui, m)),
+                ("lint", Some(m)) => try!(sub_plan_lint(ui, m)),
                 _ => unreachable!(),
             }
         }
@@ -191,11 +211,39 @@ fn sub_cli_completers(m: &ArgMatches) -> Result<()> {
     Ok(())
 }
 
+fn sub_cli_profile_create(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let name = m.value_of("PROFILE").unwrap(); // Required via clap
+    let profile = config::Profile {
+        depot_url: m.value_of("DEPOT_URL").map(|v| v.to_string()),
+        origin: m.value_of("ORIGIN").map(|v| v.to_string()),
+        auth_token: m.value_of("AUTH_TOKEN").map(|v| v.to_string()),
+        channel: m.value_of("CHANNEL").map(|v| v.to_string()),
+    };
+
+    command::cli::profile::create(ui, name, profile)
+}
+
+fn sub_cli_profile_list(ui: &mut UI) -> Result<()> {
+    command::cli::profile::list(ui)
+}
+
+fn sub_cli_profile_use(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let name = m.value_of("PROFILE").unwrap(); // Required via clap
+    command::cli::profile::activate(ui, name)
+}
+
+fn sub_cli_token_store(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let store = match m.value_of("STORE") {
+        Some(store) => Some(try!(store.parse())),
+        None => None,
+    };
+    command::cli::token_store::start(ui, store)
+}
+
 fn sub_origin_key_download(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let origin = m.value_of("ORIGIN").unwrap(); // Required via clap
     let revision = m.value_of("REVISION");
-    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
-    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let url = try!(depot_url_param_or_env(&m));
 
     command::origin::key::download::start(ui,
                                           &url,
@@ -204,12 +252,15 @@ fn sub_origin_key_download(ui: &mut UI, m: &ArgMatches) -> Result<()> {
                                           &default_cache_key_path(Some(&*FS_ROOT)))
 }
 
-fn sub_origin_key_export(m: &ArgMatches) -> Result<()> {
+fn sub_origin_key_export(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let origin = m.value_of("ORIGIN").unwrap(); // Required via clap
     let pair_type = try!(PairType::from_str(m.value_of("PAIR_TYPE").unwrap_or("public")));
     init();
 
-    command::origin::key::export::start(origin, pair_type, &default_cache_key_path(Some(&*FS_ROOT)))
+    command::origin::key::export::start(ui,
+                                        origin,
+                                        pair_type,
+                                        &default_cache_key_path(Some(&*FS_ROOT)))
 }
 
 fn sub_origin_key_generate(ui: &mut UI, m: &ArgMatches) -> Result<()> {
@@ -227,10 +278,22 @@ fn sub_origin_key_import(ui: &mut UI) -> Result<()> {
     command::origin::key::import::start(ui, &content, &default_cache_key_path(Some(&*FS_ROOT)))
 }
 
+fn sub_origin_key_revoke(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let name_with_rev = m.value_of("KEY_WITH_REV").unwrap(); // Required via clap
+    let url = try!(depot_url_param_or_env(&m));
+    let token = try!(auth_token_param_or_env(&m, &url));
+    init();
+
+    command::origin::key::revoke::start(ui,
+                                        &url,
+                                        &token,
+                                        &name_with_rev,
+                                        &default_cache_key_path(Some(&*FS_ROOT)))
+}
+
 fn sub_origin_key_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
-    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
-    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
-    let token = try!(auth_token_param_or_env(&m));
+    let url = try!(depot_url_param_or_env(&m));
+    let token = try!(auth_token_param_or_env(&m, &url));
 
     init();
 
@@ -239,7 +302,7 @@ fn sub_origin_key_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
         // you can either specify files, or infer the latest key names
         let with_secret = m.is_present("WITH_SECRET");
         command::origin::key::upload_latest::start(ui,
-                                                   url,
+                                                   &url,
                                                    &token,
                                                    origin,
                                                    with_secret,
@@ -247,7 +310,7 @@ fn sub_origin_key_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     } else {
         let keyfile = Path::new(m.value_of("PUBLIC_FILE").unwrap());
         let secret_keyfile = m.value_of("SECRET_FILE").map(|f| Path::new(f));
-        command::origin::key::upload::start(ui, url, &token, &keyfile, secret_keyfile)
+        command::origin::key::upload::start(ui, &url, &token, &keyfile, secret_keyfile)
     }
 }
 
@@ -286,17 +349,70 @@ fn sub_pkg_build(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     command::pkg::build::start(ui, plan_context, root, src, keys, reuse)
 }
 
-fn sub_pkg_config(m: &ArgMatches) -> Result<()> {
+fn sub_pkg_bundle(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())); // Required via clap
+    let url = try!(depot_url_param_or_env(&m));
+    let dst_dir = match m.value_of("DEST_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => try!(env::current_dir()),
+    };
+    init();
+    let pair = try!(SigKeyPair::get_latest_pair_for(&try!(origin_param_or_env(&m)),
+                                                    &default_cache_key_path(Some(&*FS_ROOT))));
+
+    command::pkg::bundle::start(ui,
+                                &url,
+                                &ident,
+                                &dst_dir,
+                                &pair,
+                                &*FS_ROOT,
+                                &cache_artifact_path(Some(&*FS_ROOT)),
+                                &default_cache_key_path(Some(&*FS_ROOT)))
+}
+
+fn sub_pkg_config(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap()));
 
-    try!(common::command::package::config::start(&ident, &*FS_ROOT));
+    try!(common::command::package::config::start(ui, &ident, &*FS_ROOT));
     Ok(())
 }
 
-fn sub_pkg_env(m: &ArgMatches) -> Result<()> {
+fn sub_pkg_dependencies(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())); // Required via clap
+
+    command::pkg::dependencies::start(ui,
+                                      &ident,
+                                      &*FS_ROOT,
+                                      m.is_present("TRANSITIVE"),
+                                      m.is_present("REVERSE"),
+                                      m.is_present("TREE"))
+}
+
+fn sub_pkg_diff(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let a = Path::new(m.value_of("SOURCE_A").unwrap()); // Required via clap
+    let b = Path::new(m.value_of("SOURCE_B").unwrap()); // Required via clap
+
+    command::pkg::diff::start(ui, &a, &b)
+}
+
+fn sub_pkg_sbom(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let source = m.value_of("PKG_IDENT_OR_ARTIFACT").unwrap(); // Required via clap
+    let spec = match m.value_of("SPEC") {
+        Some(s) => try!(SbomSpec::from_str(s)),
+        None => SbomSpec::default(),
+    };
+
+    command::pkg::sbom::start(ui,
+                              source,
+                              &*FS_ROOT,
+                              &cache_artifact_path(Some(&*FS_ROOT)),
+                              spec)
+}
+
+fn sub_pkg_env(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?;
 
-    command::pkg::env::start(&ident, &*FS_ROOT)
+    command::pkg::env::start(ui, &ident, &*FS_ROOT)
 }
 
 fn sub_pkg_exec(m: &ArgMatches, cmd_args: Vec<OsString>) -> Result<()> {
@@ -313,19 +429,19 @@ fn sub_pkg_export(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     command::pkg::export::start(ui, &ident, &export_fmt)
 }
 
-fn sub_pkg_hash(m: &ArgMatches) -> Result<()> {
+fn sub_pkg_hash(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     init();
     match m.value_of("SOURCE") {
         Some(source) => {
             // hash single file
-            command::pkg::hash::start(&source)
+            command::pkg::hash::start(ui, &source)
         }
         None => {
             // read files from stdin
             let stdin = io::stdin();
             for line in stdin.lock().lines() {
                 let file = try!(line);
-                try!(command::pkg::hash::start(file.trim_right()));
+                try!(command::pkg::hash::start(ui, file.trim_right()));
             }
             Ok(())
         }
@@ -339,9 +455,22 @@ fn sub_plan_init(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     command::plan::init::start(ui, origin, include_callbacks, name)
 }
 
+fn sub_plan_lint(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let plan_context = Path::new(m.value_of("PLAN_CONTEXT").unwrap_or("."));
+    let mut bind_pkgs = Vec::new();
+    if let Some(binds) = m.values_of("BIND") {
+        for bind in binds {
+            let mut parts = bind.splitn(2, '='); // Validated via clap
+            let name = parts.next().unwrap().to_string();
+            bind_pkgs.push((name, try!(PackageIdent::from_str(parts.next().unwrap()))));
+        }
+    }
+
+    command::plan::lint::start(ui, &plan_context, &*FS_ROOT, &bind_pkgs)
+}
+
 fn sub_pkg_install(ui: &mut UI, m: &ArgMatches) -> Result<()> {
-    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
-    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let url = try!(depot_url_param_or_env(&m));
     let ident_or_artifacts = m.values_of("PKG_IDENT_OR_ARTIFACT").unwrap(); // Required via clap
     let ignore_target = if m.is_present("IGNORE_TARGET") {
         true
@@ -351,14 +480,35 @@ fn sub_pkg_install(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     init();
 
     for ident_or_artifact in ident_or_artifacts {
-        let pkg_ident = try!(common::command::package::install::start(ui,
-                                                      url,
+        let pkg_ident = if m.is_present("BUNDLE") {
+            try!(common::command::package::install::start_from_bundle(ui,
+                                                      Path::new(ident_or_artifact),
+                                                      PRODUCT,
+                                                      VERSION,
+                                                      &*FS_ROOT,
+                                                      &cache_artifact_path(Some(&*FS_ROOT)),
+                                                      ignore_target))
+        } else if let Some(req) = m.value_of("VERSION_REQ") {
+            let ident = try!(PackageIdent::from_str(ident_or_artifact));
+            try!(common::command::package::install::start_matching(ui,
+                                                      &url,
+                                                      &ident,
+                                                      &try!(VersionReq::from_str(req)),
+                                                      PRODUCT,
+                                                      VERSION,
+                                                      &*FS_ROOT,
+                                                      &cache_artifact_path(Some(&*FS_ROOT)),
+                                                      ignore_target))
+        } else {
+            try!(common::command::package::install::start(ui,
+                                                      &url,
                                                       ident_or_artifact,
                                                       PRODUCT,
                                                       VERSION,
                                                       &*FS_ROOT,
                                                       &cache_artifact_path(Some(&*FS_ROOT)),
-                                                      ignore_target));
+                                                      ignore_target))
+        };
         if m.is_present("BINLINK") {
             let dest_dir = Path::new(m.value_of("DEST_DIR").unwrap_or(DEFAULT_BINLINK_DIR));
             command::pkg::binlink::binlink_all_in_pkg(ui, &pkg_ident, dest_dir, &*FS_ROOT)?;
@@ -367,26 +517,34 @@ fn sub_pkg_install(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     Ok(())
 }
 
-fn sub_pkg_path(m: &ArgMatches) -> Result<()> {
+fn sub_pkg_path(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap()));
 
-    command::pkg::path::start(&ident, &*FS_ROOT)
+    let version_req = match m.value_of("VERSION_REQ") {
+        Some(req) => Some(try!(VersionReq::from_str(req))),
+        None => None,
+    };
+
+    command::pkg::path::start(ui, &ident, version_req.as_ref(), &*FS_ROOT)
+}
+
+fn sub_pkg_list(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    command::pkg::list::start(ui, m.value_of("PKG_IDENT"), &*FS_ROOT)
 }
 
-fn sub_pkg_provides(m: &ArgMatches) -> Result<()> {
+fn sub_pkg_provides(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let filename = m.value_of("FILE").unwrap(); // Required via clap
 
     let full_releases = m.is_present("FULL_RELEASES");
     let full_paths = m.is_present("FULL_PATHS");
 
-    command::pkg::provides::start(&filename, &*FS_ROOT, full_releases, full_paths)
+    command::pkg::provides::start(ui, &filename, &*FS_ROOT, full_releases, full_paths)
 }
 
-fn sub_pkg_search(m: &ArgMatches) -> Result<()> {
-    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
-    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+fn sub_pkg_search(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let url = try!(depot_url_param_or_env(&m));
     let search_term = m.value_of("SEARCH_TERM").unwrap(); // Required via clap
-    command::pkg::search::start(&search_term, &url)
+    command::pkg::search::start(ui, &search_term, &url)
 }
 
 fn sub_pkg_sign(ui: &mut UI, m: &ArgMatches) -> Result<()> {
@@ -396,30 +554,60 @@ fn sub_pkg_sign(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let pair = try!(SigKeyPair::get_latest_pair_for(&try!(origin_param_or_env(&m)),
                                                     &default_cache_key_path(Some(&*FS_ROOT))));
 
-    command::pkg::sign::start(ui, &pair, &src, &dst)
+    if m.is_present("COUNTERSIGN") {
+        command::pkg::sign::countersign(ui, &pair, &src, &dst)
+    } else {
+        command::pkg::sign::start(ui, &pair, &src, &dst)
+    }
+}
+
+fn sub_pkg_uninstall(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())); // Required via clap
+    // KEEP_LATEST is validated via clap
+    let keep_latest = m.value_of("KEEP_LATEST").map(|n| n.parse::<usize>().unwrap());
+    init();
+
+    try!(common::command::package::uninstall::start(ui,
+                                                    &ident,
+                                                    &*FS_ROOT,
+                                                    m.is_present("FORCE"),
+                                                    keep_latest));
+    Ok(())
 }
 
 fn sub_pkg_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
-    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
     let key_path = cache_key_path(Some(&*FS_ROOT));
     // don't use a pathbuf, as the P generic param for upload::start below is bound to a &str
     let key_path = try!(key_path
                             .to_str()
                             .ok_or(Error::CryptoCLI("Invalid key path".to_string())));
-    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
-    let token = try!(auth_token_param_or_env(&m));
+    let url = try!(depot_url_param_or_env(&m));
+    let token = try!(auth_token_param_or_env(&m, &url));
+    let channel = try!(channel_param_or_profile(&m));
     let artifact_paths = m.values_of("HART_FILE").unwrap(); // Required via clap
     for artifact_path in artifact_paths {
-        try!(command::pkg::upload::start(ui, &url, &token, &artifact_path, &key_path));
+        try!(command::pkg::upload::start(ui,
+                                         &url,
+                                         &token,
+                                         &artifact_path,
+                                         &key_path,
+                                         channel.as_ref().map(|c| c.as_str())));
     }
     Ok(())
 }
 
 fn sub_pkg_verify(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let src = Path::new(m.value_of("SOURCE").unwrap()); // Required via clap
+    let mut required = artifact::required_signers();
+    if let Some(signers) = m.values_of("SIGNER") {
+        required.extend(signers.map(|s| s.to_string()));
+    }
     init();
 
-    command::pkg::verify::start(ui, &src, &default_cache_key_path(Some(&*FS_ROOT)))
+    command::pkg::verify::start(ui,
+                                &src,
+                                &default_cache_key_path(Some(&*FS_ROOT)),
+                                &required)
 }
 
 fn sub_pkg_header(ui: &mut UI, m: &ArgMatches) -> Result<()> {
@@ -469,6 +657,24 @@ fn sub_user_key_generate(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     command::user::key::generate::start(ui, user, &default_cache_key_path(Some(&*FS_ROOT)))
 }
 
+/// Exits on a usage error, printed as an error with the `usage-error` code when `--format json`
+/// was given and as clap prints it otherwise.
+fn exit_with_clap_error(ui: &mut UI, args: &[OsString], e: clap::Error) -> ! {
+    let args: Vec<Option<&str>> = args.iter().map(|a| a.to_str()).collect();
+    let json = args.windows(2).any(|w| w == [Some("--format"), Some("json")]) ||
+               args.contains(&Some("--format=json"));
+    match e.kind {
+        clap::ErrorKind::HelpDisplayed |
+        clap::ErrorKind::VersionDisplayed => e.exit(),
+        _ if json => {
+            ui.set_format(OutputFormat::Json);
+            ui.fatal_with_code("usage-error", &e.message).unwrap();
+            std::process::exit(1)
+        }
+        _ => e.exit(),
+    }
+}
+
 fn ui() -> UI {
     let isatty = if henv::var(NONINTERACTIVE_ENVVAR)
            .map(|val| val == "true")
@@ -531,9 +737,10 @@ fn raw_parse_args() -> (Vec<OsString>, Vec<OsString>) {
 }
 
 /// Check to see if the user has passed in an AUTH_TOKEN param. If not, check the
-/// HAB_AUTH_TOKEN env var. If not, check the CLI config to see if there is a default auth
-/// token set. If that's empty too, then error.
-fn auth_token_param_or_env(m: &ArgMatches) -> Result<String> {
+/// HAB_AUTH_TOKEN env var. If not, check the CLI config to see if the profile in use has an auth
+/// token or if there is a default auth token set, as long as it's for the Depot at `url`. If
+/// that's empty too, then error.
+fn auth_token_param_or_env(m: &ArgMatches, url: &str) -> Result<String> {
     match m.value_of("AUTH_TOKEN") {
         Some(o) => Ok(o.to_string()),
         None => {
@@ -541,7 +748,7 @@ fn auth_token_param_or_env(m: &ArgMatches) -> Result<String> {
                 Ok(v) => Ok(v),
                 Err(_) => {
                     let config = try!(config::load());
-                    match config.auth_token {
+                    match try!(config.auth_token_for(url)) {
                         Some(v) => Ok(v),
                         None => return Err(Error::ArgumentError("No auth token specified")),
                     }
@@ -552,8 +759,8 @@ fn auth_token_param_or_env(m: &ArgMatches) -> Result<String> {
 }
 
 /// Check to see if the user has passed in an ORIGIN param.  If not, check the HABITAT_ORIGIN env
-/// var. If not, check the CLI config to see if there is a default origin set. If that's empty too,
-/// then error.
+/// var. If not, check the CLI config to see if the profile in use has an origin or if there is a
+/// default origin set. If that's empty too, then error.
 fn origin_param_or_env(m: &ArgMatches) -> Result<String> {
     match m.value_of("ORIGIN") {
         Some(o) => Ok(o.to_string()),
@@ -562,7 +769,7 @@ fn origin_param_or_env(m: &ArgMatches) -> Result<String> {
                 Ok(v) => Ok(v),
                 Err(_) => {
                     let config = try!(config::load());
-                    match config.origin {
+                    match try!(config.origin()) {
                         Some(v) => Ok(v),
                         None => return Err(Error::CryptoCLI("No origin specified".to_string())),
                     }
@@ -572,6 +779,45 @@ fn origin_param_or_env(m: &ArgMatches) -> Result<String> {
     }
 }
 
+/// Check to see if the user has passed in a DEPOT_URL param. If not, check the HAB_DEPOT_URL env
+/// var. If not, check the CLI config to see if the profile in use has a Depot URL. If that's
+/// empty too, then use the default Depot.
+fn depot_url_param_or_env(m: &ArgMatches) -> Result<String> {
+    match m.value_of("DEPOT_URL") {
+        Some(u) => Ok(u.to_string()),
+        None => {
+            match henv::var(DEPOT_URL_ENVVAR) {
+                Ok(v) => Ok(v),
+                Err(_) => {
+                    let config = try!(config::load());
+                    Ok(try!(config.depot_url()).unwrap_or(DEFAULT_DEPOT_URL.to_string()))
+                }
+            }
+        }
+    }
+}
+
+/// Check to see if the user has passed in a CHANNEL param. If not, use the channel of the profile
+/// in use, if any.
+fn channel_param_or_profile(m: &ArgMatches) -> Result<Option<String>> {
+    match m.value_of("CHANNEL") {
+        Some(c) => Ok(Some(c.to_string())),
+        None => Ok(try!(try!(config::load()).channel())),
+    }
+}
+
+/// Returns the output format given with `--format`, which may follow any subcommand, defaulting
+/// to plain output.
+fn output_format_param(m: &ArgMatches) -> Result<OutputFormat> {
+    if let Some(f) = m.value_of("OUTPUT_FORMAT") {
+        return Ok(try!(OutputFormat::from_str(f)));
+    }
+    match m.subcommand() {
+        (_, Some(sc)) => output_format_param(sc),
+        _ => Ok(OutputFormat::default()),
+    }
+}
+
 /// Check to see if the user has passed in an ORG param.
 /// If not, check the HABITAT_ORG env var. If that's
 /// empty too, then error.
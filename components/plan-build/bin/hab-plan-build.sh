@@ -253,6 +253,15 @@
 # * `$PREFIX`: Where to install the software; same as $pkg_prefix
 # * `$LD_RUN_PATH`: Where to find binaries at run time
 #
+# ## Reproducible Builds
+#
+# The artifact is written with every file's modification time set to the
+# time of `$pkg_release`, in name order and owned by `0:0`, so two builds of
+# the same plan from the same inputs produce the same files. Set
+# `$SOURCE_DATE_EPOCH` to the release time of an earlier build to get the
+# same release, and compare the results with `hab pkg diff`. The inputs of a
+# build are recorded in the package's `BUILD_MANIFEST`.
+#
 # ## Plan Callbacks
 #
 # `hab-plan-build` allows you to alter its behavior by defining callbacks
@@ -378,8 +387,13 @@ pkg_sys=$(uname -s | tr '[:upper:]' '[:lower:]')
 pkg_target="${pkg_arch}-${pkg_sys}"
 # The package's origin (i.e. acme)
 pkg_origin=""
-# Each release is a timestamp - `YYYYMMDDhhmmss`
-pkg_release=$(date -u +%Y%m%d%H%M%S)
+# Each release is a timestamp - `YYYYMMDDhhmmss`. Setting `$SOURCE_DATE_EPOCH`
+# pins it, which is required to reproduce an earlier build.
+if [[ -n "${SOURCE_DATE_EPOCH:-}" ]]; then
+  pkg_release=$(date -u -d "@${SOURCE_DATE_EPOCH}" +%Y%m%d%H%M%S)
+else
+  pkg_release=$(date -u +%Y%m%d%H%M%S)
+fi
 # The default build deps setting - an empty array
 pkg_build_deps=()
 # The default runtime deps setting - an empty array
@@ -2536,6 +2550,8 @@ _build_metadata() {
   echo "${pkg_origin}/${pkg_name}/${pkg_version}/${pkg_release}" \
     >> $pkg_prefix/IDENT
 
+  _render_build_manifest
+
   # Only generate `SVC_USER` & `SVC_GROUP` files if this package is a service.
   # We determine this by checking if there is a `hooks/run` script and/or
   # a set `$pkg_svc_run` value.
@@ -2776,7 +2792,73 @@ EOT
   return 0
 }
 
-# **Internal** Create the package artifact with `tar`/`hab pkg sign`
+# **Internal** Quote a string as a TOML basic string.
+_toml_string() {
+  local s="${1//\\/\\\\}"
+  s="${s//\"/\\\"}"
+  s="${s//$'\n'/\\n}"
+  s="${s//$'\t'/\\t}"
+  echo "\"$s\""
+}
+
+# **Internal** Print the lines of a metafile as a TOML array of strings.
+_toml_array_from_file() {
+  local line
+  local items=()
+  if [[ -f "$1" ]]; then
+    while read -r line; do
+      items+=("$(_toml_string "$line")")
+    done < "$1"
+  fi
+  local IFS=","
+  echo "[${items[*]}]"
+}
+
+# **Internal** Write the `$pkg_prefix/BUILD_MANIFEST`, a TOML document of
+# everything that went into the build: the plan, the sources and their
+# checksums, the exact dependencies and the build environment.
+_render_build_manifest() {
+  build_line "Writing build manifest"
+  local plan_shasum
+  plan_shasum=$($_shasum_cmd "$PLAN_CONTEXT/plan.sh" | cut -d " " -f 1)
+
+  cat <<-EOT > $pkg_prefix/BUILD_MANIFEST
+format_version = 1
+ident = $(_toml_string "${pkg_origin}/${pkg_name}/${pkg_version}/${pkg_release}")
+target = $(_toml_string "$pkg_target")
+source_date_epoch = $(_release_epoch)
+plan_shasum = $(_toml_string "$plan_shasum")
+hab_version = $(_toml_string "$($HAB_BIN --version)")
+
+[source]
+url = $(_toml_string "${pkg_source:-}")
+shasum = $(_toml_string "${pkg_shasum:-}")
+
+[deps]
+deps = $(_toml_array_from_file $pkg_prefix/DEPS)
+tdeps = $(_toml_array_from_file $pkg_prefix/TDEPS)
+build_deps = $(_toml_array_from_file $pkg_prefix/BUILD_DEPS)
+build_tdeps = $(_toml_array_from_file $pkg_prefix/BUILD_TDEPS)
+
+[environment]
+CFLAGS = $(_toml_string "${CFLAGS:-}")
+CPPFLAGS = $(_toml_string "${CPPFLAGS:-}")
+CXXFLAGS = $(_toml_string "${CXXFLAGS:-}")
+LDFLAGS = $(_toml_string "${LDFLAGS:-}")
+LD_RUN_PATH = $(_toml_string "${LD_RUN_PATH:-}")
+PATH = $(_toml_string "${PATH:-}")
+EOT
+}
+
+# **Internal** Print the time of `$pkg_release` in seconds since the epoch.
+_release_epoch() {
+  date -u -d "${pkg_release:0:8} ${pkg_release:8:2}:${pkg_release:10:2}:${pkg_release:12:2}" +%s
+}
+
+# **Internal** Create the package artifact with `tar`/`hab pkg sign`. Every
+# entry gets the release's time, a fixed owner and a fixed order, and xz runs
+# single threaded since its threaded output differs, so that the same files
+# always make the same artifact.
 _generate_artifact() {
   build_line "Generating package artifact"
   local tarf="$(dirname $pkg_artifact)/.$(basename ${pkg_artifact/%.${_artifact_ext}/.tar})"
@@ -2784,8 +2866,9 @@ _generate_artifact() {
 
   mkdir -pv "$(dirname "$pkg_artifact")"
   rm -fv $tarf $xzf $pkg_artifact
-  $_tar_cmd -cf $tarf $pkg_prefix
-  $_xz_cmd --compress -6 --threads=0 $tarf
+  $_tar_cmd --format=gnu --sort=name --mtime="@$(_release_epoch)" \
+    --owner=0 --group=0 --numeric-owner -cf $tarf $pkg_prefix
+  $_xz_cmd --compress -6 --threads=1 $tarf
   $HAB_BIN pkg sign --origin $pkg_origin $xzf $pkg_artifact
   rm -f $tarf $xzf
 }
@@ -88,6 +88,7 @@ ENVIRONMENT VARIABLES:
     HAB_ORIGIN          Propagates this variable into any studios
     HAB_ORIGIN_KEYS     Installs secret keys (\`-k' option overrides)
     HAB_STUDIOS_HOME    Sets a home path for all Studios (default: /hab/studios)
+    HAB_STUDIO_ROOTLESS Set by \`hab studio --rootless' when in a user namespace
     HAB_STUDIO_ROOT     Sets a Studio root (\`-r' option overrides)
     NO_SRC_PATH         If set, do not mount source path (\`-n' flag overrides)
     QUIET               Prints less output (\`-q' flag overrides)
@@ -393,18 +394,32 @@ new_studio() {
   $bb mkdir -p $v $HAB_STUDIO_ROOT/run
   $bb mkdir -p $v $HAB_STUDIO_ROOT/var/run
 
-  # Make  a `/dev/console` device, if it doesn't exist
-  if [ ! -r $HAB_STUDIO_ROOT/dev/console ]; then
-    $bb mknod -m 600 $HAB_STUDIO_ROOT/dev/console c 5 1
-  fi
-  # Make  a `/dev/null` device, if it doesn't exist
-  if [ ! -r $HAB_STUDIO_ROOT/dev/null ]; then
-    $bb mknod -m 666 $HAB_STUDIO_ROOT/dev/null c 1 3
+  # Device nodes can't be made in a user namespace, so a rootless Studio only
+  # gets the devices of the bind mounted `/dev`
+  if [ -z "${HAB_STUDIO_ROOTLESS}" ]; then
+    # Make  a `/dev/console` device, if it doesn't exist
+    if [ ! -r $HAB_STUDIO_ROOT/dev/console ]; then
+      $bb mknod -m 600 $HAB_STUDIO_ROOT/dev/console c 5 1
+    fi
+    # Make  a `/dev/null` device, if it doesn't exist
+    if [ ! -r $HAB_STUDIO_ROOT/dev/null ]; then
+      $bb mknod -m 666 $HAB_STUDIO_ROOT/dev/null c 1 3
+    fi
   fi
 
   # Unless `$NO_MOUNT` is set, mount filesystems such as `/dev`, `/proc`, and
   # company. If the mount already exists, skip it to be all idempotent and
   # nerdy like that
+  if [ -z "${NO_MOUNT}" -a -n "${HAB_STUDIO_ROOTLESS}" ]; then
+    # A user namespace may not mount a new `devpts` with the `tty` group or a
+    # new `sysfs`, so a rootless Studio shares the host's, submounts and all
+    if ! $bb mount | $bb grep -q "on $HAB_STUDIO_ROOT/dev type"; then
+      $bb mount $v --rbind /dev $HAB_STUDIO_ROOT/dev
+    fi
+    if ! $bb mount | $bb grep -q "on $HAB_STUDIO_ROOT/sys type"; then
+      $bb mount $v --rbind /sys $HAB_STUDIO_ROOT/sys
+    fi
+  fi
   if [ -z "${NO_MOUNT}" ]; then
     if ! $bb mount | $bb grep -q "on $HAB_STUDIO_ROOT/dev type"; then
       $bb mount $v --bind /dev $HAB_STUDIO_ROOT/dev
@@ -795,7 +810,7 @@ rm_studio() {
   fi
 
   if $bb mount | $bb grep -q "on $HAB_STUDIO_ROOT/sys type"; then
-    $bb umount $v $HAB_STUDIO_ROOT/sys
+    $bb umount $v -l $HAB_STUDIO_ROOT/sys
   fi
 
   if $bb mount | $bb grep -q "on $HAB_STUDIO_ROOT/proc type"; then
@@ -24,23 +24,37 @@ use butterfly::rumor::service_config::ServiceConfig as ServiceConfigRumor;
 use butterfly::rumor::election::Election as ElectionRumor;
 use butterfly::rumor::election::ElectionUpdate as ElectionUpdateRumor;
 use butterfly::rumor::RumorStore;
+use butterfly::rumor::chunk::ChunkStore;
 use butterfly::rumor::service::SysInfo;
 use eventsrv::message::event::{CensusEntry as CensusEntryProto, PackageIdent as PackageIdentProto,
                                SysInfo as SysInfoProto};
 use hcore;
 use hcore::service::ServiceGroup;
 use hcore::package::PackageIdent;
+use manager::rings::DEFAULT_RING;
 use toml;
 
 static LOGKEY: &'static str = "CE";
 
 pub type MemberId = String;
+
+/// The census is built from the rumors of every gossip ring the Supervisor is a member of, so
+/// service groups from all rings live side by side in `census_groups`. Each ring's rumor stores
+/// have their own update counters, which are tracked separately. Member IDs are the same in every
+/// ring, so each census group remembers the ring it is gossiped in, and only takes membership and
+/// health from that ring's member list.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct CensusRing {
     pub changed: bool,
 
     census_groups: HashMap<ServiceGroup, CensusGroup>,
     local_member_id: MemberId,
+    counters: HashMap<String, RingCounters>,
+}
+
+/// The last update counters we processed from a single gossip ring.
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct RingCounters {
     last_service_counter: usize,
     last_election_counter: usize,
     last_election_update_counter: usize,
@@ -55,15 +69,11 @@ impl CensusRing {
             changed: false,
             census_groups: HashMap::new(),
             local_member_id: local_member_id.into(),
-            last_service_counter: 0,
-            last_election_counter: 0,
-            last_election_update_counter: 0,
-            last_membership_counter: 0,
-            last_service_config_counter: 0,
-            last_service_file_counter: 0,
+            counters: HashMap::new(),
         }
     }
 
+    /// Update the census from the rumors of the default ring alone.
     pub fn update_from_rumors(&mut self,
                               service_rumor_offset: usize,
                               service_rumors: &RumorStore<ServiceRumor>,
@@ -71,14 +81,41 @@ impl CensusRing {
                               election_update_rumors: &RumorStore<ElectionUpdateRumor>,
                               member_list: &MemberList,
                               service_config_rumors: &RumorStore<ServiceConfigRumor>,
-                              service_file_rumors: &RumorStore<ServiceFileRumor>) {
+                              service_file_rumors: &RumorStore<ServiceFileRumor>,
+                              chunk_store: &ChunkStore) {
         self.changed = false;
-        self.update_from_service_store(service_rumor_offset, service_rumors);
-        self.update_from_election_store(election_rumors);
-        self.update_from_election_update_store(election_update_rumors);
-        self.update_from_member_list(member_list);
-        self.update_from_service_config(service_config_rumors);
-        self.update_from_service_files(service_file_rumors);
+        self.update_from_ring_rumors(DEFAULT_RING,
+                                     service_rumor_offset,
+                                     service_rumors,
+                                     election_rumors,
+                                     election_update_rumors,
+                                     member_list,
+                                     service_config_rumors,
+                                     service_file_rumors,
+                                     chunk_store);
+    }
+
+    /// Update the census from the rumors of the ring called `ring`. Unlike `update_from_rumors`,
+    /// this does not reset `changed`, so that a Supervisor in several rings can reset it once and
+    /// then call this for each of them in turn.
+    pub fn update_from_ring_rumors(&mut self,
+                                   ring: &str,
+                                   service_rumor_offset: usize,
+                                   service_rumors: &RumorStore<ServiceRumor>,
+                                   election_rumors: &RumorStore<ElectionRumor>,
+                                   election_update_rumors: &RumorStore<ElectionUpdateRumor>,
+                                   member_list: &MemberList,
+                                   service_config_rumors: &RumorStore<ServiceConfigRumor>,
+                                   service_file_rumors: &RumorStore<ServiceFileRumor>,
+                                   chunk_store: &ChunkStore) {
+        let mut counters = self.counters.remove(ring).unwrap_or_default();
+        self.update_from_service_store(ring, &mut counters, service_rumor_offset, service_rumors);
+        self.update_from_election_store(&mut counters, election_rumors);
+        self.update_from_election_update_store(&mut counters, election_update_rumors);
+        self.update_from_member_list(ring, &mut counters, member_list);
+        self.update_from_service_config(&mut counters, service_config_rumors);
+        self.update_from_service_files(ring, &mut counters, service_file_rumors, chunk_store);
+        self.counters.insert(String::from(ring), counters);
     }
 
     pub fn census_group_for(&self, sg: &ServiceGroup) -> Option<&CensusGroup> {
@@ -90,10 +127,12 @@ impl CensusRing {
     }
 
     fn update_from_service_store(&mut self,
+                                 ring: &str,
+                                 counters: &mut RingCounters,
                                  service_rumor_offset: usize,
                                  service_rumors: &RumorStore<ServiceRumor>) {
-        self.last_service_counter += service_rumor_offset;
-        if service_rumors.get_update_counter() <= self.last_service_counter {
+        counters.last_service_counter += service_rumor_offset;
+        if service_rumors.get_update_counter() <= counters.last_service_counter {
             return;
         }
         self.changed = true;
@@ -102,14 +141,16 @@ impl CensusRing {
                                      let mut census_group =
                     self.census_groups
                     .entry(sg.clone())
-                    .or_insert(CensusGroup::new(sg, &self.local_member_id));
+                    .or_insert(CensusGroup::new(sg, ring, &self.local_member_id));
                                      census_group.update_from_service_rumors(rumors);
                                  });
-        self.last_service_counter = service_rumors.get_update_counter();
+        counters.last_service_counter = service_rumors.get_update_counter();
     }
 
-    fn update_from_election_store(&mut self, election_rumors: &RumorStore<ElectionRumor>) {
-        if election_rumors.get_update_counter() <= self.last_election_counter {
+    fn update_from_election_store(&mut self,
+                                  counters: &mut RingCounters,
+                                  election_rumors: &RumorStore<ElectionRumor>) {
+        if election_rumors.get_update_counter() <= counters.last_election_counter {
             return;
         }
         self.changed = true;
@@ -121,12 +162,13 @@ impl CensusRing {
                 }
             }
         });
-        self.last_election_counter = election_rumors.get_update_counter();
+        counters.last_election_counter = election_rumors.get_update_counter();
     }
 
     fn update_from_election_update_store(&mut self,
+                                         counters: &mut RingCounters,
                                          election_update_rumors: &RumorStore<ElectionUpdateRumor>) {
-        if election_update_rumors.get_update_counter() <= self.last_election_update_counter {
+        if election_update_rumors.get_update_counter() <= counters.last_election_update_counter {
             return;
         }
         self.changed = true;
@@ -138,27 +180,37 @@ impl CensusRing {
                 }
             }
         });
-        self.last_election_update_counter = election_update_rumors.get_update_counter();
+        counters.last_election_update_counter = election_update_rumors.get_update_counter();
     }
 
-    fn update_from_member_list(&mut self, member_list: &MemberList) {
-        if member_list.get_update_counter() <= self.last_membership_counter {
+    fn update_from_member_list(&mut self,
+                               ring: &str,
+                               counters: &mut RingCounters,
+                               member_list: &MemberList) {
+        if member_list.get_update_counter() <= counters.last_membership_counter {
             return;
         }
         self.changed = true;
-        member_list.with_members(|member| if let Some(census_member) =
-            self.find_member_mut(&member.get_id().to_string()) {
-                                     census_member.update_from_member(&member);
-                                     if let Some(health) = member_list.health_of(member) {
-                                         census_member.update_from_health(health);
-                                     }
-                                 });
-        self.last_membership_counter = member_list.get_update_counter();
+        let mut groups: Vec<&mut CensusGroup> =
+            self.census_groups.values_mut().filter(|g| g.ring == ring).collect();
+        member_list.with_members(|member| {
+            let member_id = member.get_id().to_string();
+            for group in groups.iter_mut() {
+                if let Some(census_member) = group.find_member_mut(&member_id) {
+                    census_member.update_from_member(member);
+                    if let Some(health) = member_list.health_of(member) {
+                        census_member.update_from_health(health);
+                    }
+                }
+            }
+        });
+        counters.last_membership_counter = member_list.get_update_counter();
     }
 
     fn update_from_service_config(&mut self,
+                                  counters: &mut RingCounters,
                                   service_config_rumors: &RumorStore<ServiceConfigRumor>) {
-        if service_config_rumors.get_update_counter() <= self.last_service_config_counter {
+        if service_config_rumors.get_update_counter() <= counters.last_service_config_counter {
             return;
         }
         self.changed = true;
@@ -171,11 +223,15 @@ impl CensusRing {
                 }
             }
         });
-        self.last_service_config_counter = service_config_rumors.get_update_counter();
+        counters.last_service_config_counter = service_config_rumors.get_update_counter();
     }
 
-    fn update_from_service_files(&mut self, service_file_rumors: &RumorStore<ServiceFileRumor>) {
-        if service_file_rumors.get_update_counter() <= self.last_service_file_counter {
+    fn update_from_service_files(&mut self,
+                                 ring: &str,
+                                 counters: &mut RingCounters,
+                                 service_file_rumors: &RumorStore<ServiceFileRumor>,
+                                 chunk_store: &ChunkStore) {
+        if service_file_rumors.get_update_counter() <= counters.last_service_file_counter {
             return;
         }
         self.changed = true;
@@ -184,19 +240,11 @@ impl CensusRing {
                                           let mut census_group =
                     self.census_groups
                     .entry(sg.clone())
-                    .or_insert(CensusGroup::new(sg, &self.local_member_id));
-                                          census_group.update_from_service_file_rumors(rumors);
+                    .or_insert(CensusGroup::new(sg, ring, &self.local_member_id));
+                                          census_group.update_from_service_file_rumors(rumors,
+                                                                                       chunk_store);
                                       });
-        self.last_service_file_counter = service_file_rumors.get_update_counter();
-    }
-
-    fn find_member_mut(&mut self, member_id: &MemberId) -> Option<&mut CensusMember> {
-        for group in self.census_groups.values_mut() {
-            if let Some(member) = group.find_member_mut(member_id) {
-                return Some(member);
-            }
-        }
-        None
+        counters.last_service_file_counter = service_file_rumors.get_update_counter();
     }
 }
 
@@ -227,6 +275,8 @@ pub struct CensusGroup {
     pub election_status: ElectionStatus,
     pub leader_id: Option<MemberId>,
     pub service_config: Option<ServiceConfig>,
+    /// The gossip ring the service group's rumors come from.
+    pub ring: String,
 
     local_member_id: MemberId,
     population: BTreeMap<MemberId, CensusMember>,
@@ -236,9 +286,10 @@ pub struct CensusGroup {
 }
 
 impl CensusGroup {
-    fn new(sg: ServiceGroup, local_member_id: &MemberId) -> Self {
+    fn new(sg: ServiceGroup, ring: &str, local_member_id: &MemberId) -> Self {
         CensusGroup {
             service_group: sg,
+            ring: String::from(ring),
             election_status: ElectionStatus::None,
             local_member_id: local_member_id.clone(),
             population: BTreeMap::new(),
@@ -358,7 +409,8 @@ impl CensusGroup {
     }
 
     fn update_from_service_file_rumors(&mut self,
-                                       service_file_rumors: &HashMap<String, ServiceFileRumor>) {
+                                       service_file_rumors: &HashMap<String, ServiceFileRumor>,
+                                       chunk_store: &ChunkStore) {
         self.changed_service_files.clear();
         for (_m_id, service_file_rumor) in service_file_rumors.iter() {
             let filename = service_file_rumor.get_filename().to_string();
@@ -367,15 +419,22 @@ impl CensusGroup {
                 .or_insert(ServiceFile::default());
 
             if service_file_rumor.get_incarnation() > file.incarnation {
-                match service_file_rumor.body() {
+                match service_file_rumor.body_from(chunk_store) {
                     Ok(body) => {
                         self.changed_service_files.push(filename.clone());
                         file.filename = filename.clone();
                         file.incarnation = service_file_rumor.get_incarnation();
                         file.body = body;
                     }
+                    Err(butterfly::error::Error::ServiceFileIncomplete(_, missing)) => {
+                        debug!("Waiting on {} chunk(s) of service file for {} {} {}",
+                               missing,
+                               self.service_group,
+                               service_file_rumor.get_filename(),
+                               service_file_rumor.get_incarnation())
+                    }
                     Err(e) => {
-                        warn!("Cannot decrypt service file for {} {} {}: {}",
+                        warn!("Cannot read service file for {} {} {}: {}",
                               self.service_group,
                               service_file_rumor.get_filename(),
                               service_file_rumor.get_incarnation(),
@@ -589,7 +648,7 @@ mod tests {
     mod census {
         use hcore::package::ident::PackageIdent;
         use hcore::service::ServiceGroup;
-        use butterfly::member::MemberList;
+        use butterfly::member::{Health, Member, MemberList};
         use butterfly::rumor::service::Service as ServiceRumor;
         use butterfly::rumor::service_config::ServiceConfig as ServiceConfigRumor;
         use butterfly::rumor::service_file::ServiceFile as ServiceFileRumor;
@@ -597,6 +656,7 @@ mod tests {
         use butterfly::rumor::election::ElectionUpdate as ElectionUpdateRumor;
         use butterfly::rumor::service::SysInfo;
         use butterfly::rumor::RumorStore;
+        use butterfly::rumor::chunk::ChunkStore;
         use census::CensusRing;
 
         #[test]
@@ -649,7 +709,8 @@ mod tests {
                                     &election_update_store,
                                     &member_list,
                                     &service_config_store,
-                                    &service_file_store);
+                                    &service_file_store,
+                                    &ChunkStore::default());
             let census_group_one = ring.census_group_for(&sg_one).unwrap();
             assert_eq!(census_group_one.me(), None);
             assert_eq!(census_group_one.leader().unwrap().member_id, "member-a");
@@ -665,5 +726,124 @@ mod tests {
             assert_eq!(members[0].member_id, "member-a");
             assert_eq!(members[1].member_id, "member-b");
         }
+
+        #[test]
+        fn update_from_several_rings() {
+            let sys_info = SysInfo {
+                ip: "1.2.3.4".to_string(),
+                hostname: "hostname".to_string(),
+                gossip_ip: "0.0.0.0".to_string(),
+                gossip_port: "7777".to_string(),
+                http_gateway_ip: "0.0.0.0".to_string(),
+                http_gateway_port: "9631".to_string(),
+            };
+            let pg_id = PackageIdent::new("core", "redis", Some("3.2.4"), Some("20170514150022"));
+            let sg_infra = ServiceGroup::new("redis", "infra", None).unwrap();
+            let sg_tenant = ServiceGroup::new("redis", "tenant", None).unwrap();
+
+            // The infrastructure ring has seen many more updates than the tenant ring; the
+            // tenant ring's rumors must still be picked up.
+            let infra_store: RumorStore<ServiceRumor> = RumorStore::new(100);
+            infra_store.insert(ServiceRumor::new("member-a".to_string(),
+                                                 &pg_id,
+                                                 &sg_infra,
+                                                 &sys_info,
+                                                 None));
+            let tenant_store: RumorStore<ServiceRumor> = RumorStore::default();
+            tenant_store.insert(ServiceRumor::new("member-a".to_string(),
+                                                  &pg_id,
+                                                  &sg_tenant,
+                                                  &sys_info,
+                                                  None));
+
+            let election_store: RumorStore<ElectionRumor> = RumorStore::default();
+            let election_update_store: RumorStore<ElectionUpdateRumor> = RumorStore::default();
+            let member_list = MemberList::new();
+            let service_config_store: RumorStore<ServiceConfigRumor> = RumorStore::default();
+            let service_file_store: RumorStore<ServiceFileRumor> = RumorStore::default();
+            let chunk_store = ChunkStore::default();
+
+            let mut ring = CensusRing::new("member-b".to_string());
+            ring.changed = false;
+            for &(name, store) in [("default", &infra_store), ("tenant", &tenant_store)].iter() {
+                ring.update_from_ring_rumors(name,
+                                             0,
+                                             store,
+                                             &election_store,
+                                             &election_update_store,
+                                             &member_list,
+                                             &service_config_store,
+                                             &service_file_store,
+                                             &chunk_store);
+            }
+            assert!(ring.changed);
+            assert!(ring.census_group_for(&sg_infra).is_some());
+            assert!(ring.census_group_for(&sg_tenant).is_some());
+        }
+
+        #[test]
+        fn members_take_their_health_from_their_own_ring() {
+            let sys_info = SysInfo {
+                ip: "1.2.3.4".to_string(),
+                hostname: "hostname".to_string(),
+                gossip_ip: "0.0.0.0".to_string(),
+                gossip_port: "7777".to_string(),
+                http_gateway_ip: "0.0.0.0".to_string(),
+                http_gateway_port: "9631".to_string(),
+            };
+            let pg_id = PackageIdent::new("core", "redis", Some("3.2.4"), Some("20170514150022"));
+            let sg_infra = ServiceGroup::new("redis", "infra", None).unwrap();
+            let sg_tenant = ServiceGroup::new("redis", "tenant", None).unwrap();
+
+            // member-a runs a service in each ring, and has the same ID in both. It is alive in
+            // the infrastructure ring, but the tenant ring has confirmed it dead.
+            let mut member = Member::default();
+            member.set_id(String::from("member-a"));
+            let infra_store: RumorStore<ServiceRumor> = RumorStore::default();
+            infra_store.insert(ServiceRumor::new("member-a".to_string(),
+                                                 &pg_id,
+                                                 &sg_infra,
+                                                 &sys_info,
+                                                 None));
+            let infra_members = MemberList::new();
+            infra_members.insert(member.clone(), Health::Alive);
+            let tenant_store: RumorStore<ServiceRumor> = RumorStore::default();
+            tenant_store.insert(ServiceRumor::new("member-a".to_string(),
+                                                  &pg_id,
+                                                  &sg_tenant,
+                                                  &sys_info,
+                                                  None));
+            let tenant_members = MemberList::new();
+            tenant_members.insert(member, Health::Confirmed);
+
+            let election_store: RumorStore<ElectionRumor> = RumorStore::default();
+            let election_update_store: RumorStore<ElectionUpdateRumor> = RumorStore::default();
+            let service_config_store: RumorStore<ServiceConfigRumor> = RumorStore::default();
+            let service_file_store: RumorStore<ServiceFileRumor> = RumorStore::default();
+            let chunk_store = ChunkStore::default();
+
+            let mut ring = CensusRing::new("member-b".to_string());
+            for &(name, store, members) in [("default", &infra_store, &infra_members),
+                                            ("tenant", &tenant_store, &tenant_members)]
+                        .iter() {
+                ring.update_from_ring_rumors(name,
+                                             0,
+                                             store,
+                                             &election_store,
+                                             &election_update_store,
+                                             members,
+                                             &service_config_store,
+                                             &service_file_store,
+                                             &chunk_store);
+            }
+
+            let infra = ring.census_group_for(&sg_infra).unwrap();
+            assert_eq!(infra.ring, "default");
+            assert_eq!(infra.members()[0].alive, Some(true));
+            let tenant = ring.census_group_for(&sg_tenant).unwrap();
+            assert_eq!(tenant.ring, "tenant");
+            assert_eq!(tenant.members()[0].confirmed, Some(true));
+            assert_eq!(tenant.members()[0].alive, Some(false));
+        }
     }
 }
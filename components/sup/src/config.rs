@@ -22,7 +22,7 @@
 
 use std::fmt;
 use std::io;
-use std::net::{IpAddr, Ipv4Addr, ToSocketAddrs, SocketAddr, SocketAddrV4};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, ToSocketAddrs, SocketAddr, UdpSocket};
 use std::ops::{Deref, DerefMut};
 use std::option;
 use std::result;
@@ -34,13 +34,60 @@ pub const GOSSIP_DEFAULT_PORT: u16 = 9638;
 
 static LOGKEY: &'static str = "CFG";
 
+/// The address to listen on when none is given. This is every IPv6 address if the host supports
+/// IPv6, which on Linux and macOS also accepts IPv4 connections, and every IPv4 address otherwise.
+pub fn default_listen_ip() -> IpAddr {
+    let any_v4 = IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0));
+    // Windows sockets bound to every IPv6 address do not accept IPv4 connections by default.
+    if cfg!(windows) {
+        return any_v4;
+    }
+    let any_v6 = IpAddr::V6(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 0));
+    match UdpSocket::bind(SocketAddr::new(any_v6, 0)) {
+        Ok(_) => any_v6,
+        Err(_) => any_v4,
+    }
+}
+
+/// Resolve the address of a gossip peer. Accepts `IP[:PORT]`, `HOSTNAME[:PORT]`, a bare IPv6
+/// address or a bracketed IPv6 address with a port (`[IP]:PORT`). The port defaults to
+/// `GOSSIP_DEFAULT_PORT`.
+pub fn resolve_peer(peer: &str) -> Result<SocketAddr> {
+    if let Ok(addr) = SocketAddr::from_str(peer) {
+        return Ok(addr);
+    }
+    let bare = peer.trim_left_matches('[').trim_right_matches(']');
+    if let Ok(ip) = IpAddr::from_str(bare) {
+        return Ok(SocketAddr::new(ip, GOSSIP_DEFAULT_PORT));
+    }
+    let peer_addr = if peer.find(':').is_some() {
+        peer.to_string()
+    } else {
+        format!("{}:{}", peer, GOSSIP_DEFAULT_PORT)
+    };
+    let addr = match peer_addr.to_socket_addrs() {
+        Ok(mut addrs) => addrs.next(),
+        Err(e) => {
+            outputln!("Failed to resolve peer: {}", peer_addr);
+            return Err(sup_error!(Error::NameLookup(e)));
+        }
+    };
+    match addr {
+        Some(addr) => Ok(addr),
+        None => {
+            outputln!("Failed to resolve peer: {}", peer_addr);
+            Err(sup_error!(Error::NameLookup(io::Error::new(io::ErrorKind::NotFound,
+                                                            "no addresses found"))))
+        }
+    }
+}
+
 #[derive(PartialEq, Eq, Debug)]
 pub struct GossipListenAddr(SocketAddr);
 
 impl Default for GossipListenAddr {
     fn default() -> GossipListenAddr {
-        GossipListenAddr(SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::new(0, 0, 0, 0),
-                                                          GOSSIP_DEFAULT_PORT)))
+        GossipListenAddr(SocketAddr::new(default_listen_ip(), GOSSIP_DEFAULT_PORT))
     }
 }
 
@@ -65,7 +112,7 @@ impl FromStr for GossipListenAddr {
         match SocketAddr::from_str(val) {
             Ok(addr) => Ok(GossipListenAddr(addr)),
             Err(_) => {
-                match IpAddr::from_str(val) {
+                match IpAddr::from_str(val.trim_left_matches('[').trim_right_matches(']')) {
                     Ok(ip) => {
                         let mut addr = GossipListenAddr::default();
                         addr.set_ip(ip);
@@ -91,3 +138,34 @@ impl fmt::Display for GossipListenAddr {
         write!(f, "{}", self.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::{resolve_peer, GossipListenAddr};
+
+    #[test]
+    fn resolve_peer_with_ipv4() {
+        assert_eq!(resolve_peer("10.0.0.1").unwrap().to_string(), "10.0.0.1:9638");
+        assert_eq!(resolve_peer("10.0.0.1:1234").unwrap().to_string(),
+                   "10.0.0.1:1234");
+    }
+
+    #[test]
+    fn resolve_peer_with_ipv6() {
+        assert_eq!(resolve_peer("fd00::1").unwrap().to_string(), "[fd00::1]:9638");
+        assert_eq!(resolve_peer("[fd00::1]").unwrap().to_string(),
+                   "[fd00::1]:9638");
+        assert_eq!(resolve_peer("[fd00::1]:1234").unwrap().to_string(),
+                   "[fd00::1]:1234");
+    }
+
+    #[test]
+    fn gossip_listen_addr_from_ipv6() {
+        let addr = GossipListenAddr::from_str("[::]:1234").unwrap();
+        assert_eq!(addr.to_string(), "[::]:1234");
+        let addr = GossipListenAddr::from_str("fd00::1").unwrap();
+        assert_eq!(addr.to_string(), "[fd00::1]:9638");
+    }
+}
@@ -117,6 +117,7 @@ pub enum Error {
     InvalidBinding(String),
     InvalidKeyParameter(String),
     InvalidPidFile,
+    InvalidRing(String),
     InvalidTopology(String),
     InvalidUpdateStrategy(String),
     Io(io::Error),
@@ -131,6 +132,7 @@ pub enum Error {
     ProcessLockCorrupt,
     ProcessLocked(u32),
     ProcessLockIO(PathBuf, io::Error),
+    RingKeyNotFound(String),
     ServiceLoaded(package::PackageIdent),
     ServiceSpecFileIO(PathBuf, io::Error),
     ServiceSpecParse(toml::de::Error),
@@ -145,6 +147,7 @@ pub enum Error {
     TomlMergeError(String),
     TomlParser(toml::de::Error),
     TryRecvError(mpsc::TryRecvError),
+    UnknownRing(String),
     UnpackFailed,
 }
 
@@ -193,6 +196,11 @@ impl fmt::Display for SupError {
                 format!("Invalid parameter for key generation: {:?}", e)
             }
             Error::InvalidPidFile => format!("Invalid child process PID file"),
+            Error::InvalidRing(ref ring) => {
+                format!("Invalid ring \"{}\", must be of the form <NAME>=<ADDRESS> where \
+                         <NAME> is a ring name other than \"default\"",
+                        ring)
+            }
             Error::InvalidTopology(ref t) => format!("Invalid topology: {}", t),
             Error::InvalidUpdateStrategy(ref s) => format!("Invalid update strategy: {}", s),
             Error::Io(ref err) => format!("{}", err),
@@ -225,6 +233,13 @@ impl fmt::Display for SupError {
                         path.display(),
                         err)
             }
+            Error::RingKeyNotFound(ref ring) => {
+                format!("No ring key found for the \"{}\" ring. Import its key with `hab ring \
+                         key import`, or pass --unencrypted-ring {} to gossip in it without \
+                         encryption",
+                        ring,
+                        ring)
+            }
             Error::ServiceLoaded(ref ident) => {
                 format!("Service already loaded, unload '{}' and try again", ident)
             }
@@ -252,6 +267,9 @@ impl fmt::Display for SupError {
             Error::TomlMergeError(ref e) => format!("Failed to merge TOML: {}", e),
             Error::TomlParser(ref err) => format!("Failed to parse TOML: {}", err),
             Error::TryRecvError(ref err) => format!("{}", err),
+            Error::UnknownRing(ref ring) => {
+                format!("This Supervisor is not a member of the ring \"{}\"", ring)
+            }
             Error::UnpackFailed => format!("Failed to unpack a package"),
         };
         let cstring = Red.bold().paint(content).to_string();
@@ -287,6 +305,7 @@ impl error::Error for SupError {
             Error::InvalidBinding(_) => "Invalid binding parameter",
             Error::InvalidKeyParameter(_) => "Key parameter error",
             Error::InvalidPidFile => "Invalid child process PID file",
+            Error::InvalidRing(_) => "Invalid ring parameter",
             Error::InvalidTopology(_) => "Invalid topology",
             Error::InvalidUpdateStrategy(_) => "Invalid update strategy",
             Error::Io(ref err) => err.description(),
@@ -301,6 +320,7 @@ impl error::Error for SupError {
             Error::ProcessLockCorrupt => "Unable to decode contents of process lock",
             Error::ProcessLocked(_) => "Another instance of the Habitat Supervisor is already running",
             Error::ProcessLockIO(_, _) => "Unable to write or read to a process lock",
+            Error::RingKeyNotFound(_) => "No ring key found for an additional ring",
             Error::ServiceLoaded(_) => "Service load or start called when service already loaded",
             Error::ServiceSpecFileIO(_, _) => "Unable to write or read to a service spec file",
             Error::ServiceSpecParse(_) => "Service spec could not be parsed successfully",
@@ -315,6 +335,7 @@ impl error::Error for SupError {
             Error::TomlMergeError(_) => "Failed to merge TOML!",
             Error::TomlParser(_) => "Failed to parse TOML!",
             Error::TryRecvError(_) => "A channel failed to receive a response",
+            Error::UnknownRing(_) => "This Supervisor is not a member of the ring",
             Error::UnpackFailed => "Failed to unpack a package",
         }
     }
@@ -16,14 +16,15 @@ use std::collections::HashMap;
 use std::fmt;
 use std::fs::File;
 use std::io::{self, Read};
-use std::net::{IpAddr, Ipv4Addr, ToSocketAddrs, SocketAddr, SocketAddrV4};
+use std::net::{IpAddr, ToSocketAddrs, SocketAddr};
 use std::ops::{Deref, DerefMut};
 use std::option;
 use std::result;
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 use std::thread::{self, JoinHandle};
 
+use butterfly::trace::{Trace, TraceMode, TraceObserver, TraceWrite};
 use hcore::service::ServiceGroup;
 use iron::prelude::*;
 use iron::{headers, status, typemap};
@@ -33,9 +34,11 @@ use prometheus::{self, CounterVec, HistogramVec, TextEncoder, Encoder};
 use router::Router;
 use serde_json;
 
+use config;
 use error::{Result, Error, SupError};
 use fs;
 use manager;
+use manager::rings;
 use manager::service::HealthCheck;
 use manager::service::hooks::{self, HealthCheckHook};
 
@@ -67,6 +70,31 @@ lazy_static! {
             "http_request_duration_seconds",
             "HTTP request latencies in seconds."),
         &["handler"]).unwrap();
+
+    static ref TRACE_EVENT_COUNTER: CounterVec = register_counter_vec!(
+        opts!(
+            "hab_butterfly_trace_events_total",
+            "Total number of gossip trace events, by kind and peer member."),
+        &["kind", "peer"]).unwrap();
+}
+
+/// Counts butterfly trace events in the `hab_butterfly_trace_events_total` Prometheus counter
+/// while the gossip trace is in `TraceMode::Metrics`.
+#[derive(Debug)]
+pub struct TraceMetrics;
+
+impl TraceObserver for TraceMetrics {
+    fn observe(&self, event: &TraceWrite) {
+        TRACE_EVENT_COUNTER
+            .with_label_values(&[event.kind.as_str(), event.peer()])
+            .inc();
+    }
+}
+
+#[derive(Serialize)]
+struct TraceBody {
+    mode: &'static str,
+    directory: String,
 }
 
 #[derive(PartialEq, Eq, Debug, Clone)]
@@ -74,7 +102,7 @@ pub struct ListenAddr(SocketAddr);
 
 impl Default for ListenAddr {
     fn default() -> ListenAddr {
-        ListenAddr(SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::new(0, 0, 0, 0), 9631)))
+        ListenAddr(SocketAddr::new(config::default_listen_ip(), 9631))
     }
 }
 
@@ -99,7 +127,7 @@ impl FromStr for ListenAddr {
         match SocketAddr::from_str(val) {
             Ok(addr) => Ok(ListenAddr(addr)),
             Err(_) => {
-                match IpAddr::from_str(val) {
+                match IpAddr::from_str(val.trim_left_matches('[').trim_right_matches(']')) {
                     Ok(ip) => {
                         let mut addr = ListenAddr::default();
                         addr.set_ip(ip);
@@ -132,12 +160,35 @@ impl typemap::Key for ManagerFs {
     type Value = manager::FsCfg;
 }
 
+/// The gossip trace of every ring, by ring name.
+struct GossipTraces;
+
+impl typemap::Key for GossipTraces {
+    type Value = HashMap<String, Arc<RwLock<Trace>>>;
+}
+
 pub struct Server(Iron<Chain>, ListenAddr);
 
 impl Server {
-    pub fn new(manager_state: Arc<manager::FsCfg>, listen_addr: ListenAddr) -> Self {
+    pub fn new(manager_state: Arc<manager::FsCfg>,
+               gossip_traces: HashMap<String, Arc<RwLock<Trace>>>,
+               listen_addr: ListenAddr)
+               -> Self {
         let router = router!(
             butterfly: get "/butterfly" => with_metrics!(butterfly, "butterfly"),
+            butterfly_trace: get "/butterfly/trace" => with_metrics!(trace, "trace"),
+            butterfly_trace_set: post "/butterfly/trace/:mode" => {
+                with_metrics!(trace_set, "trace_set")
+            },
+            butterfly_ring: get "/butterfly/rings/:ring" => {
+                with_metrics!(butterfly_ring, "butterfly_ring")
+            },
+            butterfly_ring_trace: get "/butterfly/rings/:ring/trace" => {
+                with_metrics!(trace, "trace")
+            },
+            butterfly_ring_trace_set: post "/butterfly/rings/:ring/trace/:mode" => {
+                with_metrics!(trace_set, "trace_set")
+            },
             census: get "/census" => with_metrics!(census, "census"),
             metrics: get "/metrics" => with_metrics!(metrics, "metrics"),
             services: get "/services" => with_metrics!(services, "services"),
@@ -154,6 +205,7 @@ impl Server {
         );
         let mut chain = Chain::new(router);
         chain.link(persistent::Read::<ManagerFs>::both(manager_state));
+        chain.link(persistent::Read::<GossipTraces>::both(gossip_traces));
         Server(Iron::new(chain), listen_addr)
     }
 
@@ -183,6 +235,68 @@ fn butterfly(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+fn butterfly_ring(req: &mut Request) -> IronResult<Response> {
+    let ring = match req.extensions.get::<Router>().unwrap().find("ring") {
+        Some(ring) if rings::valid_name(ring) => ring.to_string(),
+        _ => return Ok(Response::with(status::BadRequest)),
+    };
+    let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
+    match File::open(&state.butterfly_data_path_for(&ring)) {
+        Ok(file) => Ok(Response::with((status::Ok, Header(headers::ContentType::json()), file))),
+        Err(_) => Ok(Response::with(status::NotFound)),
+    }
+}
+
+/// The gossip trace of the ring named in the URL, or of the default ring if none is.
+fn ring_trace(req: &mut Request) -> Option<Arc<RwLock<Trace>>> {
+    let ring = req.extensions
+        .get::<Router>()
+        .unwrap()
+        .find("ring")
+        .unwrap_or(rings::DEFAULT_RING)
+        .to_string();
+    let traces = req.get::<persistent::Read<GossipTraces>>().unwrap();
+    traces.get(&ring).cloned()
+}
+
+fn trace(req: &mut Request) -> IronResult<Response> {
+    let state = match ring_trace(req) {
+        Some(state) => state,
+        None => return Ok(Response::with(status::NotFound)),
+    };
+    let trace = state.read().expect("Trace lock is poisoned");
+    let body = TraceBody {
+        mode: trace.mode().as_str(),
+        directory: trace.directory.to_string_lossy().into_owned(),
+    };
+    Ok(Response::with((status::Ok,
+                       Header(headers::ContentType::json()),
+                       serde_json::to_string(&body).unwrap())))
+}
+
+/// Switches the gossip trace to the mode named in the URL. Only callers on the loopback interface
+/// may change it, since the gateway listens on every interface by default.
+fn trace_set(req: &mut Request) -> IronResult<Response> {
+    if !req.remote_addr.ip().is_loopback() {
+        return Ok(Response::with(status::Forbidden));
+    }
+    let mode = match req.extensions
+              .get::<Router>()
+              .unwrap()
+              .find("mode")
+              .map(TraceMode::from_str) {
+        Some(Ok(mode)) => mode,
+        _ => return Ok(Response::with(status::BadRequest)),
+    };
+    let state = match ring_trace(req) {
+        Some(state) => state,
+        None => return Ok(Response::with(status::NotFound)),
+    };
+    outputln!("Setting gossip trace mode to {}", mode);
+    state.write().expect("Trace lock is poisoned").set_mode(mode);
+    trace(req)
+}
+
 fn census(req: &mut Request) -> IronResult<Response> {
     let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
     match File::open(&state.census_data_path) {
@@ -286,3 +400,64 @@ fn build_service_group(req: &mut Request) -> Result<ServiceGroup> {
                                req.extensions.get::<Router>().unwrap().find("org"))?;
     Ok(sg)
 }
+
+#[cfg(test)]
+mod test {
+    use std::io::Write;
+    use std::net::TcpStream;
+
+    use tempdir::TempDir;
+
+    use manager::FsCfg;
+    use super::*;
+
+    fn request(addr: &SocketAddr, method: &str, path: &str) -> String {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(stream,
+               "{} {} HTTP/1.0\r\nContent-Length: 0\r\n\r\n",
+               method,
+               path)
+                .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn post_switches_the_trace_mode_of_each_ring() {
+        let root = TempDir::new("http-gateway").unwrap();
+        let gossip_trace = Arc::new(RwLock::new(Trace::default()));
+        gossip_trace.write().unwrap().set_mode(TraceMode::Off);
+        let tenant_trace = Arc::new(RwLock::new(Trace::default()));
+        tenant_trace.write().unwrap().set_mode(TraceMode::Off);
+        let mut traces = HashMap::new();
+        traces.insert(String::from(rings::DEFAULT_RING), gossip_trace.clone());
+        traces.insert(String::from("tenant"), tenant_trace.clone());
+        let server = Server::new(Arc::new(FsCfg::new(root.path())),
+                                 traces,
+                                 ListenAddr::default());
+        let mut listening = server.0.http("127.0.0.1:0").unwrap();
+        let addr = listening.socket;
+
+        let response = request(&addr, "POST", "/butterfly/trace/metrics");
+        assert!(response.starts_with("HTTP/1.0 200") || response.starts_with("HTTP/1.1 200"),
+                "unexpected response: {}", response);
+        assert!(response.contains("\"mode\":\"metrics\""));
+        assert_eq!(gossip_trace.read().unwrap().mode(), TraceMode::Metrics);
+
+        let response = request(&addr, "POST", "/butterfly/trace/bogus");
+        assert!(response.contains(" 400 "), "unexpected response: {}", response);
+        assert_eq!(gossip_trace.read().unwrap().mode(), TraceMode::Metrics);
+        assert_eq!(tenant_trace.read().unwrap().mode(), TraceMode::Off);
+
+        let response = request(&addr, "POST", "/butterfly/rings/tenant/trace/json");
+        assert!(response.contains("\"mode\":\"json\""), "unexpected response: {}", response);
+        assert_eq!(tenant_trace.read().unwrap().mode(), TraceMode::Json);
+        assert_eq!(gossip_trace.read().unwrap().mode(), TraceMode::Metrics);
+
+        let response = request(&addr, "POST", "/butterfly/rings/missing/trace/json");
+        assert!(response.contains(" 404 "), "unexpected response: {}", response);
+
+        listening.close().unwrap();
+    }
+}
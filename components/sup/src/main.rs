@@ -24,7 +24,7 @@ extern crate libc;
 extern crate clap;
 extern crate url;
 
-use std::net::{SocketAddr, ToSocketAddrs};
+use std::net::SocketAddr;
 use std::path::{Path, PathBuf};
 use std::result;
 use std::str::FromStr;
@@ -39,12 +39,13 @@ use hcore::url::{DEFAULT_DEPOT_URL, DEPOT_URL_ENVVAR};
 use url::Url;
 
 use sup::VERSION;
-use sup::config::{GossipListenAddr, GOSSIP_DEFAULT_PORT};
+use sup::config::{resolve_peer, GossipListenAddr};
 use sup::error::{Error, Result};
 use sup::feat;
 use sup::command;
 use sup::http_gateway;
-use sup::manager::{Manager, ManagerConfig};
+use sup::manager::{Manager, ManagerConfig, RingConfig};
+use sup::manager::rings::{self, DEFAULT_RING};
 use sup::manager::service::{DesiredState, ServiceBind, Topology, UpdateStrategy};
 use sup::manager::service::{ServiceSpec, StartStyle};
 
@@ -117,6 +118,9 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "The update strategy; [default: none] [values: none, at-once, rolling]")
             (@arg BIND: --bind +takes_value +multiple
                 "One or more service groups to bind to a configuration")
+            (@arg GOSSIP_RING: --("gossip-ring") +takes_value
+                "The ring to gossip about this service in; the Supervisor must have joined it \
+                with --join-ring [default: default]")
             (@arg FORCE: --force -f "Load or reload an already loaded service. If the service was \
                 previously loaded and running this operation will also restart the service")
         )
@@ -134,9 +138,11 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             (about: "Start the Habitat Supervisor")
             (aliases: &["r", "ru"])
             (@arg LISTEN_GOSSIP: --("listen-gossip") +takes_value
-                "The listen address for the gossip system [default: 0.0.0.0:9638]")
+                "The listen address for the gossip system, IPv4 or IPv6 \
+                [default: [::]:9638 if IPv6 is available, otherwise 0.0.0.0:9638]")
             (@arg LISTEN_HTTP: --("listen-http") +takes_value
-                "The listen address for the HTTP gateway [default: 0.0.0.0:9631]")
+                "The listen address for the HTTP gateway, IPv4 or IPv6 \
+                [default: [::]:9631 if IPv6 is available, otherwise 0.0.0.0:9631]")
             (@arg NAME: --("override-name") +takes_value
                 "The name for the state directory if launching more than one Supervisor \
                 [default: default]")
@@ -144,9 +150,17 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "The organization that the supervisor and it's subsequent services are part of \
                 [default: default]")
             (@arg PEER: --peer +takes_value +multiple
-                "The listen address of an initial peer (IP[:PORT])")
+                "The listen address of an initial peer (IP[:PORT] or [IPV6]:PORT)")
             (@arg PERMANENT_PEER: --("permanent-peer") -I "If this Supervisor is a permanent peer")
             (@arg RING: --ring -r +takes_value "Ring key name")
+            (@arg JOIN_RING: --("join-ring") +takes_value +multiple
+                "Join an additional named ring, listening for its gossip on the given address \
+                (NAME=IP:PORT)")
+            (@arg RING_PEER: --("ring-peer") +takes_value +multiple
+                "The listen address of an initial peer in an additional ring (NAME=IP[:PORT])")
+            (@arg UNENCRYPTED_RING: --("unencrypted-ring") +takes_value +multiple
+                "Allow an additional ring joined with --join-ring to gossip without encryption \
+                when there is no ring key for it (NAME)")
         )
         (@subcommand sh =>
             (about: "Start an interactive Bourne-like shell")
@@ -158,9 +172,11 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 will additionally start one for you.")
             (aliases: &["sta", "star"])
             (@arg LISTEN_GOSSIP: --("listen-gossip") +takes_value
-                "The listen address for the gossip system [default: 0.0.0.0:9638]")
+                "The listen address for the gossip system, IPv4 or IPv6 \
+                [default: [::]:9638 if IPv6 is available, otherwise 0.0.0.0:9638]")
             (@arg LISTEN_HTTP: --("listen-http") +takes_value
-                "The listen address for the HTTP gateway [default: 0.0.0.0:9631]")
+                "The listen address for the HTTP gateway, IPv4 or IPv6 \
+                [default: [::]:9631 if IPv6 is available, otherwise 0.0.0.0:9631]")
             (@arg NAME: --("override-name") +takes_value
                 "The name for the state directory if launching more than one Supervisor \
                 [default: default]")
@@ -168,9 +184,17 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "The organization that the supervisor and it's subsequent services are part of \
                 [default: default]")
             (@arg PEER: --peer +takes_value +multiple
-                "The listen address of an initial peer (IP[:PORT])")
+                "The listen address of an initial peer (IP[:PORT] or [IPV6]:PORT)")
             (@arg PERMANENT_PEER: --("permanent-peer") -I "If this Supervisor is a permanent peer")
             (@arg RING: --ring -r +takes_value "Ring key name")
+            (@arg JOIN_RING: --("join-ring") +takes_value +multiple
+                "Join an additional named ring, listening for its gossip on the given address \
+                (NAME=IP:PORT)")
+            (@arg RING_PEER: --("ring-peer") +takes_value +multiple
+                "The listen address of an initial peer in an additional ring (NAME=IP[:PORT])")
+            (@arg UNENCRYPTED_RING: --("unencrypted-ring") +takes_value +multiple
+                "Allow an additional ring joined with --join-ring to gossip without encryption \
+                when there is no ring key for it (NAME)")
             (@arg PKG_IDENT_OR_ARTIFACT: +required +takes_value
                 "A Habitat package identifier (ex: core/redis) or filepath to a Habitat Artifact \
                 (ex: /home/core-redis-3.0.7-21120102031201-x86_64-linux.hart)")
@@ -186,6 +210,9 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "One or more service groups to bind to a configuration")
             (@arg CONFIG_DIR: --("config-from") +takes_value {dir_exists}
                 "Use package config from this path, rather than the package itself")
+            (@arg GOSSIP_RING: --("gossip-ring") +takes_value
+                "The ring to gossip about this service in; the Supervisor must have joined it \
+                with --join-ring [default: default]")
         )
         (@subcommand stop =>
             (about: "Stop a running Habitat service.")
@@ -359,23 +386,44 @@ fn mgrcfg_from_matches(m: &ArgMatches) -> Result<ManagerConfig> {
     let mut gossip_peers: Vec<SocketAddr> = Vec::new();
     if let Some(peers) = m.values_of("PEER") {
         for peer in peers {
-            let peer_addr = if peer.find(':').is_some() {
-                peer.to_string()
-            } else {
-                format!("{}:{}", peer, GOSSIP_DEFAULT_PORT)
-            };
-            let addrs: Vec<SocketAddr> = match peer_addr.to_socket_addrs() {
-                Ok(addrs) => addrs.collect(),
-                Err(e) => {
-                    outputln!("Failed to resolve peer: {}", peer_addr);
-                    return Err(sup_error!(Error::NameLookup(e)));
+            gossip_peers.push(resolve_peer(peer)?);
+        }
+    }
+    cfg.gossip_peers = gossip_peers;
+    let unencrypted: Vec<&str> = m.values_of("UNENCRYPTED_RING")
+        .map(|rings| rings.collect())
+        .unwrap_or(Vec::new());
+    if let Some(rings) = m.values_of("JOIN_RING") {
+        for ring in rings {
+            let (name, addr_str) = split_ring_arg(ring)?;
+            let ring_key = match SymKey::get_latest_pair_for(&name, &default_cache_key_path(None)) {
+                Ok(key) => Some(key.name_with_rev()),
+                Err(_) if unencrypted.contains(&name.as_str()) => {
+                    outputln!("No ring key found for {}, its gossip will not be encrypted",
+                              name);
+                    None
                 }
+                Err(_) => return Err(sup_error!(Error::RingKeyNotFound(name))),
             };
-            let addr: SocketAddr = addrs[0];
-            gossip_peers.push(addr);
+            cfg.rings
+                .push(RingConfig {
+                          name: name,
+                          gossip_listen: GossipListenAddr::from_str(addr_str)?,
+                          gossip_peers: Vec::new(),
+                          ring_key: ring_key,
+                      });
+        }
+    }
+    if let Some(peers) = m.values_of("RING_PEER") {
+        for peer in peers {
+            let (name, peer) = split_ring_arg(peer)?;
+            let addr = resolve_peer(peer)?;
+            match cfg.rings.iter_mut().find(|r| r.name == name) {
+                Some(ring) => ring.gossip_peers.push(addr),
+                None => return Err(sup_error!(Error::UnknownRing(name))),
+            }
         }
     }
-    cfg.gossip_peers = gossip_peers;
     let ring = match m.value_of("RING") {
         Some(val) => Some(try!(SymKey::get_latest_pair_for(&val, &default_cache_key_path(None)))),
         None => {
@@ -403,6 +451,17 @@ fn mgrcfg_from_matches(m: &ArgMatches) -> Result<ManagerConfig> {
     Ok(cfg)
 }
 
+/// Split a `NAME=VALUE` argument naming an additional ring.
+fn split_ring_arg(arg: &str) -> Result<(String, &str)> {
+    let mut parts = arg.splitn(2, '=');
+    match (parts.next(), parts.next()) {
+        (Some(name), Some(value)) if rings::valid_name(name) && name != DEFAULT_RING => {
+            Ok((name.to_string(), value))
+        }
+        _ => Err(sup_error!(Error::InvalidRing(arg.to_string()))),
+    }
+}
+
 fn spec_from_matches(ident: PackageIdent, m: &ArgMatches) -> Result<ServiceSpec> {
     let mut spec = ServiceSpec::default_for(ident);
     if let Some(group) = m.value_of("GROUP") {
@@ -424,6 +483,7 @@ fn spec_from_matches(ident: PackageIdent, m: &ArgMatches) -> Result<ServiceSpec>
         }
         spec.binds = binds;
     }
+    spec.ring = m.value_of("GOSSIP_RING").map(|r| r.to_string());
     if let Some(ref config_from) = m.value_of("CONFIG_DIR") {
         spec.config_from = Some(PathBuf::from(config_from));
         outputln!("");
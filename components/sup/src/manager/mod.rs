@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod rings;
 pub mod service;
 mod signals;
 mod service_updater;
@@ -45,6 +46,7 @@ use time::{SteadyTime, Duration as TimeDuration};
 use toml;
 
 pub use manager::service::{Service, ServiceConfig, ServiceSpec, UpdateStrategy, Topology};
+use self::rings::{Rings, DEFAULT_RING};
 use self::service::{DesiredState, StartStyle};
 use self::service_updater::ServiceUpdater;
 use self::spec_watcher::{SpecWatcher, SpecWatcherEvent};
@@ -81,7 +83,7 @@ pub struct FsCfg {
 }
 
 impl FsCfg {
-    fn new<T>(sup_svc_root: T) -> Self
+    pub fn new<T>(sup_svc_root: T) -> Self
         where T: Into<PathBuf>
     {
         let sup_svc_root = sup_svc_root.into();
@@ -96,6 +98,20 @@ impl FsCfg {
         }
     }
 
+    /// The directory the butterfly server for a named ring persists its rumors to.
+    fn ring_data_path(&self, ring: &str) -> PathBuf {
+        self.data_path.join("rings").join(ring)
+    }
+
+    /// Where the state of the butterfly server for `ring` is written for the `http_gateway`.
+    pub fn butterfly_data_path_for(&self, ring: &str) -> PathBuf {
+        if ring == DEFAULT_RING {
+            self.butterfly_data_path.clone()
+        } else {
+            self.data_path.join(format!("butterfly-{}.dat", ring))
+        }
+    }
+
     pub fn health_check_cache(&self, service_group: &ServiceGroup) -> PathBuf {
         self.data_path
             .join(format!("{}.health", service_group.service()))
@@ -109,13 +125,25 @@ pub struct ManagerConfig {
     pub gossip_peers: Vec<SocketAddr>,
     pub gossip_permanent: bool,
     pub ring: Option<String>,
+    pub rings: Vec<RingConfig>,
     pub name: Option<String>,
     custom_state_path: Option<PathBuf>,
     pub organization: Option<String>,
 }
 
+/// A named gossip ring to join in addition to the default ring.
+#[derive(Debug)]
+pub struct RingConfig {
+    pub name: String,
+    pub gossip_listen: GossipListenAddr,
+    pub gossip_peers: Vec<SocketAddr>,
+    /// The ring key, with revision, if the ring is encrypted.
+    pub ring_key: Option<String>,
+}
+
 pub struct Manager {
-    butterfly: butterfly::Server,
+    rings: Rings,
+    ring_gossip_listen: HashMap<String, GossipListenAddr>,
     census_ring: CensusRing,
     fs_cfg: Arc<FsCfg>,
     services: Arc<RwLock<Vec<Service>>>,
@@ -158,45 +186,94 @@ impl Manager {
 
     fn new(cfg: ManagerConfig, mut member: Member, fs_cfg: FsCfg) -> Result<Manager> {
         member.set_persistent(cfg.gossip_permanent);
-        member.set_swim_port(cfg.gossip_listen.port() as i32);
-        member.set_gossip_port(cfg.gossip_listen.port() as i32);
 
-        let ring_key = match cfg.ring {
-            Some(ref ring_with_revision) => {
+        let services = Arc::new(RwLock::new(Vec::new()));
+        let ring_key = Self::load_ring_key(cfg.ring.as_ref())?;
+        let server = Self::new_butterfly(member.clone(),
+                                         &cfg.gossip_listen,
+                                         &cfg.gossip_peers,
+                                         ring_key,
+                                         None,
+                                         fs_cfg.data_path.clone(),
+                                         services.clone())?;
+        outputln!("Butterfly Member ID {}", server.member_id());
+        let mut rings = Rings::new(server);
+        let mut ring_gossip_listen = HashMap::new();
+        for ring in cfg.rings {
+            if rings.contains(Some(ring.name.as_str())) {
+                return Err(sup_error!(Error::InvalidRing(ring.name)));
+            }
+            outputln!("Joining additional ring {} on {}",
+                      ring.name,
+                      ring.gossip_listen);
+            let ring_key = Self::load_ring_key(ring.ring_key.as_ref())?;
+            let server = Self::new_butterfly(member.clone(),
+                                             &ring.gossip_listen,
+                                             &ring.gossip_peers,
+                                             ring_key,
+                                             Some(ring.name.clone()),
+                                             fs_cfg.ring_data_path(&ring.name),
+                                             services.clone())?;
+            rings.insert(ring.name.clone(), server);
+            ring_gossip_listen.insert(ring.name, ring.gossip_listen);
+        }
+        Ok(Manager {
+               updater: ServiceUpdater::new(rings.clone()),
+               census_ring: CensusRing::new(rings.default().member_id()),
+               rings: rings,
+               ring_gossip_listen: ring_gossip_listen,
+               services: services,
+               watcher: SpecWatcher::run(&fs_cfg.specs_path)?,
+               fs_cfg: Arc::new(fs_cfg),
+               gossip_listen: cfg.gossip_listen,
+               http_listen: cfg.http_listen,
+               organization: cfg.organization,
+           })
+    }
+
+    fn load_ring_key(ring_with_revision: Option<&String>) -> Result<Option<SymKey>> {
+        match ring_with_revision {
+            Some(ring_with_revision) => {
                 outputln!("Joining ring {}", ring_with_revision);
-                Some(SymKey::get_pair_for(&ring_with_revision, &default_cache_key_path(None))?)
+                Ok(Some(SymKey::get_pair_for(&ring_with_revision, &default_cache_key_path(None))?))
             }
-            None => None,
-        };
+            None => Ok(None),
+        }
+    }
 
-        let services = Arc::new(RwLock::new(Vec::new()));
-        let server = butterfly::Server::new(&cfg.gossip_listen,
-                                            &cfg.gossip_listen,
+    /// Create the butterfly server for a single ring. Every ring shares the same member ID, so
+    /// that the census can recognise this Supervisor in all of them.
+    fn new_butterfly(mut member: Member,
+                     gossip_listen: &GossipListenAddr,
+                     gossip_peers: &[SocketAddr],
+                     ring_key: Option<SymKey>,
+                     name: Option<String>,
+                     data_path: PathBuf,
+                     services: Arc<RwLock<Vec<Service>>>)
+                     -> Result<butterfly::Server> {
+        member.set_swim_port(gossip_listen.port() as i32);
+        member.set_gossip_port(gossip_listen.port() as i32);
+        let server = butterfly::Server::new(gossip_listen,
+                                            gossip_listen,
                                             member,
                                             Trace::default(),
                                             ring_key,
-                                            None,
-                                            Some(&fs_cfg.data_path),
-                                            Box::new(SuitabilityLookup(services.clone())))?;
-        outputln!("Butterfly Member ID {}", server.member_id());
-        for peer_addr in &cfg.gossip_peers {
+                                            name,
+                                            Some(data_path),
+                                            Box::new(SuitabilityLookup(services)))?;
+        server
+            .trace
+            .write()
+            .expect("Trace lock is poisoned")
+            .set_observer(Box::new(http_gateway::TraceMetrics));
+        for peer_addr in gossip_peers {
             let mut peer = Member::default();
             peer.set_address(format!("{}", peer_addr.ip()));
             peer.set_swim_port(peer_addr.port() as i32);
             peer.set_gossip_port(peer_addr.port() as i32);
             server.member_list.add_initial_member(peer);
         }
-        Ok(Manager {
-               updater: ServiceUpdater::new(server.clone()),
-               census_ring: CensusRing::new(server.member_id()),
-               butterfly: server,
-               services: services,
-               watcher: SpecWatcher::run(&fs_cfg.specs_path)?,
-               fs_cfg: Arc::new(fs_cfg),
-               gossip_listen: cfg.gossip_listen,
-               http_listen: cfg.http_listen,
-               organization: cfg.organization,
-           })
+        Ok(server)
     }
 
     fn load_member<T>(state_path: T) -> Result<Member>
@@ -308,9 +385,27 @@ impl Manager {
         // back to us. Since we consume and deconstruct the spec in `Service::new()` which
         // `Service::load()` eventually delegates to we just can't have that. We should clean
         // this up in the future.
-        let service = match Service::load(self.butterfly.member_id(),
+        let (butterfly, gossip_listen) = match spec.ring {
+            None => (self.rings.default(), &self.gossip_listen),
+            Some(ref ring) => {
+                match (self.rings.get(Some(ring.as_str())), self.ring_gossip_listen.get(ring)) {
+                    (Some(butterfly), Some(gossip_listen)) => (butterfly, gossip_listen),
+                    (Some(butterfly), None) => (butterfly, &self.gossip_listen),
+                    (None, _) => {
+                        outputln!("Unable to start {}, {}",
+                                  &spec.ident,
+                                  sup_error!(Error::UnknownRing(ring.clone())));
+                        if spec.start_style == StartStyle::Transient {
+                            self.remove_spec(&spec);
+                        }
+                        return;
+                    }
+                }
+            }
+        };
+        let service = match Service::load(butterfly.member_id(),
                                           spec.clone(),
-                                          &self.gossip_listen,
+                                          gossip_listen,
                                           &self.http_listen,
                                           self.fs_cfg.clone(),
                                           self.organization.as_ref().map(|org| &**org)) {
@@ -323,10 +418,9 @@ impl Manager {
                 return;
             }
         };
-        self.butterfly.insert_service(service.to_rumor(1));
+        butterfly.insert_service(service.to_rumor(1));
         if service.topology == Topology::Leader {
-            self.butterfly
-                .start_election(service.service_group.clone(), 0);
+            butterfly.start_election(service.service_group.clone(), 0);
         }
         self.updater.add(&service);
         self.services
@@ -354,16 +448,24 @@ impl Manager {
         signals::init();
         self.start_initial_services_from_watcher()?;
 
-        outputln!("Starting butterfly on {}", self.butterfly.gossip_addr());
-        try!(self.butterfly.start(Timing::default()));
-        debug!("butterfly server started");
+        for butterfly in self.rings.all_mut() {
+            outputln!("Starting butterfly on {}", butterfly.gossip_addr());
+            try!(butterfly.start(Timing::default()));
+        }
+        debug!("butterfly servers started");
         self.persist_state();
         outputln!("Starting http-gateway on {}", self.http_listen);
-        try!(http_gateway::Server::new(self.fs_cfg.clone(), self.http_listen.clone()).start());
+        let traces = self.rings
+            .all()
+            .into_iter()
+            .map(|(name, server)| (String::from(name), server.trace.clone()))
+            .collect();
+        try!(http_gateway::Server::new(self.fs_cfg.clone(), traces, self.http_listen.clone())
+                     .start());
         debug!("http-gateway server started");
 
         let (event_tx, event_rx) = channel::<Vec<CensusEntryProto>>();
-        let member_id = String::from(self.butterfly.member_id());
+        let member_id = String::from(self.rings.default().member_id());
 
         thread::Builder::new()
             .name("sup-eventsrv".to_string())
@@ -403,7 +505,7 @@ impl Manager {
             })
             .expect("unable to start sup-eventsrv thread");
 
-        let mut service_rumor_offset = 0;
+        let mut service_rumor_offsets = HashMap::new();
 
         loop {
             let next_check = SteadyTime::now() + TimeDuration::milliseconds(1000);
@@ -412,17 +514,21 @@ impl Manager {
                 return Ok(());
             }
             self.update_running_services_from_watcher()?;
-            service_rumor_offset += self.check_for_updated_packages();
+            self.check_for_updated_packages(&mut service_rumor_offsets);
             self.restart_elections();
-            self.census_ring
-                .update_from_rumors(service_rumor_offset,
-                                    &self.butterfly.service_store,
-                                    &self.butterfly.election_store,
-                                    &self.butterfly.update_store,
-                                    &self.butterfly.member_list,
-                                    &self.butterfly.service_config_store,
-                                    &self.butterfly.service_file_store);
-            service_rumor_offset = 0;
+            self.census_ring.changed = false;
+            for (ring, butterfly) in self.rings.all() {
+                self.census_ring
+                    .update_from_ring_rumors(ring,
+                                             service_rumor_offsets.remove(ring).unwrap_or(0),
+                                             &butterfly.service_store,
+                                             &butterfly.election_store,
+                                             &butterfly.update_store,
+                                             &butterfly.member_list,
+                                             &butterfly.service_config_store,
+                                             &butterfly.service_file_store,
+                                             &butterfly.chunk_store);
+            }
 
             if self.census_ring.changed {
                 self.persist_state();
@@ -453,8 +559,8 @@ impl Manager {
                     .expect("Services lock is poisoned!")
                     .iter_mut() {
                 if service.tick(&self.census_ring) {
-                    self.gossip_latest_service_rumor(&service);
-                    service_rumor_offset += 1;
+                    let ring = self.gossip_latest_service_rumor(&service);
+                    *service_rumor_offsets.entry(ring).or_insert(0) += 1;
                 }
             }
             let time_to_wait = (next_check - SteadyTime::now()).num_milliseconds();
@@ -508,10 +614,10 @@ impl Manager {
     /// This updates the Service to point to the new service struct, and then marks it for
     /// restarting.
     ///
-    /// The run loop's last updated census is a required parameter on this function to inform the
-    /// main loop that we, ourselves, updated the service counter when we updated ourselves.
-    fn check_for_updated_packages(&mut self) -> usize {
-        let mut updated_services = 0;
+    /// The run loop's per-ring service rumor offsets are a required parameter on this function to
+    /// inform the main loop that we, ourselves, updated the service counter when we updated
+    /// ourselves.
+    fn check_for_updated_packages(&mut self, service_rumor_offsets: &mut HashMap<String, usize>) {
         for service in self.services
                 .write()
                 .expect("Services lock is poisoned!")
@@ -519,19 +625,24 @@ impl Manager {
             if self.updater
                    .check_for_updated_package(service, &self.census_ring) {
                 service.populate(&self.census_ring);
-                self.gossip_latest_service_rumor(&service);
-                updated_services += 1;
+                let ring = self.gossip_latest_service_rumor(&service);
+                *service_rumor_offsets.entry(ring).or_insert(0) += 1;
             }
         }
-        updated_services
     }
 
-    fn gossip_latest_service_rumor(&self, service: &Service) {
+    /// Gossip a new service rumor for `service` into its ring, returning the name of the ring.
+    fn gossip_latest_service_rumor(&self, service: &Service) -> String {
+        let ring = service
+            .ring
+            .clone()
+            .unwrap_or(String::from(DEFAULT_RING));
+        let butterfly = self.rings.get_or_default(Some(ring.as_str()));
         let member_id = {
-            self.butterfly.member_id().to_string()
+            butterfly.member_id().to_string()
         };
         let last_rumor = {
-            let list = self.butterfly
+            let list = butterfly
                 .service_store
                 .list
                 .read()
@@ -542,8 +653,8 @@ impl Manager {
                 .clone()
         };
         let incarnation = last_rumor.get_incarnation() + 1;
-        self.butterfly
-            .insert_service(service.to_rumor(incarnation));
+        butterfly.insert_service(service.to_rumor(incarnation));
+        ring
     }
 
     fn persist_state(&self) {
@@ -581,29 +692,28 @@ impl Manager {
     }
 
     fn persist_butterfly_state(&self) {
-        let tmp_file = self.fs_cfg
-            .butterfly_data_path
-            .with_extension("dat.tmp");
-        let file = match File::create(&tmp_file) {
-            Ok(file) => file,
-            Err(err) => {
-                warn!("Couldn't open temporary butterfly state file, {}", err);
-                return;
+        for (ring, butterfly) in self.rings.all() {
+            let data_path = self.fs_cfg.butterfly_data_path_for(ring);
+            let tmp_file = data_path.with_extension("dat.tmp");
+            let file = match File::create(&tmp_file) {
+                Ok(file) => file,
+                Err(err) => {
+                    warn!("Couldn't open temporary butterfly state file, {}", err);
+                    continue;
+                }
+            };
+            let mut writer = BufWriter::new(file);
+            if let Some(err) = writer
+                   .write(serde_json::to_string(butterfly).unwrap().as_bytes())
+                   .err() {
+                warn!("Couldn't write to butterfly state file, {}", err);
+            }
+            if let Some(err) = writer.flush().err() {
+                warn!("Couldn't flush butterfly state buffer to disk, {}", err);
+            }
+            if let Some(err) = fs::rename(&tmp_file, &data_path).err() {
+                warn!("Couldn't finalize butterfly state on disk, {}", err);
             }
-        };
-        let mut writer = BufWriter::new(file);
-        if let Some(err) = writer
-               .write(serde_json::to_string(&self.butterfly)
-                          .unwrap()
-                          .as_bytes())
-               .err() {
-            warn!("Couldn't write to butterfly state file, {}", err);
-        }
-        if let Some(err) = writer.flush().err() {
-            warn!("Couldn't flush butterfly state buffer to disk, {}", err);
-        }
-        if let Some(err) = fs::rename(&tmp_file, &self.fs_cfg.butterfly_data_path).err() {
-            warn!("Couldn't finalize butterfly state on disk, {}", err);
         }
     }
 
@@ -633,7 +743,9 @@ impl Manager {
 
     /// Check if any elections need restarting.
     fn restart_elections(&mut self) {
-        self.butterfly.restart_elections();
+        for (_, butterfly) in self.rings.all() {
+            butterfly.restart_elections();
+        }
     }
 
     fn shutdown(&self) {
@@ -0,0 +1,116 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The gossip rings a Supervisor is a member of.
+//!
+//! Every Supervisor is a member of the default ring, configured with `--listen-gossip`, `--peer`
+//! and `--ring`. It may additionally join any number of named rings, each with its own
+//! `butterfly::Server`, listen address, peers and ring key. A service is placed in exactly one
+//! ring by the `ring` field of its spec; its service rumors, elections and configuration are
+//! gossiped only there. The census is built from every ring, so a service may bind to a service
+//! group that lives in a different ring than its own.
+
+use std::collections::HashMap;
+
+use butterfly;
+
+/// The name of the ring every Supervisor is a member of.
+pub const DEFAULT_RING: &'static str = "default";
+
+/// Whether `name` may name a ring. Ring names end up in file names under the Supervisor's data
+/// directory and in HTTP gateway paths, so only ASCII letters, digits, `-` and `_` are allowed.
+pub fn valid_name(name: &str) -> bool {
+    !name.is_empty() &&
+    name.chars().all(|c| match c {
+                         'a'...'z' | 'A'...'Z' | '0'...'9' | '-' | '_' => true,
+                         _ => false,
+                     })
+}
+
+#[derive(Clone, Debug)]
+pub struct Rings {
+    default: butterfly::Server,
+    named: HashMap<String, butterfly::Server>,
+}
+
+impl Rings {
+    pub fn new(default: butterfly::Server) -> Self {
+        Rings {
+            default: default,
+            named: HashMap::new(),
+        }
+    }
+
+    /// Add a named ring. The name must not already be in use.
+    pub fn insert<T: Into<String>>(&mut self, name: T, server: butterfly::Server) {
+        self.named.insert(name.into(), server);
+    }
+
+    /// The server for the default ring.
+    pub fn default(&self) -> &butterfly::Server {
+        &self.default
+    }
+
+    /// The server for the ring called `name`, where `None` means the default ring.
+    pub fn get(&self, name: Option<&str>) -> Option<&butterfly::Server> {
+        match name {
+            None => Some(&self.default),
+            Some(DEFAULT_RING) => Some(&self.default),
+            Some(name) => self.named.get(name),
+        }
+    }
+
+    /// The server for the ring called `name`, falling back to the default ring if there is no
+    /// such ring. The Manager refuses to start services in rings it is not a member of, so this
+    /// only falls back for services that were never started.
+    pub fn get_or_default(&self, name: Option<&str>) -> &butterfly::Server {
+        self.get(name).unwrap_or(&self.default)
+    }
+
+    pub fn contains(&self, name: Option<&str>) -> bool {
+        self.get(name).is_some()
+    }
+
+    /// Every ring, as `(name, server)` pairs, starting with the default ring.
+    pub fn all(&self) -> Vec<(&str, &butterfly::Server)> {
+        let mut rings = vec![(DEFAULT_RING, &self.default)];
+        let mut names: Vec<&String> = self.named.keys().collect();
+        names.sort();
+        for name in names {
+            rings.push((name.as_str(), &self.named[name]));
+        }
+        rings
+    }
+
+    /// Mutable access to every server, for starting them.
+    pub fn all_mut(&mut self) -> Vec<&mut butterfly::Server> {
+        let mut rings = vec![&mut self.default];
+        rings.extend(self.named.values_mut());
+        rings
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::valid_name;
+
+    #[test]
+    fn ring_names_are_path_safe() {
+        assert!(valid_name("east-1_b"));
+        assert!(!valid_name(""));
+        assert!(!valid_name("../default"));
+        assert!(!valid_name("a/b"));
+        assert!(!valid_name("ring.dat"));
+    }
+}
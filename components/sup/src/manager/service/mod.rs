@@ -74,6 +74,8 @@ pub struct Service {
     pub start_style: StartStyle,
     pub topology: Topology,
     pub update_strategy: UpdateStrategy,
+    /// The gossip ring this service is a member of; `None` for the default ring.
+    pub ring: Option<String>,
 
     local_member_id: MemberId,
     health_check: HealthCheck,
@@ -143,6 +145,7 @@ impl Service {
                start_style: spec.start_style,
                topology: spec.topology,
                update_strategy: spec.update_strategy,
+               ring: spec.ring,
                config_from: spec.config_from,
                last_health_check: Instant::now() - *HEALTH_CHECK_INTERVAL,
            })
@@ -358,6 +361,7 @@ impl Service {
         spec.binds = self.spec_binds.clone();
         spec.start_style = self.start_style;
         spec.config_from = self.config_from.clone();
+        spec.ring = self.ring.clone();
         spec
     }
 
@@ -82,6 +82,7 @@ pub struct ServiceSpec {
     pub update_strategy: UpdateStrategy,
     pub binds: Vec<ServiceBind>,
     pub config_from: Option<PathBuf>,
+    pub ring: Option<String>,
     #[serde(
         deserialize_with = "deserialize_using_from_str",
         serialize_with = "serialize_using_to_string"
@@ -190,6 +191,7 @@ impl Default for ServiceSpec {
             update_strategy: UpdateStrategy::default(),
             binds: vec![],
             config_from: None,
+            ring: None,
             desired_state: DesiredState::default(),
             start_style: StartStyle::default(),
         }
@@ -333,6 +335,7 @@ mod test {
             binds = ["cache:redis.cache@acmecorp", "db:postgres.app@acmecorp"]
             start_style = "persistent"
             config_from = "/only/for/development"
+            ring = "tenant"
 
             extra_stuff = "should be ignored"
             "#;
@@ -349,6 +352,7 @@ mod test {
                         ServiceBind::from_str("db:postgres.app@acmecorp").unwrap()]);
         assert_eq!(spec.config_from,
                    Some(PathBuf::from("/only/for/development")));
+        assert_eq!(spec.ring, Some(String::from("tenant")));
         assert_eq!(spec.start_style, StartStyle::Persistent);
     }
 
@@ -415,6 +419,7 @@ mod test {
             binds: vec![ServiceBind::from_str("cache:redis.cache@acmecorp").unwrap(),
                         ServiceBind::from_str("db:postgres.app@acmecorp").unwrap()],
             config_from: Some(PathBuf::from("/only/for/development")),
+            ring: Some(String::from("tenant")),
             desired_state: DesiredState::Down,
             start_style: StartStyle::Persistent,
         };
@@ -430,6 +435,7 @@ mod test {
         assert!(toml.contains(r#"desired_state = "down""#));
         assert!(toml.contains(r#"start_style = "persistent""#));
         assert!(toml.contains(r#"config_from = "/only/for/development""#));
+        assert!(toml.contains(r#"ring = "tenant""#));
     }
 
     #[test]
@@ -543,6 +549,7 @@ mod test {
             binds: vec![ServiceBind::from_str("cache:redis.cache@acmecorp").unwrap(),
                         ServiceBind::from_str("db:postgres.app@acmecorp").unwrap()],
             config_from: Some(PathBuf::from("/only/for/development")),
+            ring: Some(String::from("tenant")),
             desired_state: DesiredState::Down,
             start_style: StartStyle::Persistent,
         };
@@ -559,6 +566,7 @@ mod test {
         assert!(toml.contains(r#"desired_state = "down""#));
         assert!(toml.contains(r#"start_style = "persistent""#));
         assert!(toml.contains(r#"config_from = "/only/for/development""#));
+        assert!(toml.contains(r#"ring = "tenant""#));
     }
 
     #[test]
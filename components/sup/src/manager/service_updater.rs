@@ -18,18 +18,18 @@ use std::sync::mpsc::{sync_channel, Receiver, SyncSender, TryRecvError};
 use std::thread;
 use std::time::Duration;
 
-use butterfly;
 use common::ui::UI;
 use depot_client;
 use hcore::package::{PackageIdent, PackageInstall};
 use hcore::service::ServiceGroup;
-use hcore::crypto::default_cache_key_path;
+use hcore::crypto::{artifact, default_cache_key_path};
 use hcore::fs::{CACHE_ARTIFACT_PATH, FS_ROOT_PATH};
 use time::{SteadyTime, Duration as TimeDuration};
 
 use {PRODUCT, VERSION};
 use error::Result;
 use census::CensusRing;
+use manager::rings::Rings;
 use manager::service::{Service, Topology, UpdateStrategy};
 
 static LOGKEY: &'static str = "SU";
@@ -61,14 +61,14 @@ enum FollowerState {
 
 pub struct ServiceUpdater {
     states: UpdaterStateList,
-    butterfly: butterfly::Server,
+    rings: Rings,
 }
 
 impl ServiceUpdater {
-    pub fn new(butterfly: butterfly::Server) -> Self {
+    pub fn new(rings: Rings) -> Self {
         ServiceUpdater {
             states: UpdaterStateList::default(),
-            butterfly: butterfly,
+            rings: rings,
         }
     }
 
@@ -125,7 +125,8 @@ impl ServiceUpdater {
                                 } else {
                                     u64::max_value()
                                 };
-                                self.butterfly
+                                self.rings
+                                    .get_or_default(service.ring.as_ref().map(|r| &**r))
                                     .start_update_election(service.service_group.clone(),
                                                            suitability,
                                                            0);
@@ -135,7 +136,8 @@ impl ServiceUpdater {
                         }
                     } else {
                         debug!("Rolling update, using default suitability");
-                        self.butterfly
+                        self.rings
+                            .get_or_default(service.ring.as_ref().map(|r| &**r))
                             .start_update_election(service.service_group.clone(), 0, 0);
                         *st = RollingState::InElection;
                     }
@@ -376,9 +378,10 @@ impl Worker {
                                                   &Path::new(&*FS_ROOT_PATH)
                                                        .join(CACHE_ARTIFACT_PATH),
                                                   self.ui.progress()));
-        try!(archive.verify(&default_cache_key_path(None)));
         outputln!("Installing {}", package);
-        try!(archive.unpack(None));
+        try!(archive.unpack_verified(None,
+                                     &default_cache_key_path(None),
+                                     &artifact::required_signers()));
         let pkg = PackageInstall::load(archive.ident().as_ref().unwrap(), Some(&*FS_ROOT_PATH))?;
         Ok(pkg)
     }
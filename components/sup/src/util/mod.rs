@@ -19,7 +19,7 @@ pub mod sys;
 pub mod users;
 
 use std::ffi::OsStr;
-use std::net::{Ipv4Addr, SocketAddrV4};
+use std::net::{IpAddr, SocketAddr};
 use std::str::FromStr;
 use std::process::{Command, Stdio};
 
@@ -43,6 +43,9 @@ pub fn stop_time(duration: i64) -> time::Timespec {
 /// - If you haven't sent a string, then we'll return (default_ip, default_port).
 /// - If you sent Some(some_ip_without_port), we'll return (your IP, default_port).
 /// - If you sent Some(some_ip_with_port), we'll just hand that back (your IP, your port).
+///
+/// IPv6 addresses may be given bare or in brackets, and must be in brackets when followed by a
+/// port.
 pub fn parse_ip_port_with_defaults(s: Option<&str>,
                                    default_ip: &str,
                                    default_port: u16)
@@ -55,14 +58,14 @@ pub fn parse_ip_port_with_defaults(s: Option<&str>,
     let s = s.unwrap();
 
     // is it an IP + Port combo?
-    match SocketAddrV4::from_str(s) {
+    match SocketAddr::from_str(s) {
         Ok(sa) => return Ok((sa.ip().to_string(), sa.port())),
         Err(_) => (),
     }
 
     // is it just an IP? if so, then append the default gossip port
-    if Ipv4Addr::from_str(s).is_ok() {
-        return Ok((s.to_string(), default_port));
+    if let Ok(ip) = IpAddr::from_str(s.trim_left_matches('[').trim_right_matches(']')) {
+        return Ok((ip.to_string(), default_port));
     }
 
     return Err(sup_error!(Error::IPFailed));
@@ -124,6 +127,12 @@ mod tests {
         assert!(("192.168.1.99".to_string(), 5678) ==
                 parse_ip_port_with_defaults(Some("192.168.1.99:5678"), default_ip, default_port)
                     .unwrap());
+        // pass in IPv6 addresses, with and without a port
+        assert!(("fd00::1".to_string(), 1234) ==
+                parse_ip_port_with_defaults(Some("fd00::1"), default_ip, default_port).unwrap());
+        assert!(("fd00::1".to_string(), 5678) ==
+                parse_ip_port_with_defaults(Some("[fd00::1]:5678"), default_ip, default_port)
+                    .unwrap());
         // pass in something unparseable
         assert!(parse_ip_port_with_defaults(Some("foo"), default_ip, default_port).is_err());
     }